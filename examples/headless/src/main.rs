@@ -152,6 +152,13 @@ async fn render(mut scenes: SceneSet, index: usize, args: &Args) -> Result<()> {
         width,
         height,
         antialiasing_method: vello::AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: vello::DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: vello::ClearMode::Clear,
     };
     let mut scene = Scene::new();
     scene.append(&fragment, Some(transform));
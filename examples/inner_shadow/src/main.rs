@@ -22,11 +22,13 @@
 
 use anyhow::Result;
 use std::sync::Arc;
-use vello::kurbo::{Affine, Rect, RoundedRect, Stroke, Vec2};
+use std::time::{Duration, Instant};
+use vello::debug::{FrameStats, draw_stats_overlay};
+use vello::kurbo::{Affine, Point, Rect, RoundedRect, Stroke, Vec2};
 use vello::peniko::{BlendMode, Color, Compose, Fill, Mix};
-use vello::util::{RenderContext, RenderSurface};
+use vello::util::{FramePacer, RenderContext, RenderSurface, draw_transparency_grid};
 use vello::wgpu;
-use vello::{AaConfig, Renderer, RendererOptions, Scene};
+use vello::{ClearMode, DebugView, Renderer, RendererOptions, Scene};
 use winit::application::ApplicationHandler;
 use winit::dpi::LogicalSize;
 use winit::event::{ElementState, WindowEvent};
@@ -86,6 +88,16 @@ struct InsetShadowApp {
     scene: Scene,
     params: InsetBoxShadowParams,
     modifiers: ModifiersState,
+    /// 上一帧绘制阴影时发生的参数修正,用于在标题里提示.
+    shadow_info: ShadowDrawInfo,
+    /// 是否在左上角显示帧统计(H 键切换).
+    show_stats: bool,
+    /// 是否用透明棋盘格代替深灰背景(G 键切换),方便观察阴影的 alpha.
+    show_grid: bool,
+    /// 上一帧从构建 Scene 到 present 的耗时.
+    frame_time: Duration,
+    /// 根据 GPU 耗时自动调整抗锯齿方式,模糊开销大时从 MSAA 降级以维持 60fps.
+    pacer: FramePacer,
 }
 
 impl ApplicationHandler for InsetShadowApp {
@@ -117,7 +129,7 @@ impl ApplicationHandler for InsetShadowApp {
             .get_or_insert_with(|| create_vello_renderer(&self.context, &surface));
 
         // 4) 进入 Active 状态.
-        update_window_title(&window, &self.params);
+        update_window_title(&window, &self.params, self.shadow_info);
         window.request_redraw();
         self.state = RenderState::Active {
             surface: Box::new(surface),
@@ -165,6 +177,8 @@ impl ApplicationHandler for InsetShadowApp {
                 // - A/S:    opacity
                 // - Q/W:    border-radius
                 // - R:      reset
+                // - H:      显示/隐藏帧统计
+                // - G:      切换透明棋盘格背景
                 // - Esc:    exit
                 //
                 // Shift: 加速步进.
@@ -206,7 +220,10 @@ impl ApplicationHandler for InsetShadowApp {
                                 changed = true;
                             }
                             "x" => {
-                                self.params.blur_radius += step_blur;
+                                // vello 会把 std_dev 钳制到 `Scene::MAX_BLUR_STD_DEV`,
+                                // 再大的 blur-radius 只会让 padding 无限膨胀.
+                                self.params.blur_radius =
+                                    (self.params.blur_radius + step_blur).min(MAX_BLUR_RADIUS_PX);
                                 changed = true;
                             }
                             "c" => {
@@ -240,6 +257,14 @@ impl ApplicationHandler for InsetShadowApp {
                                 self.params = InsetBoxShadowParams::default();
                                 changed = true;
                             }
+                            "h" => {
+                                self.show_stats = !self.show_stats;
+                                changed = true;
+                            }
+                            "g" => {
+                                self.show_grid = !self.show_grid;
+                                changed = true;
+                            }
                             _ => {}
                         }
                     }
@@ -247,7 +272,7 @@ impl ApplicationHandler for InsetShadowApp {
                 }
 
                 if changed {
-                    update_window_title(window, &self.params);
+                    update_window_title(window, &self.params, self.shadow_info);
                     window.request_redraw();
                 }
             }
@@ -268,23 +293,59 @@ impl ApplicationHandler for InsetShadowApp {
                     return;
                 }
 
+                let frame_start = Instant::now();
+
                 // 每帧重建 Scene.
                 self.scene.reset();
-                build_scene_inset_box_shadow(
+                // 跳过完全位于窗口之外的绘制(例如偏移量很大时移出视口的面板).
+                self.scene.set_cull_rect(Some(Rect::new(
+                    0.0,
+                    0.0,
+                    f64::from(surface.config.width),
+                    f64::from(surface.config.height),
+                )));
+                if self.show_grid {
+                    draw_transparency_grid(
+                        &mut self.scene,
+                        Rect::new(
+                            0.0,
+                            0.0,
+                            f64::from(surface.config.width),
+                            f64::from(surface.config.height),
+                        ),
+                        12.0,
+                        Color::WHITE,
+                        Color::from_rgb8(0xcc, 0xcc, 0xcc),
+                    );
+                }
+                let shadow_info = build_scene_inset_box_shadow(
                     &mut self.scene,
                     surface.config.width,
                     surface.config.height,
                     &self.params,
                 );
+                if shadow_info != self.shadow_info {
+                    self.shadow_info = shadow_info;
+                    update_window_title(window, &self.params, shadow_info);
+                }
+                if self.show_stats {
+                    // 统计在画 overlay 之前收集,这样 overlay 自身不计入.
+                    let stats = FrameStats::new(self.frame_time, &self.scene);
+                    draw_stats_overlay(&mut self.scene, &stats, Point::new(8.0, 8.0));
+                }
 
                 // 渲染到中间纹理,再 blit 到 surface.
                 let width = surface.config.width;
                 let height = surface.config.height;
                 let device_handle = &self.context.devices[surface.dev_id];
+                let renderer = self.renderers[surface.dev_id].as_mut().unwrap();
 
-                self.renderers[surface.dev_id]
-                    .as_mut()
-                    .unwrap()
+                // 设备不支持 timestamp query 时没有 GPU 耗时,始终使用 MSAA16.
+                if let Some(gpu_time) = renderer.last_frame_gpu_time() {
+                    self.pacer.update(gpu_time);
+                }
+
+                renderer
                     .render_to_texture(
                         &device_handle.device,
                         &device_handle.queue,
@@ -295,7 +356,14 @@ impl ApplicationHandler for InsetShadowApp {
                             base_color: Color::new([0.12, 0.12, 0.12, 1.0]),
                             width,
                             height,
-                            antialiasing_method: AaConfig::Msaa16,
+                            antialiasing_method: self.pacer.antialiasing_method(),
+                            text_gamma: 1.0,
+                            alpha_to_coverage: false,
+                            debug: DebugView::None,
+                            premultiplied_alpha: false,
+                            flatten_tolerance: None,
+                            viewport: None,
+                            clear_mode: ClearMode::Clear,
                         },
                     )
                     .expect("渲染到纹理失败");
@@ -314,13 +382,14 @@ impl ApplicationHandler for InsetShadowApp {
                 surface.blitter.copy(
                     &device_handle.device,
                     &mut encoder,
-                    &surface.target_view,
+                    &surface.blit_view,
                     &surface_texture
                         .texture
                         .create_view(&wgpu::TextureViewDescriptor::default()),
                 );
                 device_handle.queue.submit([encoder.finish()]);
                 surface_texture.present();
+                self.frame_time = frame_start.elapsed();
 
                 device_handle.device.poll(wgpu::PollType::Poll).unwrap();
             }
@@ -338,6 +407,16 @@ fn main() -> Result<()> {
         scene: Scene::new(),
         params: InsetBoxShadowParams::default(),
         modifiers: ModifiersState::default(),
+        shadow_info: ShadowDrawInfo::default(),
+        show_stats: false,
+        show_grid: false,
+        frame_time: Duration::ZERO,
+        // 只调整抗锯齿方式,不降低分辨率.
+        pacer: FramePacer::new(
+            Duration::from_micros(16_667),
+            RendererOptions::default().antialiasing_support,
+        )
+        .with_min_render_scale(1.0),
     };
 
     let event_loop = EventLoop::new()?;
@@ -360,12 +439,16 @@ fn create_winit_window(event_loop: &ActiveEventLoop) -> Arc<Window> {
 fn create_vello_renderer(render_cx: &RenderContext, surface: &RenderSurface<'_>) -> Renderer {
     Renderer::new(
         &render_cx.devices[surface.dev_id].device,
-        RendererOptions::default(),
+        RendererOptions {
+            // 调试构建中检查嵌套图层是否配对，而不是渲染出错误的结果。
+            debug_validation: cfg!(debug_assertions),
+            ..Default::default()
+        },
     )
     .expect("创建 renderer 失败")
 }
 
-fn update_window_title(window: &Window, params: &InsetBoxShadowParams) {
+fn update_window_title(window: &Window, params: &InsetBoxShadowParams, info: ShadowDrawInfo) {
     // -------------------------------------------------------------
     // HUD 目标:
     // - 直接展示一条可复制的 CSS inset box-shadow 字符串.
@@ -380,8 +463,20 @@ fn update_window_title(window: &Window, params: &InsetBoxShadowParams) {
         params.opacity,
         params.corner_radius
     );
-    let title =
-        format!("Vello Inset Box-Shadow | {css} | Arrows/Z X/C V/A S/Q W/R (Shift=fast, Esc=quit)");
+    // 参数被修正时在标题里提示,避免"调了但没变化"的困惑.
+    let mut warnings = String::new();
+    if info.clamped_spread {
+        warnings.push_str(" | warning: spread clamped");
+    }
+    if info.degenerated_inner {
+        warnings.push_str(" | warning: inner rect degenerated");
+    }
+    if info.clamped_offset {
+        warnings.push_str(" | warning: offset clamped");
+    }
+    let title = format!(
+        "Vello Inset Box-Shadow | {css}{warnings} | Arrows/Z X/C V/A S/Q W/R (Shift=fast, Esc=quit)"
+    );
     window.set_title(&title);
 }
 
@@ -394,7 +489,7 @@ fn build_scene_inset_box_shadow(
     width: u32,
     height: u32,
     params: &InsetBoxShadowParams,
-) {
+) -> ShadowDrawInfo {
     // -------------------------------------------------------------
     // 两个样本:
     // 1) 自适应大面板(原示例).
@@ -410,7 +505,7 @@ fn build_scene_inset_box_shadow(
     let border_color = Color::new([0.35, 0.40, 0.48, 1.0]);
 
     // 1) 先画大面板.
-    draw_inset_shadow_sample(
+    let panel_info = draw_inset_shadow_sample(
         scene,
         panel_rect,
         panel_shape,
@@ -422,7 +517,7 @@ fn build_scene_inset_box_shadow(
     );
 
     // 2) 再画按钮 Md 样本(放在大面板上下方,尽量避免重叠).
-    draw_inset_shadow_sample(
+    let button_info = draw_inset_shadow_sample(
         scene,
         button_rect,
         button_shape,
@@ -432,6 +527,8 @@ fn build_scene_inset_box_shadow(
         border_color,
         params,
     );
+
+    panel_info.merge(button_info)
 }
 
 fn draw_inset_shadow_sample(
@@ -443,7 +540,7 @@ fn draw_inset_shadow_sample(
     face_color: Color,
     border_color: Color,
     params: &InsetBoxShadowParams,
-) {
+) -> ShadowDrawInfo {
     // 1) 画底色(按钮面).
     scene.fill(Fill::NonZero, Affine::IDENTITY, face_color, None, &shape);
 
@@ -457,7 +554,7 @@ fn draw_inset_shadow_sample(
     );
 
     // 3) inset box-shadow(内阴影).
-    let shadow_color = Color::new([0.0, 0.0, 0.0, params.opacity]);
+    let shadow_color = Color::BLACK.with_alpha(params.opacity);
     draw_inset_box_shadow_rounded_rect(
         scene,
         rect,
@@ -466,7 +563,7 @@ fn draw_inset_shadow_sample(
         Vec2::new(params.offset_x, params.offset_y),
         params.blur_radius,
         params.spread_radius,
-    );
+    )
 }
 
 fn compute_centered_rounded_rect(
@@ -553,6 +650,9 @@ fn compute_button_md_rounded_rect(
 // CSS 参数 -> vello 绘制参数的映射.
 // -----------------------------------------------------------------------------
 
+/// blur-radius 的上限,对应 vello 的 `Scene::MAX_BLUR_STD_DEV`.
+const MAX_BLUR_RADIUS_PX: f64 = 2.5 * Scene::MAX_BLUR_STD_DEV;
+
 fn css_blur_radius_to_std_dev(blur_radius_px: f64) -> f64 {
     // -----------------------------------------------------------------
     // CSS blur-radius 是一个"直觉像素尺度",而 vello 的 `std_dev` 是高斯 sigma.
@@ -564,6 +664,30 @@ fn css_blur_radius_to_std_dev(blur_radius_px: f64) -> f64 {
     (blur_radius_px.max(0.0)) / 2.5
 }
 
+/// 绘制 inset shadow 时对参数做过的修正.
+///
+/// 绘制函数会静默 clamp 无效参数,这里把发生过的修正报告给调用方,方便 UI 提示.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ShadowDrawInfo {
+    /// spread 超出了 `[-min_edge, 0.5 * min_edge]` 的范围,被 clamp 过.
+    clamped_spread: bool,
+    /// inner cutout 退化(宽或高不超过 1px),被折叠成了中心 1x1.
+    degenerated_inner: bool,
+    /// offset 大到 inner cutout 已经完全移出 rect,被 clamp 过.
+    clamped_offset: bool,
+}
+
+impl ShadowDrawInfo {
+    /// 合并多次绘制的结果: 任意一次发生修正即视为发生.
+    fn merge(self, other: Self) -> Self {
+        Self {
+            clamped_spread: self.clamped_spread || other.clamped_spread,
+            degenerated_inner: self.degenerated_inner || other.degenerated_inner,
+            clamped_offset: self.clamped_offset || other.clamped_offset,
+        }
+    }
+}
+
 fn draw_inset_box_shadow_rounded_rect(
     scene: &mut Scene,
     rect: Rect,
@@ -572,7 +696,8 @@ fn draw_inset_box_shadow_rounded_rect(
     offset: Vec2,
     blur_radius_px: f64,
     spread_radius_px: f64,
-) {
+) -> ShadowDrawInfo {
+    let mut info = ShadowDrawInfo::default();
     // -----------------------------------------------------------------
     // 组合公式(示例版):
     //
@@ -586,7 +711,7 @@ fn draw_inset_box_shadow_rounded_rect(
     // -----------------------------------------------------------------
     let min_edge = rect.width().min(rect.height());
     if min_edge <= 1.0 {
-        return;
+        return info;
     }
 
     // 1) 基础 clamp(避免 radius 失控).
@@ -602,7 +727,9 @@ fn draw_inset_box_shadow_rounded_rect(
     // - 这里做一个温和 clamp,避免 inner_rect 过度膨胀导致无意义的算力浪费.
     let max_spread_pos = 0.5 * min_edge;
     let max_spread_neg = min_edge;
-    let spread_radius_px = spread_radius_px.clamp(-max_spread_neg, max_spread_pos);
+    let clamped_spread_px = spread_radius_px.clamp(-max_spread_neg, max_spread_pos);
+    info.clamped_spread = clamped_spread_px != spread_radius_px;
+    let spread_radius_px = clamped_spread_px;
 
     // 4) inner cutout 的 inset(决定"阴影吃进去多深").
     //
@@ -616,6 +743,34 @@ fn draw_inset_box_shadow_rounded_rect(
     let inner_inset_px = spread_radius_px;
 
     let base_shape = RoundedRect::from_rect(rect, radius);
+
+    // 5) inner cutout(用来扣掉中心区域).
+    let mut inner_rect = rect.inflate(-inner_inset_px, -inner_inset_px);
+    if inner_rect.width() <= 1.0 || inner_rect.height() <= 1.0 {
+        // 退化时折叠成中心 1x1,避免传入无效宽高.
+        info.degenerated_inner = true;
+        let c = rect.center();
+        inner_rect = Rect::new(c.x - 0.5, c.y - 0.5, c.x + 0.5, c.y + 0.5);
+    }
+
+    // 6) offset clamp:
+    // - 当 offset 大到 inner cutout(连同 blur 过渡带)完全移出 rect 时,再增大 offset 不会改变结果:
+    //   整个 rect 都被阴影覆盖,也就是 CSS 里"一侧极重"的 inset shadow.
+    // - 不 clamp 的话 outer padding 会随 offset 无限膨胀,blur 的计算区域和圆角也跟着失控.
+    // - 因此每个轴上把 offset 限制在"cutout 刚好离开 rect"的距离.
+    let max_offset = Vec2::new(
+        0.5 * (rect.width() + inner_rect.width()) + blur_radius_px,
+        0.5 * (rect.height() + inner_rect.height()) + blur_radius_px,
+    );
+    // NaN 也视为被修正,按 0 处理.
+    let clamp_axis = |v: f64, max: f64| if v.is_nan() { 0.0 } else { v.clamp(-max, max) };
+    let clamped_offset = Vec2::new(
+        clamp_axis(offset.x, max_offset.x),
+        clamp_axis(offset.y, max_offset.y),
+    );
+    info.clamped_offset = clamped_offset != offset;
+    let offset = clamped_offset;
+
     let offset_rect = |r: Rect| {
         Rect::new(
             r.x0 + offset.x,
@@ -625,8 +780,9 @@ fn draw_inset_box_shadow_rounded_rect(
         )
     };
 
-    // 5) outer padding:
+    // 6.1) outer padding:
     // - 用 `|offset|max + blur` 作为 padding,避免 offset 后缺口.
+    // - offset 已经 clamp 过,所以 padding 不会超过 rect 尺寸 + 2 * blur.
     let offset_extent = offset.x.abs().max(offset.y.abs()).max(0.0);
     let outer_pad = offset_extent + blur_radius_px;
     let outer_rect = rect.inflate(outer_pad, outer_pad);
@@ -634,13 +790,6 @@ fn draw_inset_box_shadow_rounded_rect(
     let outer_max_radius = 0.5 * outer_min_edge;
     let outer_radius = (radius + outer_pad).clamp(0.0, outer_max_radius);
 
-    // 6) inner cutout(用来扣掉中心区域).
-    let mut inner_rect = rect.inflate(-inner_inset_px, -inner_inset_px);
-    if inner_rect.width() <= 1.0 || inner_rect.height() <= 1.0 {
-        // 退化时折叠成中心 1x1,避免传入无效宽高.
-        let c = rect.center();
-        inner_rect = Rect::new(c.x - 0.5, c.y - 0.5, c.x + 0.5, c.y + 0.5);
-    }
     let inner_min_edge = inner_rect.width().min(inner_rect.height());
     let inner_max_radius = 0.5 * inner_min_edge;
 
@@ -670,7 +819,14 @@ fn draw_inset_box_shadow_rounded_rect(
 
     // 7) 外层 layer: 合成方式等价于 CSS 的正常 alpha blending.
     let blend = BlendMode::new(Mix::Normal, Compose::SrcOver);
-    scene.push_layer(Fill::NonZero, blend, 1.0, Affine::IDENTITY, &base_shape);
+    scene.push_layer_labeled(
+        "outer_blur",
+        Fill::NonZero,
+        blend,
+        1.0,
+        Affine::IDENTITY,
+        &base_shape,
+    );
 
     // 7.1 outer blur(并限制计算区域在 base_shape 内).
     scene.draw_blurred_rounded_rect_in(
@@ -690,9 +846,10 @@ fn draw_inset_box_shadow_rounded_rect(
     //   会留下一个半透明的"中心矩形残影"(你反馈的那个现象).
     // - 因此这里用一个 alpha=1 的不透明 mask 来做 cutout,确保中心真正被清空.
     let cutout_mask = Color::new([0.0, 0.0, 0.0, 1.0]);
-    scene.push_layer(
+    scene.push_layer_labeled(
+        "inner_cutout",
         Fill::NonZero,
-        Compose::DestOut,
+        BlendMode::from(Compose::DestOut),
         1.0,
         Affine::IDENTITY,
         &base_shape,
@@ -708,6 +865,7 @@ fn draw_inset_box_shadow_rounded_rect(
     scene.pop_layer();
 
     scene.pop_layer();
+    info
 }
 
 #[cfg(test)]
@@ -719,6 +877,86 @@ mod tests {
         assert_eq!(css_blur_radius_to_std_dev(0.0), 0.0);
     }
 
+    fn inset_shadow_info(spread_radius_px: f64) -> ShadowDrawInfo {
+        let mut scene = Scene::new();
+        draw_inset_box_shadow_rounded_rect(
+            &mut scene,
+            Rect::new(0.0, 0.0, 100.0, 40.0),
+            8.0,
+            Color::BLACK,
+            Vec2::ZERO,
+            10.0,
+            spread_radius_px,
+        )
+    }
+
+    #[test]
+    fn valid_params_report_no_adjustment() {
+        assert_eq!(inset_shadow_info(2.0), ShadowDrawInfo::default());
+    }
+
+    #[test]
+    fn large_spread_is_clamped_and_degenerates() {
+        // min_edge = 40: spread 上限为 20,此时 inner rect 高度为 0.
+        let info = inset_shadow_info(50.0);
+        assert!(info.clamped_spread);
+        assert!(info.degenerated_inner);
+    }
+
+    #[test]
+    fn spread_at_limit_degenerates_without_clamping() {
+        let info = inset_shadow_info(20.0);
+        assert!(!info.clamped_spread);
+        assert!(info.degenerated_inner);
+    }
+
+    fn inset_shadow_with_offset(offset: Vec2) -> (Scene, ShadowDrawInfo) {
+        let mut scene = Scene::new();
+        let info = draw_inset_box_shadow_rounded_rect(
+            &mut scene,
+            Rect::new(0.0, 0.0, 100.0, 40.0),
+            8.0,
+            Color::BLACK,
+            offset,
+            10.0,
+            2.0,
+        );
+        (scene, info)
+    }
+
+    #[test]
+    fn offset_within_rect_is_not_clamped() {
+        let (_, info) = inset_shadow_with_offset(Vec2::new(60.0, -20.0));
+        assert!(!info.clamped_offset);
+    }
+
+    #[test]
+    fn extreme_offset_is_clamped_to_one_sided_shadow() {
+        // inner rect 为 96x36: x 方向上限 0.5 * (100 + 96) + 10 = 108, y 方向 0.5 * (40 + 36) + 10 = 48.
+        let limit = Vec2::new(108.0, -48.0);
+        let (at_limit, info) = inset_shadow_with_offset(limit);
+        assert!(!info.clamped_offset);
+        for offset in [Vec2::new(1e4, -1e4), Vec2::new(1e12, f64::NEG_INFINITY)] {
+            let (extreme, info) = inset_shadow_with_offset(offset);
+            assert!(info.clamped_offset, "offset {offset:?} 应该被 clamp");
+            // 超出上限的 offset 和上限处绘制的内容完全一致,所以画面不会随 offset 膨胀.
+            assert_eq!(
+                extreme.encoding().draw_data,
+                at_limit.encoding().draw_data,
+                "offset {offset:?} 的绘制结果应该等于上限处的结果"
+            );
+            assert_eq!(extreme.encoding().path_data, at_limit.encoding().path_data);
+        }
+    }
+
+    #[test]
+    fn nan_offset_axis_is_treated_as_zero() {
+        let (nan, info) = inset_shadow_with_offset(Vec2::new(f64::NAN, 5.0));
+        assert!(info.clamped_offset);
+        let (zero, _) = inset_shadow_with_offset(Vec2::new(0.0, 5.0));
+        assert_eq!(nan.encoding().draw_data, zero.encoding().draw_data);
+    }
+
     #[test]
     fn blur_radius_maps_by_cutoff_ratio() {
         // 2.5*sigma ~= blur_radius
@@ -9,7 +9,7 @@ use vello::kurbo::{Affine, Circle, Ellipse, Line, RoundedRect, Stroke};
 use vello::peniko::Color;
 use vello::peniko::color::palette;
 use vello::util::{RenderContext, RenderSurface};
-use vello::{AaConfig, Renderer, RendererOptions, Scene};
+use vello::{AaConfig, ClearMode, DebugView, Renderer, RendererOptions, Scene};
 use winit::application::ApplicationHandler;
 use winit::dpi::LogicalSize;
 use winit::event::WindowEvent;
@@ -153,6 +153,13 @@ impl ApplicationHandler for SimpleVelloApp {
                             width,
                             height,
                             antialiasing_method: AaConfig::Msaa16,
+                            text_gamma: 1.0,
+                            alpha_to_coverage: false,
+                            debug: DebugView::None,
+                            premultiplied_alpha: false,
+                            flatten_tolerance: None,
+                            viewport: None,
+                            clear_mode: ClearMode::Clear,
                         },
                     )
                     .expect("failed to render to surface");
@@ -173,7 +180,7 @@ impl ApplicationHandler for SimpleVelloApp {
                 surface.blitter.copy(
                     &device_handle.device,
                     &mut encoder,
-                    &surface.target_view,
+                    &surface.blit_view,
                     &surface_texture
                         .texture
                         .create_view(&wgpu::TextureViewDescriptor::default()),
@@ -17,7 +17,7 @@ use vello::kurbo::{Affine, Circle, Ellipse, Line, RoundedRect, Stroke};
 use vello::peniko::Color;
 use vello::peniko::color::palette;
 use vello::util::{RenderContext, RenderSurface};
-use vello::{AaConfig, Renderer, RendererOptions, Scene};
+use vello::{AaConfig, ClearMode, DebugView, Renderer, RendererOptions, Scene};
 
 use vello::wgpu;
 
@@ -80,6 +80,13 @@ fn main() {
                     width,
                     height,
                     antialiasing_method: AaConfig::Msaa16,
+                    text_gamma: 1.0,
+                    alpha_to_coverage: false,
+                    debug: DebugView::None,
+                    premultiplied_alpha: false,
+                    flatten_tolerance: None,
+                    viewport: None,
+                    clear_mode: ClearMode::Clear,
                 },
             )
             .expect("failed to render to surface");
@@ -98,7 +105,7 @@ fn main() {
         surface.blitter.copy(
             &device_handle.device,
             &mut encoder,
-            &surface.target_view,
+            &surface.blit_view,
             &surface_texture
                 .texture
                 .create_view(&wgpu::TextureViewDescriptor::default()),
@@ -127,6 +134,9 @@ fn create_vello_renderer(render_cx: &RenderContext, surface: &RenderSurface<'_>)
             antialiasing_support: vello::AaSupport::all(),
             num_init_threads: NonZeroUsize::new(1),
             pipeline_cache: None,
+            msaa_sample_pattern: None,
+            debug_validation: false,
+            intermediate_budget_bytes: None,
         },
     )
     .expect("Couldn't create renderer")
@@ -17,7 +17,7 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use minimal_pipeline_cache::{get_cache_directory, load_pipeline_cache, write_pipeline_cache};
+use minimal_pipeline_cache::get_cache_directory;
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
 use vello::low_level::DebugLayers;
@@ -36,8 +36,10 @@ use clap::Parser;
 use scenes::{ExampleScene, ImageCache, SceneParams, SceneSet, SimpleText};
 use vello::kurbo::{Affine, Point, Vec2};
 use vello::peniko::{Color, color::palette};
-use vello::util::{RenderContext, RenderSurface};
-use vello::{AaConfig, Renderer, RendererOptions, Scene, low_level::BumpAllocators};
+use vello::util::{RenderContext, RenderSurface, load_pipeline_cache, write_pipeline_cache};
+use vello::{
+    AaConfig, ClearMode, DebugView, Renderer, RendererOptions, Scene, low_level::BumpAllocators,
+};
 
 use winit::dpi::LogicalSize;
 use winit::event_loop::EventLoop;
@@ -234,6 +236,9 @@ impl ApplicationHandler<UserEvent> for VelloApp {
                         antialiasing_support: AA_CONFIGS.iter().copied().collect(),
                         num_init_threads: NonZeroUsize::new(self.num_init_threads),
                         pipeline_cache: cache.as_ref().map(|(cache, _, _)| cache.clone()),
+                        msaa_sample_pattern: None,
+                        debug_validation: false,
+                        intermediate_budget_bytes: None,
                     },
                 )
                 .map_err(|e| {
@@ -547,6 +552,13 @@ impl ApplicationHandler<UserEvent> for VelloApp {
                     width,
                     height,
                     antialiasing_method,
+                    text_gamma: 1.0,
+                    alpha_to_coverage: false,
+                    debug: DebugView::None,
+                    premultiplied_alpha: false,
+                    flatten_tolerance: None,
+                    viewport: None,
+                    clear_mode: ClearMode::Clear,
                 };
                 self.scene.reset();
                 let mut transform = self.transform;
@@ -648,7 +660,7 @@ impl ApplicationHandler<UserEvent> for VelloApp {
                 surface.blitter.copy(
                     &device_handle.device,
                     &mut encoder,
-                    &surface.target_view,
+                    &surface.blit_view,
                     &surface_texture
                         .texture
                         .create_view(&wgpu::TextureViewDescriptor::default()),
@@ -768,6 +780,9 @@ fn run(
                 // anyway
                 num_init_threads: NonZeroUsize::new(1),
                 pipeline_cache: cache.as_ref().map(|(cache, _)| cache.clone()),
+                msaa_sample_pattern: None,
+                debug_validation: false,
+                intermediate_budget_bytes: None,
             },
         )
         .map_err(|e| {
@@ -788,8 +803,8 @@ fn run(
             })
             .expect("Not setting max_num_pending_frames");
         renderers[id] = Some(renderer);
-        if let Some((cache, file)) = cache {
-            let result = write_pipeline_cache(&file, &cache);
+        if let Some((_, file)) = cache {
+            let result = renderers[id].as_ref().unwrap().save_pipeline_cache(&file);
             if let Err(e) = result {
                 log::error!("Failed to write pipeline cache: {e}");
             }
@@ -0,0 +1,298 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for smoothly animating values between frames, such as the parameters of a shadow.
+//!
+//! A [`Tween`] interpolates between two values over a duration, shaped by an [`Easing`] curve.
+//! Each frame, call [`Tween::tick`] with the time since the previous frame to get the current value:
+//!
+//! ```
+//! use vello::anim::{Easing, Tween};
+//!
+//! let mut blur = Tween::new(0.0, 10.0, 0.3, Easing::EASE);
+//! let value = blur.tick(1.0 / 60.0);
+//! assert!(value > 0.0 && value < 10.0);
+//! ```
+
+use peniko::Color;
+use peniko::color::ColorSpaceTag;
+use peniko::kurbo::{Point, Vec2};
+
+use crate::ColorExt;
+
+/// A value which can be animated by a [`Tween`].
+pub trait Animatable: Clone {
+    /// Interpolates between `self` (at `t = 0.0`) and `to` (at `t = 1.0`).
+    ///
+    /// Some easing curves, such as [`Easing::Spring`], overshoot, so `t` can be outside of `0.0..=1.0`.
+    #[must_use]
+    fn interpolate(&self, to: &Self, t: f64) -> Self;
+}
+
+impl Animatable for f64 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t as Self
+    }
+}
+
+impl Animatable for Vec2 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self.lerp(*to, t)
+    }
+}
+
+impl Animatable for Point {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self.lerp(*to, t)
+    }
+}
+
+/// Colors are interpolated in premultiplied sRGB, matching CSS transitions.
+///
+/// As extrapolating could produce invalid colors, `t` is clamped to `0.0..=1.0`.
+impl Animatable for Color {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self.lerp_in(*to, t.clamp(0.0, 1.0) as f32, ColorSpaceTag::Srgb)
+    }
+}
+
+/// A cubic Bézier easing curve from `(0, 0)` to `(1, 1)`, as in CSS's `cubic-bezier()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    /// The x coordinate of the first control point, which must be in `0.0..=1.0`.
+    pub x1: f64,
+    /// The y coordinate of the first control point.
+    pub y1: f64,
+    /// The x coordinate of the second control point, which must be in `0.0..=1.0`.
+    pub x2: f64,
+    /// The y coordinate of the second control point.
+    pub y2: f64,
+}
+
+impl CubicBezier {
+    /// Creates a curve with the given control points.
+    ///
+    /// The x coordinates are clamped to `0.0..=1.0`, so that the curve is a function of x.
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Returns the y coordinate of the curve at the given x coordinate.
+    ///
+    /// `x` is clamped to `0.0..=1.0`.
+    pub fn eval(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+        let t = self.solve_t(x);
+        sample_bezier(self.y1, self.y2, t)
+    }
+
+    /// Finds the parameter `t` at which the curve has the x coordinate `x`.
+    fn solve_t(&self, x: f64) -> f64 {
+        const EPSILON: f64 = 1e-9;
+        // Newton's method converges quickly for most curves...
+        let mut t = x;
+        for _ in 0..8 {
+            let error = sample_bezier(self.x1, self.x2, t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+            let derivative = sample_bezier_derivative(self.x1, self.x2, t);
+            if derivative.abs() < 1e-6 {
+                break;
+            }
+            t -= error / derivative;
+        }
+        // ...but falls back to bisection where the curve is flat, as x is monotonic in t.
+        let (mut lo, mut hi) = (0.0, 1.0);
+        t = x;
+        while hi - lo > EPSILON {
+            if sample_bezier(self.x1, self.x2, t) < x {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            t = (lo + hi) * 0.5;
+        }
+        t
+    }
+}
+
+/// Evaluates one coordinate of a cubic Bézier from 0 to 1 with the given control points.
+fn sample_bezier(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    ((a * t + b) * t + c) * t
+}
+
+fn sample_bezier_derivative(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    (3.0 * a * t + 2.0 * b) * t + c
+}
+
+/// The shape of the progress of a [`Tween`] over time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    /// Progresses at a constant rate.
+    Linear,
+    /// Follows a cubic Bézier curve, as in CSS.
+    CubicBezier(CubicBezier),
+    /// Follows a damped spring with unit mass, which starts at rest.
+    ///
+    /// Time is measured in units of the duration of the tween, so the spring should be stiff
+    /// enough to settle before the end of the tween, at which point the value jumps to the target.
+    /// For example, a stiffness of `100.0` and damping of `10.0` overshoots by about 16%, and has
+    /// settled to within 0.3% at the end.
+    /// A damping of at least `2.0 * stiffness.sqrt()` doesn't overshoot.
+    Spring {
+        /// The stiffness of the spring, which must be positive.
+        stiffness: f64,
+        /// The damping of the spring, which must not be negative.
+        damping: f64,
+    },
+}
+
+impl Easing {
+    /// The CSS `ease` curve.
+    pub const EASE: Self = Self::CubicBezier(CubicBezier {
+        x1: 0.25,
+        y1: 0.1,
+        x2: 0.25,
+        y2: 1.0,
+    });
+    /// The CSS `ease-in` curve.
+    pub const EASE_IN: Self = Self::CubicBezier(CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 1.0,
+        y2: 1.0,
+    });
+    /// The CSS `ease-out` curve.
+    pub const EASE_OUT: Self = Self::CubicBezier(CubicBezier {
+        x1: 0.0,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    });
+    /// The CSS `ease-in-out` curve.
+    pub const EASE_IN_OUT: Self = Self::CubicBezier(CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    });
+
+    /// Creates a cubic Bézier easing curve, as in CSS's `cubic-bezier(x1, y1, x2, y2)`.
+    pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self::CubicBezier(CubicBezier::new(x1, y1, x2, y2))
+    }
+
+    /// Returns the eased progress for linear progress `t`, where `t` is clamped to `0.0..=1.0`.
+    ///
+    /// The result is `0.0` at the start and `1.0` at the end, but may be outside of that range
+    /// in between.
+    pub fn eval(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::CubicBezier(curve) => curve.eval(t),
+            Self::Spring { .. } if t >= 1.0 => 1.0,
+            Self::Spring { stiffness, damping } => spring(*stiffness, *damping, t),
+        }
+    }
+}
+
+/// The position at time `t` of a unit mass spring moving from 0 to 1, starting at rest.
+fn spring(stiffness: f64, damping: f64, t: f64) -> f64 {
+    let omega = stiffness.max(f64::EPSILON).sqrt();
+    let zeta = damping.max(0.0) / (2.0 * omega);
+    if zeta < 1.0 {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let decay = (-zeta * omega * t).exp();
+        1.0 - decay * ((omega_d * t).cos() + zeta * omega / omega_d * (omega_d * t).sin())
+    } else if zeta - 1.0 < 1e-6 {
+        // Critically damped, which is handled separately to avoid dividing by zero below.
+        1.0 - (-omega * t).exp() * (1.0 + omega * t)
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        1.0 - (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+    }
+}
+
+/// Animates a value from a start to an end over a duration.
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: f64,
+    elapsed: f64,
+    easing: Easing,
+}
+
+impl<T: Animatable> Tween<T> {
+    /// Creates a tween from `from` to `to`, which lasts `duration` seconds.
+    pub fn new(from: T, to: T, duration: f64, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration: duration.max(0.0),
+            elapsed: 0.0,
+            easing,
+        }
+    }
+
+    /// Advances the animation by `dt` seconds, and returns the new value.
+    pub fn tick(&mut self, dt: f64) -> T {
+        self.elapsed = (self.elapsed + dt.max(0.0)).min(self.duration);
+        self.value()
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> T {
+        if self.is_finished() {
+            return self.to.clone();
+        }
+        let t = self.easing.eval(self.elapsed / self.duration);
+        self.from.interpolate(&self.to, t)
+    }
+
+    /// Returns the value at the end of the animation.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns `true` if the animation has reached its end.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Starts a new animation from the current value to `to`, with the same duration and easing.
+    ///
+    /// This is useful when the target changes while an animation is in progress, such as
+    /// when a slider is dragged.
+    pub fn retarget(&mut self, to: T) {
+        self.from = self.value();
+        self.to = to;
+        self.elapsed = 0.0;
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An immediate-mode drawing API with stateful styles, in the style of the HTML canvas.
+//!
+//! A [`Canvas`] wraps a [`Scene`], and keeps track of a current transform, fill style and
+//! stroke style, which can be saved and restored. Each shape is drawn by choosing the shape,
+//! then filling or stroking it:
+//!
+//! ```
+//! use vello::Scene;
+//! use vello::canvas::Canvas;
+//! use vello::peniko::color::palette;
+//!
+//! let mut scene = Scene::new();
+//! let mut canvas = Canvas::new(&mut scene);
+//! canvas.translate((10.0, 10.0));
+//! canvas.rect(0.0, 0.0, 100.0, 40.0).fill(palette::css::TEAL);
+//! canvas.set_line_width(2.0);
+//! canvas.circle((50.0, 20.0), 15.0).stroke(palette::css::WHITE);
+//! ```
+//!
+//! Everything drawn by a `Canvas` is encoded using the existing [`Scene`] methods, so it can be
+//! freely mixed with drawing into the scene directly, using [`Canvas::scene`].
+//!
+//! This module is only available with the `canvas` feature.
+
+use peniko::kurbo::{
+    Affine, Circle, Line, Point, Rect, RoundedRect, RoundedRectRadii, Shape, Stroke, Vec2,
+};
+use peniko::{Brush, BrushRef, Fill, color::palette};
+
+use crate::Scene;
+
+/// The styles used by a [`Canvas`], which are saved by [`Canvas::save`].
+#[derive(Clone, Debug)]
+struct CanvasState {
+    transform: Affine,
+    fill_rule: Fill,
+    fill_style: Option<Brush>,
+    stroke_style: Option<Brush>,
+    stroke: Stroke,
+}
+
+impl Default for CanvasState {
+    fn default() -> Self {
+        Self {
+            transform: Affine::IDENTITY,
+            fill_rule: Fill::NonZero,
+            fill_style: Some(palette::css::BLACK.into()),
+            stroke_style: None,
+            stroke: Stroke::new(1.0),
+        }
+    }
+}
+
+/// A stateful wrapper around a [`Scene`], for drawing with an API similar to the HTML canvas.
+///
+/// See the [module level documentation](self) for an example.
+pub struct Canvas<'a> {
+    scene: &'a mut Scene,
+    state: CanvasState,
+    saved: Vec<CanvasState>,
+}
+
+impl<'a> Canvas<'a> {
+    /// Creates a canvas which draws into `scene`.
+    ///
+    /// The canvas starts with an identity transform, a black fill style, no stroke style and
+    /// a line width of 1.
+    pub fn new(scene: &'a mut Scene) -> Self {
+        Self {
+            scene,
+            state: CanvasState::default(),
+            saved: Vec::new(),
+        }
+    }
+
+    /// Returns the scene which is drawn into, for drawing which the canvas doesn't support.
+    pub fn scene(&mut self) -> &mut Scene {
+        self.scene
+    }
+
+    /// Saves the current transform and styles, to be restored by [`Canvas::restore`].
+    pub fn save(&mut self) {
+        self.saved.push(self.state.clone());
+    }
+
+    /// Restores the transform and styles saved by the matching call to [`Canvas::save`].
+    ///
+    /// Does nothing if there is no saved state.
+    pub fn restore(&mut self) {
+        if let Some(state) = self.saved.pop() {
+            self.state = state;
+        }
+    }
+
+    /// Returns the current transform.
+    pub fn transform(&self) -> Affine {
+        self.state.transform
+    }
+
+    /// Replaces the current transform.
+    pub fn set_transform(&mut self, transform: Affine) {
+        self.state.transform = transform;
+    }
+
+    /// Applies `transform` to shapes before the current transform.
+    pub fn pre_transform(&mut self, transform: Affine) {
+        self.state.transform *= transform;
+    }
+
+    /// Translates shapes drawn after this call.
+    pub fn translate(&mut self, offset: impl Into<Vec2>) {
+        self.pre_transform(Affine::translate(offset));
+    }
+
+    /// Scales shapes drawn after this call, about the current origin.
+    pub fn scale(&mut self, x: f64, y: f64) {
+        self.pre_transform(Affine::scale_non_uniform(x, y));
+    }
+
+    /// Rotates shapes drawn after this call by `angle` radians, about the current origin.
+    pub fn rotate(&mut self, angle: f64) {
+        self.pre_transform(Affine::rotate(angle));
+    }
+
+    /// Sets the brush used by [`DrawShape::fill_style`] and [`DrawShape::paint`].
+    pub fn set_fill_style(&mut self, brush: impl Into<Brush>) {
+        self.state.fill_style = Some(brush.into());
+    }
+
+    /// Removes the fill style, so that shapes aren't filled when they are painted.
+    pub fn clear_fill_style(&mut self) {
+        self.state.fill_style = None;
+    }
+
+    /// Sets the brush used by [`DrawShape::stroke_style`] and [`DrawShape::paint`].
+    pub fn set_stroke_style(&mut self, brush: impl Into<Brush>) {
+        self.state.stroke_style = Some(brush.into());
+    }
+
+    /// Removes the stroke style, so that shapes aren't stroked when they are painted.
+    pub fn clear_stroke_style(&mut self) {
+        self.state.stroke_style = None;
+    }
+
+    /// Sets the fill rule used when filling shapes.
+    pub fn set_fill_rule(&mut self, fill_rule: Fill) {
+        self.state.fill_rule = fill_rule;
+    }
+
+    /// Sets the width of strokes.
+    pub fn set_line_width(&mut self, width: f64) {
+        self.state.stroke.width = width;
+    }
+
+    /// Sets the style of strokes, including their width, joins, caps and dashes.
+    pub fn set_stroke(&mut self, stroke: Stroke) {
+        self.state.stroke = stroke;
+    }
+
+    /// Returns the current style of strokes.
+    pub fn stroke(&self) -> &Stroke {
+        &self.state.stroke
+    }
+
+    /// Chooses `shape` to be filled or stroked.
+    #[must_use = "A shape is only drawn when it is filled or stroked"]
+    pub fn shape<S: Shape>(&mut self, shape: S) -> DrawShape<'_, 'a, S> {
+        DrawShape {
+            canvas: self,
+            shape,
+        }
+    }
+
+    /// Chooses the rectangle with its top left corner at (`x`, `y`) and the given size.
+    #[must_use = "A shape is only drawn when it is filled or stroked"]
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) -> DrawShape<'_, 'a, Rect> {
+        self.shape(Rect::new(x, y, x + width, y + height))
+    }
+
+    /// Chooses a rounded rectangle with its top left corner at (`x`, `y`) and the given size.
+    #[must_use = "A shape is only drawn when it is filled or stroked"]
+    pub fn rounded_rect(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: impl Into<RoundedRectRadii>,
+    ) -> DrawShape<'_, 'a, RoundedRect> {
+        self.shape(RoundedRect::new(x, y, x + width, y + height, radii))
+    }
+
+    /// Chooses a circle.
+    #[must_use = "A shape is only drawn when it is filled or stroked"]
+    pub fn circle(&mut self, center: impl Into<Point>, radius: f64) -> DrawShape<'_, 'a, Circle> {
+        self.shape(Circle::new(center, radius))
+    }
+
+    /// Chooses a line segment. As a line has no area, it should be stroked.
+    #[must_use = "A shape is only drawn when it is filled or stroked"]
+    pub fn line(&mut self, p0: impl Into<Point>, p1: impl Into<Point>) -> DrawShape<'_, 'a, Line> {
+        self.shape(Line::new(p0, p1))
+    }
+}
+
+/// A shape chosen on a [`Canvas`], which is drawn by filling or stroking it.
+///
+/// Each method returns the `DrawShape`, so that a shape can be both filled and stroked.
+pub struct DrawShape<'c, 'a, S> {
+    canvas: &'c mut Canvas<'a>,
+    shape: S,
+}
+
+impl<S: Shape> DrawShape<'_, '_, S> {
+    /// Fills the shape with `brush`, using the canvas's transform and fill rule.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill<'b>(self, brush: impl Into<BrushRef<'b>>) -> Self {
+        let state = &self.canvas.state;
+        self.canvas
+            .scene
+            .fill(state.fill_rule, state.transform, brush, None, &self.shape);
+        self
+    }
+
+    /// Strokes the shape with `brush`, using the canvas's transform and stroke style.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke<'b>(self, brush: impl Into<BrushRef<'b>>) -> Self {
+        let state = &self.canvas.state;
+        self.canvas
+            .scene
+            .stroke(&state.stroke, state.transform, brush, None, &self.shape);
+        self
+    }
+
+    /// Fills the shape with the canvas's [fill style](Canvas::set_fill_style), if any.
+    pub fn fill_style(self) -> Self {
+        match self.canvas.state.fill_style.clone() {
+            Some(brush) => self.fill(&brush),
+            None => self,
+        }
+    }
+
+    /// Strokes the shape with the canvas's [stroke style](Canvas::set_stroke_style), if any.
+    pub fn stroke_style(self) -> Self {
+        match self.canvas.state.stroke_style.clone() {
+            Some(brush) => self.stroke(&brush),
+            None => self,
+        }
+    }
+
+    /// Fills and then strokes the shape with the canvas's fill and stroke styles.
+    pub fn paint(self) -> Self {
+        self.fill_style().stroke_style()
+    }
+}
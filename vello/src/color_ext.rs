@@ -0,0 +1,63 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Convenience methods for working with colors, such as when animating them.
+
+use peniko::Color;
+use peniko::color::{ColorSpaceTag, DynamicColor, HueDirection, PremulColor, Srgb};
+
+/// Extension methods for [`Color`].
+///
+/// To change only the alpha of a color, use the existing [`Color::with_alpha`]
+/// and [`Color::multiply_alpha`] methods.
+///
+/// Every color passed to Vello, such as in a brush or [`RenderParams::base_color`], has
+/// straight (unpremultiplied) alpha, as created by [`Color::new`]. Vello premultiplies colors
+/// itself when encoding them. When compositing your own colors, such as when working out which
+/// color to erase a mask with, use [`from_premultiplied`](Self::from_premultiplied) and
+/// [`to_premultiplied`](Self::to_premultiplied) to convert at the boundary.
+///
+/// [`RenderParams::base_color`]: crate::RenderParams::base_color
+pub trait ColorExt {
+    /// Creates a color from premultiplied sRGB components, in the order red, green, blue and
+    /// alpha.
+    #[must_use]
+    fn from_premultiplied(components: [f32; 4]) -> Self;
+
+    /// Returns the sRGB components of this color premultiplied by its alpha, in the order red,
+    /// green, blue and alpha.
+    #[must_use]
+    fn to_premultiplied(self) -> [f32; 4];
+
+    /// Interpolates between `self` (at `t = 0.0`) and `other` (at `t = 1.0`) in `space`.
+    ///
+    /// Interpolating in [`ColorSpaceTag::LinearSrgb`] or [`ColorSpaceTag::Oklab`] avoids the
+    /// muddy midpoints which interpolating directly in sRGB produces.
+    /// Interpolation is performed on premultiplied components, so fading to a transparent
+    /// color doesn't bring in the transparent color's hue.
+    ///
+    /// Values of `t` outside of `0.0..=1.0` extrapolate.
+    #[must_use]
+    fn lerp_in(self, other: Self, t: f32, space: ColorSpaceTag) -> Self;
+}
+
+impl ColorExt for Color {
+    fn from_premultiplied(components: [f32; 4]) -> Self {
+        PremulColor::<Srgb>::new(components).un_premultiply()
+    }
+
+    fn to_premultiplied(self) -> [f32; 4] {
+        self.premultiply().components
+    }
+
+    fn lerp_in(self, other: Self, t: f32, space: ColorSpaceTag) -> Self {
+        DynamicColor::from_alpha_color(self)
+            .interpolate(
+                DynamicColor::from_alpha_color(other),
+                space,
+                HueDirection::Shorter,
+            )
+            .eval(t)
+            .to_alpha_color::<Srgb>()
+    }
+}
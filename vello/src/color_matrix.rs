@@ -0,0 +1,230 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Color matrices, for color adjustments such as those of the CSS `filter` property.
+
+use peniko::Color;
+
+/// A 4×5 matrix which transforms colors, as applied by [`Scene::push_color_matrix_layer`].
+///
+/// This has the same layout as the SVG `feColorMatrix` filter: the matrix is in row-major
+/// order, with a row for each of the red, green, blue and alpha channels of the output. Each
+/// row has a weight for each of the red, green, blue and alpha channels of the input, then an
+/// offset. The channels are sRGB encoded, unpremultiplied, and in the range `0.0..=1.0`.
+/// The results are clamped to that range.
+///
+/// [`Scene::push_color_matrix_layer`]: crate::Scene::push_color_matrix_layer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorMatrix(pub [f32; 20]);
+
+impl ColorMatrix {
+    /// The matrix which doesn't change colors.
+    pub const IDENTITY: Self = Self([
+        1.0, 0.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0, 0.0, //
+    ]);
+
+    /// Returns a matrix which applies `rgb` to the color channels, keeping alpha.
+    const fn from_rgb(rgb: [[f32; 3]; 3]) -> Self {
+        let [r, g, b] = rgb;
+        Self([
+            r[0], r[1], r[2], 0.0, 0.0, //
+            g[0], g[1], g[2], 0.0, 0.0, //
+            b[0], b[1], b[2], 0.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, 0.0, //
+        ])
+    }
+
+    /// Multiplies the color channels by `amount`, like the CSS `brightness()` filter.
+    ///
+    /// An `amount` of 0 is black, 1 leaves the color unchanged, and values over 1 are
+    /// brighter. Negative amounts are treated as zero.
+    pub fn brightness(amount: f32) -> Self {
+        let b = amount.max(0.0);
+        Self::from_rgb([[b, 0.0, 0.0], [0.0, b, 0.0], [0.0, 0.0, b]])
+    }
+
+    /// Scales the color channels away from 0.5, like the CSS `contrast()` filter.
+    ///
+    /// An `amount` of 0 is completely gray, 1 leaves the color unchanged, and values over 1
+    /// have more contrast. Negative amounts are treated as zero.
+    pub fn contrast(amount: f32) -> Self {
+        let c = amount.max(0.0);
+        let offset = 0.5 - 0.5 * c;
+        Self([
+            c, 0.0, 0.0, 0.0, offset, //
+            0.0, c, 0.0, 0.0, offset, //
+            0.0, 0.0, c, 0.0, offset, //
+            0.0, 0.0, 0.0, 1.0, 0.0, //
+        ])
+    }
+
+    /// Converts colors towards gray, like the CSS `grayscale()` filter.
+    ///
+    /// An `amount` of 1 gives the luminance of the color, and 0 leaves the color unchanged.
+    /// The amount is clamped to `0.0..=1.0`.
+    pub fn grayscale(amount: f32) -> Self {
+        let s = 1.0 - amount.clamp(0.0, 1.0);
+        Self::from_rgb([
+            [
+                0.2126 + 0.7874 * s,
+                0.7152 - 0.7152 * s,
+                0.0722 - 0.0722 * s,
+            ],
+            [
+                0.2126 - 0.2126 * s,
+                0.7152 + 0.2848 * s,
+                0.0722 - 0.0722 * s,
+            ],
+            [
+                0.2126 - 0.2126 * s,
+                0.7152 - 0.7152 * s,
+                0.0722 + 0.9278 * s,
+            ],
+        ])
+    }
+
+    /// Converts colors towards sepia, like the CSS `sepia()` filter.
+    ///
+    /// An `amount` of 1 is completely sepia, and 0 leaves the color unchanged.
+    /// The amount is clamped to `0.0..=1.0`.
+    pub fn sepia(amount: f32) -> Self {
+        let s = 1.0 - amount.clamp(0.0, 1.0);
+        Self::from_rgb([
+            [0.393 + 0.607 * s, 0.769 - 0.769 * s, 0.189 - 0.189 * s],
+            [0.349 - 0.349 * s, 0.686 + 0.314 * s, 0.168 - 0.168 * s],
+            [0.272 - 0.272 * s, 0.534 - 0.534 * s, 0.131 + 0.869 * s],
+        ])
+    }
+
+    /// Scales the saturation of colors, like the CSS `saturate()` filter.
+    ///
+    /// An `amount` of 0 is completely unsaturated, 1 leaves the color unchanged, and values
+    /// over 1 are more saturated. Negative amounts are treated as zero.
+    pub fn saturate(amount: f32) -> Self {
+        let s = amount.max(0.0);
+        Self::from_rgb([
+            [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+            [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+            [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+        ])
+    }
+
+    /// Rotates the hue of colors by `degrees`, like the CSS `hue-rotate()` filter.
+    pub fn hue_rotate(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::from_rgb([
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+            ],
+        ])
+    }
+
+    /// Returns the matrix which applies `self`, then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let (a, b) = (&self.0, &next.0);
+        let mut result = [0.0; 20];
+        for row in 0..4 {
+            for column in 0..5 {
+                let mut value: f32 = (0..4).map(|k| b[row * 5 + k] * a[k * 5 + column]).sum();
+                if column == 4 {
+                    value += b[row * 5 + 4];
+                }
+                result[row * 5 + column] = value;
+            }
+        }
+        Self(result)
+    }
+
+    /// Applies the matrix to `color`.
+    #[must_use]
+    pub fn apply(&self, color: Color) -> Color {
+        let input = color.components;
+        let mut output = [0.0; 4];
+        for (channel, row) in output.iter_mut().zip(self.0.chunks_exact(5)) {
+            let value = row[4] + (0..4).map(|k| row[k] * input[k]).sum::<f32>();
+            *channel = if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            };
+        }
+        Color::new(output)
+    }
+}
+
+/// Common color adjustments, applied by [`Scene::push_adjust_layer`].
+///
+/// The adjustments are applied in the order of the fields, as with the CSS filter
+/// `brightness() contrast() saturate() hue-rotate()`. The default leaves colors unchanged, so
+/// only the adjustments which are needed have to be set:
+///
+/// ```
+/// use vello::Adjust;
+///
+/// let adjust = Adjust {
+///     contrast: 1.2,
+///     saturation: 0.8,
+///     ..Adjust::default()
+/// };
+/// ```
+///
+/// [`Scene::push_adjust_layer`]: crate::Scene::push_adjust_layer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Adjust {
+    /// The multiplier for the color channels, where 1 is unchanged. See [`ColorMatrix::brightness`].
+    pub brightness: f32,
+    /// The contrast, where 1 is unchanged. See [`ColorMatrix::contrast`].
+    pub contrast: f32,
+    /// The saturation, where 1 is unchanged. See [`ColorMatrix::saturate`].
+    pub saturation: f32,
+    /// The rotation of the hue in degrees, where 0 is unchanged. See [`ColorMatrix::hue_rotate`].
+    pub hue_rotate: f32,
+}
+
+impl Default for Adjust {
+    fn default() -> Self {
+        Self {
+            brightness: 1.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            hue_rotate: 0.0,
+        }
+    }
+}
+
+impl From<Adjust> for ColorMatrix {
+    fn from(adjust: Adjust) -> Self {
+        Self::brightness(adjust.brightness)
+            .then(&Self::contrast(adjust.contrast))
+            .then(&Self::saturate(adjust.saturation))
+            .then(&Self::hue_rotate(adjust.hue_rotate))
+    }
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f32; 20]> for ColorMatrix {
+    fn from(matrix: [f32; 20]) -> Self {
+        Self(matrix)
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Scientific colormaps, for use as the color stops of gradients.
+
+use peniko::color::DynamicColor;
+use peniko::{Color, ColorStop, ColorStops, ColorStopsSource};
+
+/// A perceptually ordered colormap, such as those used for data visualization.
+///
+/// A colormap can be used as the color stops of a gradient:
+///
+/// ```
+/// use vello::Colormap;
+/// use vello::peniko::Gradient;
+///
+/// let gradient = Gradient::new_linear((0.0, 0.0), (256.0, 0.0)).with_stops(Colormap::Viridis);
+/// ```
+///
+/// The colors are evaluated from polynomial fits of the canonical lookup tables, and
+/// are within a few units (out of 255) of the tables in sRGB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Colormap {
+    /// Matplotlib's default colormap, from dark blue through green to yellow.
+    Viridis,
+    /// From black through purple and red to light yellow.
+    Magma,
+    /// From dark blue through purple and orange to yellow.
+    Plasma,
+    /// Google's rainbow colormap, from dark blue through green, yellow and red to dark red.
+    ///
+    /// This uses Google's published polynomial approximation, as used by d3 (for example),
+    /// which is slightly lighter than the lookup table at the very ends of the map.
+    Turbo,
+}
+
+impl Colormap {
+    /// The number of color stops used for gradients.
+    ///
+    /// Interpolating linearly between this many stops is within rounding of the colormap.
+    pub const STOPS: usize = 33;
+
+    /// Returns the color at `t`, which is clamped to `0.0..=1.0`.
+    pub fn eval(self, t: f32) -> Color {
+        // Polynomial fits of the colormaps, with coefficients in order of increasing degree.
+        // Viridis, magma and plasma are from Matt Zucker's fits of the matplotlib tables, and
+        // turbo is Anton Mikhailov's approximation.
+        const VIRIDIS: [[f64; 3]; 7] = [
+            [0.2777273272234177, 0.005407344544966578, 0.3340998053353061],
+            [0.1050930431085774, 1.404613529898575, 1.384590162594685],
+            [-0.3308618287255563, 0.214847559468213, 0.09509516302823659],
+            [-4.634230498983486, -5.799100973351585, -19.33244095627987],
+            [6.228269936347081, 14.17993336680509, 56.69055260068105],
+            [4.776384997670288, -13.74514537774601, -65.35303263337234],
+            [-5.435455855934631, 4.645852612178535, 26.3124352495832],
+        ];
+        const MAGMA: [[f64; 3]; 7] = [
+            [
+                -0.002136485053939582,
+                -0.000749655052795221,
+                -0.005386127855323933,
+            ],
+            [0.2516605407371642, 0.6775232436837668, 2.494026599312351],
+            [8.353717279216625, -3.577719514958484, 0.3144679030132573],
+            [-27.66873308576866, 14.26473078096533, -13.64921318813922],
+            [52.17613981234068, -27.94360607168351, 12.94416944238394],
+            [-50.76852536473588, 29.04658282127291, 4.23415299384598],
+            [18.65570506591883, -11.48977351997711, -5.601961508734096],
+        ];
+        const PLASMA: [[f64; 3]; 7] = [
+            [0.05873234392399702, 0.02333670892565664, 0.5433401826748754],
+            [2.176514634195958, 0.2383834171260182, 0.7539604599784036],
+            [-2.689460476458034, -7.455851135738909, 3.110799939717086],
+            [6.130348345893603, 42.3461881477227, -28.51885465332158],
+            [-11.10743619062271, -82.66631109428045, 60.13984767418263],
+            [10.02306557647065, 71.4136177009535, -54.07218655560067],
+            [-3.658713842777788, -22.93153465461149, 18.19190778539828],
+        ];
+        const TURBO: [[f64; 3]; 6] = [
+            [0.13572138, 0.09140261, 0.1066733],
+            [4.6153926, 2.19418839, 12.64194608],
+            [-42.66032258, 4.84296658, -60.58204836],
+            [132.13108234, -14.18503333, 110.36276771],
+            [-152.94239396, 4.27729857, -89.90310912],
+            [59.28637943, 2.82956604, 27.34824973],
+        ];
+
+        let coefficients: &[[f64; 3]] = match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Magma => &MAGMA,
+            Self::Plasma => &PLASMA,
+            Self::Turbo => &TURBO,
+        };
+        let t = if t.is_nan() {
+            0.0
+        } else {
+            f64::from(t.clamp(0.0, 1.0))
+        };
+        let mut rgb = [0.0_f64; 3];
+        // Horner's method, from the highest degree.
+        for coefficient in coefficients.iter().rev() {
+            for (channel, c) in rgb.iter_mut().zip(coefficient) {
+                *channel = *channel * t + c;
+            }
+        }
+        let [r, g, b] = rgb.map(|channel| channel.clamp(0.0, 1.0) as f32);
+        Color::new([r, g, b, 1.0])
+    }
+}
+
+impl ColorStopsSource for Colormap {
+    fn collect_stops(self, stops: &mut ColorStops) {
+        for i in 0..Self::STOPS {
+            let offset = i as f32 / (Self::STOPS - 1) as f32;
+            stops.push(ColorStop {
+                offset,
+                color: DynamicColor::from_alpha_color(self.eval(offset)),
+            });
+        }
+    }
+}
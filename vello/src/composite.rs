@@ -0,0 +1,210 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compositing of the rendered scene over the existing contents of the target, for
+//! [`ClearMode::None`](crate::ClearMode::None).
+
+use wgpu::{Device, Queue, Texture, TextureView};
+
+const SHADER: &str = r"
+@group(0) @binding(0) var source_texture: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    // A single triangle which covers the full viewport.
+    let uv = vec2(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4(uv * vec2(2.0, -2.0) + vec2(-1.0, 1.0), 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    // The source is the same size as the target, so this is the same pixel.
+    return textureLoad(source_texture, vec2<i32>(position.xy), 0);
+}
+";
+
+/// Returns the blend state which draws a fragment over the target with the "over" operator.
+///
+/// Targets with straight alpha are blended as `src * src.a + dst * (1 - src.a)`, which is
+/// exact where the target is opaque. The alpha is exact in both cases.
+pub(crate) fn over(premultiplied: bool) -> wgpu::BlendState {
+    if premultiplied {
+        wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING
+    } else {
+        wgpu::BlendState::ALPHA_BLENDING
+    }
+}
+
+/// The state needed to composite a scene over the target.
+///
+/// The pipelines and intermediate texture are created lazily, as most renderers clear
+/// the target.
+#[derive(Default)]
+pub(crate) struct Composite {
+    pipelines: Option<Pipelines>,
+    intermediate: Option<(Texture, TextureView)>,
+}
+
+struct Pipelines {
+    straight: wgpu::RenderPipeline,
+    premultiplied: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl Composite {
+    /// Returns the texture which the scene should be rendered into before [`Self::run`] is called.
+    ///
+    /// This is the size of the whole target, so that the scene is rendered into the same
+    /// region of it as the viewport.
+    pub(crate) fn intermediate_view(
+        &mut self,
+        device: &Device,
+        width: u32,
+        height: u32,
+    ) -> &TextureView {
+        let needs_new = self
+            .intermediate
+            .as_ref()
+            .is_none_or(|(texture, _)| texture.width() != width || texture.height() != height);
+        if needs_new {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("vello.composite.source"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            self.intermediate = Some((texture, view));
+        }
+        &self.intermediate.as_ref().unwrap().1
+    }
+
+    /// Draws the region of the intermediate texture with its top left corner at `origin`
+    /// over the same region of `target`.
+    pub(crate) fn run(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        target: &TextureView,
+        origin: [u32; 2],
+        [width, height]: [u32; 2],
+        premultiplied: bool,
+    ) {
+        let pipelines = self
+            .pipelines
+            .get_or_insert_with(|| create_pipelines(device));
+        let Some((_, source)) = &self.intermediate else {
+            return;
+        };
+        let pipeline = if premultiplied {
+            &pipelines.premultiplied
+        } else {
+            &pipelines.straight
+        };
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("vello.composite"),
+            layout: &pipelines.bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(source),
+            }],
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.composite"),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("vello.composite"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            pass.set_viewport(
+                origin[0] as f32,
+                origin[1] as f32,
+                width as f32,
+                height as f32,
+                0.0,
+                1.0,
+            );
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        queue.submit([encoder.finish()]);
+    }
+}
+
+fn create_pipelines(device: &Device) -> Pipelines {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("vello.composite"),
+        source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("vello.composite"),
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        }],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("vello.composite"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = |premultiplied| {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("vello.composite"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: Some(over(premultiplied)),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        })
+    };
+    Pipelines {
+        straight: pipeline(false),
+        premultiplied: pipeline(true),
+        bind_group_layout,
+    }
+}
@@ -1,6 +1,9 @@
 // Copyright 2023 the Vello Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! Tools for debugging and profiling scenes.
+
+mod overlay;
 #[cfg(all(feature = "debug_layers", feature = "wgpu"))]
 mod renderer;
 #[cfg(all(feature = "debug_layers", feature = "wgpu"))]
@@ -8,9 +11,68 @@ mod validate;
 
 use std::fmt::Debug;
 
+pub use overlay::{FrameStats, draw_stats_overlay};
+
 #[cfg(all(feature = "debug_layers", feature = "wgpu"))]
 pub(crate) use renderer::*;
 
+/// A visualization which is rendered instead of the scene, set using [`RenderParams::debug`].
+///
+/// These are useful for working out why a complex fill renders incorrectly. Unlike
+/// [`DebugLayers`], most of them don't need the `debug_layers` feature.
+///
+/// [`RenderParams::debug`]: crate::RenderParams::debug
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DebugView {
+    /// Render the scene normally.
+    #[default]
+    None,
+    /// Render the coverage of every path, including clip paths, as white on black,
+    /// ignoring their brushes.
+    Coverage,
+    /// Render the winding number of the topmost path covering each pixel, with positive
+    /// winding numbers in green and negative winding numbers in red.
+    ///
+    /// Each unit of winding is a quarter of the full brightness, so the winding number can be
+    /// read for self-intersecting paths. At the edges of a path, the winding number is
+    /// averaged over the pixel.
+    ///
+    /// This is only available with [`AaConfig::Area`]. With the multisampling methods,
+    /// nothing is drawn.
+    ///
+    /// [`AaConfig::Area`]: crate::AaConfig::Area
+    Winding,
+    /// Render the scene normally, with the edges of the 16x16 pixel tiles used by the
+    /// rasterizer outlined.
+    Tiles,
+    /// Render a heatmap of the draw which painted last at each pixel, to find unexpected
+    /// overdraw.
+    ///
+    /// Draws are numbered in the order they were added to the scene, including clip layers,
+    /// and the heatmap runs from blue for the first draw, through green, to red for the last.
+    /// Pixels which no draw covers are black. A draw counts as covering a pixel once it covers
+    /// at least half of it, and clips are not applied, so draws which are clipped out still
+    /// show.
+    ///
+    /// This requires the `debug_layers` feature.
+    #[cfg(feature = "debug_layers")]
+    PaintOrder,
+}
+
+impl DebugView {
+    /// The value of this view in the shaders' `debug_view` config field.
+    pub(crate) fn to_u32(self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Coverage => 1,
+            Self::Winding => 2,
+            Self::Tiles => 3,
+            #[cfg(feature = "debug_layers")]
+            Self::PaintOrder => 4,
+        }
+    }
+}
+
 /// Bitflags for enabled debug operations.
 ///
 /// Currently, all layers additionally require the `debug_layers` feature.
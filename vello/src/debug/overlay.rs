@@ -0,0 +1,222 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+
+use peniko::kurbo::{Affine, BezPath, Cap, Point, Rect, RoundedRect, Stroke};
+use peniko::{Color, Fill};
+
+use crate::Scene;
+
+/// Statistics about a frame, shown by [`draw_stats_overlay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// The time taken by the frame.
+    pub frame_time: Duration,
+    /// The number of paths in the scene.
+    pub paths: u32,
+    /// The number of draw objects in the scene, including layers.
+    pub draw_objects: usize,
+    /// The size of the encoded scene, in bytes.
+    pub encoded_bytes: usize,
+}
+
+impl FrameStats {
+    /// Collects the statistics for `scene`, which took `frame_time` to render.
+    ///
+    /// This should be called before the overlay is drawn into `scene`, so that the overlay
+    /// itself isn't counted.
+    pub fn new(frame_time: Duration, scene: &Scene) -> Self {
+        let encoding = scene.encoding();
+        let encoded_bytes = size_of_val(encoding.path_tags.as_slice())
+            + size_of_val(encoding.path_data.as_slice())
+            + size_of_val(encoding.draw_tags.as_slice())
+            + size_of_val(encoding.draw_data.as_slice())
+            + size_of_val(encoding.transforms.as_slice())
+            + size_of_val(encoding.styles.as_slice());
+        Self {
+            frame_time,
+            paths: encoding.n_paths,
+            draw_objects: encoding.draw_tags.len(),
+            encoded_bytes,
+        }
+    }
+}
+
+/// The width of the overlay, in pixels.
+const WIDTH: f64 = 150.0;
+/// The height of the text, in pixels.
+const TEXT_SIZE: f64 = 10.0;
+const LINE_HEIGHT: f64 = 16.0;
+const PADDING: f64 = 6.0;
+/// The frame time which fills the whole bar, which is two frames at 60Hz.
+const BAR_FULL_SCALE: Duration = Duration::from_micros(33_333);
+
+/// Draws a small panel showing `stats` into `scene`, with its top left corner at `at`.
+///
+/// The panel shows the frames per second and frame time in milliseconds, with a bar which turns
+/// from green to yellow and red as the frame time exceeds the 60Hz and 30Hz budgets, followed by
+/// the number of paths and draw objects, and the size of the encoded scene in bytes.
+///
+/// The text is drawn as simple seven-segment style strokes, so that no font is needed, and the
+/// whole panel is only a handful of paths.
+pub fn draw_stats_overlay(scene: &mut Scene, stats: &FrameStats, at: Point) {
+    let transform = Affine::translate(at.to_vec2());
+    let height = PADDING * 2.0 + LINE_HEIGHT * 5.0;
+    scene.fill(
+        Fill::NonZero,
+        transform,
+        Color::from_rgba8(0, 0, 0, 180),
+        None,
+        &RoundedRect::new(0.0, 0.0, WIDTH, height, 4.0),
+    );
+
+    let seconds = stats.frame_time.as_secs_f64();
+    let fps = if seconds > 0.0 { 1.0 / seconds } else { 0.0 };
+    let lines = [
+        format!("{fps:5.1} FPS"),
+        format!("{:5.1}", seconds * 1000.0),
+        format!("{} PAtHS", stats.paths),
+        format!("{} obJ", stats.draw_objects),
+        format!("{} bytE", stats.encoded_bytes),
+    ];
+    let mut text = BezPath::new();
+    for (row, line) in lines.iter().enumerate() {
+        seven_segment_text(&mut text, Point::new(PADDING, row_y(row)), line);
+    }
+    scene.stroke(
+        &Stroke::new(1.5).with_caps(Cap::Round),
+        transform,
+        Color::WHITE,
+        None,
+        &text,
+    );
+
+    // The frame time bar, next to the frame time.
+    let bar_x = PADDING + 5.0 * advance(' ');
+    let bar_width = WIDTH - bar_x - PADDING;
+    let bar_y = row_y(1);
+    let fraction = (seconds / BAR_FULL_SCALE.as_secs_f64()).min(1.0);
+    let color = if fraction <= 0.5 {
+        Color::from_rgb8(0x4c, 0xd9, 0x64)
+    } else if fraction < 1.0 {
+        Color::from_rgb8(0xff, 0xcc, 0x00)
+    } else {
+        Color::from_rgb8(0xff, 0x3b, 0x30)
+    };
+    scene.fill(
+        Fill::NonZero,
+        transform,
+        Color::from_rgba8(255, 255, 255, 40),
+        None,
+        &Rect::new(bar_x, bar_y, bar_x + bar_width, bar_y + TEXT_SIZE),
+    );
+    scene.fill(
+        Fill::NonZero,
+        transform,
+        color,
+        None,
+        &Rect::new(
+            bar_x,
+            bar_y,
+            bar_x + bar_width * fraction,
+            bar_y + TEXT_SIZE,
+        ),
+    );
+    // Mark the 60Hz budget.
+    let budget_x = bar_x + bar_width * 0.5;
+    scene.fill(
+        Fill::NonZero,
+        transform,
+        Color::WHITE,
+        None,
+        &Rect::new(
+            budget_x - 0.5,
+            bar_y - 1.0,
+            budget_x + 0.5,
+            bar_y + TEXT_SIZE + 1.0,
+        ),
+    );
+}
+
+/// Returns the y coordinate of the top of the text in the given row.
+fn row_y(row: usize) -> f64 {
+    PADDING + LINE_HEIGHT * row as f64 + (LINE_HEIGHT - TEXT_SIZE) * 0.5
+}
+
+/// Returns the segments which are lit for `c`, as bits `0..7` for segments a to g.
+///
+/// Characters which can't be shown using seven segments are drawn as spaces.
+fn segments(c: char) -> u8 {
+    match c {
+        '0' | 'O' => 0x3f,
+        '1' => 0x06,
+        '2' => 0x5b,
+        '3' => 0x4f,
+        '4' => 0x66,
+        '5' | 'S' => 0x6d,
+        '6' => 0x7d,
+        '7' => 0x07,
+        '8' => 0x7f,
+        '9' => 0x6f,
+        'A' => 0x77,
+        'b' => 0x7c,
+        'C' => 0x39,
+        'd' => 0x5e,
+        'E' => 0x79,
+        'F' => 0x71,
+        'H' => 0x76,
+        'J' => 0x1e,
+        'L' => 0x38,
+        'n' => 0x54,
+        'o' => 0x5c,
+        'P' => 0x73,
+        'r' => 0x50,
+        't' => 0x78,
+        'U' => 0x3e,
+        'y' => 0x6e,
+        '-' => 0x40,
+        _ => 0,
+    }
+}
+
+fn advance(c: char) -> f64 {
+    if c == '.' {
+        TEXT_SIZE * 0.3
+    } else {
+        TEXT_SIZE * 0.9
+    }
+}
+
+/// Appends the strokes for `text` to `path`, with the top left of the first character at `origin`.
+fn seven_segment_text(path: &mut BezPath, origin: Point, text: &str) {
+    let width = TEXT_SIZE * 0.6;
+    let half = TEXT_SIZE * 0.5;
+    let mut x = origin.x;
+    let y = origin.y;
+    for c in text.chars() {
+        if c == '.' {
+            // A dot, drawn as a very short line with round caps.
+            path.move_to((x, y + TEXT_SIZE));
+            path.line_to((x + 0.5, y + TEXT_SIZE));
+        } else {
+            let bits = segments(c);
+            let lines = [
+                ((0.0, 0.0), (width, 0.0)),
+                ((width, 0.0), (width, half)),
+                ((width, half), (width, TEXT_SIZE)),
+                ((0.0, TEXT_SIZE), (width, TEXT_SIZE)),
+                ((0.0, half), (0.0, TEXT_SIZE)),
+                ((0.0, 0.0), (0.0, half)),
+                ((0.0, half), (width, half)),
+            ];
+            for (i, (start, end)) in lines.into_iter().enumerate() {
+                if bits & (1 << i) != 0 {
+                    path.move_to((x + start.0, y + start.1));
+                    path.line_to((x + end.0, y + end.1));
+                }
+            }
+        }
+        x += advance(c);
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measuring the GPU time taken to render, using timestamp queries.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
+
+use wgpu::{
+    Buffer, BufferUsages, CommandEncoder, ComputePassDescriptor, ComputePassTimestampWrites,
+    Device, QuerySet, Queue,
+};
+
+/// The readback buffer hasn't finished mapping.
+const MAP_WAITING: u8 = 0;
+/// The readback buffer is mapped, and contains the timestamps.
+const MAP_DONE: u8 = 1;
+/// Mapping the readback buffer failed.
+const MAP_FAILED: u8 = 2;
+
+/// Times the work submitted for a recording, by writing a timestamp before and after it.
+///
+/// The timestamps are read back asynchronously, so the time taken by a render is available
+/// once the device has been polled after the render has finished, which is usually at the
+/// start of the next frame.
+/// While a measurement is being read back, further renders aren't timed.
+pub(crate) struct GpuTimer {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+    readback_buffer: Buffer,
+    /// The state of mapping the readback buffer, or `None` if no measurement is in flight.
+    map_state: Option<Arc<AtomicU8>>,
+    /// Nanoseconds per timestamp tick.
+    period: f32,
+    last: Option<Duration>,
+}
+
+impl GpuTimer {
+    /// Size of the two timestamps.
+    const SIZE: u64 = 2 * size_of::<u64>() as u64;
+
+    /// Creates a timer, or returns `None` if `device` doesn't support timestamp queries.
+    pub(crate) fn new(device: &Device) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("vello.gpu_timer"),
+            ty: wgpu::QueryType::Timestamp,
+            count: 2,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vello.gpu_timer.resolve"),
+            size: Self::SIZE,
+            usage: BufferUsages::QUERY_RESOLVE | BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vello.gpu_timer.readback"),
+            size: Self::SIZE,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Some(Self {
+            query_set,
+            resolve_buffer,
+            readback_buffer,
+            map_state: None,
+            period: 1.0,
+            last: None,
+        })
+    }
+
+    /// The GPU time taken by the most recent render which has been read back.
+    pub(crate) fn last(&self) -> Option<Duration> {
+        self.last
+    }
+
+    /// Writes the starting timestamp into `encoder`, before any other work.
+    ///
+    /// Returns `false` if the render can't be timed, because the previous measurement
+    /// is still being read back.
+    pub(crate) fn begin(&mut self, encoder: &mut CommandEncoder) -> bool {
+        self.read_back();
+        if self.map_state.is_some() {
+            return false;
+        }
+        self.write_timestamp(encoder, Some(0), None);
+        true
+    }
+
+    /// Writes the ending timestamp into `encoder`, after all other work, and copies the
+    /// timestamps into the readback buffer.
+    pub(crate) fn end(&mut self, encoder: &mut CommandEncoder, queue: &Queue) {
+        self.write_timestamp(encoder, None, Some(1));
+        encoder.resolve_query_set(&self.query_set, 0..2, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            Self::SIZE,
+        );
+        self.period = queue.get_timestamp_period();
+    }
+
+    /// Starts reading back the timestamps, once the encoder passed to [`Self::end`] has been
+    /// submitted.
+    pub(crate) fn map(&mut self) {
+        let map_state = Arc::new(AtomicU8::new(MAP_WAITING));
+        let callback_state = map_state.clone();
+        self.readback_buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let state = if result.is_ok() { MAP_DONE } else { MAP_FAILED };
+                callback_state.store(state, Ordering::Release);
+            });
+        self.map_state = Some(map_state);
+    }
+
+    /// Reads the timestamps of the measurement in flight, if they are available.
+    fn read_back(&mut self) {
+        let Some(map_state) = &self.map_state else {
+            return;
+        };
+        match map_state.load(Ordering::Acquire) {
+            MAP_DONE => {
+                {
+                    let data = self.readback_buffer.slice(..).get_mapped_range();
+                    let start = u64::from_le_bytes(data[0..8].try_into().unwrap());
+                    let end = u64::from_le_bytes(data[8..16].try_into().unwrap());
+                    let nanos = end.saturating_sub(start) as f64 * f64::from(self.period);
+                    self.last = Some(Duration::from_nanos(nanos as u64));
+                }
+                self.readback_buffer.unmap();
+                self.map_state = None;
+            }
+            MAP_FAILED => self.map_state = None,
+            _ => {}
+        }
+    }
+
+    fn write_timestamp(
+        &self,
+        encoder: &mut CommandEncoder,
+        beginning_of_pass_write_index: Option<u32>,
+        end_of_pass_write_index: Option<u32>,
+    ) {
+        // An empty pass, which only writes the timestamp. This only needs the
+        // `TIMESTAMP_QUERY` feature, unlike writing timestamps directly into the encoder.
+        let _pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("vello.gpu_timer"),
+            timestamp_writes: Some(ComputePassTimestampWrites {
+                query_set: &self.query_set,
+                beginning_of_pass_write_index,
+                end_of_pass_write_index,
+            }),
+        });
+    }
+}
@@ -69,6 +69,13 @@
 //!          width,
 //!          height,
 //!          antialiasing_method: AaConfig::Msaa16,
+//!          text_gamma: 1.0,
+//!          alpha_to_coverage: false,
+//!          debug: DebugView::None,
+//!          premultiplied_alpha: false,
+//!          flatten_tolerance: None,
+//!          viewport: None,
+//!          clear_mode: vello::ClearMode::Clear,
 //!       },
 //!    )
 //!    .expect("Failed to render to a texture");
@@ -106,12 +113,35 @@
     reason = "Deferred, only apply in some feature sets so not expect"
 )]
 
-mod debug;
+pub mod anim;
+#[cfg(feature = "canvas")]
+pub mod canvas;
+mod color_ext;
+mod color_matrix;
+mod colormap;
+#[cfg(feature = "wgpu")]
+mod composite;
+pub mod debug;
+#[cfg(feature = "wgpu")]
+mod gpu_timer;
+#[cfg(feature = "wgpu")]
+mod mipmaps;
+pub mod path;
+mod perspective;
+#[cfg(feature = "wgpu")]
+mod post_process;
 mod recording;
 mod render;
 mod scene;
+mod scene_patch;
 mod shaders;
+pub mod stroke;
+pub mod text;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "ui_texture")]
+pub mod ui_texture;
 #[cfg(feature = "wgpu")]
 pub mod util;
 #[cfg(feature = "wgpu")]
@@ -142,18 +172,29 @@ use peniko::ImageData;
 #[cfg(feature = "wgpu")]
 pub use wgpu;
 
-pub use scene::{DrawGlyphs, Scene};
-pub use vello_encoding::{Glyph, NormalizedCoord};
+pub use color_ext::ColorExt;
+pub use color_matrix::{Adjust, ColorMatrix};
+pub use colormap::Colormap;
+pub use debug::DebugView;
+pub use perspective::Perspective;
+#[cfg(feature = "wgpu")]
+pub use post_process::PostProcessShader;
+pub use scene::{
+    BoxShadowInstance, CostEstimate, DrawGlyphs, DrawId, GlowKind, LayerLabel, OutlineStyle,
+    PreparedPath, ScaleMode, Scene, ShadowMask, StackingContext,
+};
+pub use scene_patch::ScenePatch;
+pub use vello_encoding::{Glyph, ImageColorSpace, NormalizedCoord};
 
 use low_level::ShaderId;
 #[cfg(feature = "wgpu")]
-use low_level::{BumpAllocators, FullShaders, Recording, Render};
+use low_level::{BumpAllocators, Command, FullShaders, Recording, Render, ResourceProxy};
 use thiserror::Error;
 
 #[cfg(feature = "wgpu")]
 use debug::DebugLayers;
 #[cfg(feature = "wgpu")]
-use vello_encoding::Resolver;
+use vello_encoding::{DrawTag, Resolver};
 #[cfg(feature = "wgpu")]
 use wgpu_engine::{ExternalResource, WgpuEngine};
 
@@ -251,6 +292,58 @@ impl FromIterator<AaConfig> for AaSupport {
     }
 }
 
+/// The optional capabilities of a device, and the anti-aliasing methods of a [`Renderer`],
+/// returned by [`Renderer::capabilities`].
+///
+/// Applications can use this to disable optional effects which the device doesn't support,
+/// rather than waiting for them to fail.
+#[cfg(feature = "wgpu")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RendererCaps {
+    /// Whether 32-bit floating point textures can be blended when rendered to, as is useful
+    /// for HDR render targets.
+    ///
+    /// This is whether the adapter reports [`wgpu::TextureFormatFeatureFlags::BLENDABLE`]
+    /// for [`wgpu::TextureFormat::Rgba32Float`].
+    ///
+    /// Vello itself always renders to [`wgpu::TextureFormat::Rgba8Unorm`].
+    pub float_targets: bool,
+    /// Whether GPU timestamp queries are supported ([`wgpu::Features::TIMESTAMP_QUERY`]).
+    pub timestamps: bool,
+    /// Whether subgroup operations are supported in shaders ([`wgpu::Features::SUBGROUP`]).
+    pub subgroup_ops: bool,
+    /// The largest width or height of a 2D texture, which limits the size of a render target.
+    ///
+    /// Larger images can be rendered using [`Renderer::render_to_image_tiled`].
+    pub max_texture_size: u32,
+    /// The anti-aliasing methods which can be used with the renderer.
+    ///
+    /// Vello implements MSAA in its compute shaders, rather than using the device's
+    /// multisampling, so every device supports each method. This is the
+    /// [`RendererOptions::antialiasing_support`] which the renderer was created with.
+    pub antialiasing: AaSupport,
+}
+
+/// A pass which a [`Renderer`] runs to render a scene, returned by
+/// [`Renderer::describe_pipeline`].
+#[cfg(feature = "wgpu")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassInfo {
+    /// The label of the pass, such as `"vello.coarse"`.
+    ///
+    /// This is also the label of the pass in GPU debuggers and profilers.
+    pub label: &'static str,
+    /// The labels of the buffers and images which the pass reads or writes, in the order of
+    /// their bindings.
+    ///
+    /// A buffer written by one pass and bound to a later pass is a dependency between them.
+    /// The image rendered into is labelled `"vello.target"`.
+    pub resources: Vec<&'static str>,
+    /// Whether the pass runs on the CPU, rather than the GPU, as when the renderer was created
+    /// with [`RendererOptions::use_cpu`].
+    pub cpu: bool,
+}
+
 /// Errors that can occur in Vello.
 #[derive(Error, Debug)]
 #[non_exhaustive]
@@ -259,6 +352,12 @@ pub enum Error {
     #[cfg(feature = "wgpu")]
     #[error("Couldn't find suitable device")]
     NoCompatibleDevice,
+    /// The device can't run compute shaders, which Vello's pipeline is made of.
+    ///
+    /// This is the case for WebGL2 and other downlevel backends.
+    #[cfg(feature = "wgpu")]
+    #[error("Device doesn't support compute shaders, which are required by Vello")]
+    ComputeShadersUnsupported,
     /// Failed to create surface.
     /// See [`wgpu::CreateSurfaceError`] for more information.
     #[cfg(feature = "wgpu")]
@@ -267,11 +366,18 @@ pub enum Error {
     /// Surface doesn't support the required texture formats.
     /// Make sure that you have a surface which provides one of
     /// [`TextureFormat::Rgba8Unorm`][wgpu::TextureFormat::Rgba8Unorm]
-    /// or [`TextureFormat::Bgra8Unorm`][wgpu::TextureFormat::Bgra8Unorm] as texture formats.
+    /// or [`TextureFormat::Bgra8Unorm`][wgpu::TextureFormat::Bgra8Unorm] as texture formats,
+    /// or their sRGB variants.
     // TODO: Why does this restriction exist?
     #[cfg(feature = "wgpu")]
-    #[error("Couldn't find `Rgba8Unorm` or `Bgra8Unorm` texture formats for surface")]
+    #[error(
+        "Couldn't find `Rgba8Unorm` or `Bgra8Unorm` texture formats (or their sRGB variants) for surface"
+    )]
     UnsupportedSurfaceFormat,
+    /// The options given to [`RendererOptionsBuilder::build`] can't be used to create a renderer.
+    #[cfg(feature = "wgpu")]
+    #[error("Invalid renderer options: {0}")]
+    InvalidRendererOptions(&'static str),
 
     /// Used a buffer inside a recording while it was not available.
     /// Check if you have created it and not freed before its last usage.
@@ -293,6 +399,90 @@ pub enum Error {
     #[error("wgpu Error from scope")]
     WgpuErrorFromScope(#[from] wgpu::Error),
 
+    /// Failed to read or write a pipeline cache file.
+    #[cfg(feature = "wgpu")]
+    #[error("Failed to access pipeline cache file")]
+    PipelineCacheIo(#[source] std::io::Error),
+
+    /// The pixel passed to [`Renderer::read_pixel`] is outside of the texture.
+    #[cfg(feature = "wgpu")]
+    #[error("Pixel ({0}, {1}) is outside of the texture")]
+    PixelOutOfBounds(u32, u32),
+
+    /// The texture passed to [`Renderer::read_pixel`] doesn't have a supported format.
+    #[cfg(feature = "wgpu")]
+    #[error("Can't read pixels from a texture with format {0:?}")]
+    UnsupportedReadFormat(wgpu::TextureFormat),
+
+    /// The tile size passed to [`Renderer::render_to_image_tiled`] is zero, or larger
+    /// than the device supports.
+    #[cfg(feature = "wgpu")]
+    #[error("Tile size {0} is not supported by the device")]
+    InvalidTileSize(u32),
+
+    /// The texture passed to [`Renderer::validate_target`] can't be rendered into.
+    #[cfg(feature = "wgpu")]
+    #[error(
+        "Target texture has format {format:?} and usages {usages:?}, but must be `Rgba8Unorm` with usages {required_usages:?}"
+    )]
+    IncompatibleTarget {
+        /// The format of the texture.
+        format: wgpu::TextureFormat,
+        /// The usages of the texture.
+        usages: wgpu::TextureUsages,
+        /// The usages which the texture must have.
+        required_usages: wgpu::TextureUsages,
+    },
+
+    /// The array layer passed to [`Renderer::array_layer_target`] doesn't exist in the texture.
+    #[cfg(feature = "wgpu")]
+    #[error("Array layer {layer} is out of bounds for a texture with {layers} layers")]
+    ArrayLayerOutOfBounds {
+        /// The requested layer.
+        layer: u32,
+        /// The number of array layers in the texture.
+        layers: u32,
+    },
+
+    /// The device or queue passed to the [`Renderer`] isn't the one it was created with.
+    ///
+    /// A renderer's pipelines and resources belong to the device passed to [`Renderer::new`],
+    /// so each device needs its own renderer.
+    #[cfg(feature = "wgpu")]
+    #[error("Renderer was used with a different device or queue than it was created with")]
+    DeviceMismatch,
+
+    /// The patch passed to [`Scene::apply_patch`] was created from a different scene.
+    #[error("Scene patch doesn't apply to this scene")]
+    ScenePatchMismatch,
+
+    /// The scene failed the checks enabled by [`RendererOptions::debug_validation`], such as
+    /// having a layer which was pushed but not popped.
+    #[cfg(feature = "wgpu")]
+    #[error("Invalid scene: {0}")]
+    InvalidScene(String),
+
+    /// The scene could need more memory for nested layers than allowed by
+    /// [`RendererOptions::intermediate_budget_bytes`].
+    #[cfg(feature = "wgpu")]
+    #[error(
+        "Layer {layer} could need {required} bytes of intermediate memory, over the budget of {budget} bytes"
+    )]
+    OutOfIntermediateMemory {
+        /// The index of the first layer which could exceed the budget, counting the layers in
+        /// the order they were pushed, from zero.
+        layer: usize,
+        /// The memory which could be needed once this layer is pushed, in bytes.
+        required: u64,
+        /// The budget, after being limited to the device's largest storage buffer.
+        budget: u64,
+    },
+
+    /// Failed to write an image in [`testing`].
+    #[cfg(feature = "testing")]
+    #[error("Failed to write test image")]
+    TestImageEncoding(#[from] png::EncodingError),
+
     /// Failed to create [`GpuProfiler`].
     /// See [`wgpu_profiler::CreationError`] for more information.
     #[cfg(feature = "wgpu-profiler")]
@@ -318,19 +508,21 @@ pub(crate) type Result<T, E = Error> = std::result::Result<T, E>;
 /// Currently, each renderer only supports a single surface format, if it
 /// supports drawing to surfaces at all.
 /// This is an assumption which is known to be limiting, and is planned to change.
+///
+/// A renderer is bound to the device it was created with, and to the queue of that device which
+/// it is first used with. Rendering with a different device or queue returns
+/// [`Error::DeviceMismatch`], so when using multiple devices, create a renderer for each of them.
 #[cfg(feature = "wgpu")]
 pub struct Renderer {
-    #[cfg_attr(
-        not(feature = "hot_reload"),
-        expect(
-            dead_code,
-            reason = "Options are only used to reinitialise on a hot reload"
-        )
-    )]
     options: RendererOptions,
+    device: Device,
+    queue: Option<Queue>,
     engine: WgpuEngine,
     resolver: Resolver,
     shaders: FullShaders,
+    post_process: Option<post_process::PostProcess>,
+    mipmaps: mipmaps::Mipmaps,
+    composite: composite::Composite,
     #[cfg(feature = "debug_layers")]
     debug: debug::DebugRenderer,
     #[cfg(feature = "wgpu-profiler")]
@@ -365,6 +557,123 @@ pub struct RenderParams {
     /// The anti-aliasing algorithm. The selected algorithm must have been initialized while
     /// constructing the `Renderer`.
     pub antialiasing_method: AaConfig,
+
+    /// The gamma applied to the coverage of glyphs, similar to the gamma adjustment of `FreeType`.
+    ///
+    /// Coverage is blended in sRGB space, which can make small text look too light or too
+    /// heavy depending on the colors involved.
+    /// Values above `1.0` increase partial coverage (making text heavier), and values
+    /// below `1.0` decrease it (making text lighter).
+    /// Only glyphs drawn with [`Scene::draw_glyphs`] are affected.
+    ///
+    /// Use `1.0` to leave glyph coverage unchanged. Values which are not finite and
+    /// positive are treated as `1.0`.
+    pub text_gamma: f32,
+
+    /// Whether the alpha of blurred rounded rectangles is converted to sample coverage
+    /// when using [`AaConfig::Msaa8`] or [`AaConfig::Msaa16`].
+    ///
+    /// Normally, the blur's alpha is multiplied by the MSAA coverage of the shape which bounds
+    /// it, so soft shadow edges are anti-aliased twice, which can shimmer as they move.
+    /// When this is enabled, the alpha is instead quantized to the number of samples and
+    /// combined with the shape's coverage as overlapping sample masks, like hardware
+    /// alpha-to-coverage. This gives consistent edges at the cost of visible banding in
+    /// the blur, with 8 or 16 levels.
+    ///
+    /// This has no effect with [`AaConfig::Area`].
+    pub alpha_to_coverage: bool,
+
+    /// A debug visualization to render instead of the scene, such as the winding number
+    /// of each path.
+    ///
+    /// Use [`DebugView::None`] to render the scene normally.
+    pub debug: DebugView,
+
+    /// Whether the output is written with premultiplied alpha, rather than straight alpha.
+    ///
+    /// Use this when the rendered texture is composited over other content by another
+    /// compositor which expects premultiplied alpha, such as a surface with a premultiplied
+    /// composite alpha mode.
+    /// The [`base_color`](Self::base_color) should then be transparent, so that the
+    /// output only contains the scene. Compositing the output with the
+    /// premultiplied "over" operator, `out = src + dst * (1 - src.a)`, gives the same result
+    /// (within rounding) as rendering the scene with the destination as the base color,
+    /// including on anti-aliased and blurred edges.
+    ///
+    /// With straight alpha, the colors of nearly transparent pixels are divided by a small
+    /// alpha after being quantized, so edges lose precision, and must be premultiplied again
+    /// by the compositor.
+    pub premultiplied_alpha: bool,
+
+    /// The maximum distance, in pixels, between curves and the lines which they are
+    /// flattened to, overriding the default of 0.25 pixels.
+    ///
+    /// Paths are flattened on the GPU after they have been transformed, so the tolerance
+    /// is in the coordinate system of the target. Curves which are scaled up therefore
+    /// automatically use more lines, and stay smooth at any zoom level, while curves which are
+    /// scaled down use fewer lines. A larger tolerance uses fewer lines, which can be
+    /// faster for scenes with many small curves, at the cost of visible facets.
+    ///
+    /// Use `None` for the default. Values which are not finite and positive are
+    /// treated as `None`.
+    pub flatten_tolerance: Option<f64>,
+
+    /// The region of the target to render into, in pixels, such as for rendering several
+    /// scenes into different regions of one texture.
+    ///
+    /// The scene is rendered as if the viewport were the whole target: its origin is at the
+    /// top left corner of the viewport, and the [`base_color`](Self::base_color) is only
+    /// drawn within it. Pixels of the target outside the viewport are left unchanged.
+    /// [`width`](Self::width) and [`height`](Self::height) are still the size of the whole
+    /// target.
+    ///
+    /// The viewport is rounded to whole pixels, and clipped to the target. Nothing is
+    /// rendered if it is then empty. Use `None` to render into the whole target.
+    pub viewport: Option<kurbo::Rect>,
+
+    /// Whether the target is cleared before the scene is drawn, or the scene is drawn over
+    /// its existing contents, such as for accumulating trails over several frames.
+    ///
+    /// Use [`ClearMode::Clear`] to replace the contents of the target.
+    pub clear_mode: ClearMode,
+}
+
+impl RenderParams {
+    /// Returns the position and size of the region of the target which is rendered into,
+    /// in whole pixels, or `None` if it is empty.
+    pub(crate) fn viewport_region(&self) -> Option<([u32; 2], [u32; 2])> {
+        let Some(viewport) = self.viewport else {
+            return Some(([0, 0], [self.width, self.height]));
+        };
+        // Casting saturates, so negative and NaN coordinates are clamped to zero.
+        let x0 = (viewport.x0.round() as u32).min(self.width);
+        let y0 = (viewport.y0.round() as u32).min(self.height);
+        let x1 = (viewport.x1.round() as u32).min(self.width);
+        let y1 = (viewport.y1.round() as u32).min(self.height);
+        (x1 > x0 && y1 > y0).then_some(([x0, y0], [x1 - x0, y1 - y0]))
+    }
+}
+
+/// How the existing contents of the target are treated when rendering, set using
+/// [`RenderParams::clear_mode`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ClearMode {
+    /// The target is replaced with the [`base_color`](RenderParams::base_color) and the scene.
+    #[default]
+    Clear,
+    /// The [`base_color`](RenderParams::base_color) and the scene are drawn over the existing
+    /// contents of the target, using the "over" operator.
+    ///
+    /// Rendering a scene several times accumulates it, so drawing a rectangle with an alpha of
+    /// `0.3` twice gives an alpha of `0.51`. A partially transparent base color fades out what
+    /// was previously rendered, which can be used to draw trails behind moving content.
+    ///
+    /// The scene is rendered into an intermediate texture, which is then drawn over the target
+    /// using a render pass, so the target must have the
+    /// [`wgpu::TextureUsages::RENDER_ATTACHMENT`] usage. Blending is exact with
+    /// [premultiplied alpha](RenderParams::premultiplied_alpha). With straight alpha, colors
+    /// are only exact where the existing contents are opaque, though alpha is always exact.
+    None,
 }
 
 #[cfg(feature = "wgpu")]
@@ -387,12 +696,26 @@ pub struct RendererOptions {
 
     /// How many threads to use for initialisation of shaders.
     ///
-    /// Use `Some(1)` to use a single thread. This is recommended when on macOS
-    /// (see <https://github.com/bevyengine/bevy/pull/10812#discussion_r1496138004>)
+    /// Most of the time taken by [`Renderer::new`] is spent compiling a compute pipeline for
+    /// each stage of Vello's pipeline, and for each enabled
+    /// [anti-aliasing method](Self::antialiasing_support). These are independent, so they
+    /// can be compiled on several threads at once, which
+    /// reduces the time to create a renderer on a cold start roughly in proportion to the
+    /// number of threads, up to the number of pipelines. The threads are only used while the
+    /// renderer is created. When the pipelines are loaded from a warm
+    /// [pipeline cache](Self::pipeline_cache), there is much less work to share out, so the
+    /// number of threads matters less.
     ///
-    /// Set to `None` to use a heuristic which will use many but not all threads
+    /// Use `Some(1)` to compile the pipelines on the calling thread, without starting any
+    /// other threads. This is recommended when on macOS
+    /// (see <https://github.com/bevyengine/bevy/pull/10812#discussion_r1496138004>),
+    /// and on machines with a single core, where extra threads would only add overhead.
     ///
-    /// Has no effect on WebAssembly
+    /// Set to `None` to use a heuristic which will use many but not all threads.
+    /// This leaves two cores unused, but uses at least two threads.
+    ///
+    /// Has no effect on WebAssembly, where the pipelines are always created on the calling
+    /// thread.
     ///
     /// Will default to `None` on most platforms, `Some(1)` on macOS.
     pub num_init_threads: Option<NonZeroUsize>,
@@ -400,7 +723,70 @@ pub struct RendererOptions {
     /// The pipeline cache to use when creating the shaders.
     ///
     /// For much more discussion of expected usage patterns, see the documentation on that type.
+    /// To persist the cache between runs, load it using [`util::load_pipeline_cache`],
+    /// and write it back using [`Renderer::save_pipeline_cache`] once the renderer is created.
     pub pipeline_cache: Option<wgpu::PipelineCache>,
+
+    /// Custom sample positions for MSAA, to use instead of the default positions.
+    ///
+    /// Each sample is an `[x, y]` position within the pixel, where both coordinates are in the
+    /// range `0.0..1.0`. A pattern with 8 samples is used for [`AaConfig::Msaa8`], and one with
+    /// 16 samples for [`AaConfig::Msaa16`]; the other method uses its default positions.
+    ///
+    /// Vello's fine rasterization assumes that the samples are spread evenly over the rows of
+    /// the pixel, so the pattern must have exactly one sample in each of its 8 or 16 rows.
+    /// The x positions can be chosen freely, such as for a rotated grid, and each y position
+    /// is moved to the center of its row.
+    /// Patterns which don't meet these requirements are ignored, and a warning is logged when
+    /// the renderer is created. They are rejected by [`RendererOptionsBuilder::build`].
+    ///
+    /// The default is `None`, which uses the default positions.
+    pub msaa_sample_pattern: Option<Vec<[f32; 2]>>,
+
+    /// If true, check scenes and GPU work for errors, rather than rendering them as well as
+    /// possible.
+    ///
+    /// Before each scene is rendered, its encoding is checked using [`Encoding::validate`],
+    /// and [`Error::InvalidScene`] is returned if it is invalid, such as if a layer was pushed
+    /// without being popped, or popped without being pushed. Without this, open layers are
+    /// popped at the end of the scene, and extra pops are ignored.
+    /// Validation errors reported by wgpu while rendering are also returned, as
+    /// [`Error::WgpuErrorFromScope`], rather than being passed to the device's error handler.
+    /// On WebAssembly, this is only done by [`Renderer::render_to_texture_async`], as
+    /// the other methods can't wait for the errors.
+    ///
+    /// The lines produced on the GPU can also be checked by rendering with the `VALIDATION`
+    /// debug layer, when the `debug_layers` feature is enabled.
+    ///
+    /// These checks take time proportional to the size of the scene, and wait for the GPU
+    /// work to be submitted, so this should only be enabled during development.
+    /// The default is `false`.
+    ///
+    /// [`Encoding::validate`]: vello_encoding::Encoding::validate
+    pub debug_validation: bool,
+
+    /// The most memory, in bytes, which may be used to store deeply nested layers.
+    ///
+    /// Vello blends layers in place, rather than allocating an intermediate texture for each
+    /// layer. Each 16×16 pixel tile keeps up to four nested layers in GPU registers, and deeper
+    /// layers are stored in a scratch buffer, which uses 1 KiB for each tile and each layer
+    /// beyond the fourth. This limits the size of that buffer, so can be used to limit memory use
+    /// on devices with little memory, or to allow more deeply nested layers. The buffer is sized
+    /// for the most deeply nested layers of each scene, and is at least 4 MiB, unless the budget
+    /// is smaller than that.
+    ///
+    /// When set, each scene is checked before it is rendered, and
+    /// [`Error::OutOfIntermediateMemory`] is returned with the first layer which could exceed
+    /// the budget, rather than rendering the scene. The check assumes that the most deeply
+    /// nested layers cover the whole target (or [viewport](RenderParams::viewport)), so scenes
+    /// whose deep layers are small may be rejected even though they would fit. The budget is
+    /// limited to the device's [`max_storage_buffer_binding_size`].
+    ///
+    /// The default is `None`, which uses a 4 MiB buffer without checking scenes. Scenes which
+    /// need more than this are not rendered.
+    ///
+    /// [`max_storage_buffer_binding_size`]: wgpu::Limits::max_storage_buffer_binding_size
+    pub intermediate_budget_bytes: Option<u64>,
 }
 
 #[cfg(feature = "wgpu")]
@@ -414,7 +800,144 @@ impl Default for RendererOptions {
             #[cfg(not(target_os = "macos"))]
             num_init_threads: None,
             pipeline_cache: None,
+            msaa_sample_pattern: None,
+            debug_validation: false,
+            intermediate_budget_bytes: None,
+        }
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl RendererOptions {
+    /// Creates a builder for `RendererOptions`, starting from the [default](Self::default) options.
+    ///
+    /// Unlike constructing the options directly, [`RendererOptionsBuilder::build`] checks that
+    /// the options can be used to create a [`Renderer`].
+    pub fn builder() -> RendererOptionsBuilder {
+        RendererOptionsBuilder {
+            options: Self::default(),
+        }
+    }
+}
+
+/// Builder for [`RendererOptions`], created using [`RendererOptions::builder`].
+#[cfg(feature = "wgpu")]
+pub struct RendererOptionsBuilder {
+    options: RendererOptions,
+}
+
+#[cfg(feature = "wgpu")]
+impl RendererOptionsBuilder {
+    /// Sets whether to run all stages up to fine rasterization on the CPU.
+    ///
+    /// This is only useful for debugging, as it is expected to have poor performance.
+    /// The default value is `false`.
+    ///
+    /// See [`RendererOptions::use_cpu`].
+    #[must_use]
+    pub fn use_cpu(mut self, use_cpu: bool) -> Self {
+        self.options.use_cpu = use_cpu;
+        self
+    }
+
+    /// Sets the anti-aliasing methods which can be used with the renderer.
+    ///
+    /// A fine rasterization pipeline is compiled for each enabled method, so enabling only the
+    /// methods you use reduces startup time. At least one method must be enabled.
+    /// The default value is [`AaSupport::all`].
+    ///
+    /// See [`RendererOptions::antialiasing_support`].
+    #[must_use]
+    pub fn antialiasing_support(mut self, antialiasing_support: AaSupport) -> Self {
+        self.options.antialiasing_support = antialiasing_support;
+        self
+    }
+
+    /// Sets how many threads to use to compile shaders when the renderer is created.
+    ///
+    /// More threads reduce the time taken to create the renderer, and `Some(1)` compiles them
+    /// on the calling thread. `None` uses a heuristic which uses many but not all threads.
+    /// The default value is `Some(1)` on macOS, and `None` elsewhere.
+    /// This has no effect on WebAssembly.
+    ///
+    /// See [`RendererOptions::num_init_threads`].
+    #[must_use]
+    pub fn num_init_threads(mut self, num_init_threads: Option<NonZeroUsize>) -> Self {
+        self.options.num_init_threads = num_init_threads;
+        self
+    }
+
+    /// Sets the pipeline cache used when compiling shaders.
+    ///
+    /// The default value is `None`, meaning that no cache is used.
+    ///
+    /// See [`RendererOptions::pipeline_cache`].
+    #[must_use]
+    pub fn pipeline_cache(mut self, pipeline_cache: Option<wgpu::PipelineCache>) -> Self {
+        self.options.pipeline_cache = pipeline_cache;
+        self
+    }
+
+    /// Sets custom sample positions for MSAA.
+    ///
+    /// The default value is `None`, which uses the default positions.
+    ///
+    /// See [`RendererOptions::msaa_sample_pattern`] for the requirements of the pattern.
+    #[must_use]
+    pub fn msaa_sample_pattern(mut self, msaa_sample_pattern: Option<Vec<[f32; 2]>>) -> Self {
+        self.options.msaa_sample_pattern = msaa_sample_pattern;
+        self
+    }
+
+    /// Sets whether to check scenes and GPU work for errors when rendering.
+    ///
+    /// This should only be enabled during development.
+    /// The default value is `false`.
+    ///
+    /// See [`RendererOptions::debug_validation`].
+    #[must_use]
+    pub fn debug_validation(mut self, debug_validation: bool) -> Self {
+        self.options.debug_validation = debug_validation;
+        self
+    }
+
+    /// Sets the most memory, in bytes, which may be used to store layers nested more than four
+    /// deep.
+    ///
+    /// Scenes which could exceed the budget fail to render with
+    /// [`Error::OutOfIntermediateMemory`]. The default value is `None`, which uses a 4 MiB
+    /// buffer without checking scenes.
+    ///
+    /// See [`RendererOptions::intermediate_budget_bytes`].
+    #[must_use]
+    pub fn intermediate_budget_bytes(mut self, intermediate_budget_bytes: Option<u64>) -> Self {
+        self.options.intermediate_budget_bytes = intermediate_budget_bytes;
+        self
+    }
+
+    /// Validates and returns the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRendererOptions`] if no anti-aliasing method is enabled,
+    /// as such a renderer could not render anything, or if the
+    /// [MSAA sample pattern](RendererOptions::msaa_sample_pattern) doesn't have exactly one
+    /// sample in each row of the pixel, which [`Renderer::new`] would ignore.
+    pub fn build(self) -> Result<RendererOptions> {
+        let aa_support = &self.options.antialiasing_support;
+        if !(aa_support.area || aa_support.msaa8 || aa_support.msaa16) {
+            return Err(Error::InvalidRendererOptions(
+                "at least one anti-aliasing method must be supported",
+            ));
         }
+        if let Some(pattern) = &self.options.msaa_sample_pattern
+            && vello_encoding::make_mask_lut_for_pattern(pattern).is_none()
+        {
+            return Err(Error::InvalidRendererOptions(
+                "the MSAA sample pattern must have exactly one sample in each row of the pixel",
+            ));
+        }
+        Ok(self.options)
     }
 }
 
@@ -425,27 +948,95 @@ struct RenderResult {
     captured: Option<render::CapturedBuffers>,
 }
 
+/// The callback passed to [`Renderer::new_with_progress`].
+#[cfg(feature = "wgpu")]
+type Progress<'a> = dyn FnMut(&str, usize, usize) + 'a;
+
 #[cfg(feature = "wgpu")]
 impl Renderer {
     /// Creates a new renderer for the specified device.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ComputeShadersUnsupported`] if `device` can't run compute shaders, such as
+    /// on WebGL2. Vello has no fallback for these devices; see the crate's README for the options
+    /// on the web.
     pub fn new(device: &Device, options: RendererOptions) -> Result<Self> {
+        Self::new_inner(device, options, None)
+    }
+
+    /// Creates a new renderer for the specified device, reporting progress as its pipelines are compiled.
+    ///
+    /// Compiling the pipelines can take a noticeable amount of time, so this can be used to
+    /// display progress (e.g. on a splash screen).
+    /// `progress` is called with a label for the stage which has just finished, the number of
+    /// stages completed so far, and the total number of stages.
+    /// On native platforms, each stage is a single pipeline.
+    /// On WebAssembly, the pipelines are compiled on the calling thread, and a single
+    /// stage is reported once they are all created.
+    ///
+    /// The resulting renderer is the same as one created using [`Renderer::new`].
+    pub fn new_with_progress(
+        device: &Device,
+        options: RendererOptions,
+        mut progress: impl FnMut(&str, usize, usize),
+    ) -> Result<Self> {
+        Self::new_inner(device, options, Some(&mut progress))
+    }
+
+    fn new_inner(
+        device: &Device,
+        options: RendererOptions,
+        progress: Option<&mut Progress<'_>>,
+    ) -> Result<Self> {
+        // Downlevel devices without compute shaders, such as WebGL2, report zero for the
+        // compute limits. Fail early with a clear error, rather than with a validation error.
+        let limits = device.limits();
+        if limits.max_compute_invocations_per_workgroup == 0
+            || limits.max_storage_buffers_per_shader_stage == 0
+        {
+            return Err(Error::ComputeShadersUnsupported);
+        }
+        if let Some(pattern) = &options.msaa_sample_pattern
+            && vello_encoding::make_mask_lut_for_pattern(pattern).is_none()
+        {
+            log::warn!(
+                "Ignoring MSAA sample pattern with {} samples, which doesn't have exactly one sample in each row of the pixel",
+                pattern.len()
+            );
+        }
         let mut engine = WgpuEngine::new(options.use_cpu, options.pipeline_cache.clone());
-        // If we are running in parallel (i.e. the number of threads is not 1)
-        if options.num_init_threads != NonZeroUsize::new(1) {
+        // If we are running in parallel (i.e. the number of threads is not 1), or need to
+        // report progress for each shader.
+        if options.num_init_threads != NonZeroUsize::new(1) || progress.is_some() {
             #[cfg(not(target_arch = "wasm32"))]
             engine.use_parallel_initialisation();
         }
         let shaders = shaders::full_shaders(device, &mut engine, &options)?;
         #[cfg(not(target_arch = "wasm32"))]
-        engine.build_shaders_if_needed(device, options.num_init_threads);
+        engine.build_shaders_if_needed(
+            device,
+            options.num_init_threads,
+            progress.unwrap_or(&mut |_, _, _| {}),
+        );
+        #[cfg(target_arch = "wasm32")]
+        if let Some(progress) = progress {
+            progress("vello.shaders", 1, 1);
+        }
+        engine.gpu_timer = gpu_timer::GpuTimer::new(device);
         #[cfg(feature = "debug_layers")]
         let debug = debug::DebugRenderer::new(device, wgpu::TextureFormat::Rgba8Unorm, &mut engine);
 
         Ok(Self {
             options,
+            device: device.clone(),
+            queue: None,
             engine,
             resolver: Resolver::new(),
             shaders,
+            post_process: None,
+            mipmaps: mipmaps::Mipmaps::default(),
+            composite: composite::Composite::default(),
             #[cfg(feature = "debug_layers")]
             debug,
             #[cfg(feature = "wgpu-profiler")]
@@ -455,6 +1046,35 @@ impl Renderer {
         })
     }
 
+    /// The size of the tiles which the renderer rasterizes the target in, as `(width, height)` in pixels.
+    ///
+    /// Vello's fine rasterization stage renders the target in independent tiles of this size, and the
+    /// rendered area is rounded up to a whole number of tiles internally.
+    /// Custom compute passes which operate on the same target can align their workgroups to this size.
+    /// Clips, layers and blurs are also evaluated per tile, so e.g. the cost of a layer depends on the
+    /// number of tiles it touches.
+    ///
+    /// This is part of Vello's public contract, and will only change in a breaking release.
+    /// It is currently 16x16 for all renderers.
+    pub fn tile_size(&self) -> (u32, u32) {
+        (vello_encoding::TILE_WIDTH, vello_encoding::TILE_HEIGHT)
+    }
+
+    /// Writes the contents of the pipeline cache used by this renderer to `path`.
+    ///
+    /// When the cache is next loaded using [`util::load_pipeline_cache`] and passed in
+    /// [`RendererOptions::pipeline_cache`], the pipelines will be loaded from the cache rather
+    /// than being compiled from scratch, which reduces startup time.
+    ///
+    /// Returns `Ok(false)` if this renderer doesn't have a pipeline cache, or if the backend
+    /// can't provide the data in its cache. In that case, nothing is written.
+    pub fn save_pipeline_cache(&self, path: &std::path::Path) -> Result<bool> {
+        match &self.options.pipeline_cache {
+            Some(cache) => util::write_pipeline_cache(path, cache),
+            None => Ok(false),
+        }
+    }
+
     /// Renders a scene to the target texture.
     ///
     /// The texture is assumed to be of the specified dimensions and have been created with
@@ -465,10 +1085,27 @@ impl Renderer {
     /// 1) Render to an intermediate texture, which is the same size as the surface.
     ///    You would then use [`TextureBlitter`][wgpu::util::TextureBlitter] to blit the rendered result from
     ///    that texture to the surface.
+    ///    If the surface has an sRGB format, blit from a view created with
+    ///    [`util::blit_source_view`], so that the colors aren't encoded twice.
     ///    This pattern is supported by the [`util`] module.
     /// 2) Call `render_to_texture` directly on the [`SurfaceTexture`][wgpu::SurfaceTexture]'s texture, if
     ///    it has the right usages. This should generally be avoided, as some GPUs assume that you will not
     ///    be rendering to the surface using a compute pipeline, and optimise accordingly.
+    ///
+    /// If a [post-process shader](Self::set_post_process) is set, or the
+    /// [`clear_mode`](RenderParams::clear_mode) is [`ClearMode::None`], the texture must instead
+    /// have the [`wgpu::TextureUsages::RENDER_ATTACHMENT`] flag set.
+    ///
+    /// As only a view of the texture is provided, these requirements aren't checked here, and
+    /// an incompatible texture causes a wgpu validation error. When rendering into a texture
+    /// which is owned elsewhere, such as by a render graph, check it using
+    /// [`validate_target`](Self::validate_target) first.
+    ///
+    /// The view may also target a single layer of a 2D texture array, such as a texture atlas.
+    /// Use [`array_layer_target`](Self::array_layer_target) to create a validated view of a layer.
+    ///
+    /// `device` must be the device this renderer was created with, and `queue` must be the same
+    /// queue each time, otherwise [`Error::DeviceMismatch`] is returned.
     pub fn render_to_texture(
         &mut self,
         device: &Device,
@@ -477,12 +1114,77 @@ impl Renderer {
         texture: &TextureView,
         params: &RenderParams,
     ) -> Result<()> {
-        let (recording, target) =
-            render::render_full(scene, &mut self.resolver, &self.shaders, params);
-        let external_resources = [ExternalResource::Image(
-            *target.as_image().unwrap(),
-            texture,
-        )];
+        self.check_device(device, queue)?;
+        if !self.options.debug_validation {
+            return self.render_to_texture_unchecked(device, queue, scene, texture, params);
+        }
+        scene.encoding().validate().map_err(Error::InvalidScene)?;
+        // Waiting for the errors from wgpu would block forever on the web.
+        if cfg!(target_arch = "wasm32") {
+            return self.render_to_texture_unchecked(device, queue, scene, texture, params);
+        }
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let result = self.render_to_texture_unchecked(device, queue, scene, texture, params);
+        if let Some(error) = util::block_on_wgpu(device, device.pop_error_scope()) {
+            return Err(error.into());
+        }
+        result
+    }
+
+    /// Returns [`Error::DeviceMismatch`] if `device` isn't the device this renderer was created
+    /// with, or `queue` isn't the queue it was first used with.
+    fn check_device(&mut self, device: &Device, queue: &Queue) -> Result<()> {
+        if *device != self.device {
+            return Err(Error::DeviceMismatch);
+        }
+        // wgpu doesn't expose which device a queue belongs to, so bind to the first queue used.
+        match &self.queue {
+            Some(bound) if bound != queue => Err(Error::DeviceMismatch),
+            Some(_) => Ok(()),
+            None => {
+                self.queue = Some(queue.clone());
+                Ok(())
+            }
+        }
+    }
+
+    /// Renders a scene to the target texture, without the checks enabled by
+    /// [`RendererOptions::debug_validation`].
+    fn render_to_texture_unchecked(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &Scene,
+        texture: &TextureView,
+        params: &RenderParams,
+    ) -> Result<()> {
+        let Some((_, [viewport_width, viewport_height])) = params.viewport_region() else {
+            return Ok(());
+        };
+        let blend_spill_size = self.blend_spill_size(device, scene, params)?;
+        // The post-process shader is run over the viewport, so the intermediate texture only
+        // needs to cover it, and the viewport is rendered at its origin.
+        let (recording, target) = render::render_full(
+            scene,
+            &mut self.resolver,
+            &self.shaders,
+            params,
+            self.options.msaa_sample_pattern.as_deref(),
+            blend_spill_size,
+            self.post_process.is_some(),
+        );
+        let compose_over = params.clear_mode == ClearMode::None;
+        let output = match &mut self.post_process {
+            Some(post_process) => {
+                post_process.intermediate_view(device, viewport_width, viewport_height)
+            }
+            None if compose_over => {
+                self.composite
+                    .intermediate_view(device, params.width, params.height)
+            }
+            None => texture,
+        };
+        let external_resources = [ExternalResource::Image(*target.as_image().unwrap(), output)];
         self.engine.run_recording(
             device,
             queue,
@@ -492,6 +1194,7 @@ impl Renderer {
             #[cfg(feature = "wgpu-profiler")]
             &mut self.profiler,
         )?;
+        self.finish_output(device, queue, texture, params);
         // N.B. This is horrible; this integration of wgpu-profiler really needs some work...
         #[cfg(feature = "wgpu-profiler")]
         {
@@ -507,6 +1210,635 @@ impl Renderer {
         Ok(())
     }
 
+    /// Runs the post-process shader, or composites the image over `texture` for
+    /// [`ClearMode::None`], once the scene has been rendered into the intermediate texture.
+    ///
+    /// Does nothing when the scene was rendered straight into `texture`.
+    fn finish_output(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        texture: &TextureView,
+        params: &RenderParams,
+    ) {
+        let Some((viewport_origin, [viewport_width, viewport_height])) = params.viewport_region()
+        else {
+            return;
+        };
+        let compose_over = params.clear_mode == ClearMode::None;
+        if let Some(post_process) = &mut self.post_process {
+            let blend = compose_over.then(|| composite::over(params.premultiplied_alpha));
+            post_process.run(
+                device,
+                queue,
+                texture,
+                viewport_origin,
+                viewport_width,
+                viewport_height,
+                blend,
+            );
+        } else if compose_over {
+            self.composite.run(
+                device,
+                queue,
+                texture,
+                viewport_origin,
+                [viewport_width, viewport_height],
+                params.premultiplied_alpha,
+            );
+        }
+    }
+
+    /// Returns the size of the buffer for layers nested more than four deep, in 32-bit words,
+    /// sized for the most deeply nested layers of `scene` and limited by
+    /// [`RendererOptions::intermediate_budget_bytes`].
+    ///
+    /// Returns [`Error::OutOfIntermediateMemory`] if `scene` could need more than the budget.
+    fn blend_spill_size(
+        &self,
+        device: &Device,
+        scene: &Scene,
+        params: &RenderParams,
+    ) -> Result<Option<u32>> {
+        /// The number of nested layers which each tile keeps in registers.
+        const BLEND_STACK_SPLIT: usize = 4;
+        /// The memory used by each tile for each layer beyond [`BLEND_STACK_SPLIT`].
+        const SPILLED_LAYER_BYTES: u64 = 16 * 16 * 4;
+        /// The size of the buffer when no budget is set, which is also its smallest size.
+        const DEFAULT_BYTES: u64 = 4 << 20;
+        let Some(budget) = self.options.intermediate_budget_bytes else {
+            return Ok(None);
+        };
+        let budget = budget.min(u64::from(device.limits().max_storage_buffer_binding_size));
+        let [width, height] = params
+            .viewport_region()
+            .map(|(_, size)| size)
+            .unwrap_or_default();
+        let tiles = u64::from(width.div_ceil(16)) * u64::from(height.div_ceil(16));
+        let mut layer = 0;
+        let mut depth = 0_usize;
+        let mut max_required = 0;
+        for &tag in &scene.encoding().draw_tags {
+            if tag == DrawTag::BEGIN_CLIP {
+                depth += 1;
+                if depth > BLEND_STACK_SPLIT {
+                    let spilled = (depth - BLEND_STACK_SPLIT) as u64;
+                    let required = spilled * SPILLED_LAYER_BYTES * tiles;
+                    if required > budget {
+                        return Err(Error::OutOfIntermediateMemory {
+                            layer,
+                            required,
+                            budget,
+                        });
+                    }
+                    max_required = max_required.max(required);
+                }
+                layer += 1;
+            } else if tag == DrawTag::END_CLIP {
+                depth = depth.saturating_sub(1);
+            }
+        }
+        let size = max_required.max(DEFAULT_BYTES).min(budget);
+        // Buffers can't be empty.
+        Ok(Some(u32::try_from(size / 4).unwrap_or(u32::MAX).max(1)))
+    }
+
+    /// Renders a scene to the target texture, running custom GPU work before and after Vello's passes.
+    ///
+    /// This behaves like [`render_to_texture`](Self::render_to_texture), with the same requirements on
+    /// `texture`, and is intended for integrating with other wgpu rendering, such as a 3D viewport.
+    /// Each hook is given its own [`CommandEncoder`](wgpu::CommandEncoder), and the view of the target
+    /// texture. The commands recorded in each hook are submitted to `queue` in order:
+    ///
+    /// - `before` is submitted before any of Vello's work. Note that Vello writes every pixel of the
+    ///   target (starting from [`RenderParams::base_color`]), so anything drawn into the target in
+    ///   this hook will be overwritten, unless the [`clear_mode`](RenderParams::clear_mode) is
+    ///   [`ClearMode::None`]. To draw content behind the scene otherwise, render it to a separate
+    ///   texture, and draw it as part of the scene using [`register_texture`](Self::register_texture).
+    ///   This hook is most useful for preparing resources, such as textures drawn by the scene.
+    /// - `after` is submitted once Vello's work (including any
+    ///   [post-process shader](Self::set_post_process)) has been submitted, so the target contains
+    ///   the rendered scene. Content drawn in this hook will appear over the scene.
+    ///   To draw into the target using a render pass, it must have the
+    ///   [`wgpu::TextureUsages::RENDER_ATTACHMENT`] flag set.
+    pub fn render_to_texture_with(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &Scene,
+        texture: &TextureView,
+        params: &RenderParams,
+        before: impl FnOnce(&mut wgpu::CommandEncoder, &TextureView),
+        after: impl FnOnce(&mut wgpu::CommandEncoder, &TextureView),
+    ) -> Result<()> {
+        self.check_device(device, queue)?;
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.render_to_texture_with.before"),
+        });
+        before(&mut encoder, texture);
+        queue.submit([encoder.finish()]);
+
+        self.render_to_texture(device, queue, scene, texture, params)?;
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.render_to_texture_with.after"),
+        });
+        after(&mut encoder, texture);
+        queue.submit([encoder.finish()]);
+        Ok(())
+    }
+
+    /// Renders a scene to the first mip level of `texture`, and then fills the rest of its mip
+    /// chain by downsampling, such as for a thumbnail of the scene.
+    ///
+    /// Each mip level is the average of the 2x2 texels of the previous level, blended with
+    /// premultiplied alpha, so that transparent texels don't darken the edges of the scene.
+    /// The downsampling is submitted straight after the scene, without waiting for it.
+    ///
+    /// As well as the requirements of [`render_to_texture`](Self::render_to_texture), which
+    /// are checked using [`validate_target`](Self::validate_target), the texture must have the
+    /// [`wgpu::TextureUsages::STORAGE_BINDING`] and [`wgpu::TextureUsages::TEXTURE_BINDING`]
+    /// usages, which are used to downsample each level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncompatibleTarget`] if the texture doesn't meet these requirements.
+    pub fn render_to_texture_with_mips(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &Scene,
+        texture: &wgpu::Texture,
+        params: &RenderParams,
+    ) -> Result<()> {
+        self.validate_target(texture, params.clear_mode)?;
+        let mip_usages =
+            wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING;
+        if texture.mip_level_count() > 1 && !texture.usage().contains(mip_usages) {
+            return Err(Error::IncompatibleTarget {
+                format: texture.format(),
+                usages: texture.usage(),
+                required_usages: mip_usages,
+            });
+        }
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("vello.render_to_texture_with_mips"),
+            base_mip_level: 0,
+            mip_level_count: Some(1),
+            ..Default::default()
+        });
+        self.render_to_texture(device, queue, scene, &view, params)?;
+        self.mipmaps
+            .generate(device, queue, texture, params.premultiplied_alpha);
+        Ok(())
+    }
+
+    /// Returns the optional capabilities of `device`, and the anti-aliasing methods supported
+    /// by this renderer.
+    ///
+    /// `device` should be the device which this renderer was created with, and `adapter`
+    /// the adapter which `device` was requested from.
+    pub fn capabilities(&self, adapter: &wgpu::Adapter, device: &Device) -> RendererCaps {
+        let features = device.features();
+        let float_format = adapter.get_texture_format_features(wgpu::TextureFormat::Rgba32Float);
+        RendererCaps {
+            float_targets: float_format
+                .flags
+                .contains(wgpu::TextureFormatFeatureFlags::BLENDABLE),
+            timestamps: features.contains(wgpu::Features::TIMESTAMP_QUERY),
+            subgroup_ops: features.contains(wgpu::Features::SUBGROUP),
+            max_texture_size: device.limits().max_texture_dimension_2d,
+            antialiasing: self.options.antialiasing_support,
+        }
+    }
+
+    /// Describes the passes which are run to render a scene using `antialiasing_method`,
+    /// in the order that they run, with the resources that each of them uses.
+    ///
+    /// This is descriptive metadata, for learning how Vello works, for debugging alongside
+    /// [GPU timings](Self::last_frame_gpu_time), and for tools. The passes are those for a
+    /// scene which contains clips; scenes without clips skip `"vello.clip_reduce"` and
+    /// `"vello.clip_leaf"`, and scenes with very many paths use an extra level of path tag
+    /// scan. Blurs are computed analytically in fine rasterization, so they have no pass of
+    /// their own. If a [post-process shader](Self::set_post_process) is set, it runs last as
+    /// `"vello.post_process"`. Blitting to a surface is done by the caller, such as using
+    /// [`util::RenderSurface`], so it isn't included.
+    ///
+    /// The passes and their labels are stable within a major version of Vello.
+    ///
+    /// # Panics
+    ///
+    /// If the renderer doesn't support `antialiasing_method`.
+    pub fn describe_pipeline(&self, antialiasing_method: AaConfig) -> Vec<PassInfo> {
+        let mut scene = Scene::new();
+        let rect = kurbo::Rect::new(0.0, 0.0, 16.0, 16.0);
+        scene.push_clip_layer(peniko::Fill::NonZero, kurbo::Affine::IDENTITY, &rect);
+        scene.fill(
+            peniko::Fill::NonZero,
+            kurbo::Affine::IDENTITY,
+            peniko::Color::WHITE,
+            None,
+            &rect,
+        );
+        scene.pop_layer();
+        let params = RenderParams {
+            base_color: peniko::Color::BLACK,
+            width: 16,
+            height: 16,
+            antialiasing_method,
+            text_gamma: 1.0,
+            alpha_to_coverage: false,
+            debug: DebugView::None,
+            premultiplied_alpha: false,
+            flatten_tolerance: None,
+            viewport: None,
+            clear_mode: ClearMode::Clear,
+        };
+        let mut render = Render::new();
+        let mut recording = render.render_encoding_coarse(
+            scene.encoding(),
+            &mut Resolver::new(),
+            &self.shaders,
+            &params,
+            false,
+        );
+        let image_labels = render.image_labels();
+        render.record_fine(&self.shaders, &mut recording);
+        let resource_label = |resource: &ResourceProxy| match resource {
+            ResourceProxy::Buffer(proxy) | ResourceProxy::BufferRange { proxy, .. } => proxy.name,
+            ResourceProxy::Image(proxy) => image_labels
+                .iter()
+                .find(|(id, _)| *id == proxy.id)
+                .map_or("vello.image", |(_, label)| *label),
+        };
+        let mut passes: Vec<PassInfo> = recording
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                Command::Dispatch(shader, _, resources)
+                | Command::DispatchIndirect(shader, _, _, resources) => {
+                    let (label, cpu) = self.engine.shader_info(*shader);
+                    Some(PassInfo {
+                        label,
+                        resources: resources.iter().map(resource_label).collect(),
+                        cpu,
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+        if self.post_process.is_some() {
+            passes.push(PassInfo {
+                label: "vello.post_process",
+                resources: vec!["vello.target"],
+                cpu: false,
+            });
+        }
+        passes
+    }
+
+    /// Returns the GPU time taken by a recent render, measured using timestamp queries.
+    ///
+    /// This covers Vello's compute passes for the scene, from the first to the last, but not
+    /// [post-processing](Self::set_post_process) or work done in render hooks.
+    /// The timestamps are read back without blocking, once the device has been polled after the
+    /// render has finished, so this lags behind the most recent render by a frame or more.
+    /// Renders which are submitted while the previous measurement is being read back aren't
+    /// timed.
+    ///
+    /// Returns `None` if the device doesn't support timestamp queries (see
+    /// [`RendererCaps::timestamps`]), or if no render has been timed yet.
+    /// This can be used with [`util::FramePacer`] to adapt the quality of rendering to a frame
+    /// time budget.
+    pub fn last_frame_gpu_time(&self) -> Option<std::time::Duration> {
+        self.engine
+            .gpu_timer
+            .as_ref()
+            .and_then(|timer| timer.last())
+    }
+
+    /// Checks that `texture` can be used as the target of [`render_to_texture`](Self::render_to_texture)
+    /// with the given [`clear_mode`](RenderParams::clear_mode).
+    ///
+    /// The texture must be a two dimensional [`wgpu::TextureFormat::Rgba8Unorm`] texture,
+    /// with the [`wgpu::TextureUsages::STORAGE_BINDING`] usage, or the
+    /// [`wgpu::TextureUsages::RENDER_ATTACHMENT`] usage if a
+    /// [post-process shader](Self::set_post_process) is set or `clear_mode` is
+    /// [`ClearMode::None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncompatibleTarget`] if the texture doesn't meet these requirements.
+    pub fn validate_target(&self, texture: &wgpu::Texture, clear_mode: ClearMode) -> Result<()> {
+        let required_usages = if self.post_process.is_some() || clear_mode == ClearMode::None {
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+        } else {
+            wgpu::TextureUsages::STORAGE_BINDING
+        };
+        let compatible = texture.format() == wgpu::TextureFormat::Rgba8Unorm
+            && texture.dimension() == wgpu::TextureDimension::D2
+            && texture.sample_count() == 1
+            && texture.usage().contains(required_usages);
+        if compatible {
+            Ok(())
+        } else {
+            Err(Error::IncompatibleTarget {
+                format: texture.format(),
+                usages: texture.usage(),
+                required_usages,
+            })
+        }
+    }
+
+    /// Creates a view of one layer of a 2D texture array, to be rendered into using
+    /// [`render_to_texture`](Self::render_to_texture).
+    ///
+    /// This allows many small scenes to be packed into one texture array, so that they can be
+    /// sampled together elsewhere. The scene is rendered into the top left of the layer, and
+    /// the rest of the layer is left unchanged if the scene is smaller than the texture.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncompatibleTarget`] if the texture can't be rendered into with
+    /// `clear_mode`, as described in [`validate_target`](Self::validate_target), or
+    /// [`Error::ArrayLayerOutOfBounds`] if `layer` isn't one of the texture's array layers.
+    pub fn array_layer_target(
+        &self,
+        texture: &wgpu::Texture,
+        layer: u32,
+        clear_mode: ClearMode,
+    ) -> Result<TextureView> {
+        self.validate_target(texture, clear_mode)?;
+        let layers = texture.depth_or_array_layers();
+        if layer >= layers {
+            return Err(Error::ArrayLayerOutOfBounds { layer, layers });
+        }
+        Ok(texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("vello.array_layer_target"),
+            dimension: Some(wgpu::TextureViewDimension::D2),
+            base_array_layer: layer,
+            array_layer_count: Some(1),
+            ..Default::default()
+        }))
+    }
+
+    /// Renders a scene into an image in tiles, so that images larger than the device's maximum
+    /// texture size can be rendered.
+    ///
+    /// The scene is rendered into an RGBA8 image of `width` by `height` pixels, as with
+    /// [`render_to_texture`](Self::render_to_texture). The image has premultiplied alpha if
+    /// [`RenderParams::premultiplied_alpha`] is set, and straight alpha otherwise.
+    /// The `width`, `height` and `viewport` of `params` are ignored.
+    /// Each tile is at most `tile_size` pixels square, and the tiles are read back from the
+    /// GPU and stitched together on the CPU.
+    ///
+    /// Each pixel rendered by Vello only depends on the scene content at that pixel, including
+    /// blurred rounded rectangles, whose blur is evaluated analytically. This means that the
+    /// tiles don't need to overlap, and the result matches rendering the image in one pass
+    /// (within rounding), with no seams between tiles.
+    /// In particular, a shadow whose blur crosses a tile boundary doesn't need a halo of
+    /// `2.5 * std_dev` around the tile, as each tile still contains the whole shadow, just
+    /// translated so that only its visible part is rasterized.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTileSize`] if `tile_size` is zero or larger than the maximum
+    /// texture dimension of `device`.
+    ///
+    /// # Panics
+    ///
+    /// On the web, as this blocks until each tile has been read back.
+    pub fn render_to_image_tiled(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &Scene,
+        width: u32,
+        height: u32,
+        tile_size: u32,
+        params: &RenderParams,
+    ) -> Result<ImageData> {
+        self.check_device(device, queue)?;
+        if tile_size == 0 || tile_size > device.limits().max_texture_dimension_2d {
+            return Err(Error::InvalidTileSize(tile_size));
+        }
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("vello.render_to_image_tiled"),
+            size: wgpu::Extent3d {
+                width: tile_size,
+                height: tile_size,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::STORAGE_BINDING
+                | wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let padded_row_bytes = (tile_size * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vello.render_to_image_tiled"),
+            size: u64::from(padded_row_bytes) * u64::from(tile_size),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let row_bytes = width as usize * 4;
+        let mut image = vec![0_u8; row_bytes * height as usize];
+        let mut tile_scene = Scene::new();
+        for y0 in (0..height).step_by(tile_size as usize) {
+            for x0 in (0..width).step_by(tile_size as usize) {
+                let tile_width = tile_size.min(width - x0);
+                let tile_height = tile_size.min(height - y0);
+                tile_scene.reset();
+                tile_scene.append(
+                    scene,
+                    Some(kurbo::Affine::translate((-f64::from(x0), -f64::from(y0)))),
+                );
+                let tile_params = RenderParams {
+                    base_color: params.base_color,
+                    width: tile_width,
+                    height: tile_height,
+                    antialiasing_method: params.antialiasing_method,
+                    text_gamma: params.text_gamma,
+                    alpha_to_coverage: params.alpha_to_coverage,
+                    debug: params.debug,
+                    premultiplied_alpha: params.premultiplied_alpha,
+                    flatten_tolerance: params.flatten_tolerance,
+                    viewport: None,
+                    // Each tile is rendered into a new texture.
+                    clear_mode: ClearMode::Clear,
+                };
+                self.render_to_texture(device, queue, &tile_scene, &view, &tile_params)?;
+
+                let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("vello.render_to_image_tiled"),
+                });
+                encoder.copy_texture_to_buffer(
+                    texture.as_image_copy(),
+                    wgpu::TexelCopyBufferInfo {
+                        buffer: &buffer,
+                        layout: wgpu::TexelCopyBufferLayout {
+                            offset: 0,
+                            bytes_per_row: Some(padded_row_bytes),
+                            rows_per_image: None,
+                        },
+                    },
+                    wgpu::Extent3d {
+                        width: tile_width,
+                        height: tile_height,
+                        depth_or_array_layers: 1,
+                    },
+                );
+                queue.submit([encoder.finish()]);
+                let slice = buffer.slice(..);
+                let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+                slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+                if let Some(recv_result) = util::block_on_wgpu(device, receiver.receive()) {
+                    recv_result?;
+                }
+                {
+                    let data = slice.get_mapped_range();
+                    let tile_row_bytes = tile_width as usize * 4;
+                    for row in 0..tile_height as usize {
+                        let src = row * padded_row_bytes as usize;
+                        let dst = (y0 as usize + row) * row_bytes + x0 as usize * 4;
+                        image[dst..dst + tile_row_bytes]
+                            .copy_from_slice(&data[src..src + tile_row_bytes]);
+                    }
+                }
+                buffer.unmap();
+            }
+        }
+        Ok(ImageData {
+            data: peniko::Blob::new(std::sync::Arc::new(image)),
+            format: peniko::ImageFormat::Rgba8,
+            alpha_type: if params.premultiplied_alpha {
+                peniko::ImageAlphaType::AlphaPremultiplied
+            } else {
+                peniko::ImageAlphaType::Alpha
+            },
+            width,
+            height,
+        })
+    }
+
+    /// Reads the color of a single pixel of `texture`, such as for an eyedropper tool.
+    ///
+    /// Only the requested pixel is copied from the GPU, so this is much cheaper than
+    /// reading back the whole texture. This waits for all work previously submitted to
+    /// `queue` to complete, including rendering into `texture`.
+    ///
+    /// The texture must have the [`wgpu::TextureUsages::COPY_SRC`] flag set, and have the
+    /// `Rgba8Unorm` or `Bgra8Unorm` format (or their sRGB variants).
+    /// The returned color has straight (unpremultiplied) alpha, as Vello writes to its target.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PixelOutOfBounds`] if `(x, y)` is outside of the texture, and
+    /// [`Error::UnsupportedReadFormat`] if the texture has an unsupported format.
+    ///
+    /// # Panics
+    ///
+    /// On the web, as this blocks until the pixel has been copied.
+    pub fn read_pixel(
+        &self,
+        device: &Device,
+        queue: &Queue,
+        texture: &wgpu::Texture,
+        x: u32,
+        y: u32,
+    ) -> Result<peniko::Color> {
+        use wgpu::TextureFormat;
+
+        if x >= texture.width() || y >= texture.height() {
+            return Err(Error::PixelOutOfBounds(x, y));
+        }
+        let bgra = match texture.format() {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => false,
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => true,
+            format => return Err(Error::UnsupportedReadFormat(format)),
+        };
+        // A single row doesn't need `bytes_per_row`, so only one pixel needs to be copied.
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vello.read_pixel"),
+            size: 4,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.read_pixel"),
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x, y, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit([encoder.finish()]);
+        let slice = buffer.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+        if let Some(recv_result) = util::block_on_wgpu(device, receiver.receive()) {
+            recv_result?;
+        }
+        let [c0, g, c2, a] = <[u8; 4]>::try_from(&slice.get_mapped_range()[..]).unwrap();
+        let (r, b) = if bgra { (c2, c0) } else { (c0, c2) };
+        Ok(peniko::Color::from_rgba8(r, g, b, a))
+    }
+
+    /// Sets a shader which is run over the rendered scene in each subsequent call to
+    /// [`render_to_texture`](Self::render_to_texture).
+    ///
+    /// This can be used for full-screen effects such as chromatic aberration or scanlines.
+    /// The scene is first rendered into an intermediate texture owned by the renderer, and then
+    /// the shader is run as a fragment shader over the full target, sampling from that texture.
+    /// See [`PostProcessShader`] for the bindings which are available to the shader.
+    ///
+    /// The shader is compiled in the next render, and any errors in the shader will be reported
+    /// through wgpu's error handling.
+    /// The target texture must have the [`wgpu::TextureUsages::RENDER_ATTACHMENT`] flag set.
+    pub fn set_post_process(&mut self, shader: PostProcessShader) {
+        self.post_process = Some(post_process::PostProcess::new(shader));
+    }
+
+    /// Removes the shader set using [`set_post_process`](Self::set_post_process), so that scenes
+    /// are rendered directly to the target.
+    pub fn clear_post_process(&mut self) {
+        self.post_process = None;
+    }
+
+    /// Sets the value of the `time` uniform passed to the [post-process shader](Self::set_post_process).
+    ///
+    /// By default (or if `time` is `None`), this is the number of seconds since the shader was set,
+    /// except on WebAssembly, where it is always zero.
+    /// Setting a time is useful to keep animations in sync with the rest of your application,
+    /// or to make the output deterministic.
+    pub fn set_post_process_time(&mut self, time: Option<f32>) {
+        if let Some(post_process) = &mut self.post_process {
+            post_process.set_time(time);
+        }
+    }
+
     /// Overwrite `image` with `texture`.
     ///
     /// Most users should prefer [`register_texture`](Self::register_texture), which
@@ -591,6 +1923,9 @@ impl Renderer {
     #[cfg(feature = "hot_reload")]
     #[doc(hidden)] // End-users of Vello should not have `hot_reload` enabled.
     pub async fn reload_shaders(&mut self, device: &Device) -> Result<(), Error> {
+        if *device != self.device {
+            return Err(Error::DeviceMismatch);
+        }
         device.push_error_scope(wgpu::ErrorFilter::Validation);
         let mut engine = WgpuEngine::new(self.options.use_cpu, self.options.pipeline_cache.clone());
         // We choose not to initialise these shaders in parallel, to ensure the error scope works correctly
@@ -601,6 +1936,7 @@ impl Renderer {
         if let Some(error) = error {
             return Err(error.into());
         }
+        engine.gpu_timer = self.engine.gpu_timer.take();
         self.engine = engine;
         self.shaders = shaders;
         #[cfg(feature = "debug_layers")]
@@ -632,6 +1968,7 @@ impl Renderer {
         params: &RenderParams,
         debug_layers: DebugLayers,
     ) -> Result<Option<BumpAllocators>> {
+        self.check_device(device, queue)?;
         if cfg!(not(feature = "debug_layers")) && !debug_layers.is_empty() {
             static HAS_WARNED: AtomicBool = AtomicBool::new(false);
             if !HAS_WARNED.swap(true, std::sync::atomic::Ordering::Release) {
@@ -641,9 +1978,19 @@ impl Renderer {
             }
         }
 
+        if self.options.debug_validation {
+            scene.encoding().validate().map_err(Error::InvalidScene)?;
+            device.push_error_scope(wgpu::ErrorFilter::Validation);
+        }
         let result = self
             .render_to_texture_async_internal(device, queue, scene, texture, params)
-            .await?;
+            .await;
+        if self.options.debug_validation
+            && let Some(error) = device.pop_error_scope().await
+        {
+            return Err(error.into());
+        }
+        let result = result?;
 
         #[cfg(feature = "debug_layers")]
         {
@@ -706,7 +2053,18 @@ impl Renderer {
         texture: &TextureView,
         params: &RenderParams,
     ) -> Result<RenderResult> {
+        let Some((_, [viewport_width, viewport_height])) = params.viewport_region() else {
+            return Ok(RenderResult {
+                bump: None,
+                #[cfg(feature = "debug_layers")]
+                captured: None,
+            });
+        };
+        let blend_spill_size = self.blend_spill_size(device, scene, params)?;
         let mut render = Render::new();
+        render.set_msaa_sample_pattern(self.options.msaa_sample_pattern.as_deref());
+        render.set_blend_spill_size(blend_spill_size);
+        render.set_viewport_at_origin(self.post_process.is_some());
         let encoding = scene.encoding();
         // TODO: turn this on; the download feature interacts with CPU dispatch.
         // Currently this is always enabled when the `debug_layers` setting is enabled as the bump
@@ -719,6 +2077,7 @@ impl Renderer {
             params,
             robust,
         );
+        let recording = render::with_layer_markers(scene, recording);
         let target = render.out_image();
         let bump_buf = render.bump_buf();
         #[cfg(feature = "debug_layers")]
@@ -748,7 +2107,17 @@ impl Renderer {
         // Maybe clear to reuse allocation?
         let mut recording = Recording::default();
         render.record_fine(&self.shaders, &mut recording);
-        let external_resources = [ExternalResource::Image(target, texture)];
+        let output = match &mut self.post_process {
+            Some(post_process) => {
+                post_process.intermediate_view(device, viewport_width, viewport_height)
+            }
+            None if params.clear_mode == ClearMode::None => {
+                self.composite
+                    .intermediate_view(device, params.width, params.height)
+            }
+            None => texture,
+        };
+        let external_resources = [ExternalResource::Image(target, output)];
         self.engine.run_recording(
             device,
             queue,
@@ -758,6 +2127,7 @@ impl Renderer {
             #[cfg(feature = "wgpu-profiler")]
             &mut self.profiler,
         )?;
+        self.finish_output(device, queue, texture, params);
         Ok(RenderResult {
             bump,
             #[cfg(feature = "debug_layers")]
@@ -765,6 +2135,7 @@ impl Renderer {
         })
     }
 }
+
 #[cfg(all(feature = "debug_layers", feature = "wgpu"))]
 pub(crate) struct DebugDownloads<'a> {
     pub lines: wgpu::BufferSlice<'a>,
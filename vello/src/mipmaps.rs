@@ -0,0 +1,177 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generation of the mip chain of a rendered target, for
+//! [`Renderer::render_to_texture_with_mips`](crate::Renderer::render_to_texture_with_mips).
+
+use wgpu::{Device, Queue, Texture};
+
+const SHADER: &str = r"
+@group(0) @binding(0) var source_texture: texture_2d<f32>;
+@group(0) @binding(1) var target_texture: texture_storage_2d<rgba8unorm, write>;
+
+// Averages the 2x2 texels of the source under each texel of the target, with premultiplied
+// alpha. The last row or column of a source with an odd size is clamped to.
+fn downsample(id: vec2<u32>, premultiplied: bool) -> vec4<f32> {
+    let source_max = vec2<i32>(textureDimensions(source_texture)) - vec2(1);
+    let base = vec2<i32>(id * 2u);
+    var sum = vec4(0.0);
+    for (var y = 0; y < 2; y += 1) {
+        for (var x = 0; x < 2; x += 1) {
+            let texel = textureLoad(source_texture, min(base + vec2(x, y), source_max), 0);
+            if premultiplied {
+                sum += texel;
+            } else {
+                sum += vec4(texel.rgb * texel.a, texel.a);
+            }
+        }
+    }
+    let average = sum * 0.25;
+    if premultiplied {
+        return average;
+    }
+    // Max with a small epsilon to avoid NaNs
+    return vec4(average.rgb / max(average.a, 1e-6), average.a);
+}
+
+@compute @workgroup_size(8, 8)
+fn downsample_straight(@builtin(global_invocation_id) id: vec3<u32>) {
+    if all(id.xy < textureDimensions(target_texture)) {
+        textureStore(target_texture, id.xy, downsample(id.xy, false));
+    }
+}
+
+@compute @workgroup_size(8, 8)
+fn downsample_premultiplied(@builtin(global_invocation_id) id: vec3<u32>) {
+    if all(id.xy < textureDimensions(target_texture)) {
+        textureStore(target_texture, id.xy, downsample(id.xy, true));
+    }
+}
+";
+
+/// The pipelines used to generate mip chains.
+///
+/// These are created lazily, as creating them requires a device, and most renderers never
+/// generate mips.
+#[derive(Default)]
+pub(crate) struct Mipmaps {
+    pipelines: Option<Pipelines>,
+}
+
+struct Pipelines {
+    straight: wgpu::ComputePipeline,
+    premultiplied: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl Mipmaps {
+    /// Fills every mip level of `texture` after the first by downsampling the previous level.
+    pub(crate) fn generate(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        texture: &Texture,
+        premultiplied: bool,
+    ) {
+        if texture.mip_level_count() < 2 {
+            return;
+        }
+        let pipelines = self
+            .pipelines
+            .get_or_insert_with(|| create_pipelines(device));
+        let pipeline = if premultiplied {
+            &pipelines.premultiplied
+        } else {
+            &pipelines.straight
+        };
+        let level_view = |level| {
+            texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("vello.mipmaps.level"),
+                base_mip_level: level,
+                mip_level_count: Some(1),
+                ..Default::default()
+            })
+        };
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.mipmaps"),
+        });
+        for level in 1..texture.mip_level_count() {
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("vello.mipmaps"),
+                layout: &pipelines.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&level_view(level - 1)),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&level_view(level)),
+                    },
+                ],
+            });
+            let width = (texture.width() >> level).max(1);
+            let height = (texture.height() >> level).max(1);
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("vello.mipmaps"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(width.div_ceil(8), height.div_ceil(8), 1);
+        }
+        queue.submit([encoder.finish()]);
+    }
+}
+
+fn create_pipelines(device: &Device) -> Pipelines {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("vello.mipmaps"),
+        source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("vello.mipmaps"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::StorageTexture {
+                    access: wgpu::StorageTextureAccess::WriteOnly,
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                },
+                count: None,
+            },
+        ],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("vello.mipmaps"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = |entry_point| {
+        device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("vello.mipmaps"),
+            layout: Some(&pipeline_layout),
+            module: &module,
+            entry_point: Some(entry_point),
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+            cache: None,
+        })
+    };
+    Pipelines {
+        straight: pipeline("downsample_straight"),
+        premultiplied: pipeline("downsample_premultiplied"),
+        bind_group_layout,
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Building paths on the CPU: boolean operations, and rectangles with smoothed corners.
+//!
+//! For boolean operations, the paths are flattened to polygons before the operation, so the
+//! result only contains straight lines. This is intended for building shapes to fill, such as
+//! a ring around a rounded rectangle, rather than for precise geometry editing.
+
+use std::collections::{HashMap, HashSet};
+
+use peniko::kurbo::{Arc, BezPath, PathEl, Point, Rect, RoundedRect, Shape, Vec2};
+
+/// Returns a rectangle with smoothed corners, also known as a squircle or "continuous
+/// corners", as used by iOS and Figma.
+///
+/// Each corner is a circular arc of `radius`, which is eased into the straight edges using
+/// cubic Béziers, so the curvature changes gradually rather than abruptly. `smoothing` is
+/// the proportion of the corner which is eased, from 0 to 1, where 0 gives the same path as a
+/// [`RoundedRect`], and 0.6 matches the iOS app icon shape. The eased corner extends
+/// `(1 + smoothing) * radius` along each edge; if that is more than half of the shorter
+/// side, the smoothing is reduced to fit, and the radius is clamped to half of the shorter
+/// side.
+///
+/// Negative or NaN values of `radius` and `smoothing` are treated as zero, and `smoothing` is
+/// clamped to at most 1.
+pub fn squircle_rect(rect: Rect, radius: f64, smoothing: f64) -> BezPath {
+    // See the note about tolerances in `Scene::stroke_with_alpha`.
+    const SHAPE_TOLERANCE: f64 = 0.01;
+
+    let rect = rect.abs();
+    let max_extent = 0.5 * rect.width().min(rect.height());
+    let radius = radius.max(0.0).min(max_extent);
+    let mut smoothing = if smoothing.is_nan() {
+        0.0
+    } else {
+        smoothing.clamp(0.0, 1.0)
+    };
+    if radius > 0.0 {
+        smoothing = smoothing.min(max_extent / radius - 1.0);
+    }
+    if smoothing <= 0.0 || radius == 0.0 {
+        return RoundedRect::from_rect(rect, radius).to_path(SHAPE_TOLERANCE);
+    }
+    let extent = (1.0 + smoothing) * radius;
+
+    // The corner is an arc which is symmetric about its diagonal, between two cubic Béziers,
+    // following https://www.figma.com/blog/desperately-seeking-squircles/.
+    let arc_angle = 0.5 * std::f64::consts::PI * (1.0 - smoothing);
+    let arc_length = (0.5 * arc_angle).sin() * radius * std::f64::consts::SQRT_2;
+    let alpha = 0.25 * std::f64::consts::PI - 0.5 * arc_angle;
+    let beta = 0.25 * std::f64::consts::PI * smoothing;
+    let c = radius * (0.5 * alpha).tan() * beta.cos();
+    let d = c * beta.tan();
+    let b = (extent - arc_length - c - d) / 3.0;
+    let a = 2.0 * b;
+
+    let mut path = BezPath::new();
+    // Each corner, with the direction of the edge into it and the edge out of it.
+    let corners = [
+        (
+            Point::new(rect.x1, rect.y0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ),
+        (
+            Point::new(rect.x1, rect.y1),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-1.0, 0.0),
+        ),
+        (
+            Point::new(rect.x0, rect.y1),
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(0.0, -1.0),
+        ),
+        (
+            Point::new(rect.x0, rect.y0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(1.0, 0.0),
+        ),
+    ];
+    for (index, (corner, u, v)) in corners.into_iter().enumerate() {
+        let start = corner - u * extent;
+        if index == 0 {
+            path.move_to(start);
+        } else {
+            path.line_to(start);
+        }
+        let arc_start = start + u * (a + b + c) + v * d;
+        path.curve_to(start + u * a, start + u * (a + b), arc_start);
+        let center = corner - u * radius + v * radius;
+        let arc = Arc::new(
+            center,
+            (radius, radius),
+            (arc_start - center).atan2(),
+            arc_angle,
+            0.0,
+        );
+        path.extend(arc.append_iter(SHAPE_TOLERANCE));
+        let arc_end = arc_start + (u + v) * arc_length;
+        path.curve_to(
+            arc_end + u * d + v * c,
+            arc_end + u * d + v * (b + c),
+            corner + v * extent,
+        );
+    }
+    path.close_path();
+    path
+}
+
+/// Returns the radius of the rounded rectangle which approximates [`squircle_rect`] most
+/// closely, by removing the same area from each corner.
+///
+/// Its outline is within 4% of `radius` of the squircle's.
+pub(crate) fn squircle_equivalent_radius(rect: Rect, radius: f64, smoothing: f64) -> f64 {
+    let rect = rect.abs();
+    let max_extent = 0.5 * rect.width().min(rect.height());
+    let removed = 0.25 * (rect.area() - squircle_rect(rect, radius, smoothing).area().abs());
+    let equivalent = (removed.max(0.0) / (1.0 - 0.25 * std::f64::consts::PI)).sqrt();
+    if equivalent.is_finite() {
+        equivalent.min(max_extent)
+    } else {
+        0.0
+    }
+}
+
+/// The tolerance used by [`boolean`] to flatten its inputs.
+pub const DEFAULT_TOLERANCE: f64 = 0.01;
+
+/// A boolean operation on two paths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoolOp {
+    /// The area covered by either path.
+    Union,
+    /// The area covered by both paths.
+    Intersection,
+    /// The area covered by the first path but not the second.
+    Difference,
+    /// The area covered by exactly one of the paths.
+    Xor,
+}
+
+impl BoolOp {
+    fn apply(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            Self::Union => in_a || in_b,
+            Self::Intersection => in_a && in_b,
+            Self::Difference => in_a && !in_b,
+            Self::Xor => in_a != in_b,
+        }
+    }
+}
+
+/// Returns the result of combining `a` and `b` with `op`.
+///
+/// Both shapes are interpreted with the [non-zero](peniko::Fill::NonZero) fill rule, and
+/// their curves are flattened to within [`DEFAULT_TOLERANCE`].
+/// The result is made of closed polygons, which wind in the same direction around the area
+/// of the result, so it can be filled with either fill rule.
+/// A hole in the result is a polygon winding in the opposite direction to the one around it.
+///
+/// This is a simple `O(n²)` algorithm in the number of flattened segments, which is intended
+/// for shapes with up to a few thousand segments.
+pub fn boolean(a: &impl Shape, b: &impl Shape, op: BoolOp) -> BezPath {
+    boolean_with_tolerance(a, b, op, DEFAULT_TOLERANCE)
+}
+
+/// Returns the result of combining `a` and `b` with `op`, flattening their curves to within
+/// `tolerance`.
+///
+/// See [`boolean`] for more details.
+pub fn boolean_with_tolerance(
+    a: &impl Shape,
+    b: &impl Shape,
+    op: BoolOp,
+    tolerance: f64,
+) -> BezPath {
+    let a = flatten(a, tolerance);
+    let b = flatten(b, tolerance);
+    let mut points = a.iter().chain(&b).flat_map(|edge| [edge.p0, edge.p1]);
+    let Some(first) = points.next() else {
+        return BezPath::new();
+    };
+    let bounds = points.fold(Rect::from_points(first, first), |r, p| r.union_pt(p));
+    // The distance either side of an edge at which the inputs are sampled. This needs to be
+    // small enough to not reach other edges, but large enough to not be lost to rounding.
+    let offset = 1e-7 * (1.0 + bounds.width().max(bounds.height()));
+
+    let pieces = split_edges(&a, &b);
+    let mut boundary: Vec<Edge> = Vec::new();
+    let mut seen = HashSet::new();
+    for piece in pieces {
+        let direction = piece.p1 - piece.p0;
+        let length = direction.hypot();
+        if length == 0.0 {
+            continue;
+        }
+        let mid = piece.p0.midpoint(piece.p1);
+        let normal = Vec2::new(-direction.y, direction.x) * (offset / length);
+        let inside = |point: Point| op.apply(winding(&a, point) != 0, winding(&b, point) != 0);
+        let left = inside(mid + normal);
+        let right = inside(mid - normal);
+        if left == right {
+            continue;
+        }
+        // Orient the edge so that the result is on its left.
+        let edge = if left {
+            piece
+        } else {
+            Edge {
+                p0: piece.p1,
+                p1: piece.p0,
+            }
+        };
+        // Edges of `a` and `b` which coincide only form one edge of the result.
+        if seen.insert((key(edge.p0), key(edge.p1))) {
+            boundary.push(edge);
+        }
+    }
+    chain(&boundary)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    p0: Point,
+    p1: Point,
+}
+
+/// Flattens `shape` to a list of edges, closing each subpath.
+fn flatten(shape: &impl Shape, tolerance: f64) -> Vec<Edge> {
+    fn close(edges: &mut Vec<Edge>, start: Option<Point>, last: Point) {
+        if let Some(start) = start
+            && start != last
+        {
+            edges.push(Edge {
+                p0: last,
+                p1: start,
+            });
+        }
+    }
+
+    let mut edges = Vec::new();
+    let mut start = None;
+    let mut last = Point::ZERO;
+    let path = shape.path_elements(tolerance);
+    peniko::kurbo::flatten(path, tolerance, |el| match el {
+        PathEl::MoveTo(p) => {
+            close(&mut edges, start, last);
+            start = Some(p);
+            last = p;
+        }
+        PathEl::LineTo(p) => {
+            if start.is_none() {
+                start = Some(last);
+            }
+            if p != last {
+                edges.push(Edge { p0: last, p1: p });
+            }
+            last = p;
+        }
+        PathEl::ClosePath => {
+            close(&mut edges, start, last);
+            if let Some(start) = start {
+                last = start;
+            }
+            start = None;
+        }
+        // `flatten` only produces lines.
+        PathEl::QuadTo(..) | PathEl::CurveTo(..) => {}
+    });
+    close(&mut edges, start, last);
+    edges.retain(|edge| edge.p0.is_finite() && edge.p1.is_finite());
+    edges
+}
+
+/// The non-zero winding number of the polygon made of `edges` around `point`.
+fn winding(edges: &[Edge], point: Point) -> i32 {
+    let mut winding = 0;
+    for edge in edges {
+        let cross = (edge.p1 - edge.p0).cross(point - edge.p0);
+        if edge.p0.y <= point.y {
+            if edge.p1.y > point.y && cross > 0.0 {
+                winding += 1;
+            }
+        } else if edge.p1.y <= point.y && cross < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Splits the edges of `a` and `b` where they intersect any other edge, so that edges only
+/// meet at their endpoints.
+fn split_edges(a: &[Edge], b: &[Edge]) -> Vec<Edge> {
+    let edges: Vec<Edge> = a.iter().chain(b).copied().collect();
+    // The points at which each edge is split, with their position along the edge.
+    let mut splits: Vec<Vec<(f64, Point)>> = vec![Vec::new(); edges.len()];
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            intersect(&edges, i, j, &mut splits);
+        }
+    }
+    let mut pieces = Vec::with_capacity(edges.len());
+    for (edge, mut points) in edges.iter().zip(splits) {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut p0 = edge.p0;
+        for (_, point) in points {
+            if point != p0 && point != edge.p1 {
+                pieces.push(Edge { p0, p1: point });
+                p0 = point;
+            }
+        }
+        pieces.push(Edge { p0, p1: edge.p1 });
+    }
+    pieces
+}
+
+/// Finds where edges `i` and `j` intersect, and records the points at which they should be
+/// split.
+///
+/// Each intersection is computed once, and the same point is recorded for both edges, so that
+/// the pieces of the edges meet exactly.
+fn intersect(edges: &[Edge], i: usize, j: usize, splits: &mut [Vec<(f64, Point)>]) {
+    const EPSILON: f64 = 1e-9;
+    let (e, f) = (edges[i], edges[j]);
+    let e_bounds = Rect::from_points(e.p0, e.p1);
+    let f_bounds = Rect::from_points(f.p0, f.p1);
+    if e_bounds.x1 < f_bounds.x0
+        || f_bounds.x1 < e_bounds.x0
+        || e_bounds.y1 < f_bounds.y0
+        || f_bounds.y1 < e_bounds.y0
+    {
+        return;
+    }
+    let d = e.p1 - e.p0;
+    let g = f.p1 - f.p0;
+    let denominator = d.cross(g);
+    let scale = d.hypot() * g.hypot();
+    // The position of `point` along an edge, if it is strictly within the edge.
+    let along = |edge: Edge, point: Point| {
+        let direction = edge.p1 - edge.p0;
+        let t = (point - edge.p0).dot(direction) / direction.hypot2();
+        let distance = (point - edge.p0).cross(direction).abs() / direction.hypot();
+        (t > EPSILON && t < 1.0 - EPSILON && distance < EPSILON * (1.0 + direction.hypot()))
+            .then_some(t)
+    };
+    if denominator.abs() <= EPSILON * scale {
+        // Parallel edges only need splitting where they overlap, at each other's endpoints.
+        for (index, edge, other) in [(i, e, f), (j, f, e)] {
+            for point in [other.p0, other.p1] {
+                if let Some(t) = along(edge, point) {
+                    splits[index].push((t, point));
+                }
+            }
+        }
+        return;
+    }
+    let t = (f.p0 - e.p0).cross(g) / denominator;
+    let u = (f.p0 - e.p0).cross(d) / denominator;
+    if !(-EPSILON..=1.0 + EPSILON).contains(&t) || !(-EPSILON..=1.0 + EPSILON).contains(&u) {
+        return;
+    }
+    let near_end = |t: f64| t <= EPSILON || t >= 1.0 - EPSILON;
+    match (near_end(t), near_end(u)) {
+        // The edges meet at their endpoints, so neither needs splitting.
+        (true, true) => {}
+        // An endpoint of `e` lies on `f`.
+        (true, false) => {
+            let point = if t <= EPSILON { e.p0 } else { e.p1 };
+            splits[j].push((u, point));
+        }
+        // An endpoint of `f` lies on `e`.
+        (false, true) => {
+            let point = if u <= EPSILON { f.p0 } else { f.p1 };
+            splits[i].push((t, point));
+        }
+        (false, false) => {
+            let point = e.p0 + d * t;
+            splits[i].push((t, point));
+            splits[j].push((u, point));
+        }
+    }
+}
+
+/// A key for exactly comparing points.
+fn key(point: Point) -> (u64, u64) {
+    (point.x.to_bits(), point.y.to_bits())
+}
+
+/// Joins directed edges into closed polygons, removing points which are in the middle of a
+/// straight line.
+fn chain(edges: &[Edge]) -> BezPath {
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (index, edge) in edges.iter().enumerate() {
+        outgoing.entry(key(edge.p0)).or_default().push(index);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut path = BezPath::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut polygon = vec![edges[first].p0];
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let end = edges[current].p1;
+            let next = outgoing
+                .get(&key(end))
+                .and_then(|candidates| candidates.iter().copied().find(|&index| !used[index]));
+            match next {
+                Some(next) => {
+                    polygon.push(end);
+                    current = next;
+                }
+                None => break,
+            }
+        }
+        simplify(&mut polygon);
+        if polygon.len() < 3 {
+            continue;
+        }
+        path.move_to(polygon[0]);
+        for &point in &polygon[1..] {
+            path.line_to(point);
+        }
+        path.close_path();
+    }
+    path
+}
+
+/// Removes the points of a closed polygon which are in the middle of a straight line.
+fn simplify(polygon: &mut Vec<Point>) {
+    let mut changed = true;
+    while changed && polygon.len() >= 3 {
+        changed = false;
+        let n = polygon.len();
+        for index in 0..n {
+            let prev = polygon[(index + n - 1) % n];
+            let point = polygon[index];
+            let next = polygon[(index + 1) % n];
+            let (incoming, outgoing) = (point - prev, next - point);
+            if incoming.cross(outgoing) == 0.0 && incoming.dot(outgoing) >= 0.0 {
+                polygon.remove(index);
+                changed = true;
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Perspective transforms, for 2.5D effects such as tilting or flipping cards.
+
+use peniko::kurbo::{Affine, BezPath, PathEl, Point, Rect, Shape};
+
+/// The smallest `w` coordinate of a point in front of the viewer.
+const MIN_W: f64 = 1e-6;
+
+/// A 3×3 projective transform, as applied by [`Scene::fill_perspective`] and
+/// [`Scene::stroke_perspective`].
+///
+/// The matrix is in row-major order. A point `(x, y)` is mapped to `(x' / w, y' / w)`,
+/// where `[x', y', w]` is the product of the matrix with `[x, y, 1]`. Unlike an [`Affine`]
+/// transform, this can map a rectangle to any convex quadrilateral, such as the trapezoid
+/// of a card which is tilted away from the viewer.
+///
+/// Points which are mapped to a `w` of zero or less are on or behind the plane of the
+/// viewer, and have no position.
+///
+/// [`Scene::fill_perspective`]: crate::Scene::fill_perspective
+/// [`Scene::stroke_perspective`]: crate::Scene::stroke_perspective
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Perspective(pub [f64; 9]);
+
+impl Perspective {
+    /// The transform which doesn't move any points.
+    pub const IDENTITY: Self = Self([
+        1.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, //
+        0.0, 0.0, 1.0, //
+    ]);
+
+    /// Returns the transform which maps the corners of `rect` to the corners of `quad`.
+    ///
+    /// The corners of `quad` are in the order of the top left, top right, bottom right and
+    /// bottom left corners of `rect`. Returns `None` if `rect` has no area, or if three of
+    /// the corners of `quad` are on a line.
+    pub fn rect_to_quad(rect: Rect, quad: [Point; 4]) -> Option<Self> {
+        if rect.is_zero_area() {
+            return None;
+        }
+        let [p0, p1, p2, p3] = quad;
+        let collinear = |a: Point, b: Point, c: Point| (b - a).cross(c - a) == 0.0;
+        if collinear(p0, p1, p2)
+            || collinear(p1, p2, p3)
+            || collinear(p2, p3, p0)
+            || collinear(p3, p0, p1)
+        {
+            return None;
+        }
+        // The transform from the unit square to `quad`, following Heckbert's "Fundamentals
+        // of Texture Mapping and Image Warping".
+        let (dx1, dy1) = (p1.x - p2.x, p1.y - p2.y);
+        let (dx2, dy2) = (p3.x - p2.x, p3.y - p2.y);
+        let (dx3, dy3) = (p0.x - p1.x + p2.x - p3.x, p0.y - p1.y + p2.y - p3.y);
+        let det = dx1 * dy2 - dx2 * dy1;
+        if det == 0.0 {
+            return None;
+        }
+        let g = (dx3 * dy2 - dx2 * dy3) / det;
+        let h = (dx1 * dy3 - dx3 * dy1) / det;
+        let from_unit = Self([
+            p1.x - p0.x + g * p1.x,
+            p3.x - p0.x + h * p3.x,
+            p0.x,
+            p1.y - p0.y + g * p1.y,
+            p3.y - p0.y + h * p3.y,
+            p0.y,
+            g,
+            h,
+            1.0,
+        ]);
+        let to_unit = Affine::translate(-rect.origin().to_vec2())
+            .then_scale_non_uniform(1.0 / rect.width(), 1.0 / rect.height());
+        let transform = Self::from(to_unit).then(&from_unit);
+        transform
+            .0
+            .iter()
+            .all(|x| x.is_finite())
+            .then_some(transform)
+    }
+
+    /// Returns the transform which rotates the plane by `angle` radians around a horizontal
+    /// axis through `center`, as seen by a viewer `distance` units in front of `center`.
+    ///
+    /// Positive angles bring the top of the plane towards the viewer. An angle of a quarter
+    /// turn leaves the plane edge on, and a smaller `distance` gives a stronger perspective.
+    pub fn tilt_x(angle: f64, center: Point, distance: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let depth = sin / distance;
+        let tilt = Self([
+            1.0, 0.0, 0.0, //
+            0.0, cos, 0.0, //
+            0.0, depth, 1.0, //
+        ]);
+        Self::around(center, tilt)
+    }
+
+    /// Returns the transform which rotates the plane by `angle` radians around a vertical
+    /// axis through `center`, as seen by a viewer `distance` units in front of `center`.
+    ///
+    /// Positive angles bring the left of the plane towards the viewer, as when a card is
+    /// flipped from right to left.
+    pub fn tilt_y(angle: f64, center: Point, distance: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let depth = sin / distance;
+        let tilt = Self([
+            cos, 0.0, 0.0, //
+            0.0, 1.0, 0.0, //
+            depth, 0.0, 1.0, //
+        ]);
+        Self::around(center, tilt)
+    }
+
+    /// Returns `transform` applied with `center` as the origin.
+    fn around(center: Point, transform: Self) -> Self {
+        Self::from(Affine::translate(-center.to_vec2()))
+            .then(&transform)
+            .then(&Self::from(Affine::translate(center.to_vec2())))
+    }
+
+    /// Returns the transform which applies `self`, then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let (a, b) = (&self.0, &next.0);
+        let mut result = [0.0; 9];
+        for row in 0..3 {
+            for column in 0..3 {
+                result[row * 3 + column] = (0..3).map(|k| b[row * 3 + k] * a[k * 3 + column]).sum();
+            }
+        }
+        Self(result)
+    }
+
+    /// Applies the transform to `point`.
+    ///
+    /// Returns `None` if the point is mapped to the plane of the viewer or behind it.
+    pub fn apply(&self, point: Point) -> Option<Point> {
+        let [a, b, c, d, e, f, g, h, i] = self.0;
+        let w = g * point.x + h * point.y + i;
+        // Written so that a NaN `w` has no position.
+        if w.is_nan() || w < MIN_W {
+            return None;
+        }
+        Some(Point::new(
+            (a * point.x + b * point.y + c) / w,
+            (d * point.x + e * point.y + f) / w,
+        ))
+    }
+
+    /// Returns `shape`, drawn with `transform`, mapped by the perspective transform.
+    ///
+    /// As curves aren't preserved by perspective, the shape is flattened to lines with
+    /// `tolerance`, in the coordinates after `transform`. Returns `None` if any point of the
+    /// shape has no position after the transform.
+    pub(crate) fn warp(
+        &self,
+        transform: Affine,
+        shape: &impl Shape,
+        tolerance: f64,
+    ) -> Option<BezPath> {
+        let mut path = BezPath::new();
+        let mut valid = true;
+        let elements = shape
+            .path_elements(tolerance)
+            .map(|element| transform * element);
+        peniko::kurbo::flatten(elements, tolerance, |element| {
+            // Once a point has no position, the rest of the path can't be connected to it.
+            if !valid {
+                return;
+            }
+            let element = match element {
+                PathEl::MoveTo(p) => self.apply(p).map(PathEl::MoveTo),
+                PathEl::LineTo(p) => self.apply(p).map(PathEl::LineTo),
+                PathEl::ClosePath => Some(PathEl::ClosePath),
+                // `flatten` only produces lines.
+                PathEl::QuadTo(..) | PathEl::CurveTo(..) => None,
+            };
+            match element {
+                Some(element) => path.push(element),
+                None => valid = false,
+            }
+        });
+        valid.then_some(path)
+    }
+}
+
+impl Default for Perspective {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f64; 9]> for Perspective {
+    fn from(matrix: [f64; 9]) -> Self {
+        Self(matrix)
+    }
+}
+
+impl From<Affine> for Perspective {
+    fn from(affine: Affine) -> Self {
+        let [a, b, c, d, e, f] = affine.as_coeffs();
+        Self([
+            a, c, e, //
+            b, d, f, //
+            0.0, 0.0, 1.0, //
+        ])
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for running a user-provided fragment shader over the rendered scene.
+
+use std::borrow::Cow;
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+use wgpu::{Device, Queue, Texture, TextureFormat, TextureView};
+
+/// A WGSL fragment shader which is run over the rendered scene, set using
+/// [`Renderer::set_post_process`](crate::Renderer::set_post_process).
+///
+/// The source is appended to a prelude, which provides:
+///
+/// ```wgsl
+/// struct PostProcessUniforms {
+///     // The size of the target, or of the `RenderParams::viewport`, in pixels.
+///     resolution: vec2<f32>,
+///     // The time in seconds, see `Renderer::set_post_process_time`.
+///     time: f32,
+/// }
+///
+/// // The scene rendered by Vello, with unpremultiplied alpha.
+/// @group(0) @binding(0) var source_texture: texture_2d<f32>;
+/// // A bilinear sampler, which clamps to the edge of the texture.
+/// @group(0) @binding(1) var source_sampler: sampler;
+/// @group(0) @binding(2) var<uniform> uniforms: PostProcessUniforms;
+///
+/// struct VertexOutput {
+///     @builtin(position) position: vec4<f32>,
+///     // The texture coordinate of this fragment, from (0, 0) in the top left to (1, 1).
+///     @location(0) uv: vec2<f32>,
+/// }
+/// ```
+///
+/// The shader must contain a fragment entry point with the signature
+/// `fn(in: VertexOutput) -> @location(0) vec4<f32>`, whose output is written to the target.
+/// For example, a shader which inverts the colors of the scene is:
+///
+/// ```wgsl
+/// @fragment
+/// fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+///     let color = textureSample(source_texture, source_sampler, in.uv);
+///     return vec4(1.0 - color.rgb, color.a);
+/// }
+/// ```
+///
+/// # Animated fills
+///
+/// The `time` uniform can be used to animate procedural effects, such as noise or a shimmer,
+/// without re-encoding the scene each frame. To apply an effect to a single shape, fill that
+/// shape with a key color which isn't used elsewhere in the scene, and replace it in the shader.
+/// For example, this sweeps a band of light across shapes filled with pure red:
+///
+/// ```wgsl
+/// const KEY: vec3<f32> = vec3(1.0, 0.0, 0.0);
+///
+/// @fragment
+/// fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+///     let color = textureSample(source_texture, source_sampler, in.uv);
+///     // How much of this pixel is covered by the keyed fill.
+///     let key = 1.0 - clamp(distance(color.rgb, KEY) * 4.0, 0.0, 1.0);
+///     // A band of light which sweeps across the target once per second.
+///     let phase = fract(uniforms.time) * 2.0 - in.uv.x;
+///     let shimmer = vec3(1.0, 0.5 + 0.5 * cos(phase * 6.2831853), 0.0);
+///     return vec4(mix(color.rgb, shimmer, key), color.a);
+/// }
+/// ```
+///
+/// The scene only needs to be rendered again when the time changes, so that the shader runs.
+#[derive(Clone, Debug)]
+pub struct PostProcessShader {
+    /// The WGSL source of the shader.
+    pub source: Cow<'static, str>,
+    /// The name of the fragment entry point in `source`.
+    pub entry_point: Cow<'static, str>,
+}
+
+impl PostProcessShader {
+    /// Creates a post-process shader from WGSL source, with the fragment entry point `fs_main`.
+    pub fn new(source: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            source: source.into(),
+            entry_point: "fs_main".into(),
+        }
+    }
+
+    /// Sets the name of the fragment entry point.
+    #[must_use]
+    pub fn with_entry_point(mut self, entry_point: impl Into<Cow<'static, str>>) -> Self {
+        self.entry_point = entry_point.into();
+        self
+    }
+}
+
+const PRELUDE: &str = r"
+struct PostProcessUniforms {
+    resolution: vec2<f32>,
+    time: f32,
+    _padding: f32,
+}
+
+@group(0) @binding(0) var source_texture: texture_2d<f32>;
+@group(0) @binding(1) var source_sampler: sampler;
+@group(0) @binding(2) var<uniform> uniforms: PostProcessUniforms;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vello_post_process_vertex(@builtin(vertex_index) index: u32) -> VertexOutput {
+    // A single triangle which covers the full target.
+    let uv = vec2(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.position = vec4(uv * vec2(2.0, -2.0) + vec2(-1.0, 1.0), 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+";
+
+/// This must be kept in sync with `PostProcessUniforms` in [`PRELUDE`].
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+#[repr(C)]
+struct PostProcessUniforms {
+    resolution: [f32; 2],
+    time: f32,
+    _padding: f32,
+}
+
+/// The state needed to run a [`PostProcessShader`].
+///
+/// The pipeline is created lazily, as creating it requires a device, and is created again
+/// if the blend state it was created with changes.
+pub(crate) struct PostProcess {
+    shader: PostProcessShader,
+    pipeline: Option<(
+        Option<wgpu::BlendState>,
+        wgpu::RenderPipeline,
+        wgpu::BindGroupLayout,
+    )>,
+    intermediate: Option<(Texture, TextureView)>,
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+    time: Option<f32>,
+}
+
+impl PostProcess {
+    pub(crate) fn new(shader: PostProcessShader) -> Self {
+        Self {
+            shader,
+            pipeline: None,
+            intermediate: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+            time: None,
+        }
+    }
+
+    pub(crate) fn set_time(&mut self, time: Option<f32>) {
+        self.time = time;
+    }
+
+    fn time(&self) -> f32 {
+        #[cfg(not(target_arch = "wasm32"))]
+        let elapsed = self.start.elapsed().as_secs_f32();
+        // `std::time::Instant` isn't available on the web.
+        #[cfg(target_arch = "wasm32")]
+        let elapsed = 0.0;
+        self.time.unwrap_or(elapsed)
+    }
+
+    /// Returns the texture which the scene should be rendered into before [`Self::run`] is called.
+    pub(crate) fn intermediate_view(
+        &mut self,
+        device: &Device,
+        width: u32,
+        height: u32,
+    ) -> &TextureView {
+        let needs_new = self
+            .intermediate
+            .as_ref()
+            .is_none_or(|(texture, _)| texture.width() != width || texture.height() != height);
+        if needs_new {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("vello.post_process.source"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+                format: TextureFormat::Rgba8Unorm,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            self.intermediate = Some((texture, view));
+        }
+        &self.intermediate.as_ref().unwrap().1
+    }
+
+    /// Runs the shader over the intermediate texture, writing the result to the region of
+    /// `target` with its top left corner at `origin`, blended using `blend` if it is set.
+    pub(crate) fn run(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        target: &TextureView,
+        origin: [u32; 2],
+        width: u32,
+        height: u32,
+        blend: Option<wgpu::BlendState>,
+    ) {
+        let time = self.time();
+        if self
+            .pipeline
+            .as_ref()
+            .is_none_or(|(created_with, ..)| *created_with != blend)
+        {
+            let (pipeline, bind_group_layout) = create_pipeline(device, &self.shader, blend);
+            self.pipeline = Some((blend, pipeline, bind_group_layout));
+        }
+        let (_, pipeline, bind_group_layout) = self.pipeline.as_ref().unwrap();
+        let Some((_, source)) = &self.intermediate else {
+            return;
+        };
+        let uniforms = PostProcessUniforms {
+            resolution: [width as f32, height as f32],
+            time,
+            _padding: 0.0,
+        };
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("vello.post_process.uniforms"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("vello.post_process.sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("vello.post_process"),
+            layout: bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.post_process"),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("vello.post_process"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        // The target outside the viewport is left unchanged.
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            pass.set_viewport(
+                origin[0] as f32,
+                origin[1] as f32,
+                width as f32,
+                height as f32,
+                0.0,
+                1.0,
+            );
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        queue.submit([encoder.finish()]);
+    }
+}
+
+fn create_pipeline(
+    device: &Device,
+    shader: &PostProcessShader,
+    blend: Option<wgpu::BlendState>,
+) -> (wgpu::RenderPipeline, wgpu::BindGroupLayout) {
+    let source = format!("{PRELUDE}\n{}", shader.source);
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("vello.post_process"),
+        source: wgpu::ShaderSource::Wgsl(source.into()),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("vello.post_process"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("vello.post_process"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("vello.post_process"),
+        layout: Some(&pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: &module,
+            entry_point: Some("vello_post_process_vertex"),
+            buffers: &[],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &module,
+            entry_point: Some(&shader.entry_point),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: TextureFormat::Rgba8Unorm,
+                blend,
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview: None,
+        cache: None,
+    });
+    (pipeline, bind_group_layout)
+}
@@ -84,6 +84,12 @@ pub enum Command {
     DispatchIndirect(ShaderId, BufferProxy, u64, Vec<ResourceProxy>),
     #[cfg(feature = "debug_layers")]
     Draw(DrawParams),
+    /// Commands to begin a debug group, which is shown in graphics debuggers.
+    PushDebugGroup(String),
+    /// Commands to end the innermost debug group.
+    PopDebugGroup,
+    /// Commands to insert a debug marker, which is shown in graphics debuggers.
+    InsertDebugMarker(String),
 }
 
 /// The type of resource that will be bound to a slot in a shader.
@@ -203,6 +209,22 @@ impl Recording {
         self.push(Command::Clear(buf, 0, None));
     }
 
+    /// Begins a debug group, which contains the following commands until
+    /// [`pop_debug_group`](Self::pop_debug_group) is called.
+    pub fn push_debug_group(&mut self, label: String) {
+        self.push(Command::PushDebugGroup(label));
+    }
+
+    /// Ends the innermost debug group.
+    pub fn pop_debug_group(&mut self) {
+        self.push(Command::PopDebugGroup);
+    }
+
+    /// Inserts a debug marker.
+    pub fn insert_debug_marker(&mut self, label: String) {
+        self.push(Command::InsertDebugMarker(label));
+    }
+
     /// Commands to free the given buffer.
     pub fn free_buffer(&mut self, buf: BufferProxy) {
         self.push(Command::FreeBuffer(buf));
@@ -9,14 +9,27 @@ use crate::{AaConfig, RenderParams};
 
 #[cfg(feature = "wgpu")]
 use crate::Scene;
+#[cfg(feature = "wgpu")]
+use crate::recording::ResourceId;
 
-use vello_encoding::{Encoding, Resolver, WorkgroupSize, make_mask_lut, make_mask_lut_16};
+use vello_encoding::{
+    BufferSize, Encoding, Resolver, WorkgroupSize, make_mask_lut, make_mask_lut_16,
+    make_mask_lut_for_pattern,
+};
 
 /// State for a render in progress.
 pub struct Render {
     fine_wg_count: Option<WorkgroupSize>,
     fine_resources: Option<FineResources>,
     mask_buf: Option<ResourceProxy>,
+    /// Custom sample positions for MSAA, see [`crate::RendererOptions::msaa_sample_pattern`].
+    msaa_sample_pattern: Option<Vec<[f32; 2]>>,
+    /// The size of the blend spill buffer in words, see
+    /// [`crate::RendererOptions::intermediate_budget_bytes`].
+    blend_spill_size: Option<u32>,
+    /// Whether the viewport is rendered at the origin of the output image, see
+    /// [`Render::set_viewport_at_origin`].
+    viewport_at_origin: bool,
 
     #[cfg(feature = "debug_layers")]
     captured_buffers: Option<CapturedBuffers>,
@@ -79,8 +92,57 @@ pub(crate) fn render_full(
     resolver: &mut Resolver,
     shaders: &FullShaders,
     params: &RenderParams,
+    msaa_sample_pattern: Option<&[[f32; 2]]>,
+    blend_spill_size: Option<u32>,
+    viewport_at_origin: bool,
 ) -> (Recording, ResourceProxy) {
-    render_encoding_full(scene.encoding(), resolver, shaders, params)
+    let (recording, target) = render_encoding_full(
+        scene.encoding(),
+        resolver,
+        shaders,
+        params,
+        msaa_sample_pattern,
+        blend_spill_size,
+        viewport_at_origin,
+    );
+    (with_layer_markers(scene, recording), target)
+}
+
+/// Returns `recording`, preceded by debug markers for the labels of the layers of `scene`.
+///
+/// The markers are in a `vello.layers` debug group, with a nested group for each labeled
+/// layer, so that they show the structure of the layers in graphics debuggers. They are
+/// recorded before the passes, as all layers are rasterized in the same passes.
+#[cfg(feature = "wgpu")]
+pub(crate) fn with_layer_markers(scene: &Scene, recording: Recording) -> Recording {
+    let labels = scene.layer_labels();
+    if labels.is_empty() {
+        return recording;
+    }
+    let mut markers = Recording::default();
+    markers.push_debug_group("vello.layers".into());
+    // Whether each layer which is currently open has a label, and so a debug group.
+    let mut open = Vec::new();
+    let mut labels = labels.iter().peekable();
+    for (index, tag) in scene.encoding().draw_tags.iter().enumerate() {
+        if *tag == vello_encoding::DrawTag::BEGIN_CLIP {
+            let label = labels.next_if(|label| label.draw_index == index);
+            if let Some(label) = label {
+                markers.push_debug_group(label.label.clone());
+                markers.insert_debug_marker(format!("draw {index}"));
+            }
+            open.push(label.is_some());
+        } else if *tag == vello_encoding::DrawTag::END_CLIP && open.pop() == Some(true) {
+            markers.pop_debug_group();
+        }
+    }
+    // Layers which are still open at the end of the scene.
+    for _ in open.into_iter().filter(|labeled| *labeled) {
+        markers.pop_debug_group();
+    }
+    markers.pop_debug_group();
+    markers.commands.extend(recording.commands);
+    markers
 }
 
 #[cfg(feature = "wgpu")]
@@ -93,8 +155,14 @@ pub(crate) fn render_encoding_full(
     resolver: &mut Resolver,
     shaders: &FullShaders,
     params: &RenderParams,
+    msaa_sample_pattern: Option<&[[f32; 2]]>,
+    blend_spill_size: Option<u32>,
+    viewport_at_origin: bool,
 ) -> (Recording, ResourceProxy) {
     let mut render = Render::new();
+    render.set_msaa_sample_pattern(msaa_sample_pattern);
+    render.set_blend_spill_size(blend_spill_size);
+    render.set_viewport_at_origin(viewport_at_origin);
     let mut recording = render.render_encoding_coarse(encoding, resolver, shaders, params, false);
     let out_image = render.out_image();
     render.record_fine(shaders, &mut recording);
@@ -113,11 +181,35 @@ impl Render {
             fine_wg_count: None,
             fine_resources: None,
             mask_buf: None,
+            msaa_sample_pattern: None,
+            blend_spill_size: None,
+            viewport_at_origin: false,
             #[cfg(feature = "debug_layers")]
             captured_buffers: None,
         }
     }
 
+    /// Set the sample positions used for MSAA, instead of the default positions.
+    ///
+    /// Patterns which don't have the number of samples of the anti-aliasing method, or which
+    /// are rejected by [`make_mask_lut_for_pattern`], are ignored.
+    pub fn set_msaa_sample_pattern(&mut self, pattern: Option<&[[f32; 2]]>) {
+        self.msaa_sample_pattern = pattern.map(<[_]>::to_vec);
+    }
+
+    /// Set the size of the buffer for layers nested more than four deep, in 32-bit words,
+    /// instead of the default size.
+    pub fn set_blend_spill_size(&mut self, size: Option<u32>) {
+        self.blend_spill_size = size;
+    }
+
+    /// Set whether the [viewport](crate::RenderParams::viewport) is rendered at the origin of
+    /// the output image, rather than at its position in the target, such as when the output is
+    /// an intermediate texture which only covers the viewport.
+    pub fn set_viewport_at_origin(&mut self, viewport_at_origin: bool) {
+        self.viewport_at_origin = viewport_at_origin;
+    }
+
     /// Prepare a recording for the coarse rasterization phase.
     ///
     /// The `robust` parameter controls whether we're preparing for readback
@@ -154,8 +246,30 @@ impl Render {
         for image in images.images {
             recording.write_image(image_atlas, image.1, image.2, image.0.clone());
         }
-        let cpu_config =
-            RenderConfig::new(&layout, params.width, params.height, &params.base_color);
+        // The scene is rendered into the viewport, as if it were the whole target. Callers
+        // skip rendering when the viewport is empty.
+        let ([offset_x, offset_y], [width, height]) = params.viewport_region().unwrap_or_default();
+        let mut cpu_config = RenderConfig::new(&layout, width, height, &params.base_color);
+        if !self.viewport_at_origin {
+            cpu_config.gpu.target_offset_x = offset_x;
+            cpu_config.gpu.target_offset_y = offset_y;
+        }
+        if params.text_gamma.is_finite() && params.text_gamma > 0.0 {
+            cpu_config.gpu.text_gamma = params.text_gamma;
+        }
+        cpu_config.gpu.alpha_to_coverage = params.alpha_to_coverage.into();
+        cpu_config.gpu.debug_view = params.debug.to_u32();
+        cpu_config.gpu.premultiplied_output = params.premultiplied_alpha.into();
+        if let Some(size) = self.blend_spill_size {
+            cpu_config.buffer_sizes.blend_spill = BufferSize::new(size);
+            cpu_config.gpu.blend_size = size;
+        }
+        if let Some(tolerance) = params.flatten_tolerance
+            && tolerance.is_finite()
+            && tolerance > 0.0
+        {
+            cpu_config.gpu.flatten_tolerance = tolerance as f32;
+        }
         // HACK: The coarse workgroup counts is the number of active bins.
         if (cpu_config.workgroup_counts.coarse.0
             * cpu_config.workgroup_counts.coarse.1
@@ -534,11 +648,20 @@ impl Render {
             }
             _ => {
                 if self.mask_buf.is_none() {
-                    let mask_lut = match fine.aa_config {
-                        AaConfig::Msaa16 => make_mask_lut_16(),
-                        AaConfig::Msaa8 => make_mask_lut(),
+                    let n_samples = match fine.aa_config {
+                        AaConfig::Msaa16 => 16,
+                        AaConfig::Msaa8 => 8,
                         _ => unreachable!(),
                     };
+                    let custom_lut = self
+                        .msaa_sample_pattern
+                        .as_deref()
+                        .filter(|pattern| pattern.len() == n_samples)
+                        .and_then(make_mask_lut_for_pattern);
+                    let mask_lut = custom_lut.unwrap_or_else(|| match fine.aa_config {
+                        AaConfig::Msaa16 => make_mask_lut_16(),
+                        _ => make_mask_lut(),
+                    });
                     let buf = recording.upload("vello.mask_lut", mask_lut);
                     self.mask_buf = Some(buf.into());
                 }
@@ -590,6 +713,21 @@ impl Render {
         self.fine_resources.as_ref().unwrap().out_image
     }
 
+    /// The labels of the images used by fine rasterization, which unlike buffers aren't named
+    /// in the recording.
+    #[cfg(feature = "wgpu")]
+    pub(crate) fn image_labels(&self) -> [(ResourceId, &'static str); 3] {
+        let fine = self.fine_resources.as_ref().unwrap();
+        [
+            (
+                fine.gradient_image.as_image().unwrap().id,
+                "vello.gradients",
+            ),
+            (fine.image_atlas.as_image().unwrap().id, "vello.image_atlas"),
+            (fine.out_image.id, "vello.target"),
+        ]
+    }
+
     pub fn bump_buf(&self) -> BufferProxy {
         *self
             .fine_resources
@@ -1,13 +1,17 @@
 // Copyright 2022 the Vello Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::ops::Range;
 use std::sync::Arc;
 
 use peniko::{
     BlendMode, Blob, Brush, BrushRef, Color, ColorStop, ColorStops, ColorStopsSource, Compose,
-    Extend, Fill, FontData, Gradient, ImageBrush, ImageBrushRef, ImageData, StyleRef,
+    Extend, Fill, FontData, Gradient, ImageBrush, ImageBrushRef, ImageData, Mix, StyleRef,
     color::{AlphaColor, DynamicColor, Srgb, palette},
-    kurbo::{Affine, BezPath, Point, Rect, Shape, Stroke, StrokeOpts, Vec2},
+    kurbo::{
+        self, Affine, BezPath, Cap, Circle, Ellipse, Point, Rect, RoundedRect, RoundedRectRadii,
+        Shape, Stroke, StrokeOpts, Vec2,
+    },
 };
 use png::{BitDepth, ColorType, Transformations};
 use skrifa::bitmap::BitmapFormat;
@@ -21,7 +25,15 @@ use skrifa::{
 };
 #[cfg(feature = "bump_estimate")]
 use vello_encoding::BumpAllocatorMemory;
-use vello_encoding::{DrawBeginClip, Encoding, Glyph, GlyphRun, NormalizedCoord, Patch, Transform};
+use vello_encoding::{
+    DrawBeginClip, DrawBlurRoundedRect, DrawTag, Encoding, Glyph, GlyphRun, ImageColorSpace,
+    NormalizedCoord, Patch, PathTag, Transform,
+};
+
+use crate::Error;
+use crate::color_matrix::{Adjust, ColorMatrix};
+use crate::perspective::Perspective;
+use crate::scene_patch::ScenePatch;
 
 // TODO - Document invariants and edge cases (#470)
 // - What happens when we pass a transform matrix with NaN values to the Scene?
@@ -41,6 +53,9 @@ use vello_encoding::{DrawBeginClip, Encoding, Glyph, GlyphRun, NormalizedCoord,
 #[derive(Clone, Default)]
 pub struct Scene {
     encoding: Encoding,
+    layer_labels: Vec<LayerLabel>,
+    draw_ids: Vec<DrawId>,
+    cull_rect: Option<Rect>,
     #[cfg(feature = "bump_estimate")]
     estimator: vello_encoding::BumpEstimator,
 }
@@ -52,13 +67,179 @@ impl Scene {
         Self::default()
     }
 
+    /// Returns a scene which skips drawing anything which lies entirely outside `rect`.
+    ///
+    /// See [`Scene::set_cull_rect`] for details.
+    #[must_use]
+    pub fn with_cull_rect(mut self, rect: Rect) -> Self {
+        self.cull_rect = Some(rect);
+        self
+    }
+
+    /// Sets the rectangle outside of which draws are skipped when they are encoded.
+    ///
+    /// This is useful for scrolling views, where most of the content is off-screen. Each
+    /// fill, stroke, image and blurred rounded rectangle whose bounding box is entirely
+    /// outside `rect` isn't encoded, which saves both encoding and GPU time. Draws which are
+    /// partly inside `rect` are encoded as normal. The rectangle is in the coordinate space of
+    /// the scene, i.e. after each draw's transform has been applied; this is usually the
+    /// viewport in device pixels.
+    ///
+    /// Layers and glyph runs are never culled, and neither are scenes added using
+    /// [`Scene::append`]. The cull rectangle is kept when the scene is reset.
+    pub fn set_cull_rect(&mut self, rect: Option<Rect>) {
+        self.cull_rect = rect;
+    }
+
+    /// Returns the rectangle set using [`Scene::set_cull_rect`].
+    pub fn cull_rect(&self) -> Option<Rect> {
+        self.cull_rect
+    }
+
+    /// Sets whether fills, strokes and layer clips drawn after this call are anti-aliased.
+    ///
+    /// When disabled, the coverage of each pixel is rounded to either fully covered or not
+    /// covered, giving hard edges. This is useful for pixel art, or for shapes which are
+    /// aligned to the pixel grid and shouldn't have blended edges with their neighbours.
+    /// Only draws encoded while disabled are affected, so anti-aliasing can be set separately
+    /// for a fill and the stroke around it.
+    ///
+    /// Glyph runs drawn using [`Scene::draw_glyphs`] are always anti-aliased.
+    /// Anti-aliasing is enabled by default, and again after [`Scene::reset`].
+    pub fn set_antialiasing(&mut self, enabled: bool) {
+        if enabled {
+            self.encoding.flags &= !Encoding::ALIASED;
+        } else {
+            self.encoding.flags |= Encoding::ALIASED;
+        }
+    }
+
+    /// Returns whether draws are currently anti-aliased, as set using
+    /// [`Scene::set_antialiasing`].
+    pub fn antialiasing(&self) -> bool {
+        self.encoding.flags & Encoding::ALIASED == 0
+    }
+
+    /// Returns `true` if a draw with the bounding box `bounds`, in local coordinates, is
+    /// entirely outside the cull rectangle after applying `transform`.
+    fn is_culled(&self, transform: Affine, bounds: Rect) -> bool {
+        let Some(cull_rect) = self.cull_rect else {
+            return false;
+        };
+        let bounds = transform.transform_rect_bbox(bounds);
+        // Written so that bounds containing NaN are never culled.
+        bounds.x1 < cull_rect.x0
+            || bounds.x0 > cull_rect.x1
+            || bounds.y1 < cull_rect.y0
+            || bounds.y0 > cull_rect.y1
+    }
+
+    /// Returns the bounding box of `shape`, for checking with [`Self::is_degenerate`].
+    ///
+    /// Unlike [`Shape::bounding_box`], a rectangle with a NaN coordinate has non-finite bounds,
+    /// rather than the NaN being dropped when the rectangle is normalized.
+    fn shape_bounds(shape: &impl Shape) -> Rect {
+        match shape.as_rect() {
+            Some(rect) if !rect.is_finite() => rect,
+            _ => shape.bounding_box(),
+        }
+    }
+
+    /// Returns `true` if geometry with the given bounds should be skipped, because it or
+    /// `transform` isn't finite, such as from NaN in animation math.
+    ///
+    /// If `needs_area` is `true`, geometry whose bounds have no area is also skipped, as
+    /// filling it would draw nothing.
+    fn is_degenerate(transform: Affine, bounds: Rect, needs_area: bool) -> bool {
+        if !transform.is_finite() || !bounds.is_finite() {
+            log::debug!("Skipping a draw with non-finite geometry: {bounds:?} with {transform:?}");
+            return true;
+        }
+        needs_area && bounds.is_zero_area()
+    }
+
+    /// The largest standard deviation of a blur, in device pixels.
+    ///
+    /// Larger blurs are clamped to this. A blur is evaluated over an extent of 2.5 standard
+    /// deviations on each side of its rectangle, so at this size the blurred area is already
+    /// wider than the largest render target supported by most devices (8192 pixels), and
+    /// larger blurs would only add work, and memory for the tiles of the blurred area.
+    pub const MAX_BLUR_STD_DEV: f64 = 2048.0;
+
+    /// Returns the largest standard deviation of a blur drawn with `transform`, in local
+    /// coordinates, i.e. [`Scene::MAX_BLUR_STD_DEV`] divided by the scale of `transform`.
+    ///
+    /// Blurs with a larger standard deviation are clamped to this, so comparing against it
+    /// reports whether a blur will be clamped, such as to limit a slider in a user interface.
+    pub fn max_blur_std_dev(transform: Affine) -> f64 {
+        let (max_scale, _) = Self::scale_factors(transform);
+        if max_scale.is_finite() && max_scale > 1e-9 {
+            Self::MAX_BLUR_STD_DEV / max_scale
+        } else {
+            Self::MAX_BLUR_STD_DEV
+        }
+    }
+
+    /// Clamps a finite, non-negative `std_dev` to [`Self::max_blur_std_dev`].
+    ///
+    /// Infinite values are kept, so that they are still rejected as degenerate.
+    fn clamp_blur_std_dev(transform: Affine, std_dev: f64) -> f64 {
+        if std_dev.is_finite() {
+            std_dev.min(Self::max_blur_std_dev(transform))
+        } else {
+            std_dev
+        }
+    }
+
+    /// The tolerance for converting a shape drawn with `transform` to a Bézier path, in the
+    /// shape's local coordinates.
+    ///
+    /// This only affects shapes which aren't already Bézier paths, such as arcs and ellipses.
+    /// The tolerance is scaled by the largest scale factor of `transform`, so that the error
+    /// stays below a hundredth of a pixel however much the shape is magnified.
+    fn shape_tolerance(transform: Affine) -> f64 {
+        const DEVICE_TOLERANCE: f64 = 0.01;
+        let (max_scale, _) = Self::scale_factors(transform);
+        if max_scale.is_finite() && max_scale > 1e-9 {
+            DEVICE_TOLERANCE / max_scale
+        } else {
+            DEVICE_TOLERANCE
+        }
+    }
+
+    /// Returns the largest and smallest scale factors of `transform`, i.e. the singular values
+    /// of its linear part, ignoring the translation.
+    fn scale_factors(transform: Affine) -> (f64, f64) {
+        let [a, b, c, d, _, _] = transform.as_coeffs();
+        let q = (0.5 * (a + d)).hypot(0.5 * (b - c));
+        let r = (0.5 * (a - d)).hypot(0.5 * (b + c));
+        (q + r, (q - r).abs())
+    }
+
     /// Removes all content from the scene.
+    ///
+    /// The memory allocated for the scene's contents is kept, so that a scene which is
+    /// reset and rebuilt every frame doesn't need to reallocate once it has reached the size
+    /// of a typical frame. To also release that memory, use [`Scene::reset_and_shrink`].
     pub fn reset(&mut self) {
         self.encoding.reset();
+        self.layer_labels.clear();
+        self.draw_ids.clear();
         #[cfg(feature = "bump_estimate")]
         self.estimator.reset();
     }
 
+    /// Removes all content from the scene, and releases the memory which was allocated for it.
+    ///
+    /// This is useful in memory constrained environments, such as after rendering an
+    /// unusually complex frame. Building the next scene will need to allocate again.
+    pub fn reset_and_shrink(&mut self) {
+        self.reset();
+        self.encoding.shrink_to_fit();
+        self.layer_labels.shrink_to_fit();
+        self.draw_ids.shrink_to_fit();
+    }
+
     /// Tally up the bump allocator estimate for the current state of the encoding,
     /// taking into account an optional `transform` applied to the entire scene.
     #[cfg(feature = "bump_estimate")]
@@ -67,7 +248,89 @@ impl Scene {
             .tally(transform.as_ref().map(Transform::from_kurbo).as_ref())
     }
 
+    /// Estimates how expensive the scene is to render on the GPU, from its encoding.
+    ///
+    /// This is an advisory, coarse signal for detecting pathological scenes, such as those with
+    /// dozens of nested translucent layers or many large blurs, so that an application can warn
+    /// about them. It doesn't account for the cost of paths, which depends on their complexity
+    /// and the area they cover on screen, and isn't a prediction of frame time.
+    /// See [`CostEstimate`] for how the scene is scored.
+    ///
+    /// Glyph runs are counted as single draw objects, and layers in scenes appended with
+    /// [`Self::append`] are counted as encoded.
+    pub fn estimate_gpu_cost(&self) -> CostEstimate {
+        let encoding = &self.encoding;
+        let mut estimate = CostEstimate::default();
+        // Each draw object has exactly one path, so the transform which applies to each draw
+        // object is found by walking the path tags in step with the draw tags.
+        let mut path_tags = encoding.path_tags.iter();
+        let mut n_transforms = 0;
+        let mut draw_data_offset = 0;
+        // Whether each open layer is composited, rather than being a clip.
+        let mut layers = Vec::new();
+        let mut composited_depth = 0_usize;
+        for &tag in &encoding.draw_tags {
+            for &path_tag in path_tags.by_ref() {
+                if path_tag == PathTag::TRANSFORM {
+                    n_transforms += 1;
+                } else if path_tag == PathTag::PATH {
+                    break;
+                }
+            }
+            let data = &encoding.draw_data[draw_data_offset..];
+            draw_data_offset += tag.scene_size() as usize;
+            match tag {
+                DrawTag::BEGIN_CLIP => {
+                    let clip: DrawBeginClip =
+                        bytemuck::pod_read_unaligned(bytemuck::cast_slice(&data[..2]));
+                    let composited =
+                        clip.blend_mode != DrawBeginClip::CLIP_BLEND_MODE || clip.alpha != 1.0;
+                    estimate.layers += 1;
+                    if composited {
+                        estimate.composited_layers += 1;
+                        composited_depth += 1;
+                    }
+                    layers.push(composited);
+                    estimate.max_layer_depth = estimate.max_layer_depth.max(layers.len());
+                }
+                DrawTag::END_CLIP => {
+                    if layers.pop() == Some(true) {
+                        composited_depth -= 1;
+                    }
+                }
+                DrawTag::BLUR_RECT => {
+                    estimate.draws += 1;
+                    estimate.blurs += 1;
+                    let blur: DrawBlurRoundedRect =
+                        bytemuck::pod_read_unaligned(bytemuck::cast_slice(&data[..5]));
+                    let scale = match n_transforms {
+                        0 => 1.0,
+                        n => {
+                            let [a, b, c, d] = encoding.transforms[n - 1].matrix;
+                            f64::from(a * d - b * c).abs()
+                        }
+                    };
+                    // The blur is evaluated over its extent of 2.5 standard deviations, and
+                    // its pixels are composited again by each enclosing composited layer.
+                    let extent = 5.0 * f64::from(blur.std_dev.max(0.0));
+                    let area = (f64::from(blur.width) + extent)
+                        * (f64::from(blur.height) + extent)
+                        * scale;
+                    if area.is_finite() {
+                        estimate.blur_area += area * (1 + composited_depth) as f64;
+                    }
+                }
+                _ => estimate.draws += 1,
+            }
+        }
+        estimate
+    }
+
     /// Returns the underlying raw encoding.
+    ///
+    /// This is the data which is uploaded to the GPU to render the scene, and can be used
+    /// by tools which inspect scenes. See [`Encoding`] for the meaning of each stream, and
+    /// [`Encoding::dump_layout`] for a readable summary.
     pub fn encoding(&self) -> &Encoding {
         &self.encoding
     }
@@ -87,6 +350,11 @@ impl Scene {
     /// - Use [`Fill`] to clip to the interior of the shape, with the chosen fill rule.
     /// - Use [`Stroke`] (via `&Stroke`) to clip to the stroked outline of the shape.
     ///
+    /// The `blend` is a [`BlendMode`], which combines a [`Mix`](peniko::Mix) for the colors
+    /// with a [`Compose`] operator for the alpha. For convenience, a [`Mix`](peniko::Mix) or a
+    /// [`Compose`] can be passed alone, which uses [`Compose::SrcOver`] or
+    /// [`Mix::Normal`](peniko::Mix::Normal) respectively for the other part.
+    ///
     /// Every drawing command after this call will be clipped by the shape
     /// until the layer is [popped](Self::pop_layer).
     /// For layers which are only added for clipping, you should
@@ -94,6 +362,26 @@ impl Scene {
     ///
     /// **However, the transforms are *not* saved or modified by the layer stack.**
     /// That is, the `transform` argument to this function only applies a transform to the `clip` shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vello::Scene;
+    /// use vello::kurbo::{Affine, Rect};
+    /// use vello::peniko::{BlendMode, Compose, Fill, Mix};
+    ///
+    /// let mut scene = Scene::new();
+    /// let clip = Rect::new(0.0, 0.0, 100.0, 100.0);
+    /// // Content in this layer is multiplied with the content below it.
+    /// let multiply = BlendMode::new(Mix::Multiply, Compose::SrcOver);
+    /// scene.push_layer(Fill::NonZero, multiply, 1.0, Affine::IDENTITY, &clip);
+    /// scene.pop_layer();
+    /// // Content in this layer erases the content below it.
+    /// // This is the same as `BlendMode::new(Mix::Normal, Compose::DestOut)`.
+    /// let erase = BlendMode::from(Compose::DestOut);
+    /// scene.push_layer(Fill::NonZero, erase, 1.0, Affine::IDENTITY, &clip);
+    /// scene.pop_layer();
+    /// ```
     #[expect(
         single_use_lifetimes,
         reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
@@ -110,12 +398,148 @@ impl Scene {
         let blend = blend.into();
         self.push_layer_inner(
             DrawBeginClip::new(blend, alpha.clamp(0.0, 1.0)),
+            None,
             clip_style.into(),
             transform,
             clip,
         );
     }
 
+    /// Pushes a new layer, as in [`push_layer`](Self::push_layer), with a label used for debugging.
+    ///
+    /// When the scene is rendered, the labels of its layers are inserted as debug groups
+    /// in a `vello.layers` debug group, which are nested like the layers, so that they can be
+    /// found in graphics debuggers such as `RenderDoc`. Note that all layers are rasterized in the
+    /// same compute passes, so the groups are recorded before the passes, and each contains a
+    /// marker with the index of the layer's draw object in [`Encoding::draw_tags`], rather
+    /// than separate GPU work.
+    ///
+    /// The labels are also available using [`layer_labels`](Self::layer_labels).
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    #[track_caller]
+    pub fn push_layer_labeled<'a>(
+        &mut self,
+        label: impl Into<String>,
+        clip_style: impl Into<StyleRef<'a>>,
+        blend: impl Into<BlendMode>,
+        alpha: f32,
+        transform: Affine,
+        clip: &impl Shape,
+    ) {
+        self.push_layer(clip_style, blend, alpha, transform, clip);
+        self.layer_labels.push(LayerLabel {
+            label: label.into(),
+            draw_index: self.encoding.draw_tags.len() - 1,
+        });
+    }
+
+    /// Returns the labels of the layers pushed using [`push_layer_labeled`](Self::push_layer_labeled).
+    pub fn layer_labels(&self) -> &[LayerLabel] {
+        &self.layer_labels
+    }
+
+    /// Calls `draw` to draw into this scene, and tags the draw objects it adds with `id`.
+    ///
+    /// The id is metadata which isn't rendered. It allows draws to be correlated with the
+    /// elements of an application's own scene graph, such as for targeting animations or
+    /// updates, using [`draw_ids`](Self::draw_ids) or [`draw_id`](Self::draw_id).
+    /// The id is kept when the scene is [appended](Self::append) to another scene.
+    ///
+    /// Nothing is recorded if `draw` doesn't add any draw objects, such as when its shape is
+    /// culled. Draws which are tagged inside `draw` are tagged with their own ids, and aren't
+    /// tagged with `id`. Glyph runs are only expanded into draw objects when the scene is
+    /// rendered, so they can't be tagged.
+    pub fn draw_with_id(&mut self, id: u64, draw: impl FnOnce(&mut Self)) {
+        let start = self.encoding.draw_tags.len();
+        let first_id = self.draw_ids.len();
+        draw(self);
+        let end = self.encoding.draw_tags.len();
+        // Tag the draws between any nested tagged draws, keeping the tags in draw order.
+        let mut tagged = Vec::new();
+        let mut next = start;
+        for nested in &self.draw_ids[first_id..] {
+            if nested.draws.start > next {
+                tagged.push(DrawId {
+                    id,
+                    draws: next..nested.draws.start,
+                });
+            }
+            tagged.push(nested.clone());
+            next = nested.draws.end;
+        }
+        if end > next {
+            tagged.push(DrawId {
+                id,
+                draws: next..end,
+            });
+        }
+        self.draw_ids.truncate(first_id);
+        self.draw_ids.extend(tagged);
+    }
+
+    /// Fills a shape, as in [`fill`](Self::fill), and tags its draw objects with `id`.
+    ///
+    /// See [`draw_with_id`](Self::draw_with_id) for how ids are used.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_with_id<'b>(
+        &mut self,
+        id: u64,
+        style: Fill,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        self.draw_with_id(id, |scene| {
+            scene.fill(style, transform, brush, brush_transform, shape);
+        });
+    }
+
+    /// Strokes a shape, as in [`stroke`](Self::stroke), and tags its draw objects with `id`.
+    ///
+    /// See [`draw_with_id`](Self::draw_with_id) for how ids are used.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke_with_id<'b>(
+        &mut self,
+        id: u64,
+        style: &Stroke,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        self.draw_with_id(id, |scene| {
+            scene.stroke(style, transform, brush, brush_transform, shape);
+        });
+    }
+
+    /// Returns the ids of the draws tagged using [`draw_with_id`](Self::draw_with_id), in the
+    /// order of their draw objects.
+    pub fn draw_ids(&self) -> &[DrawId] {
+        &self.draw_ids
+    }
+
+    /// Returns the id of the draw object at `draw_index` in [`Encoding::draw_tags`], if it was
+    /// tagged using [`draw_with_id`](Self::draw_with_id).
+    pub fn draw_id(&self, draw_index: usize) -> Option<u64> {
+        let index = self
+            .draw_ids
+            .partition_point(|tagged| tagged.draws.end <= draw_index);
+        self.draw_ids
+            .get(index)
+            .filter(|tagged| tagged.draws.contains(&draw_index))
+            .map(|tagged| tagged.id)
+    }
+
     /// Pushes a new layer clipped by the specified shape and treated like a luminance
     /// mask for the current layer.
     ///
@@ -157,6 +581,7 @@ impl Scene {
     ) {
         self.push_layer_inner(
             DrawBeginClip::luminance_mask(alpha.clamp(0.0, 1.0)),
+            None,
             clip_style.into(),
             transform,
             clip,
@@ -192,13 +617,20 @@ impl Scene {
         transform: Affine,
         clip: &impl Shape,
     ) {
-        self.push_layer_inner(DrawBeginClip::clip(), clip_style.into(), transform, clip);
+        self.push_layer_inner(
+            DrawBeginClip::clip(),
+            None,
+            clip_style.into(),
+            transform,
+            clip,
+        );
     }
 
     /// Helper for logic shared between [`Self::push_layer`] and [`Self::push_luminance_mask_layer`]
     fn push_layer_inner<'a>(
         &mut self,
         parameters: DrawBeginClip,
+        color_matrix: Option<ColorMatrix>,
         clip_style: StyleRef<'a>,
         transform: Affine,
         clip: &impl Shape,
@@ -241,7 +673,12 @@ impl Scene {
                     .count_path(path.into_iter(), &Transform::IDENTITY, None);
             }
         }
-        self.encoding.encode_begin_clip(parameters);
+        match color_matrix {
+            Some(matrix) => self
+                .encoding
+                .encode_begin_clip_with_color_matrix(parameters, matrix.0),
+            None => self.encoding.encode_begin_clip(parameters),
+        }
     }
 
     /// Pops the current layer.
@@ -249,15 +686,123 @@ impl Scene {
         self.encoding.encode_end_clip();
     }
 
+    /// Pushes a new layer clipped by `clip`, whose content is transformed by the color
+    /// `matrix` before it is composited, like the CSS `filter` property.
+    ///
+    /// The constructors of [`ColorMatrix`] give the matrices of the CSS `grayscale()`,
+    /// `sepia()`, `hue-rotate()` and `saturate()` filters, and a `[f32; 20]` in the layout of
+    /// the SVG `feColorMatrix` filter can also be passed. The matrix is applied to the
+    /// rendered pixels of the layer, so it applies to everything drawn in it, including
+    /// images, glyphs and scenes added using [`append`](Self::append), after they have been
+    /// blended together. NaN values in the matrix are treated as zero.
+    ///
+    /// The layer is composited with [`Mix::Normal`] and is ended by
+    /// [`pop_layer`](Self::pop_layer). To also set the layer's blend mode or opacity, use
+    /// [`push_stacking_context`](Self::push_stacking_context). Like other layers, the
+    /// layer's content is only transformed within `clip`, and the transparent pixels in it
+    /// are transformed too, so a matrix which adds to the alpha channel colors the whole clip.
+    ///
+    /// Up to 32767 layers with a color matrix can be rendered in one scene. Layers beyond that
+    /// are composited without their color matrix.
+    pub fn push_color_matrix_layer(
+        &mut self,
+        matrix: impl Into<ColorMatrix>,
+        transform: Affine,
+        clip: &impl Shape,
+    ) {
+        let ColorMatrix(matrix) = matrix.into();
+        let matrix = matrix.map(|value| if value.is_nan() { 0.0 } else { value });
+        self.push_layer_inner(
+            DrawBeginClip::new(BlendMode::default(), 1.0),
+            Some(ColorMatrix(matrix)),
+            Fill::NonZero.into(),
+            transform,
+            clip,
+        );
+    }
+
+    /// Pushes a new layer clipped by `clip`, whose rendered content is adjusted by `adjust`
+    /// before it is composited, like the CSS `brightness()`, `contrast()`, `saturate()` and
+    /// `hue-rotate()` filters.
+    ///
+    /// This is a [color matrix layer](Self::push_color_matrix_layer) with the matrix of
+    /// `adjust`, so it applies to everything drawn in the layer after it has been blended
+    /// together, and is ended by [`pop_layer`](Self::pop_layer).
+    pub fn push_adjust_layer(&mut self, adjust: Adjust, transform: Affine, clip: &impl Shape) {
+        self.push_color_matrix_layer(adjust, transform, clip);
+    }
+
+    /// Pushes a stacking context, which clips and composites everything drawn into it as a
+    /// group, like a stacking context in CSS, optionally filtering its colors.
+    ///
+    /// This is a single layer with the context's blend mode and opacity, whose content is
+    /// transformed by the context's [`filter`](StackingContext::filter), as for
+    /// [`push_color_matrix_layer`](Self::push_color_matrix_layer), before it is composited.
+    /// The stacking context is ended by a single [`pop_layer`](Self::pop_layer).
+    #[track_caller]
+    pub fn push_stacking_context(
+        &mut self,
+        context: &StackingContext,
+        transform: Affine,
+        clip: &impl Shape,
+    ) {
+        self.push_layer_inner(
+            DrawBeginClip::new(context.blend, context.opacity.clamp(0.0, 1.0)),
+            context.filter,
+            Fill::NonZero.into(),
+            transform,
+            clip,
+        );
+    }
+
+    /// Clears everything drawn so far in the current layer within `shape`, making it fully transparent.
+    ///
+    /// The edges of the cleared region are anti-aliased.
+    /// This also clears the [base color](crate::RenderParams::base_color) within `shape`, if it
+    /// is drawn at the root of the scene.
+    ///
+    /// This is a single draw, which scales the content under it by one minus its coverage, so
+    /// unlike drawing with [`Compose::DestOut`], it doesn't need a layer.
+    /// To clear only content drawn in a layer, push it using [`Self::push_layer`]. Layers
+    /// pushed using [`Self::push_clip_layer`] may be drawn directly into the layer below them
+    /// where the clip is opaque, so clearing in one can also clear the layer below.
+    pub fn clear_region(&mut self, shape: &impl Shape, transform: Affine) {
+        let bounds = Self::shape_bounds(shape);
+        if Self::is_degenerate(transform, bounds, true) || self.is_culled(transform, bounds) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_clear_style(Fill::NonZero);
+        if self.encoding.encode_shape(shape, true) {
+            // The color isn't painted, but fills the draw object of the path.
+            self.encoding.encode_color(palette::css::TRANSPARENT);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator
+                .count_path(shape.path_elements(0.1), &t, None);
+        }
+    }
+
     /// Draw a rounded rectangle blurred with a gaussian filter.
-    pub fn draw_blurred_rounded_rect(
+    ///
+    /// See [`Self::draw_blurred_rounded_rect_in`] for how brushes other than a solid color
+    /// are drawn. For example, a shadow or soft light whose color varies from its center can
+    /// be drawn with a radial gradient brush.
+    /// A negative or NaN `std_dev` is treated as zero, i.e. no blur, and a `std_dev` larger
+    /// than [`Scene::max_blur_std_dev`] is clamped to it.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn draw_blurred_rounded_rect<'b>(
         &mut self,
         transform: Affine,
         rect: Rect,
-        brush: Color,
+        brush: impl Into<BrushRef<'b>>,
         radius: f64,
         std_dev: f64,
     ) {
+        let std_dev = Self::clamp_blur_std_dev(transform, std_dev.max(0.0));
         // The impulse response of a gaussian filter is infinite.
         // For performance reason we cut off the filter at some extent where the response is close to zero.
         let kernel_size = 2.5 * std_dev;
@@ -266,106 +811,905 @@ impl Scene {
         self.draw_blurred_rounded_rect_in(&shape, transform, rect, brush, radius, std_dev);
     }
 
-    /// Draw a rounded rectangle blurred with a gaussian filter in `shape`.
+    /// Draws a rectangle with smoothed corners, as drawn by [`Self::fill_squircle_rect`],
+    /// blurred with a gaussian filter, such as for the shadow of a squircle.
     ///
-    /// For performance reasons, `shape` should not extend more than approximately 2.5 times
-    /// `std_dev` away from the edges of `rect` (as any such points will not be perceptably painted to,
-    /// but calculations will still be performed for them).
+    /// The blur is computed analytically for a rounded rectangle, so the squircle is
+    /// approximated by the rounded rectangle which removes the same area from each corner.
+    /// The outlines of the two are within 4% of `radius` of each other, which is hidden by the
+    /// blur. If `std_dev` is zero, the squircle itself is filled, so the shadow's silhouette
+    /// matches a squircle filled with the same arguments.
     ///
-    /// This method effectively draws the blurred rounded rectangle clipped to the given shape.
-    /// If just the blurred rounded rectangle is desired without clipping,
-    /// use the simpler [`Self::draw_blurred_rounded_rect`].
-    /// For many users, that method will be easier to use.
-    pub fn draw_blurred_rounded_rect_in(
+    /// A negative or NaN `std_dev` is treated as zero, and a `std_dev` larger than
+    /// [`Scene::max_blur_std_dev`] is clamped to it.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn draw_blurred_squircle_rect<'b>(
         &mut self,
-        shape: &impl Shape,
         transform: Affine,
         rect: Rect,
-        brush: Color,
+        brush: impl Into<BrushRef<'b>>,
         radius: f64,
+        smoothing: f64,
         std_dev: f64,
     ) {
-        let t = Transform::from_kurbo(&transform);
-        self.encoding.encode_transform(t);
-
-        self.encoding.encode_fill_style(Fill::NonZero);
-        if self.encoding.encode_shape(&shape, true) {
-            let brush_transform =
-                Transform::from_kurbo(&transform.pre_translate(rect.center().to_vec2()));
-            if self.encoding.encode_transform(brush_transform) {
-                self.encoding.swap_last_path_tags();
-            }
-            self.encoding.encode_blurred_rounded_rect(
-                brush,
-                rect.width() as _,
-                rect.height() as _,
-                radius as _,
-                std_dev as _,
-            );
+        let std_dev = Self::clamp_blur_std_dev(transform, std_dev.max(0.0));
+        if std_dev == 0.0 {
+            self.fill_squircle_rect(transform, rect, brush, radius, smoothing);
+            return;
         }
+        let radius = crate::path::squircle_equivalent_radius(rect, radius, smoothing);
+        self.draw_blurred_rounded_rect(transform, rect, brush, radius, std_dev);
     }
 
-    /// Fills a shape using the specified style and brush.
+    /// Draws a blurred glow along the edge of a rounded rectangle, such as for a focus ring.
+    ///
+    /// This is like a box shadow with no offset. With [`GlowKind::Outer`], the glow surrounds
+    /// `shape` without covering it, like a CSS `box-shadow`. With [`GlowKind::Inner`], the glow
+    /// is inside `shape` and fades towards its center, like a CSS `inset` box shadow.
+    /// `spread` moves the edge of the glow away from `shape` before it is blurred: outwards for
+    /// outer glows and inwards for inner glows. Negative values are clamped to zero.
+    ///
+    /// The blur uses a single corner radius, which is the largest of the radii of `shape`.
+    /// A negative or NaN `std_dev` is treated as zero, and a `std_dev` larger than
+    /// [`Scene::max_blur_std_dev`] is clamped to it.
+    ///
+    /// The brush can be a gradient, such as a radial gradient for a colored light which changes
+    /// color away from its center. As with [`Self::draw_blurred_rounded_rect_in`], only the
+    /// coverage of the glow is blurred, and the brush is sampled in the coordinate system given
+    /// by `transform`.
     #[expect(
         single_use_lifetimes,
         reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
     )]
-    pub fn fill<'b>(
+    pub fn draw_glow_rounded_rect<'b>(
         &mut self,
-        style: Fill,
         transform: Affine,
+        shape: RoundedRect,
         brush: impl Into<BrushRef<'b>>,
-        brush_transform: Option<Affine>,
-        shape: &impl Shape,
+        spread: f64,
+        std_dev: f64,
+        kind: GlowKind,
     ) {
-        let t = Transform::from_kurbo(&transform);
-        self.encoding.encode_transform(t);
-        self.encoding.encode_fill_style(style);
-        if self.encoding.encode_shape(shape, true) {
-            if let Some(brush_transform) = brush_transform
-                && self
-                    .encoding
-                    .encode_transform(Transform::from_kurbo(&(transform * brush_transform)))
-            {
-                self.encoding.swap_last_path_tags();
+        // See the note about tolerances in `Self::stroke_with_alpha`.
+        const SHAPE_TOLERANCE: f64 = 0.01;
+
+        let brush = brush.into();
+        let std_dev = Self::clamp_blur_std_dev(transform, std_dev.max(0.0));
+        let spread = spread.max(0.0);
+        let rect = shape.rect();
+        let radii = shape.radii();
+        let radius = radii
+            .top_left
+            .max(radii.top_right)
+            .max(radii.bottom_right)
+            .max(radii.bottom_left);
+        match kind {
+            GlowKind::Outer => {
+                let glow_rect = rect.inflate(spread, spread);
+                let kernel_size = 2.5 * std_dev;
+                // Clip to the area between the extent of the blur and `shape`, so that the glow
+                // doesn't cover the shape.
+                let mut ring = glow_rect
+                    .inflate(kernel_size + 1.0, kernel_size + 1.0)
+                    .to_path(SHAPE_TOLERANCE);
+                ring.extend(shape.path_elements(SHAPE_TOLERANCE));
+                self.push_clip_layer(Fill::EvenOdd, transform, &ring);
+                self.draw_blurred_rounded_rect(
+                    transform,
+                    glow_rect,
+                    brush,
+                    radius + spread,
+                    std_dev,
+                );
+                self.pop_layer();
+            }
+            GlowKind::Inner => {
+                // The glow is the shape, minus a blurred copy of the shape which is inset by
+                // `spread`, so it is strongest at the edge of the shape.
+                let inset = spread.min(0.5 * rect.width().min(rect.height()));
+                let inner_rect = rect.inflate(-inset, -inset);
+                self.push_layer(Fill::NonZero, Mix::Normal, 1.0, transform, &shape);
+                self.fill(Fill::NonZero, transform, brush, None, &shape);
+                self.push_layer(Fill::NonZero, Compose::DestOut, 1.0, transform, &shape);
+                self.draw_blurred_rounded_rect(
+                    transform,
+                    inner_rect,
+                    palette::css::BLACK,
+                    (radius - inset).max(0.0),
+                    std_dev,
+                );
+                self.pop_layer();
+                self.pop_layer();
             }
-            self.encoding.encode_brush(brush, 1.0);
-            #[cfg(feature = "bump_estimate")]
-            self.estimator
-                .count_path(shape.path_elements(0.1), &t, None);
         }
     }
 
-    /// Strokes a shape using the specified style and brush.
+    /// Draws an outline around a rounded rectangle, like the CSS `outline` property.
+    ///
+    /// Unlike a stroke of `shape`, which straddles its edge, the outline is entirely outside
+    /// `shape`: its inner edge is `offset` away from `shape`, and it is `width` wide. The corner
+    /// radii of the outline grow with the offset, so the outline stays concentric with rounded
+    /// corners, while square corners stay square. A negative `offset` moves the outline
+    /// inside `shape`, as in CSS.
+    ///
+    /// This is useful for focus indicators, as the outline doesn't overlap the shape's
+    /// own border.
     #[expect(
         single_use_lifetimes,
         reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
     )]
-    pub fn stroke<'b>(
+    pub fn draw_outline<'b>(
         &mut self,
-        style: &Stroke,
         transform: Affine,
+        shape: RoundedRect,
         brush: impl Into<BrushRef<'b>>,
-        brush_transform: Option<Affine>,
-        shape: &impl Shape,
+        width: f64,
+        offset: f64,
+        style: OutlineStyle,
     ) {
-        // The setting for tolerance are a compromise. For most applications,
-        // shape tolerance doesn't matter, as the input is likely Bézier paths,
-        // which is exact. Note that shape tolerance is hard-coded as 0.1 in
-        // the encoding crate.
-        //
+        if width.is_nan() || width <= 0.0 {
+            return;
+        }
+        let rect = shape.rect();
+        // The outline is drawn as a stroke along its center line.
+        let distance = (offset + 0.5 * width).max(-0.5 * rect.width().min(rect.height()));
+        let grow = |radius: f64| {
+            if radius > 0.0 {
+                (radius + distance).max(0.0)
+            } else {
+                0.0
+            }
+        };
+        let radii = shape.radii();
+        let center_line = RoundedRect::from_rect(
+            rect.inflate(distance, distance),
+            RoundedRectRadii::new(
+                grow(radii.top_left),
+                grow(radii.top_right),
+                grow(radii.bottom_right),
+                grow(radii.bottom_left),
+            ),
+        );
+        let stroke = Stroke::new(width);
+        let stroke = match style {
+            OutlineStyle::Solid => stroke,
+            OutlineStyle::Dashed => stroke.with_dashes(0.0, [3.0 * width, 3.0 * width]),
+            // Each dot is a very short dash with round caps. The dashes aren't zero length, as
+            // the direction of the caps would then be undefined.
+            OutlineStyle::Dotted => stroke
+                .with_caps(Cap::Round)
+                .with_dashes(0.0, [0.01 * width, 1.99 * width]),
+        };
+        self.stroke(&stroke, transform, brush, None, &center_line);
+    }
+
+    /// Draw many blurred rounded rectangles, such as the shadows under a set of cards.
+    ///
+    /// This is a loop calling [`Self::draw_blurred_rounded_rect`] for each instance in order,
+    /// which reserves space for the whole batch up front, so it produces the same encoding and
+    /// overlapping shadows composite as they would if drawn individually.
+    ///
+    /// As with [`Self::fill_rounded_rects`], Vello's pipeline does not have a concept of
+    /// instanced draws, so each shadow is still processed as its own path on the GPU.
+    pub fn draw_box_shadows_batch(&mut self, shadows: &[BoxShadowInstance]) {
+        // Each shadow has its own brush transform, as well as the draw data for the blurred rect.
+        self.encoding.transforms.reserve(shadows.len() * 2);
+        self.encoding.draw_tags.reserve(shadows.len());
+        for shadow in shadows {
+            self.draw_blurred_rounded_rect(
+                shadow.transform,
+                shadow.rect,
+                shadow.color,
+                shadow.radius,
+                shadow.std_dev,
+            );
+        }
+    }
+
+    /// Draws the shadow of a surface raised to a Material Design elevation, given in dp, under
+    /// `shape`.
+    ///
+    /// The shadow is made of an ambient shadow and a darker shadow from a key light above the
+    /// surface, as computed by [`BoxShadowInstance::material_elevation`]. The shadow isn't
+    /// clipped, so `shape` should be filled after drawing it. Nothing is drawn for an
+    /// elevation of zero or less, or NaN.
+    pub fn draw_elevation_shadow(
+        &mut self,
+        transform: Affine,
+        shape: RoundedRect,
+        elevation_dp: f64,
+    ) {
+        if elevation_dp.is_nan() || elevation_dp <= 0.0 {
+            return;
+        }
+        let shadows = BoxShadowInstance::material_elevation(transform, shape, elevation_dp);
+        self.draw_box_shadows_batch(&shadows);
+    }
+
+    /// Draws only the shadow of a rounded rectangle of `size`, as an alpha mask for baking into
+    /// a reusable shadow sprite.
+    ///
+    /// The shadow is drawn in opaque black, translated so that the extent of its blur starts at
+    /// the origin. When the scene is rendered over a transparent
+    /// [`base_color`](crate::RenderParams::base_color), into a texture of the returned
+    /// [`ShadowMask::width`] and [`ShadowMask::height`], every pixel has zero color channels and
+    /// an alpha of the shadow's coverage, with either setting of
+    /// [`premultiplied_alpha`](crate::RenderParams::premultiplied_alpha).
+    /// The sprite can then be drawn as an image with any color, such as by multiplying it with a
+    /// color in a layer using [`Compose::SrcIn`].
+    ///
+    /// A negative or NaN `std_dev` is treated as zero, and a `std_dev` larger than
+    /// [`Scene::MAX_BLUR_STD_DEV`] is clamped to it. Nothing is drawn if `size` is empty or
+    /// any argument isn't finite.
+    pub fn draw_shadow_mask(&mut self, size: kurbo::Size, radius: f64, std_dev: f64) -> ShadowMask {
+        let std_dev = Self::clamp_blur_std_dev(Affine::IDENTITY, std_dev.max(0.0));
+        // The same extent as `Self::draw_blurred_rounded_rect`.
+        let margin = 2.5 * std_dev;
+        let rect = Rect::from_origin_size((margin, margin), size);
+        self.draw_blurred_rounded_rect(
+            Affine::IDENTITY,
+            rect,
+            palette::css::BLACK,
+            radius,
+            std_dev,
+        );
+        let extent = |length: f64| {
+            let length = (length + 2.0 * margin).ceil();
+            if length.is_finite() && length > 0.0 {
+                length as u32
+            } else {
+                0
+            }
+        };
+        let (width, height) = (extent(size.width), extent(size.height));
+        // The corners of the rectangle affect the shadow up to the blur's extent beyond them.
+        let slice_inset = (2.0 * margin + radius.max(0.0))
+            .min(0.5 * f64::from(width.min(height)))
+            .max(0.0);
+        ShadowMask {
+            width,
+            height,
+            offset: Vec2::new(margin, margin),
+            slice_inset,
+        }
+    }
+
+    /// Draw a rounded rectangle blurred with a gaussian filter in `shape`.
+    ///
+    /// For performance reasons, `shape` should not extend more than approximately 2.5 times
+    /// `std_dev` away from the edges of `rect` (as any such points will not be perceptably painted to,
+    /// but calculations will still be performed for them).
+    ///
+    /// This method effectively draws the blurred rounded rectangle clipped to the given shape.
+    /// If just the blurred rounded rectangle is desired without clipping,
+    /// use the simpler [`Self::draw_blurred_rounded_rect`].
+    /// For many users, that method will be easier to use.
+    ///
+    /// If `std_dev` is zero, there is no blur, and if `shape` is a rectangle which contains
+    /// `rect`, the rounded rectangle is drawn as a plain anti-aliased fill instead, as with
+    /// [`Self::fill`]. Otherwise, the rounded rectangle is still drawn with crisp, anti-aliased
+    /// edges. A negative or NaN `std_dev` is treated as zero, and a `std_dev` larger than
+    /// [`Scene::max_blur_std_dev`] is clamped to it, to bound the work done for the blur.
+    ///
+    /// Nothing is drawn if `rect` has a zero or negative width or height, or if `rect`, `shape`,
+    /// `transform`, `radius` or `std_dev` aren't finite.
+    ///
+    /// The brush can be a gradient or an image, such as for a glow which changes color across
+    /// its extent. Only the coverage of the rounded rectangle is blurred: the brush itself is
+    /// sampled at full resolution, in the coordinate system given by `transform`, as with
+    /// [`Self::fill`]. Brushes other than a solid color are drawn using two layers, which
+    /// is more expensive.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn draw_blurred_rounded_rect_in<'b>(
+        &mut self,
+        shape: &impl Shape,
+        transform: Affine,
+        rect: Rect,
+        brush: impl Into<BrushRef<'b>>,
+        radius: f64,
+        std_dev: f64,
+    ) {
+        let std_dev = Self::clamp_blur_std_dev(transform, std_dev.max(0.0));
+        let bounds = Self::shape_bounds(shape);
+        if Self::is_degenerate_blur(transform, bounds, rect, radius, std_dev)
+            || self.is_culled(transform, bounds)
+        {
+            return;
+        }
+        let brush = brush.into();
+        if std_dev == 0.0
+            && let Some(clip) = shape.as_rect()
+            && clip.union(rect) == clip
+        {
+            self.fill_sharp_rounded_rect(transform, rect, brush, radius);
+            return;
+        }
+        match brush {
+            BrushRef::Solid(color) => {
+                self.encode_blurred_rounded_rect_in(shape, transform, rect, color, radius, std_dev);
+            }
+            _ => {
+                // The blurred rounded rectangle is drawn opaque, as a mask for the brush, which
+                // is then composited only where the mask has coverage.
+                self.push_layer(Fill::NonZero, Mix::Normal, 1.0, transform, shape);
+                self.encode_blurred_rounded_rect_in(
+                    shape,
+                    transform,
+                    rect,
+                    palette::css::BLACK,
+                    radius,
+                    std_dev,
+                );
+                self.push_layer(Fill::NonZero, Compose::SrcIn, 1.0, transform, shape);
+                self.fill(Fill::NonZero, transform, brush, None, shape);
+                self.pop_layer();
+                self.pop_layer();
+            }
+        }
+    }
+
+    /// Returns `true` if a blurred rounded rectangle should be skipped, because its geometry
+    /// isn't finite or is empty, as described in [`Self::is_degenerate`].
+    fn is_degenerate_blur(
+        transform: Affine,
+        bounds: Rect,
+        rect: Rect,
+        radius: f64,
+        std_dev: f64,
+    ) -> bool {
+        Self::is_degenerate(transform, bounds, true)
+            || !rect.is_finite()
+            || rect.width() <= 0.0
+            || rect.height() <= 0.0
+            || !radius.is_finite()
+            || std_dev.is_infinite()
+    }
+
+    /// Fills the rounded rectangle which a blurred rounded rectangle with a standard deviation
+    /// of zero would draw.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    fn fill_sharp_rounded_rect<'b>(
+        &mut self,
+        transform: Affine,
+        rect: Rect,
+        brush: impl Into<BrushRef<'b>>,
+        radius: f64,
+    ) {
+        let rounded_rect = RoundedRect::from_rect(rect, radius);
+        self.fill(Fill::NonZero, transform, brush, None, &rounded_rect);
+    }
+
+    /// Encodes a blurred rounded rectangle, without the special cases of
+    /// [`Self::draw_blurred_rounded_rect_in`].
+    fn encode_blurred_rounded_rect_in(
+        &mut self,
+        shape: &impl Shape,
+        transform: Affine,
+        rect: Rect,
+        brush: Color,
+        radius: f64,
+        std_dev: f64,
+    ) {
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+
+        self.encoding.encode_fill_style(Fill::NonZero);
+        if self.encoding.encode_shape(&shape, true) {
+            let brush_transform =
+                Transform::from_kurbo(&transform.pre_translate(rect.center().to_vec2()));
+            if self.encoding.encode_transform(brush_transform) {
+                self.encoding.swap_last_path_tags();
+            }
+            self.encoding.encode_blurred_rounded_rect(
+                brush,
+                rect.width() as _,
+                rect.height() as _,
+                radius as _,
+                std_dev as _,
+            );
+        }
+    }
+
+    /// Fills a shape using the specified style and brush.
+    ///
+    /// `brush_transform` is applied to the brush's coordinates only, before `transform`, so a
+    /// gradient or image can be moved within the shape without moving the shape, such as to
+    /// scroll a texture inside a fixed rectangle. If it is `None`, the brush is in the same
+    /// coordinates as the shape.
+    ///
+    /// Nothing is drawn if the shape's bounds or `transform` aren't finite, such as from NaN
+    /// in animation math, or if the shape's bounds have no area.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill<'b>(
+        &mut self,
+        style: Fill,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        self.fill_with_alpha(style, transform, brush, 1.0, brush_transform, shape);
+    }
+
+    /// Fills a shape using the specified style and brush, with the alpha of the brush
+    /// multiplied by `alpha`.
+    ///
+    /// For a single shape, this is equivalent to filling it within a layer pushed with
+    /// [`Self::push_layer`] with an opacity of `alpha`, but doesn't need the intermediate
+    /// blend buffer which a layer uses, so is much cheaper.
+    /// Unlike a layer, the alpha applies to each draw independently, so where shapes drawn
+    /// with alpha overlap, the shapes below show through.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_with_alpha<'b>(
+        &mut self,
+        style: Fill,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        alpha: f32,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        let bounds = Self::shape_bounds(shape);
+        if Self::is_degenerate(transform, bounds, true) || self.is_culled(transform, bounds) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_fill_style(style);
+        if self.encoding.encode_shape(shape, true) {
+            if let Some(brush_transform) = brush_transform
+                && self
+                    .encoding
+                    .encode_transform(Transform::from_kurbo(&(transform * brush_transform)))
+            {
+                self.encoding.swap_last_path_tags();
+            }
+            self.encoding.encode_brush(brush.into(), alpha);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator
+                .count_path(shape.path_elements(0.1), &t, None);
+        }
+    }
+
+    /// Fills a shape with a solid color, drawn with `transform` and then warped by the
+    /// projective transform `perspective`, such as for a card tilted away from the viewer.
+    ///
+    /// See [`Perspective`] for constructors of common transforms, such as
+    /// [`Perspective::rect_to_quad`]. Curves aren't preserved by perspective, so the shape is
+    /// flattened to lines on the CPU before it is warped, which is slower to encode than
+    /// [`Self::fill`]. Nothing is drawn if any part of the shape is on or behind the plane of
+    /// the viewer.
+    ///
+    /// Only solid colors are supported, as Vello can't sample gradients or images through a
+    /// projective transform.
+    pub fn fill_perspective(
+        &mut self,
+        style: Fill,
+        perspective: impl Into<Perspective>,
+        transform: Affine,
+        color: impl Into<Color>,
+        shape: &impl Shape,
+    ) {
+        if Self::is_degenerate(transform, Self::shape_bounds(shape), true) {
+            return;
+        }
+        let Some(path) = perspective
+            .into()
+            .warp(transform, shape, PERSPECTIVE_TOLERANCE)
+        else {
+            return;
+        };
+        self.fill(style, Affine::IDENTITY, color.into(), None, &path);
+    }
+
+    /// Fills a path made of several subpaths, where the subpaths inside others are holes,
+    /// such as the outline of the letter "O".
+    ///
+    /// This uses the [`Fill::EvenOdd`] rule, so each region is filled if it is inside an odd
+    /// number of subpaths, and is a hole otherwise. Unlike with [`Fill::NonZero`], this doesn't
+    /// depend on the direction of the subpaths, which is often inconsistent in paths imported
+    /// from SVG or other formats. Subpaths which aren't explicitly closed are closed with a
+    /// straight line, as for every fill.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_path_with_holes<'b>(
+        &mut self,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        shape: &impl Shape,
+    ) {
+        self.fill(Fill::EvenOdd, transform, brush, None, shape);
+    }
+
+    /// Fills an axis-aligned rectangle.
+    ///
+    /// This is equivalent to calling [`Self::fill`] with [`Fill::NonZero`] and `rect`, but is
+    /// cheaper to encode, as the corners of the rectangle are encoded directly, rather than
+    /// through the generic [`Shape`] path iterator. `transform` is applied as usual, so the
+    /// rectangle can be rotated or skewed.
+    ///
+    /// The rectangle is still rendered as a path on the GPU, so this only reduces the cost of
+    /// building the scene.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_rect<'b>(&mut self, transform: Affine, rect: Rect, brush: impl Into<BrushRef<'b>>) {
+        if Self::is_degenerate(transform, rect, true) || self.is_culled(transform, rect) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_fill_style(Fill::NonZero);
+        if self.encoding.encode_rect(rect, true) {
+            self.encoding.encode_brush(brush.into(), 1.0);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator.count_path(rect.path_elements(0.1), &t, None);
+        }
+    }
+
+    /// Strokes the outline of an axis-aligned rectangle.
+    ///
+    /// This is equivalent to calling [`Self::stroke`] with `rect`, but is cheaper to encode,
+    /// in the same way as [`Self::fill_rect`]. Strokes with a dash pattern are drawn using
+    /// [`Self::stroke`].
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke_rect<'b>(
+        &mut self,
+        style: &Stroke,
+        transform: Affine,
+        rect: Rect,
+        brush: impl Into<BrushRef<'b>>,
+    ) {
+        if !style.dash_pattern.is_empty() {
+            self.stroke(style, transform, brush, None, &rect);
+            return;
+        }
+        if style.width == 0. || !style.width.is_finite() {
+            return;
+        }
+        if Self::is_degenerate(transform, rect, false) {
+            return;
+        }
+        // Miter joins at the corners extend up to √2 half-widths from the rectangle.
+        let extent = 0.5 * style.width.abs() * std::f64::consts::SQRT_2;
+        if self.is_culled(transform, rect.inflate(extent, extent)) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        let encoded_stroke = self.encoding.encode_stroke_style(style);
+        debug_assert!(encoded_stroke, "Stroke width is non-zero");
+        #[cfg(feature = "bump_estimate")]
+        self.estimator
+            .count_path(rect.path_elements(0.1), &t, Some(style));
+        if self.encoding.encode_rect(rect, false) {
+            self.encoding.encode_brush(brush.into(), 1.0);
+        }
+    }
+
+    /// Fills a rectangle with smoothed corners, also known as a squircle, like the
+    /// "continuous corners" of iOS and the corner smoothing of Figma.
+    ///
+    /// `smoothing` is the proportion of each corner which is eased into the edges, from 0 to 1,
+    /// where 0.6 matches iOS. With a `smoothing` of zero, this is the same as filling a
+    /// [`RoundedRect`] of `radius`. See [`path::squircle_rect`](crate::path::squircle_rect) for
+    /// the exact shape, which can also be used for strokes and clips.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_squircle_rect<'b>(
+        &mut self,
+        transform: Affine,
+        rect: Rect,
+        brush: impl Into<BrushRef<'b>>,
+        radius: f64,
+        smoothing: f64,
+    ) {
+        if smoothing.is_nan() || smoothing <= 0.0 {
+            let shape = RoundedRect::from_rect(rect, radius);
+            self.fill(Fill::NonZero, transform, brush, None, &shape);
+            return;
+        }
+        let shape = crate::path::squircle_rect(rect, radius, smoothing);
+        self.fill(Fill::NonZero, transform, brush, None, &shape);
+    }
+
+    /// Fills a rounded rectangle, with a border of `border_width` drawn inside its edge, as
+    /// for a CSS box with `box-sizing: border-box`.
+    ///
+    /// Filling the shape and then stroking its inside edge would blend the anti-aliased outer
+    /// edge of the fill with the border, leaving a faint line of the fill color around the
+    /// outside of the border. Filling the shape inside the border instead would leave a seam
+    /// of the background between the fill and the border, where both of their edges are
+    /// anti-aliased. This avoids both, by drawing the fill only up to the middle of the border,
+    /// so that its edge is hidden under the border, and drawing the border as a ring between
+    /// the shape and its inset. Pixels on the outer edge of the border are only covered by the
+    /// border, and pixels on the inner edge are covered by the border over the fill.
+    ///
+    /// As in CSS, the fill shows through a partially transparent border brush, in the inner
+    /// half of the border. The radii of the inside edge of the border are reduced by
+    /// `border_width`. If `border_width` isn't positive, only the fill is drawn, and if the
+    /// border is wide enough to cover the whole shape, only the border is drawn.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_rounded_rect_with_border<'b, 'c>(
+        &mut self,
+        transform: Affine,
+        shape: RoundedRect,
+        fill_brush: impl Into<BrushRef<'b>>,
+        border_brush: impl Into<BrushRef<'c>>,
+        border_width: f64,
+    ) {
+        let rect = shape.rect();
+        if border_width.is_nan() || border_width <= 0.0 {
+            self.fill(Fill::NonZero, transform, fill_brush, None, &shape);
+            return;
+        }
+        if 2.0 * border_width >= rect.width().min(rect.height()) {
+            self.fill(Fill::NonZero, transform, border_brush, None, &shape);
+            return;
+        }
+        let inset = |width: f64| {
+            let radii = shape.radii();
+            let radius = |r: f64| (r - width).max(0.0);
+            RoundedRect::from_rect(
+                rect.inflate(-width, -width),
+                RoundedRectRadii::new(
+                    radius(radii.top_left),
+                    radius(radii.top_right),
+                    radius(radii.bottom_right),
+                    radius(radii.bottom_left),
+                ),
+            )
+        };
+        self.fill(
+            Fill::NonZero,
+            transform,
+            fill_brush,
+            None,
+            &inset(0.5 * border_width),
+        );
+        let tolerance = Self::shape_tolerance(transform);
+        let mut ring = shape.to_path(tolerance);
+        ring.extend(inset(border_width).path_elements(tolerance));
+        self.fill(Fill::EvenOdd, transform, border_brush, None, &ring);
+    }
+
+    /// Fills many rounded rectangles, each with a solid color.
+    ///
+    /// This is equivalent to calling [`Self::fill`] with [`Fill::NonZero`] for each instance,
+    /// but the transform and fill style are encoded once for the whole batch, and
+    /// only the path and color of each rectangle is encoded per instance.
+    /// This is the most efficient way to draw a large number of similar cards or buttons.
+    ///
+    /// Note that Vello's pipeline does not have a concept of instanced draws, so each
+    /// rectangle is still processed as its own path on the GPU.
+    pub fn fill_rounded_rects(&mut self, transform: Affine, instances: &[(RoundedRect, Color)]) {
+        if instances.is_empty() {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_fill_style(Fill::NonZero);
+        self.encoding.draw_tags.reserve(instances.len());
+        self.encoding.draw_data.reserve(instances.len());
+        for (rect, color) in instances {
+            if Self::is_degenerate(transform, rect.rect(), true)
+                || self.is_culled(transform, rect.rect())
+            {
+                continue;
+            }
+            if self.encoding.encode_shape(rect, true) {
+                self.encoding.encode_color(*color);
+                #[cfg(feature = "bump_estimate")]
+                self.estimator.count_path(rect.path_elements(0.1), &t, None);
+            }
+        }
+    }
+
+    /// Fills a circle with the given center and radius.
+    ///
+    /// If the brush is a solid color, the coverage of the circle is computed analytically in the
+    /// fine rasterization stage, from its signed distance. This avoids the faceting which can be
+    /// seen on the edges of large circles when they are flattened into lines, so circles are
+    /// perfectly round at any size. Circles drawn with [anti-aliasing](Self::set_antialiasing)
+    /// disabled are filled as paths instead.
+    ///
+    /// Otherwise, this is equivalent to calling [`Self::fill`] with a [`Circle`] and
+    /// [`Fill::NonZero`].
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_circle<'b>(
+        &mut self,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        center: impl Into<Point>,
+        radius: f64,
+    ) {
+        let center = center.into();
+        let brush = brush.into();
+        if let BrushRef::Solid(color) = brush
+            && self.fill_ellipse_analytic(transform, color, center, Vec2::new(radius, radius))
+        {
+            return;
+        }
+        let circle = Circle::new(center, radius);
+        self.fill(Fill::NonZero, transform, brush, None, &circle);
+    }
+
+    /// Fills an axis-aligned ellipse with the given center and radii.
+    ///
+    /// Use `transform` to rotate the ellipse.
+    /// As with [`Self::fill_circle`], the coverage is computed analytically if the brush is a
+    /// solid color. Otherwise, this is equivalent to calling [`Self::fill`] with an [`Ellipse`]
+    /// and [`Fill::NonZero`].
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_ellipse<'b>(
+        &mut self,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        center: impl Into<Point>,
+        radii: impl Into<Vec2>,
+    ) {
+        let (center, radii) = (center.into(), radii.into());
+        let brush = brush.into();
+        if let BrushRef::Solid(color) = brush
+            && self.fill_ellipse_analytic(transform, color, center, radii)
+        {
+            return;
+        }
+        let ellipse = Ellipse::new(center, radii, 0.0);
+        self.fill(Fill::NonZero, transform, brush, None, &ellipse);
+    }
+
+    /// Encodes an ellipse whose coverage is computed from its signed distance, rather than by
+    /// flattening it.
+    ///
+    /// This uses the blurred rounded rectangle draw object with a standard deviation of zero,
+    /// for a unit circle which is scaled to the ellipse. The bounding shape extends one
+    /// device pixel beyond the ellipse, so that the anti-aliased edge isn't clipped.
+    ///
+    /// Returns `false` if nothing was encoded because the transform or radii are degenerate,
+    /// or the ellipse isn't anti-aliased, which the signed distance doesn't account for. The ellipse should then be filled as a path.
+    fn fill_ellipse_analytic(
+        &mut self,
+        transform: Affine,
+        color: Color,
+        center: Point,
+        radii: Vec2,
+    ) -> bool {
+        if !self.antialiasing() {
+            return false;
+        }
+        let bounds = Ellipse::new(center, radii, 0.0).bounding_box();
+        if Self::is_degenerate(transform, bounds, true) {
+            return false;
+        }
+        let unit_transform = transform
+            * Affine::translate(center.to_vec2())
+            * Affine::scale_non_uniform(radii.x, radii.y);
+        let (_, min_scale) = Self::scale_factors(unit_transform);
+        if !(min_scale.is_finite() && min_scale > 1e-9) {
+            return false;
+        }
+        let unit = Rect::new(-1.0, -1.0, 1.0, 1.0);
+        let margin = 1.0 / min_scale;
+        let shape = unit.inflate(margin, margin);
+        if !self.is_culled(unit_transform, shape) {
+            self.encode_blurred_rounded_rect_in(&shape, unit_transform, unit, color, 1.0, 0.0);
+        }
+        true
+    }
+
+    /// Strokes a shape using the specified style and brush.
+    ///
+    /// Any [`Shape`] can be stroked, including open shapes such as a
+    /// [`Line`](peniko::kurbo::Line), [`Arc`](peniko::kurbo::Arc) or
+    /// [`CubicBez`](peniko::kurbo::CubicBez), which are stroked from their start to their end
+    /// point with the style's caps.
+    /// Shapes which aren't Bézier paths, such as arcs, are converted to Bézier paths with a
+    /// tolerance which adapts to the scale of `transform`, so they stay accurate when magnified.
+    ///
+    /// If the style has a [dash pattern](Stroke::dash_pattern), the dashes are measured by
+    /// arc length along the shape, including around curves such as the corners of a
+    /// [`RoundedRect`], so the dash phase is continuous where straight edges meet curves.
+    /// The pattern starts at the start of the shape's path, offset by
+    /// [`dash_offset`](Stroke::dash_offset). For closed shapes, the dashes either side of
+    /// that point are only continuous if the pattern evenly divides the shape's perimeter.
+    ///
+    /// As with [`fill`](Self::fill), `brush_transform` moves the brush independently of the
+    /// shape.
+    ///
+    /// Nothing is drawn if the shape's bounds, `transform` or the stroke width aren't finite.
+    /// Unlike fills, shapes with no area, such as a straight line, are stroked.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke<'b>(
+        &mut self,
+        style: &Stroke,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        self.stroke_with_alpha(style, transform, brush, 1.0, brush_transform, shape);
+    }
+
+    /// Strokes a shape using the specified style and brush, with the alpha of the brush
+    /// multiplied by `alpha`.
+    ///
+    /// As with [`Self::fill_with_alpha`], this is a cheaper alternative to drawing the stroke
+    /// within a layer with an opacity of `alpha`.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke_with_alpha<'b>(
+        &mut self,
+        style: &Stroke,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        alpha: f32,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        // The setting for tolerance are a compromise. For most applications,
+        // shape tolerance doesn't matter, as the input is likely Bézier paths,
+        // which is exact. Other shapes, such as arcs, are converted using
+        // `Self::shape_tolerance`, which adapts to the scale of the transform.
+        //
         // Stroke tolerance is a different matter. Generally, the cost scales
         // with inverse O(n^6), so there is moderate rendering cost to setting
         // too fine a value. On the other hand, error scales with the transform
         // applied post-stroking, so may exceed visible threshold. When we do
         // GPU-side stroking, the transform will be known. In the meantime,
         // this is a compromise.
-        const SHAPE_TOLERANCE: f64 = 0.01;
-        const STROKE_TOLERANCE: f64 = SHAPE_TOLERANCE;
+        const STROKE_TOLERANCE: f64 = 0.01;
 
         const GPU_STROKES: bool = true; // Set this to `true` to enable GPU-side stroking
         if GPU_STROKES {
-            if style.width == 0. {
+            if style.width == 0. || !style.width.is_finite() {
+                return;
+            }
+            let bounds = Self::shape_bounds(shape);
+            if Self::is_degenerate(transform, bounds, false) {
+                return;
+            }
+            // Miter joins extend up to `miter_limit` half-widths from the path, and square caps
+            // up to √2 half-widths.
+            let extent = 0.5 * style.width.abs() * style.miter_limit.max(std::f64::consts::SQRT_2);
+            if self.is_culled(transform, bounds.inflate(extent, extent)) {
                 return;
             }
             let encode_result = self.stroke_gpu_inner(style, transform, shape);
@@ -377,17 +1721,239 @@ impl Scene {
                 {
                     self.encoding.swap_last_path_tags();
                 }
-                self.encoding.encode_brush(brush, 1.0);
+                self.encoding.encode_brush(brush.into(), alpha);
             }
         } else {
-            let stroked = peniko::kurbo::stroke(
-                shape.path_elements(SHAPE_TOLERANCE),
+            let stroked = kurbo::stroke(
+                shape.path_elements(Self::shape_tolerance(transform)),
                 style,
                 &StrokeOpts::default(),
                 STROKE_TOLERANCE,
             );
-            self.fill(Fill::NonZero, transform, brush, brush_transform, &stroked);
+            self.fill_with_alpha(
+                Fill::NonZero,
+                transform,
+                brush,
+                alpha,
+                brush_transform,
+                &stroked,
+            );
+        }
+    }
+
+    /// Strokes a shape with a solid color, drawn with `transform` and then warped by the
+    /// projective transform `perspective`.
+    ///
+    /// The stroke is converted to a fill in the shape's local coordinates, so its width
+    /// narrows with the perspective like the rest of the shape, and is then drawn using
+    /// [`Self::fill_perspective`]. Nothing is drawn if the stroke width isn't finite.
+    pub fn stroke_perspective(
+        &mut self,
+        style: &Stroke,
+        perspective: impl Into<Perspective>,
+        transform: Affine,
+        color: impl Into<Color>,
+        shape: &impl Shape,
+    ) {
+        if style.width == 0. || !style.width.is_finite() {
+            return;
+        }
+        let outline = kurbo::stroke(
+            shape.path_elements(Self::shape_tolerance(transform)),
+            style,
+            &StrokeOpts::default(),
+            Self::shape_tolerance(transform),
+        );
+        self.fill_perspective(Fill::NonZero, perspective, transform, color, &outline);
+    }
+
+    /// Strokes a shape, with a choice of whether the stroke scales with `transform`.
+    ///
+    /// With [`ScaleMode::World`], this is the same as [`Self::stroke`]. With
+    /// [`ScaleMode::Screen`], the stroke's width and dash pattern, and `brush_transform`, are
+    /// in the coordinate space of the scene rather than the shape's local coordinates.
+    /// The stroke then keeps the same width and dash lengths on screen as the view is zoomed,
+    /// like the `non-scaling-stroke` vector effect in SVG.
+    /// This is implemented by transforming the shape's path on the CPU before stroking it.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke_with_scale_mode<'b>(
+        &mut self,
+        style: &Stroke,
+        scale_mode: ScaleMode,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        shape: &impl Shape,
+    ) {
+        match scale_mode {
+            ScaleMode::World => self.stroke(style, transform, brush, brush_transform, shape),
+            ScaleMode::Screen => {
+                let mut path = shape.into_path(Self::shape_tolerance(transform));
+                path.apply_affine(transform);
+                self.stroke(style, Affine::IDENTITY, brush, brush_transform, &path);
+            }
+        }
+    }
+
+    /// Fills a shape and then strokes it, such as for a two-tone icon, so the stroke is drawn
+    /// over the fill.
+    ///
+    /// This is equivalent to calling [`Self::fill`] and then [`Self::stroke`] with the same
+    /// shape and transform, but converts the shape to path elements once, and shares the
+    /// encoded transform between the two draws. The path itself is still encoded twice, as
+    /// the renderer closes every subpath of a fill but not of a stroke. To also avoid encoding
+    /// the path for each frame, use [`Self::prepare_path`].
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_and_stroke<'b, 'c>(
+        &mut self,
+        fill_style: Fill,
+        stroke_style: &Stroke,
+        transform: Affine,
+        fill_brush: impl Into<BrushRef<'b>>,
+        stroke_brush: impl Into<BrushRef<'c>>,
+        shape: &impl Shape,
+    ) {
+        let path;
+        let elements = match shape.as_path_slice() {
+            Some(elements) => elements,
+            None => {
+                path = shape.into_path(Self::shape_tolerance(transform));
+                path.elements()
+            }
+        };
+        self.fill(fill_style, transform, fill_brush, None, &elements);
+        self.stroke(stroke_style, transform, stroke_brush, None, &elements);
+    }
+
+    /// Prepares `shape` to be drawn many times, such as a complex static path in an animated
+    /// scene.
+    ///
+    /// The shape is converted to path elements using `tolerance` (which only matters for shapes
+    /// with curves which aren't Bézier segments, such as circles), and encoded once for filling
+    /// and once for stroking. [`Self::fill_prepared`] and [`Self::stroke_prepared`] then copy
+    /// the encoded path into the scene, rather than encoding the shape again.
+    ///
+    /// Note that paths are flattened by the GPU when the scene is rendered, so this saves the
+    /// CPU time spent encoding the path, not flattening it. The prepared path doesn't depend on
+    /// the transform, so it remains valid as the transform is animated. There is no cache to
+    /// invalidate: the prepared path is dropped when it is no longer needed.
+    pub fn prepare_path(shape: &impl Shape, tolerance: f64) -> PreparedPath {
+        let path = shape.into_path(tolerance);
+        PreparedPath {
+            fill: EncodedPath::new(&path, true),
+            stroke: EncodedPath::new(&path, false),
+            bounds: path.bounding_box(),
+            path,
+        }
+    }
+
+    /// Fills a path prepared by [`Self::prepare_path`].
+    ///
+    /// This is equivalent to calling [`Self::fill`] with the shape which was prepared.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn fill_prepared<'b>(
+        &mut self,
+        style: Fill,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        path: &PreparedPath,
+    ) {
+        if Self::is_degenerate(transform, path.bounds, true)
+            || self.is_culled(transform, path.bounds)
+        {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_fill_style(style);
+        if self.encode_prepared(&path.fill) {
+            if let Some(brush_transform) = brush_transform
+                && self
+                    .encoding
+                    .encode_transform(Transform::from_kurbo(&(transform * brush_transform)))
+            {
+                self.encoding.swap_last_path_tags();
+            }
+            self.encoding.encode_brush(brush.into(), 1.0);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator
+                .count_path(path.path.path_elements(0.1), &t, None);
+        }
+    }
+
+    /// Strokes a path prepared by [`Self::prepare_path`].
+    ///
+    /// This is equivalent to calling [`Self::stroke`] with the shape which was prepared.
+    /// Dashed strokes are converted into stroked paths on the CPU, as with [`Self::stroke`],
+    /// so only strokes without dashes benefit from the prepared path.
+    #[expect(
+        single_use_lifetimes,
+        reason = "False positive: https://github.com/rust-lang/rust/issues/129255"
+    )]
+    pub fn stroke_prepared<'b>(
+        &mut self,
+        style: &Stroke,
+        transform: Affine,
+        brush: impl Into<BrushRef<'b>>,
+        brush_transform: Option<Affine>,
+        path: &PreparedPath,
+    ) {
+        if !style.dash_pattern.is_empty() {
+            self.stroke(style, transform, brush, brush_transform, &path.path);
+            return;
         }
+        if style.width == 0.
+            || !style.width.is_finite()
+            || Self::is_degenerate(transform, path.bounds, false)
+        {
+            return;
+        }
+        // See the note about the extent of strokes in `Self::stroke_with_alpha`.
+        let extent = 0.5 * style.width.abs() * style.miter_limit.max(std::f64::consts::SQRT_2);
+        if self.is_culled(transform, path.bounds.inflate(extent, extent)) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        let encoded_stroke = self.encoding.encode_stroke_style(style);
+        debug_assert!(encoded_stroke, "Stroke width is non-zero");
+        if self.encode_prepared(&path.stroke) {
+            if let Some(brush_transform) = brush_transform
+                && self
+                    .encoding
+                    .encode_transform(Transform::from_kurbo(&(transform * brush_transform)))
+            {
+                self.encoding.swap_last_path_tags();
+            }
+            self.encoding.encode_brush(brush.into(), 1.0);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator
+                .count_path(path.path.path_elements(0.1), &t, Some(style));
+        }
+    }
+
+    /// Copies an encoded path into the scene's encoding.
+    ///
+    /// Returns `true` if a non-zero number of segments were encoded.
+    fn encode_prepared(&mut self, path: &EncodedPath) -> bool {
+        if path.n_segments == 0 {
+            return false;
+        }
+        self.encoding.path_tags.extend_from_slice(&path.tags);
+        self.encoding.path_data.extend_from_slice(&path.data);
+        self.encoding.n_path_segments += path.n_segments;
+        self.encoding.n_paths += 1;
+        true
     }
 
     /// Encodes the stroke of a shape using the specified style. The stroke style must have
@@ -397,8 +1963,8 @@ impl Scene {
     ///
     /// Returns `true` if a non-zero number of segments were encoded.
     fn stroke_gpu_inner(&mut self, style: &Stroke, transform: Affine, shape: &impl Shape) -> bool {
-        // See the note about tolerances in `Self::stroke`.
-        const SHAPE_TOLERANCE: f64 = 0.01;
+        // See the note about tolerances in `Self::stroke_with_alpha`.
+        let tolerance = Self::shape_tolerance(transform);
 
         let t = Transform::from_kurbo(&transform);
         self.encoding.encode_transform(t);
@@ -414,16 +1980,17 @@ impl Scene {
         if style.dash_pattern.is_empty() {
             #[cfg(feature = "bump_estimate")]
             self.estimator
-                .count_path(shape.path_elements(SHAPE_TOLERANCE), &t, Some(style));
-            self.encoding.encode_shape(shape, false)
+                .count_path(shape.path_elements(tolerance), &t, Some(style));
+            self.encoding
+                .encode_path_elements(shape.path_elements(tolerance), false)
         } else {
             // TODO: We currently collect the output of the dash iterator because
             // `encode_path_elements` wants to consume the iterator. We want to avoid calling
             // `dash` twice when `bump_estimate` is enabled because it internally allocates.
             // Bump estimation will move to resolve time rather than scene construction time,
             // so we can revert this back to not collecting when that happens.
-            let dashed = peniko::kurbo::dash(
-                shape.path_elements(SHAPE_TOLERANCE),
+            let dashed = kurbo::dash(
+                shape.path_elements(tolerance),
                 style.dash_offset,
                 &style.dash_pattern,
             )
@@ -437,6 +2004,17 @@ impl Scene {
     }
 
     /// Draws an image at its natural size with the given transform.
+    ///
+    /// The [`alpha_type`](peniko::ImageData::alpha_type) of the image says whether its color
+    /// channels are premultiplied by alpha. Images with straight (unpremultiplied) alpha, such
+    /// as most PNG files, should use [`ImageAlphaType::Alpha`](peniko::ImageAlphaType::Alpha),
+    /// and are premultiplied by Vello as each pixel is sampled, before filtering.
+    /// This means that no conversion is needed when uploading images, and that the colors of
+    /// fully transparent pixels don't bleed into their neighbors.
+    /// Using the wrong alpha type causes dark or light fringes on transparent edges.
+    ///
+    /// The color channels of the image are treated as sRGB. Use
+    /// [`Self::draw_image_with_color_space`] for images with linear color channels.
     pub fn draw_image<'b>(&mut self, image: impl Into<ImageBrushRef<'b>>, transform: Affine) {
         let brush = image.into();
         let rect = Rect::new(
@@ -448,6 +2026,118 @@ impl Scene {
         self.fill(Fill::NonZero, transform, brush, None, &rect);
     }
 
+    /// Draws an image at its natural size with the given transform, where the color channels of
+    /// the image are in `color_space`.
+    ///
+    /// Vello composites in sRGB, so images with [`ImageColorSpace::LinearSrgb`] are converted
+    /// to sRGB as they are sampled. Drawing a linear image as if it were sRGB makes it look too
+    /// dark, and drawing an sRGB image as if it were linear makes it look washed out.
+    /// With [`ImageColorSpace::Srgb`], this is the same as [`Self::draw_image`].
+    pub fn draw_image_with_color_space<'b>(
+        &mut self,
+        image: impl Into<ImageBrushRef<'b>>,
+        color_space: ImageColorSpace,
+        transform: Affine,
+    ) {
+        let brush = image.into();
+        let (width, height) = (brush.image.width, brush.image.height);
+        let rect = Rect::new(0.0, 0.0, f64::from(width), f64::from(height));
+        self.fill_image(
+            brush,
+            color_space,
+            [0, 0, width, height],
+            transform,
+            None,
+            rect,
+        );
+    }
+
+    /// Fills `rect` with an image brush whose color channels are in `color_space`, sampling only
+    /// the texels in `sub_rect` of the image, given as `[x, y, width, height]`.
+    fn fill_image(
+        &mut self,
+        brush: ImageBrushRef<'_>,
+        color_space: ImageColorSpace,
+        sub_rect: [u32; 4],
+        transform: Affine,
+        brush_transform: Option<Affine>,
+        rect: Rect,
+    ) {
+        if Self::is_degenerate(transform, rect, true) || self.is_culled(transform, rect) {
+            return;
+        }
+        let t = Transform::from_kurbo(&transform);
+        self.encoding.encode_transform(t);
+        self.encoding.encode_fill_style(Fill::NonZero);
+        if self.encoding.encode_shape(&rect, true) {
+            if let Some(brush_transform) = brush_transform
+                && self
+                    .encoding
+                    .encode_transform(Transform::from_kurbo(&(transform * brush_transform)))
+            {
+                self.encoding.swap_last_path_tags();
+            }
+            self.encoding
+                .encode_image_sub_rect(brush, 1.0, color_space, sub_rect);
+            #[cfg(feature = "bump_estimate")]
+            self.estimator.count_path(rect.path_elements(0.1), &t, None);
+        }
+    }
+
+    /// Draws the part of an image inside `sub_rect`, such as one entry of a texture atlas, as
+    /// if it were an image of that size, with the given transform.
+    ///
+    /// `sub_rect` is in texels of the image, and is rounded to whole texels and limited to the
+    /// image. Nothing is drawn if the sub-rectangle is empty.
+    ///
+    /// If `atlas_safe` is `true`, sampling is clamped to the sub-rectangle, including bilinear
+    /// filtering and the image's extend modes, so neighboring entries of a packed atlas never
+    /// bleed in at the edges. This makes it suitable for the slices of a nine-slice image.
+    /// Otherwise, the rest of the image is sampled as usual, so filtering at the edges blends
+    /// in the neighboring texels, as when drawing the whole image clipped to the sub-rectangle.
+    ///
+    /// As with [`Self::fill`], nothing is drawn if `transform` isn't finite.
+    pub fn draw_image_sub_rect<'b>(
+        &mut self,
+        image: impl Into<ImageBrushRef<'b>>,
+        sub_rect: Rect,
+        atlas_safe: bool,
+        transform: Affine,
+    ) {
+        let brush = image.into();
+        let (width, height) = (brush.image.width, brush.image.height);
+        // Casting saturates, so negative and NaN coordinates are clamped to zero.
+        let x0 = (sub_rect.x0.round() as u32).min(width);
+        let y0 = (sub_rect.y0.round() as u32).min(height);
+        let x1 = (sub_rect.x1.round() as u32).min(width);
+        let y1 = (sub_rect.y1.round() as u32).min(height);
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+        let rect = Rect::new(0.0, 0.0, f64::from(x1 - x0), f64::from(y1 - y0));
+        if atlas_safe {
+            let sub_rect = [x0, y0, x1 - x0, y1 - y0];
+            self.fill_image(
+                brush,
+                ImageColorSpace::Srgb,
+                sub_rect,
+                transform,
+                None,
+                rect,
+            );
+        } else {
+            let offset = Affine::translate((-f64::from(x0), -f64::from(y0)));
+            self.fill_image(
+                brush,
+                ImageColorSpace::Srgb,
+                [0, 0, width, height],
+                transform,
+                Some(offset),
+                rect,
+            );
+        }
+    }
+
     /// Returns a builder for encoding a glyph run.
     pub fn draw_glyphs(&mut self, font: &FontData) -> DrawGlyphs<'_> {
         // TODO: Integrate `BumpEstimator` with the glyph cache.
@@ -460,10 +2150,392 @@ impl Scene {
     /// This is an O(N) operation.
     pub fn append(&mut self, other: &Self, transform: Option<Affine>) {
         let t = transform.as_ref().map(Transform::from_kurbo);
+        let draw_offset = self.encoding.draw_tags.len();
         self.encoding.append(&other.encoding, &t);
+        self.layer_labels
+            .extend(other.layer_labels.iter().map(|label| LayerLabel {
+                label: label.label.clone(),
+                draw_index: label.draw_index + draw_offset,
+            }));
+        self.draw_ids
+            .extend(other.draw_ids.iter().map(|tagged| DrawId {
+                id: tagged.id,
+                draws: tagged.draws.start + draw_offset..tagged.draws.end + draw_offset,
+            }));
         #[cfg(feature = "bump_estimate")]
         self.estimator.append(&other.estimator, t.as_ref());
     }
+
+    /// Appends each of `fragments` to this scene, in order.
+    ///
+    /// This allows large scenes to be encoded in parallel: as `Scene` is `Send`, independent
+    /// parts of the scene can be built as separate scenes on worker threads, then merged on
+    /// one thread. The indices of gradient color stops, glyph runs and layer labels in each
+    /// fragment are remapped so that they refer to the merged scene. This is equivalent to
+    /// calling [`Scene::append`] for each fragment without a transform.
+    ///
+    /// ```
+    /// use vello::Scene;
+    /// use vello::kurbo::{Affine, Circle};
+    /// use vello::peniko::{Fill, color::palette};
+    ///
+    /// let fragments: Vec<Scene> = std::thread::scope(|s| {
+    ///     let workers: Vec<_> = (0..4)
+    ///         .map(|i| {
+    ///             s.spawn(move || {
+    ///                 let mut fragment = Scene::new();
+    ///                 let circle = Circle::new((f64::from(i) * 20.0, 10.0), 8.0);
+    ///                 fragment.fill(Fill::NonZero, Affine::IDENTITY, palette::css::RED, None, &circle);
+    ///                 fragment
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     workers.into_iter().map(|w| w.join().unwrap()).collect()
+    /// });
+    /// let mut scene = Scene::new();
+    /// scene.merge_fragments(fragments);
+    /// ```
+    pub fn merge_fragments(&mut self, fragments: impl IntoIterator<Item = Self>) {
+        for fragment in fragments {
+            self.append(&fragment, None);
+        }
+    }
+
+    /// Returns the changes which turn `previous` into this scene, such as for sending only what
+    /// changed between frames to a client which renders them.
+    ///
+    /// Applying the patch to `previous` with [`Self::apply_patch`] produces a scene with the
+    /// same encoding as this one. See [`ScenePatch`] for how changes are found.
+    ///
+    /// ```
+    /// use vello::Scene;
+    /// use vello::kurbo::{Affine, Rect};
+    /// use vello::peniko::{Fill, color::palette};
+    ///
+    /// let mut previous = Scene::new();
+    /// let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+    /// previous.fill(Fill::NonZero, Affine::IDENTITY, palette::css::RED, None, &rect);
+    ///
+    /// let mut current = previous.clone();
+    /// current.fill(Fill::NonZero, Affine::IDENTITY, palette::css::BLUE, None, &rect);
+    ///
+    /// let patch = current.diff(&previous);
+    /// assert_eq!((patch.copied_draws(), patch.new_draws()), (1, 1));
+    ///
+    /// // On the client, which has its own copy of the previous scene.
+    /// let mut client = previous;
+    /// client.apply_patch(patch).unwrap();
+    /// assert_eq!(client.encoding().draw_tags, current.encoding().draw_tags);
+    /// ```
+    pub fn diff(&self, previous: &Self) -> ScenePatch {
+        ScenePatch::new(
+            &self.encoding,
+            &previous.encoding,
+            self.layer_labels.clone(),
+            self.draw_ids.clone(),
+        )
+    }
+
+    /// Applies a patch created by [`Self::diff`], replacing the contents of this scene.
+    ///
+    /// This scene must have the same encoding as the previous scene passed to
+    /// [`Self::diff`], otherwise [`Error::ScenePatchMismatch`] is returned and this scene is
+    /// unchanged. The cull rectangle of this scene is kept.
+    pub fn apply_patch(&mut self, patch: ScenePatch) -> Result<(), Error> {
+        let (encoding, layer_labels, draw_ids) = patch
+            .apply(&self.encoding)
+            .ok_or(Error::ScenePatchMismatch)?;
+        self.encoding = encoding;
+        self.layer_labels = layer_labels;
+        self.draw_ids = draw_ids;
+        // The estimate can't be patched, so it only covers content drawn after the patch.
+        #[cfg(feature = "bump_estimate")]
+        self.estimator.reset();
+        Ok(())
+    }
+}
+
+/// The label of a layer, added using [`Scene::push_layer_labeled`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerLabel {
+    /// The label of the layer.
+    pub label: String,
+    /// The index of the layer's [`DrawTag::BEGIN_CLIP`](vello_encoding::DrawTag::BEGIN_CLIP)
+    /// in [`Encoding::draw_tags`].
+    pub draw_index: usize,
+}
+
+/// The id of a run of draw objects, added using [`Scene::draw_with_id`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DrawId {
+    /// The id given by the caller.
+    pub id: u64,
+    /// The indices of the draw objects in [`Encoding::draw_tags`].
+    pub draws: Range<usize>,
+}
+
+/// Whether a stroke drawn by [`Scene::stroke_with_scale_mode`] scales with its transform.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScaleMode {
+    /// The stroke's width and dashes are in the shape's local coordinates, so they are scaled
+    /// by the transform.
+    #[default]
+    World,
+    /// The stroke's width and dashes are in the scene's coordinates, usually device pixels,
+    /// so they are the same size however the shape is transformed.
+    Screen,
+}
+
+/// Which side of a rounded rectangle a glow drawn by [`Scene::draw_glow_rounded_rect`] is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GlowKind {
+    /// The glow is inside the rounded rectangle, and fades towards its center.
+    Inner,
+    /// The glow is outside the rounded rectangle, and fades away from it.
+    Outer,
+}
+
+/// The style of the line drawn by [`Scene::draw_outline`], like the CSS `outline-style` property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutlineStyle {
+    /// A continuous line.
+    #[default]
+    Solid,
+    /// Dashes which are three times as long as the width of the outline, separated by gaps of
+    /// the same length.
+    Dashed,
+    /// Round dots with a diameter of the width of the outline, separated by gaps of the
+    /// same size.
+    Dotted,
+}
+
+/// The layout of a shadow mask drawn by [`Scene::draw_shadow_mask`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadowMask {
+    /// The width of the mask in pixels, which should be the width of the texture it is
+    /// rendered into.
+    pub width: u32,
+    /// The height of the mask in pixels.
+    pub height: u32,
+    /// The position of the top left corner of the rounded rectangle within the mask.
+    ///
+    /// To draw the sprite under a rectangle, translate it to the rectangle's origin minus this
+    /// offset.
+    pub offset: Vec2,
+    /// The distance from each edge of the mask which contains the blurred corners, for
+    /// 9-slice scaling.
+    ///
+    /// Between the slices, the mask doesn't vary along the direction of the edge, so the
+    /// sprite can be stretched there to fit a larger rectangle with the same radius and blur.
+    pub slice_inset: f64,
+}
+
+/// The parameters of a stacking context, pushed using [`Scene::push_stacking_context`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StackingContext {
+    /// The blend mode which the stacking context is composited with.
+    pub blend: BlendMode,
+    /// The opacity of the stacking context, from `0.0` to `1.0`.
+    pub opacity: f32,
+    /// The filter applied to the rendered content of the stacking context before it is
+    /// composited, like the CSS `filter` property, such as from [`ColorMatrix::grayscale`] or
+    /// an [`Adjust`].
+    ///
+    /// This is applied as for [`Scene::push_color_matrix_layer`].
+    pub filter: Option<ColorMatrix>,
+}
+
+impl Default for StackingContext {
+    fn default() -> Self {
+        Self {
+            blend: BlendMode::default(),
+            opacity: 1.0,
+            filter: None,
+        }
+    }
+}
+
+/// A single blurred rounded rectangle drawn by [`Scene::draw_box_shadows_batch`].
+///
+/// The fields correspond to the parameters of [`Scene::draw_blurred_rounded_rect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxShadowInstance {
+    /// The transform applied to the shadow.
+    pub transform: Affine,
+    /// The rectangle which is blurred.
+    pub rect: Rect,
+    /// The color of the shadow.
+    pub color: Color,
+    /// The corner radius of `rect`.
+    pub radius: f64,
+    /// The standard deviation of the gaussian blur.
+    ///
+    /// A negative or NaN value is treated as zero, and a value larger than
+    /// [`Scene::max_blur_std_dev`] is clamped to it.
+    pub std_dev: f64,
+}
+
+impl BoxShadowInstance {
+    /// Returns the shadows of a surface raised to a Material Design elevation, given in dp,
+    /// with the same outline as `shape`, in the order they are drawn.
+    ///
+    /// These are the two shadows of the Material 3 elevation levels: an ambient shadow with an
+    /// opacity of 15%, then a key shadow with an opacity of 30%. The levels are at 0, 1, 3, 6,
+    /// 8 and 12 dp, and other elevations are interpolated between them, with elevations
+    /// above 12 dp treated as 12 dp. For example, at 8 dp the key shadow is offset down by 2
+    /// with a blur radius of 3, and the ambient shadow is offset by 6 with a blur radius of
+    /// 10 and a spread of 4, as for the CSS `box-shadow`
+    /// `0 2px 3px rgba(0, 0, 0, 0.3), 0 6px 10px 4px rgba(0, 0, 0, 0.15)`.
+    /// As in CSS, the standard deviation of each blur is half of its blur radius.
+    ///
+    /// The lengths are in the coordinate system of `transform`. The shadows use a single
+    /// corner radius, which is the largest of the radii of `shape`.
+    /// Negative and NaN elevations are treated as zero.
+    pub fn material_elevation(
+        transform: Affine,
+        shape: RoundedRect,
+        elevation_dp: f64,
+    ) -> [Self; 2] {
+        const LEVELS_DP: [f64; 6] = [0.0, 1.0, 3.0, 6.0, 8.0, 12.0];
+
+        let dp = if elevation_dp.is_nan() {
+            0.0
+        } else {
+            elevation_dp.clamp(0.0, LEVELS_DP[5])
+        };
+        let level = LEVELS_DP
+            .windows(2)
+            .enumerate()
+            .find(|(_, levels)| dp <= levels[1])
+            .map_or(5.0, |(i, levels)| {
+                i as f64 + (dp - levels[0]) / (levels[1] - levels[0])
+            });
+        // The Material 3 elevation levels, as piecewise linear functions of the level.
+        let ramp = |start: f64, len: f64| (level - start).clamp(0.0, len);
+        let key_offset = ramp(0.0, 1.0) + ramp(3.0, 1.0) + 2.0 * ramp(4.0, 1.0);
+        let key_blur = 2.0 * ramp(0.0, 1.0) + ramp(2.0, 1.0) + ramp(4.0, 1.0);
+        let ambient_offset = ramp(0.0, 1.0) + ramp(1.0, 1.0) + 2.0 * ramp(2.0, 3.0);
+        let ambient_blur = 3.0 * ramp(0.0, 2.0) + 2.0 * ramp(2.0, 3.0);
+        let ambient_spread = ramp(0.0, 4.0) + 2.0 * ramp(4.0, 1.0);
+
+        let rect = shape.rect();
+        let radii = shape.radii();
+        let radius = radii
+            .top_left
+            .max(radii.top_right)
+            .max(radii.bottom_right)
+            .max(radii.bottom_left);
+        let ambient = Self {
+            transform,
+            rect: rect.inflate(ambient_spread, ambient_spread) + Vec2::new(0.0, ambient_offset),
+            color: palette::css::BLACK.with_alpha(0.15),
+            // As in CSS, spreading square corners keeps them square.
+            radius: if radius > 0.0 {
+                radius + ambient_spread
+            } else {
+                0.0
+            },
+            std_dev: 0.5 * ambient_blur,
+        };
+        let key = Self {
+            transform,
+            rect: rect + Vec2::new(0.0, key_offset),
+            color: palette::css::BLACK.with_alpha(0.3),
+            radius,
+            std_dev: 0.5 * key_blur,
+        };
+        [ambient, key]
+    }
+}
+
+/// A shape which has been encoded ahead of time by [`Scene::prepare_path`].
+///
+/// This can be drawn into any number of scenes, with any transform and brush, using
+/// [`Scene::fill_prepared`] and [`Scene::stroke_prepared`].
+#[derive(Clone, Debug)]
+pub struct PreparedPath {
+    path: BezPath,
+    bounds: Rect,
+    fill: EncodedPath,
+    stroke: EncodedPath,
+}
+
+impl PreparedPath {
+    /// Returns the path which was prepared.
+    pub fn path(&self) -> &BezPath {
+        &self.path
+    }
+
+    /// Returns the bounding box of the path, in its local coordinates.
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+}
+
+/// The path tags and data of a single encoded path.
+#[derive(Clone, Debug)]
+struct EncodedPath {
+    tags: Vec<PathTag>,
+    data: Vec<u32>,
+    n_segments: u32,
+}
+
+impl EncodedPath {
+    fn new(path: &BezPath, is_fill: bool) -> Self {
+        let mut encoding = Encoding::new();
+        encoding.encode_shape(path, is_fill);
+        Self {
+            tags: encoding.path_tags,
+            data: encoding.path_data,
+            n_segments: encoding.n_path_segments,
+        }
+    }
+}
+
+/// A coarse estimate of the cost of rendering a scene on the GPU, from
+/// [`Scene::estimate_gpu_cost`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CostEstimate {
+    /// The number of draw objects, not including layers.
+    pub draws: usize,
+    /// The number of layers, including clips.
+    pub layers: usize,
+    /// The number of layers which are composited with an opacity or blend mode, rather than
+    /// only clipping their contents.
+    pub composited_layers: usize,
+    /// The deepest nesting of layers.
+    ///
+    /// Layers nested more than four deep are stored in a slower scratch buffer
+    /// on the GPU.
+    pub max_layer_depth: usize,
+    /// The number of blurred rounded rectangles.
+    pub blurs: usize,
+    /// The area covered by blurred rounded rectangles, in pixels, including the extent of the
+    /// blur.
+    ///
+    /// The area of each blur is multiplied by one more than the number of composited layers
+    /// which it is inside, as each of those layers blends the blurred pixels again.
+    pub blur_area: f64,
+}
+
+impl CostEstimate {
+    /// The layer depth beyond which layers are stored in a scratch buffer.
+    const BLEND_STACK_SPLIT: usize = 4;
+
+    /// A unitless score combining the other fields, where larger values are more expensive.
+    ///
+    /// The score is scaled so that a full screen blur at 1920x1080 scores about 1.0, with
+    /// further contributions from the number of draw objects and layers. Simple user interfaces
+    /// typically score well below 1.0, and scores above 10.0 are likely to be slow on
+    /// integrated GPUs.
+    pub fn score(&self) -> f64 {
+        const FULL_HD: f64 = 1920.0 * 1080.0;
+        let spilled_depth = self.max_layer_depth.saturating_sub(Self::BLEND_STACK_SPLIT);
+        self.blur_area / FULL_HD
+            + self.draws as f64 / 10_000.0
+            + self.composited_layers as f64 / 100.0
+            + spilled_depth as f64 / 4.0
+    }
 }
 
 impl From<Encoding> for Scene {
@@ -472,6 +2544,9 @@ impl From<Encoding> for Scene {
         // removed at some point - see https://github.com/linebender/vello/issues/541
         Self {
             encoding,
+            layer_labels: Vec::new(),
+            draw_ids: Vec::new(),
+            cull_rect: None,
             #[cfg(feature = "bump_estimate")]
             estimator: vello_encoding::BumpEstimator::default(),
         }
@@ -924,6 +2999,8 @@ enum EmojiLikeGlyph<'a> {
     Colr(ColorGlyph<'a>),
 }
 const BOUND: f64 = 100_000.;
+/// The tolerance used to flatten shapes drawn with a perspective transform, in pixels.
+const PERSPECTIVE_TOLERANCE: f64 = 0.1;
 // Hack: If we don't have a clip box, we guess a rectangle we hope is big enough
 const DEFAULT_CLIP_RECT: Rect = Rect::new(-BOUND, -BOUND, BOUND, BOUND);
 
@@ -0,0 +1,538 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Differences between the encodings of two scenes, for sending only what changed.
+
+use std::collections::HashMap;
+
+use peniko::{ColorStop, Extend, ImageData, InterpolationAlphaSpace};
+use vello_encoding::{DrawTag, Encoding, Patch, PathTag, Style, Transform};
+
+use crate::{DrawId, LayerLabel};
+
+/// The changes which turn one [`Scene`](crate::Scene) into another, created by
+/// [`Scene::diff`](crate::Scene::diff) and applied using
+/// [`Scene::apply_patch`](crate::Scene::apply_patch).
+///
+/// A patch is made of draw operations: each fill, stroke, image, blurred rectangle or layer
+/// boundary in the scene's encoding, along with the path, transform and style it uses.
+/// Draws which are unchanged from the previous scene are referenced by their position in it,
+/// so only new or changed draws are stored in the patch.
+/// A draw is only unchanged if its encoding is identical, including its transform, so a
+/// draw which moves is stored again.
+///
+/// Scenes which contain glyph runs, which are only expanded into draws when the scene is
+/// rendered, can't be split into draws. A patch to or from such a scene contains the whole
+/// scene.
+///
+/// Note that Vello doesn't have a serialization format for scenes, so sending a patch over a
+/// network requires the application to serialize it.
+#[derive(Clone)]
+pub struct ScenePatch {
+    kind: PatchKind,
+    layer_labels: Vec<LayerLabel>,
+    draw_ids: Vec<DrawId>,
+}
+
+#[derive(Clone)]
+enum PatchKind {
+    Edits {
+        /// The fingerprint of the scene the patch applies to.
+        base: u64,
+        edits: Vec<Edit>,
+        /// Transforms and styles encoded after the last draw.
+        tail: DrawOp,
+        n_clips: u32,
+        n_open_clips: u32,
+        n_unmatched_end_clips: u32,
+        flags: u32,
+    },
+    Replace(Encoding),
+}
+
+#[derive(Clone)]
+enum Edit {
+    /// Draws which are copied from the previous scene.
+    Copy { start: usize, len: usize },
+    /// Draws which are new in this scene.
+    Insert(Vec<DrawOp>),
+}
+
+/// A single draw, with the parts of each encoded stream which belong to it.
+#[derive(Clone)]
+struct DrawOp {
+    path_tags: Vec<PathTag>,
+    path_data: Vec<u32>,
+    transforms: Vec<Transform>,
+    styles: Vec<Style>,
+    /// The draw tag, which is `None` for the tail of the encoding.
+    draw_tag: Option<DrawTag>,
+    draw_data: Vec<u32>,
+    /// Late bound resources, with offsets relative to the start of `draw_data`.
+    resources: Vec<OpResource>,
+}
+
+#[derive(Clone)]
+enum OpResource {
+    Ramp {
+        draw_data_offset: usize,
+        stops: Vec<ColorStop>,
+        extend: Extend,
+        interpolation_alpha_space: InterpolationAlphaSpace,
+    },
+    Image {
+        draw_data_offset: usize,
+        image: ImageData,
+    },
+    ColorMatrix {
+        draw_data_offset: usize,
+        matrix: [f32; 20],
+    },
+}
+
+impl ScenePatch {
+    pub(crate) fn new(
+        current: &Encoding,
+        previous: &Encoding,
+        layer_labels: Vec<LayerLabel>,
+        draw_ids: Vec<DrawId>,
+    ) -> Self {
+        let (Some((ops, tail)), Some((previous_ops, _))) =
+            (split_draws(current), split_draws(previous))
+        else {
+            return Self {
+                kind: PatchKind::Replace(current.clone()),
+                layer_labels,
+                draw_ids,
+            };
+        };
+
+        let mut by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (i, op) in previous_ops.iter().enumerate() {
+            by_hash.entry(op.content_hash()).or_default().push(i);
+        }
+        let mut edits = Vec::new();
+        for op in ops {
+            // Continue copying the previous run of draws if this draw follows on from it.
+            if let Some(Edit::Copy { start, len }) = edits.last_mut()
+                && previous_ops.get(*start + *len) == Some(&op)
+            {
+                *len += 1;
+                continue;
+            }
+            let found = by_hash
+                .get(&op.content_hash())
+                .and_then(|candidates| candidates.iter().copied().find(|&i| previous_ops[i] == op));
+            if let Some(start) = found {
+                edits.push(Edit::Copy { start, len: 1 });
+            } else if let Some(Edit::Insert(inserted)) = edits.last_mut() {
+                inserted.push(op);
+            } else {
+                edits.push(Edit::Insert(vec![op]));
+            }
+        }
+
+        Self {
+            kind: PatchKind::Edits {
+                base: fingerprint(previous),
+                edits,
+                tail,
+                n_clips: current.n_clips,
+                n_open_clips: current.n_open_clips,
+                n_unmatched_end_clips: current.n_unmatched_end_clips,
+                flags: current.flags,
+            },
+            layer_labels,
+            draw_ids,
+        }
+    }
+
+    /// Returns the number of draws which are copied from the previous scene.
+    pub fn copied_draws(&self) -> usize {
+        match &self.kind {
+            PatchKind::Edits { edits, .. } => edits
+                .iter()
+                .map(|edit| match edit {
+                    Edit::Copy { len, .. } => *len,
+                    Edit::Insert(_) => 0,
+                })
+                .sum(),
+            PatchKind::Replace(_) => 0,
+        }
+    }
+
+    /// Returns the number of draws which are stored in the patch, because they are new or
+    /// have changed.
+    pub fn new_draws(&self) -> usize {
+        match &self.kind {
+            PatchKind::Edits { edits, .. } => edits
+                .iter()
+                .map(|edit| match edit {
+                    Edit::Copy { .. } => 0,
+                    Edit::Insert(ops) => ops.len(),
+                })
+                .sum(),
+            PatchKind::Replace(encoding) => encoding.draw_tags.len(),
+        }
+    }
+
+    /// Returns `true` if the patch contains the whole scene, rather than the changes to it.
+    pub fn is_replacement(&self) -> bool {
+        matches!(self.kind, PatchKind::Replace(_))
+    }
+
+    /// Builds the encoding of the patched scene, or returns `None` if `previous` isn't the scene
+    /// this patch was created from.
+    pub(crate) fn apply(
+        self,
+        previous: &Encoding,
+    ) -> Option<(Encoding, Vec<LayerLabel>, Vec<DrawId>)> {
+        let (base, edits, tail, n_clips, n_open_clips, n_unmatched_end_clips, flags) =
+            match self.kind {
+                PatchKind::Replace(encoding) => {
+                    return Some((encoding, self.layer_labels, self.draw_ids));
+                }
+                PatchKind::Edits {
+                    base,
+                    edits,
+                    tail,
+                    n_clips,
+                    n_open_clips,
+                    n_unmatched_end_clips,
+                    flags,
+                } => (
+                    base,
+                    edits,
+                    tail,
+                    n_clips,
+                    n_open_clips,
+                    n_unmatched_end_clips,
+                    flags,
+                ),
+            };
+        if fingerprint(previous) != base {
+            return None;
+        }
+        let (previous_ops, _) = split_draws(previous)?;
+        let mut encoding = Encoding::new();
+        for edit in edits {
+            match edit {
+                Edit::Copy { start, len } => {
+                    for op in previous_ops.get(start..start + len)? {
+                        op.encode(&mut encoding);
+                    }
+                }
+                Edit::Insert(ops) => {
+                    for op in &ops {
+                        op.encode(&mut encoding);
+                    }
+                }
+            }
+        }
+        tail.encode(&mut encoding);
+        encoding.n_clips = n_clips;
+        encoding.n_open_clips = n_open_clips;
+        encoding.n_unmatched_end_clips = n_unmatched_end_clips;
+        encoding.flags = flags;
+        Some((encoding, self.layer_labels, self.draw_ids))
+    }
+}
+
+impl DrawOp {
+    fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv::new();
+        hasher.write(bytemuck::cast_slice(&self.path_tags));
+        hasher.write(bytemuck::cast_slice(&self.path_data));
+        hasher.write(bytemuck::cast_slice(&self.transforms));
+        hasher.write(bytemuck::cast_slice(&self.draw_data));
+        if let Some(tag) = self.draw_tag {
+            hasher.write(bytemuck::bytes_of(&tag));
+        }
+        hasher.finish()
+    }
+
+    /// Appends this draw to `encoding`.
+    ///
+    /// A draw which doesn't encode its own transform or style uses those of the draws before
+    /// it, so the draws must be appended in the same order as in the scene being patched.
+    fn encode(&self, encoding: &mut Encoding) {
+        let draw_data_base = encoding.draw_data.len();
+        encoding.path_tags.extend_from_slice(&self.path_tags);
+        encoding.path_data.extend_from_slice(&self.path_data);
+        encoding.transforms.extend_from_slice(&self.transforms);
+        encoding.styles.extend_from_slice(&self.styles);
+        encoding.draw_data.extend_from_slice(&self.draw_data);
+        if let Some(tag) = self.draw_tag {
+            encoding.draw_tags.push(tag);
+        }
+        for tag in &self.path_tags {
+            if tag.is_path_segment() {
+                encoding.n_path_segments += 1;
+            } else if *tag == PathTag::PATH {
+                encoding.n_paths += 1;
+            }
+        }
+        for resource in &self.resources {
+            let patch = match resource {
+                OpResource::Ramp {
+                    draw_data_offset,
+                    stops,
+                    extend,
+                    interpolation_alpha_space,
+                } => {
+                    let start = encoding.resources.color_stops.len();
+                    encoding.resources.color_stops.extend_from_slice(stops);
+                    Patch::Ramp {
+                        draw_data_offset: draw_data_base + draw_data_offset,
+                        stops: start..start + stops.len(),
+                        extend: *extend,
+                        interpolation_alpha_space: *interpolation_alpha_space,
+                    }
+                }
+                OpResource::Image {
+                    draw_data_offset,
+                    image,
+                } => Patch::Image {
+                    draw_data_offset: draw_data_base + draw_data_offset,
+                    image: image.clone(),
+                },
+                OpResource::ColorMatrix {
+                    draw_data_offset,
+                    matrix,
+                } => Patch::ColorMatrix {
+                    draw_data_offset: draw_data_base + draw_data_offset,
+                    matrix: *matrix,
+                },
+            };
+            encoding.resources.patches.push(patch);
+        }
+    }
+}
+
+impl PartialEq for DrawOp {
+    fn eq(&self, other: &Self) -> bool {
+        // Transforms and styles are compared bitwise, as they are in the encoded streams.
+        self.path_tags == other.path_tags
+            && self.path_data == other.path_data
+            && bytemuck::cast_slice::<_, u8>(&self.transforms)
+                == bytemuck::cast_slice::<_, u8>(&other.transforms)
+            && bytemuck::cast_slice::<_, u8>(&self.styles)
+                == bytemuck::cast_slice::<_, u8>(&other.styles)
+            && self.draw_tag == other.draw_tag
+            && self.draw_data == other.draw_data
+            && self.resources.len() == other.resources.len()
+            && self
+                .resources
+                .iter()
+                .zip(&other.resources)
+                .all(|(a, b)| a.same_as(b))
+    }
+}
+
+impl OpResource {
+    fn same_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Ramp {
+                    draw_data_offset: offset_a,
+                    stops: stops_a,
+                    extend: extend_a,
+                    interpolation_alpha_space: space_a,
+                },
+                Self::Ramp {
+                    draw_data_offset: offset_b,
+                    stops: stops_b,
+                    extend: extend_b,
+                    interpolation_alpha_space: space_b,
+                },
+            ) => {
+                offset_a == offset_b
+                    && stops_a == stops_b
+                    && extend_a == extend_b
+                    && space_a == space_b
+            }
+            (
+                Self::Image {
+                    draw_data_offset: offset_a,
+                    image: a,
+                },
+                Self::Image {
+                    draw_data_offset: offset_b,
+                    image: b,
+                },
+            ) => {
+                offset_a == offset_b
+                    && a.data.id() == b.data.id()
+                    && a.format == b.format
+                    && a.alpha_type == b.alpha_type
+                    && a.width == b.width
+                    && a.height == b.height
+            }
+            (
+                Self::ColorMatrix {
+                    draw_data_offset: offset_a,
+                    matrix: a,
+                },
+                Self::ColorMatrix {
+                    draw_data_offset: offset_b,
+                    matrix: b,
+                },
+            ) => {
+                offset_a == offset_b
+                    && bytemuck::cast_slice::<_, u8>(a) == bytemuck::cast_slice::<_, u8>(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Splits an encoding into its draws, and the transforms and styles encoded after the
+/// last draw.
+///
+/// Returns `None` if the encoding can't be split, because it contains glyph runs or
+/// its streams are inconsistent.
+fn split_draws(encoding: &Encoding) -> Option<(Vec<DrawOp>, DrawOp)> {
+    if !encoding.resources.glyph_runs.is_empty() {
+        return None;
+    }
+    let mut ops = Vec::with_capacity(encoding.draw_tags.len());
+    let mut draw_tags = encoding.draw_tags.iter();
+    let mut op_start = 0;
+    let (mut path_data, mut transforms, mut styles, mut draw_data) = (0, 0, 0, 0);
+    let mut patches = encoding.resources.patches.iter().peekable();
+    let mut next_op = |end: usize,
+                       (path_data_end, transforms_end, styles_end): (usize, usize, usize),
+                       draw_tag: Option<DrawTag>,
+                       draw_data_end: usize| {
+        let op = DrawOp {
+            path_tags: encoding.path_tags.get(op_start..end)?.to_vec(),
+            path_data: encoding.path_data.get(path_data..path_data_end)?.to_vec(),
+            transforms: encoding
+                .transforms
+                .get(transforms..transforms_end)?
+                .to_vec(),
+            styles: encoding.styles.get(styles..styles_end)?.to_vec(),
+            draw_tag,
+            draw_data: encoding.draw_data.get(draw_data..draw_data_end)?.to_vec(),
+            resources: Vec::new(),
+        };
+        op_start = end;
+        path_data = path_data_end;
+        transforms = transforms_end;
+        styles = styles_end;
+        let draw_data_start = std::mem::replace(&mut draw_data, draw_data_end);
+        Some((op, draw_data_start))
+    };
+
+    let (mut path_data_end, mut transforms_end, mut styles_end) = (0, 0, 0);
+    let mut draw_data_end = 0;
+    for (i, &tag) in encoding.path_tags.iter().enumerate() {
+        let points = usize::from(tag.path_segment_type().0) + usize::from(tag.is_subpath_end());
+        path_data_end += if tag.is_f32() { 2 * points } else { points };
+        if tag == PathTag::TRANSFORM {
+            transforms_end += 1;
+        } else if tag == PathTag::STYLE {
+            styles_end += 1;
+        } else if tag == PathTag::PATH {
+            let draw_tag = *draw_tags.next()?;
+            draw_data_end += draw_tag.scene_size() as usize;
+            let (mut op, start) = next_op(
+                i + 1,
+                (path_data_end, transforms_end, styles_end),
+                Some(draw_tag),
+                draw_data_end,
+            )?;
+            while let Some(patch) = patches.next_if(|&patch| patch_offset(patch) < draw_data_end) {
+                op.resources.push(match patch {
+                    Patch::Ramp {
+                        draw_data_offset,
+                        stops,
+                        extend,
+                        interpolation_alpha_space,
+                    } => OpResource::Ramp {
+                        draw_data_offset: draw_data_offset.checked_sub(start)?,
+                        stops: encoding.resources.color_stops.get(stops.clone())?.to_vec(),
+                        extend: *extend,
+                        interpolation_alpha_space: *interpolation_alpha_space,
+                    },
+                    Patch::Image {
+                        draw_data_offset,
+                        image,
+                    } => OpResource::Image {
+                        draw_data_offset: draw_data_offset.checked_sub(start)?,
+                        image: image.clone(),
+                    },
+                    Patch::ColorMatrix {
+                        draw_data_offset,
+                        matrix,
+                    } => OpResource::ColorMatrix {
+                        draw_data_offset: draw_data_offset.checked_sub(start)?,
+                        matrix: *matrix,
+                    },
+                    Patch::GlyphRun { .. } => return None,
+                });
+            }
+            ops.push(op);
+        }
+    }
+    if draw_tags.next().is_some() || patches.next().is_some() {
+        return None;
+    }
+    let (tail, _) = next_op(
+        encoding.path_tags.len(),
+        (path_data_end, transforms_end, styles_end),
+        None,
+        draw_data_end,
+    )?;
+    Some((ops, tail))
+}
+
+fn patch_offset(patch: &Patch) -> usize {
+    match patch {
+        Patch::Ramp {
+            draw_data_offset, ..
+        }
+        | Patch::Image {
+            draw_data_offset, ..
+        }
+        | Patch::ColorMatrix {
+            draw_data_offset, ..
+        } => *draw_data_offset,
+        Patch::GlyphRun { .. } => 0,
+    }
+}
+
+/// A fingerprint of the encoded streams of a scene, used to check that a patch is applied to
+/// the scene it was created from.
+fn fingerprint(encoding: &Encoding) -> u64 {
+    let mut hasher = Fnv::new();
+    hasher.write(bytemuck::cast_slice(&encoding.path_tags));
+    hasher.write(bytemuck::cast_slice(&encoding.path_data));
+    hasher.write(bytemuck::cast_slice(&encoding.draw_tags));
+    hasher.write(bytemuck::cast_slice(&encoding.draw_data));
+    hasher.write(bytemuck::cast_slice(&encoding.transforms));
+    hasher.write(bytemuck::cast_slice(&encoding.styles));
+    hasher.finish()
+}
+
+/// The FNV-1a hash, which is used rather than the standard library's hasher so that
+/// fingerprints are stable between builds, such as on a server and its clients.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+        // Separate the streams, so that moving bytes between them changes the hash.
+        self.0 = (self.0 ^ bytes.len() as u64).wrapping_mul(0x0100_0000_01b3);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
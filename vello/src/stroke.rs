@@ -0,0 +1,39 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Converting strokes to filled outlines on the CPU.
+//!
+//! Vello expands strokes on the GPU when rendering, so this isn't needed to draw strokes.
+//! It is useful when the outline itself is needed, such as for exporting to formats without
+//! strokes, hit testing, or boolean operations.
+
+use peniko::kurbo::{self, BezPath, Stroke, StrokeOpts};
+
+/// The tolerance used by [`outline`], the same as the tolerance Vello uses for
+/// strokes expanded on the CPU.
+pub const DEFAULT_TOLERANCE: f64 = 0.01;
+
+/// Returns the outline of `path` stroked with `stroke`, as a path which should be filled
+/// with [`Fill::NonZero`](peniko::Fill::NonZero).
+///
+/// This includes the joins, caps and [dash pattern](Stroke::dash_pattern) of `stroke`.
+/// The curves of the outline are within [`DEFAULT_TOLERANCE`] of the exact outline.
+/// The outline of a stroke with a width of zero is empty.
+///
+/// The outline is the same shape which [`Scene::stroke`](crate::Scene::stroke) draws, but
+/// it isn't identical to the segments which the GPU generates, so the rendered edges may
+/// differ very slightly.
+pub fn outline(path: &BezPath, stroke: &Stroke) -> BezPath {
+    outline_with_tolerance(path, stroke, DEFAULT_TOLERANCE)
+}
+
+/// Returns the outline of `path` stroked with `stroke`, with the curves of the outline within
+/// `tolerance` of the exact outline.
+///
+/// See [`outline`] for more details.
+pub fn outline_with_tolerance(path: &BezPath, stroke: &Stroke, tolerance: f64) -> BezPath {
+    if stroke.width == 0.0 || !stroke.width.is_finite() {
+        return BezPath::new();
+    }
+    kurbo::stroke(path, stroke, &StrokeOpts::default(), tolerance)
+}
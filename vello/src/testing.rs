@@ -0,0 +1,351 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Utilities for snapshot ("golden image") testing of scenes.
+//!
+//! This module is only available when the `testing` feature is enabled.
+//!
+//! The main entry point is [`assert_scene_matches`], which renders a scene headlessly and
+//! compares the result to a reference PNG file. [`edge_quality`] measures the anti-aliasing
+//! of a rendered edge, to guard against regressions which snapshots are too coarse to catch.
+
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use peniko::color::palette;
+use peniko::kurbo::{Line, Shape, Vec2};
+use peniko::{Color, ImageAlphaType, ImageData};
+use png::{BitDepth, ColorType, Transformations};
+use wgpu::{
+    BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Extent3d, TexelCopyBufferInfo,
+    TextureDescriptor, TextureFormat, TextureUsages,
+};
+
+use crate::util::{RenderContext, block_on_wgpu};
+use crate::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Result, Scene,
+};
+
+/// The environment variable which, when set to any value, makes [`assert_scene_matches`]
+/// overwrite the reference images rather than comparing against them.
+pub const UPDATE_ENV_VAR: &str = "VELLO_TEST_UPDATE";
+
+/// Renders `scene` on a headless device, returning its pixels as unpremultiplied RGBA8.
+///
+/// The scene is rendered over an opaque black background using area anti-aliasing.
+pub fn render_scene(scene: &Scene, width: u32, height: u32) -> Result<Vec<u8>> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).ok_or(Error::NoCompatibleDevice)?;
+    let device_handle = &context.devices[device_id];
+    let device = &device_handle.device;
+    let queue = &device_handle.queue;
+    let mut renderer = Renderer::new(
+        device,
+        RendererOptions {
+            num_init_threads: NonZeroUsize::new(1),
+            antialiasing_support: std::iter::once(AaConfig::Area).collect(),
+            ..Default::default()
+        },
+    )?;
+    let size = Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let target = device.create_texture(&TextureDescriptor {
+        label: Some("vello.testing.target"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    renderer.render_to_texture(
+        device,
+        queue,
+        scene,
+        &view,
+        &RenderParams {
+            base_color: palette::css::BLACK,
+            width,
+            height,
+            antialiasing_method: AaConfig::Area,
+            text_gamma: 1.0,
+            alpha_to_coverage: false,
+            debug: DebugView::None,
+            premultiplied_alpha: false,
+            flatten_tolerance: None,
+            viewport: None,
+            clear_mode: ClearMode::Clear,
+        },
+    )?;
+    let padded_byte_width = (width * 4).next_multiple_of(256);
+    let buffer = device.create_buffer(&BufferDescriptor {
+        label: Some("vello.testing.readback"),
+        size: u64::from(padded_byte_width) * u64::from(height),
+        usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+        label: Some("vello.testing.copy_out"),
+    });
+    encoder.copy_texture_to_buffer(
+        target.as_image_copy(),
+        TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_byte_width),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit([encoder.finish()]);
+    let buf_slice = buffer.slice(..);
+    let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+    buf_slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+    if let Some(recv_result) = block_on_wgpu(device, receiver.receive()) {
+        recv_result?;
+    }
+    let data = buf_slice.get_mapped_range();
+    let mut result = Vec::with_capacity(width as usize * height as usize * 4);
+    for row in 0..height as usize {
+        let start = row * padded_byte_width as usize;
+        result.extend_from_slice(&data[start..start + width as usize * 4]);
+    }
+    Ok(result)
+}
+
+/// Renders `scene` and asserts that it matches the PNG image at `reference_path`.
+///
+/// The scene is rendered at the size of the reference image, as described in [`render_scene`].
+/// Each pixel is compared using [`Color::difference`] on premultiplied colors, and the
+/// test fails if any pixel differs by more than `tolerance`.
+/// A `tolerance` of about `0.01` allows for differences in rounding between GPUs.
+///
+/// On failure, the rendered image is written next to the reference with the extension
+/// `actual.png`, along with a `diff.png` image, in which the differing pixels are red.
+///
+/// If the [`UPDATE_ENV_VAR`] environment variable is set, the rendered image is written to
+/// `reference_path` instead, and the assertion passes.
+/// New reference images can be created using [`write_scene_reference`].
+///
+/// # Panics
+///
+/// If the scene doesn't match the reference image, if the reference image doesn't exist,
+/// or if the scene couldn't be rendered.
+#[track_caller]
+pub fn assert_scene_matches(scene: &Scene, reference_path: impl AsRef<Path>, tolerance: f32) {
+    let reference_path = reference_path.as_ref();
+    let Some((width, height, expected)) = read_png(reference_path) else {
+        panic!(
+            "No reference image at {}. Create it using `write_scene_reference`",
+            reference_path.display()
+        );
+    };
+    let actual = render_scene(scene, width, height).expect("Failed to render scene");
+    if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+        write_png(reference_path, width, height, &actual).expect("Failed to update reference");
+        return;
+    }
+    let mut failures = 0_usize;
+    let mut max_difference = 0_f32;
+    let mut diff = Vec::with_capacity(actual.len());
+    for (actual, expected) in actual.chunks_exact(4).zip(expected.chunks_exact(4)) {
+        let difference = pixel_difference(actual, expected);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance {
+            failures += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // Show the matching content faintly, so that the failing pixels can be located.
+            let [r, g, b, _] = <[u8; 4]>::try_from(actual).unwrap();
+            diff.extend_from_slice(&[r / 4, g / 4, b / 4, 255]);
+        }
+    }
+    if failures > 0 {
+        let actual_path = reference_path.with_extension("actual.png");
+        let diff_path = reference_path.with_extension("diff.png");
+        write_png(&actual_path, width, height, &actual).expect("Failed to write rendered image");
+        write_png(&diff_path, width, height, &diff).expect("Failed to write diff image");
+        panic!(
+            "Scene doesn't match {reference}: {failures} pixels differ by more than {tolerance} \
+            (maximum difference {max_difference}).\n\
+            Wrote rendered image to {actual} and difference to {diff}.\n\
+            Set {UPDATE_ENV_VAR} to update the reference image.",
+            reference = reference_path.display(),
+            actual = actual_path.display(),
+            diff = diff_path.display(),
+        );
+    }
+}
+
+/// Renders `scene` at the given size, and writes it to `reference_path` as a PNG.
+///
+/// This is used to create the reference images for [`assert_scene_matches`].
+pub fn write_scene_reference(
+    scene: &Scene,
+    reference_path: impl AsRef<Path>,
+    width: u32,
+    height: u32,
+) -> Result<()> {
+    let image = render_scene(scene, width, height)?;
+    write_png(reference_path.as_ref(), width, height, &image)
+}
+
+/// Measures how closely the anti-aliasing of a straight edge in `image` matches the exact
+/// coverage of each pixel, returning a score from `0.0` to `1.0`, where `1.0` is exact.
+///
+/// `edge` is the position of the edge in pixels, which should separate two regions of
+/// different solid colors, with at least three pixels of each color on either side. Only the
+/// pixels alongside the edge, and at least a pixel away from its ends, are measured. The
+/// brightness of each pixel is normalized between the colors of the two regions, and compared
+/// to the fraction of the pixel on the first region's side of the edge. The score is one
+/// minus the root mean square of the differences.
+///
+/// Area anti-aliasing scores close to `1.0`, and aliased edges score much lower, as each pixel
+/// is either one color or the other. The score can be compared between the anti-aliasing
+/// methods, such as to check that [`AaConfig::Msaa16`] is closer to exact than
+/// [`AaConfig::Msaa8`]. It is `0.0` if no edge is found, such as if both regions are the same
+/// color.
+///
+/// The image must be in an 8-bit RGBA or BGRA format.
+pub fn edge_quality(image: &ImageData, edge: Line) -> f64 {
+    let length = edge.length();
+    if !length.is_finite() || length < 2.0 {
+        return 0.0;
+    }
+    let direction = (edge.p1 - edge.p0) / length;
+    let normal = Vec2::new(-direction.y, direction.x);
+    let data = image.data.data();
+    let brightness = |x: u32, y: u32| {
+        let index = (y as usize * image.width as usize + x as usize) * 4;
+        let [r, g, b, a] = <[u8; 4]>::try_from(&data[index..index + 4]).unwrap();
+        let rgb = (f64::from(r) + f64::from(g) + f64::from(b)) / (3.0 * 255.0);
+        match image.alpha_type {
+            ImageAlphaType::Alpha => rgb * f64::from(a) / 255.0,
+            ImageAlphaType::AlphaPremultiplied => rgb,
+        }
+    };
+
+    // Pixels within 1.5 pixels of the edge are measured, and pixels from 2 to 3.5 pixels away
+    // give the colors of the two regions.
+    let bounds = edge.bounding_box().inflate(4.0, 4.0);
+    let x0 = (bounds.x0.floor().max(0.0) as u32).min(image.width);
+    let y0 = (bounds.y0.floor().max(0.0) as u32).min(image.height);
+    let x1 = (bounds.x1.ceil().max(0.0) as u32).min(image.width);
+    let y1 = (bounds.y1.ceil().max(0.0) as u32).min(image.height);
+    let mut measured = Vec::new();
+    let (mut first, mut second) = ((0.0, 0_u32), (0.0, 0_u32));
+    for y in y0..y1 {
+        for x in x0..x1 {
+            let center = Vec2::new(f64::from(x) + 0.5, f64::from(y) + 0.5) - edge.p0.to_vec2();
+            let along = center.dot(direction);
+            if along < 1.0 || along > length - 1.0 {
+                continue;
+            }
+            let distance = center.dot(normal);
+            let value = brightness(x, y);
+            if distance.abs() < 1.5 {
+                measured.push((distance, value));
+            } else if (2.0..3.5).contains(&distance.abs()) {
+                let side = if distance < 0.0 {
+                    &mut first
+                } else {
+                    &mut second
+                };
+                side.0 += value;
+                side.1 += 1;
+            }
+        }
+    }
+    if first.1 == 0 || second.1 == 0 || measured.is_empty() {
+        return 0.0;
+    }
+    let first = first.0 / f64::from(first.1);
+    let second = second.0 / f64::from(second.1);
+    if (first - second).abs() < 1e-3 {
+        return 0.0;
+    }
+    let squared_error = measured
+        .iter()
+        .map(|&(distance, value)| {
+            let coverage = (value - second) / (first - second);
+            (coverage - pixel_coverage(normal, -distance)).powi(2)
+        })
+        .sum::<f64>();
+    (1.0 - (squared_error / measured.len() as f64).sqrt()).max(0.0)
+}
+
+/// The fraction of a unit pixel for which `normal · (p - center) <= offset`, where `normal`
+/// is a unit vector.
+fn pixel_coverage(normal: Vec2, offset: f64) -> f64 {
+    let (u, v) = (
+        normal.x.abs().max(normal.y.abs()),
+        normal.x.abs().min(normal.y.abs()),
+    );
+    // The pixel's projection onto the normal is `[-outer, outer]`, and its coverage is linear
+    // in the offset within `[-inner, inner]`, with quadratic corners outside of that.
+    let outer = 0.5 * (u + v);
+    let inner = 0.5 * (u - v);
+    if offset <= -outer {
+        0.0
+    } else if offset >= outer {
+        1.0
+    } else if offset < -inner {
+        (offset + outer).powi(2) / (2.0 * u * v)
+    } else if offset > inner {
+        1.0 - (outer - offset).powi(2) / (2.0 * u * v)
+    } else {
+        0.5 + offset / u
+    }
+}
+
+fn pixel_difference(actual: &[u8], expected: &[u8]) -> f32 {
+    let [r0, g0, b0, a0] = <[u8; 4]>::try_from(actual).unwrap();
+    let [r1, g1, b1, a1] = <[u8; 4]>::try_from(expected).unwrap();
+    let actual = Color::from_rgba8(r0, g0, b0, a0).premultiply();
+    let expected = Color::from_rgba8(r1, g1, b1, a1).premultiply();
+    actual.difference(expected)
+}
+
+/// Reads an RGBA8 PNG, returning `None` if it doesn't exist.
+fn read_png(path: &Path) -> Option<(u32, u32, Vec<u8>)> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => panic!("Failed to open reference image {}: {e}", path.display()),
+    };
+    let mut decoder = png::Decoder::new(std::io::BufReader::new(file));
+    decoder.set_transformations(Transformations::ALPHA | Transformations::STRIP_16);
+    let mut reader = decoder
+        .read_info()
+        .unwrap_or_else(|e| panic!("Invalid reference image {}: {e}", path.display()));
+    assert_eq!(
+        reader.output_color_type(),
+        (ColorType::Rgba, BitDepth::Eight),
+        "Unsupported color type in reference image {}",
+        path.display()
+    );
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .unwrap_or_else(|e| panic!("Invalid reference image {}: {e}", path.display()));
+    buf.truncate(info.buffer_size());
+    Some((info.width, info.height, buf))
+}
+
+fn write_png(path: &Path, width: u32, height: u32, data: &[u8]) -> Result<()> {
+    let file = std::fs::File::create(path).map_err(png::EncodingError::from)?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(data)?;
+    writer.finish()?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measuring text before it is drawn, such as to size a button around its label.
+//!
+//! Vello doesn't shape text, so [`measure`] takes the glyph ids of a run which has already been
+//! mapped from characters (for example, using a shaping library, or the font's character map).
+//! The glyphs are laid out one after another using their advances, as when drawing them with
+//! [`Scene::draw_glyphs`](crate::Scene::draw_glyphs) at positions spaced by their advances.
+//! Only the font's tables are read, so this doesn't need a GPU.
+
+use peniko::FontData;
+use peniko::kurbo::Rect;
+use skrifa::instance::{LocationRef, Size};
+use skrifa::{GlyphId, MetadataProvider};
+
+/// The metrics of a run of glyphs, measured by [`measure`].
+///
+/// All values are in pixels, for the font size which was measured. Vertical distances use
+/// the same coordinate system as the rest of Vello, where y increases downwards from the
+/// baseline.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextMetrics {
+    /// The sum of the advances of the glyphs, which is the distance from the start of the run
+    /// to where the next glyph would be placed.
+    pub width: f32,
+    /// The font's ascent, the distance from the baseline to the top of its tallest glyphs.
+    pub ascent: f32,
+    /// The font's descent, the distance from the baseline to the bottom of its lowest glyphs.
+    ///
+    /// This is positive for fonts whose glyphs extend below the baseline.
+    pub descent: f32,
+    /// The bounding box of the outlines of the glyphs, relative to the start of the
+    /// run on the baseline.
+    ///
+    /// This is [`Rect::ZERO`] if none of the glyphs have an outline, such as for spaces.
+    pub bounds: Rect,
+}
+
+impl TextMetrics {
+    /// The height of a line of this text, from the ascent to the descent.
+    pub fn height(&self) -> f32 {
+        self.ascent + self.descent
+    }
+}
+
+/// Measures the run of glyphs with ids `glyph_ids` in `font`, at `font_size` pixels per em.
+///
+/// Variable fonts are measured at their default instance.
+/// Glyph ids which aren't in the font have no advance or bounds.
+///
+/// Returns `None` if `font` can't be read.
+pub fn measure(
+    font: &FontData,
+    font_size: f32,
+    glyph_ids: impl IntoIterator<Item = u32>,
+) -> Option<TextMetrics> {
+    let font_ref = skrifa::FontRef::from_index(font.data.as_ref(), font.index).ok()?;
+    let size = Size::new(font_size);
+    let location = LocationRef::default();
+    let metrics = font_ref.metrics(size, location);
+    let glyph_metrics = font_ref.glyph_metrics(size, location);
+
+    let mut pen_x = 0.0_f32;
+    let mut bounds: Option<Rect> = None;
+    for id in glyph_ids {
+        let id = GlyphId::new(id);
+        if let Some(glyph_bounds) = glyph_metrics.bounds(id) {
+            // Font units have y increasing upwards, so the y coordinates are flipped.
+            let glyph_rect = Rect::new(
+                f64::from(pen_x + glyph_bounds.x_min),
+                f64::from(-glyph_bounds.y_max),
+                f64::from(pen_x + glyph_bounds.x_max),
+                f64::from(-glyph_bounds.y_min),
+            );
+            if glyph_rect.area() > 0.0 {
+                bounds = Some(bounds.map_or(glyph_rect, |bounds| bounds.union(glyph_rect)));
+            }
+        }
+        pen_x += glyph_metrics.advance_width(id).unwrap_or_default();
+    }
+    Some(TextMetrics {
+        width: pen_x,
+        ascent: metrics.ascent,
+        descent: -metrics.descent,
+        bounds: bounds.unwrap_or(Rect::ZERO),
+    })
+}
@@ -0,0 +1,221 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Rendering scenes into textures which are displayed by a UI library, such as egui.
+//!
+//! This module is only available when the `ui_texture` feature is enabled.
+//!
+//! [`UiTexture`] owns a texture which Vello can render into, and which can also be sampled,
+//! so it can be registered with a UI library's renderer. It doesn't depend on any UI library,
+//! so it works with any version of egui (or other libraries) which uses the same
+//! [`wgpu`] version as Vello.
+//!
+//! With [`egui_wgpu`], the view is registered once using `Renderer::register_native_texture`,
+//! giving an `egui::TextureId` to draw with `egui::Image`. If the texture is resized, it
+//! should be updated using `Renderer::update_egui_texture_from_wgpu_texture`.
+//! Alternatively, the pixels can be read back using [`UiTexture::read_rgba8`], and uploaded
+//! as an `egui::ColorImage`, which is slower but doesn't need access to the device.
+//!
+//! [`egui_wgpu`]: https://docs.rs/egui-wgpu
+//!
+//! ```no_run
+//! use vello::kurbo::{Affine, Circle};
+//! use vello::peniko::{Color, Fill, color::palette};
+//! use vello::ui_texture::UiTexture;
+//! use vello::{AaConfig, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene};
+//!
+//! # fn example(device: &vello::wgpu::Device, queue: &vello::wgpu::Queue) -> Result<(), vello::Error> {
+//! let mut renderer = Renderer::new(device, RendererOptions::default())?;
+//! // The size of the area which egui allocated for the image, in physical pixels.
+//! let mut texture = UiTexture::new(device, 256, 256);
+//! // let id = egui_renderer.register_native_texture(device, texture.view(), Linear);
+//!
+//! // Each frame:
+//! let mut scene = Scene::new();
+//! let circle = Circle::new((128.0, 128.0), 96.0);
+//! scene.fill(Fill::NonZero, Affine::IDENTITY, palette::css::ORANGE, None, &circle);
+//! if texture.resize(device, 320, 240) {
+//!     // egui_renderer.update_egui_texture_from_wgpu_texture(device, texture.view(), Linear, id);
+//! }
+//! let params = RenderParams {
+//!     base_color: Color::TRANSPARENT,
+//!     // The size of the texture is used instead.
+//!     width: 0,
+//!     height: 0,
+//!     antialiasing_method: AaConfig::Area,
+//!     text_gamma: 1.0,
+//!     alpha_to_coverage: false,
+//!     debug: DebugView::None,
+//!     premultiplied_alpha: true,
+//!     flatten_tolerance: None,
+//!     viewport: None,
+//!     clear_mode: ClearMode::Clear,
+//! };
+//! texture.render(&mut renderer, device, queue, &scene, &params)?;
+//! // ui.image((id, egui::vec2(320.0, 240.0) / pixels_per_point));
+//! # Ok(())
+//! # }
+//! ```
+
+use wgpu::{Device, Queue, Texture, TextureFormat, TextureUsages, TextureView};
+
+use crate::util::block_on_wgpu;
+use crate::{RenderParams, Renderer, Result, Scene};
+
+/// A texture which scenes are rendered into, to be sampled by a UI library.
+///
+/// See the [module level documentation](self) for how to use this with egui.
+pub struct UiTexture {
+    texture: Texture,
+    /// The `Rgba8Unorm` view which Vello renders into.
+    target_view: TextureView,
+    /// The `Rgba8UnormSrgb` view which is sampled.
+    view: TextureView,
+}
+
+impl UiTexture {
+    /// Creates a texture of the given size in pixels.
+    ///
+    /// Sizes of zero are rounded up to one pixel, as textures can't be empty.
+    pub fn new(device: &Device, width: u32, height: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("vello.ui_texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: TextureFormat::Rgba8Unorm,
+            usage: TextureUsages::STORAGE_BINDING
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC,
+            view_formats: &[TextureFormat::Rgba8UnormSrgb],
+        });
+        let target_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(TextureFormat::Rgba8UnormSrgb),
+            ..Default::default()
+        });
+        Self {
+            texture,
+            target_view,
+            view,
+        }
+    }
+
+    /// Recreates the texture with a new size, if it differs from the current size.
+    ///
+    /// Returns `true` if the texture was recreated, in which case the new [view](Self::view)
+    /// must be registered with the UI library again, and the texture must be rendered into
+    /// again before it is displayed.
+    pub fn resize(&mut self, device: &Device, width: u32, height: u32) -> bool {
+        if (width.max(1), height.max(1)) == self.size() {
+            return false;
+        }
+        *self = Self::new(device, width, height);
+        true
+    }
+
+    /// The width and height of the texture in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        (self.texture.width(), self.texture.height())
+    }
+
+    /// The texture which is rendered into.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// The view of the texture which should be sampled, such as by registering it with
+    /// egui.
+    ///
+    /// Vello writes sRGB encoded colors, so this is an `Rgba8UnormSrgb` view, which decodes
+    /// them to linear colors when sampled. This matches how egui's own textures are sampled.
+    pub fn view(&self) -> &TextureView {
+        &self.view
+    }
+
+    /// Renders `scene` into the texture, covering all of it.
+    ///
+    /// The `width` and `height` of `params` are ignored, and the size of the texture is used
+    /// instead. As egui blends with premultiplied alpha, scenes with transparent content
+    /// should be rendered with [`RenderParams::premultiplied_alpha`] set, over a transparent
+    /// [`base_color`](RenderParams::base_color).
+    pub fn render(
+        &self,
+        renderer: &mut Renderer,
+        device: &Device,
+        queue: &Queue,
+        scene: &Scene,
+        params: &RenderParams,
+    ) -> Result<()> {
+        let (width, height) = self.size();
+        let params = RenderParams {
+            base_color: params.base_color,
+            width,
+            height,
+            antialiasing_method: params.antialiasing_method,
+            text_gamma: params.text_gamma,
+            alpha_to_coverage: params.alpha_to_coverage,
+            debug: params.debug,
+            premultiplied_alpha: params.premultiplied_alpha,
+            flatten_tolerance: params.flatten_tolerance,
+            viewport: params.viewport,
+            clear_mode: params.clear_mode,
+        };
+        renderer.render_to_texture(device, queue, scene, &self.target_view, &params)
+    }
+
+    /// Reads back the pixels of the texture as RGBA8, four bytes per pixel in rows from top
+    /// to bottom, such as for uploading as an `egui::ColorImage`.
+    ///
+    /// The colors are sRGB encoded, with premultiplied alpha if the texture was last rendered
+    /// with [`RenderParams::premultiplied_alpha`] set, and straight alpha otherwise.
+    /// This waits for all work previously submitted to `queue` to complete.
+    ///
+    /// # Panics
+    ///
+    /// On the web, as this blocks until the pixels have been copied.
+    pub fn read_rgba8(&self, device: &Device, queue: &Queue) -> Result<Vec<u8>> {
+        let (width, height) = self.size();
+        let row_bytes = width * 4;
+        let padded_row_bytes = row_bytes.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vello.ui_texture.read_rgba8"),
+            size: u64::from(padded_row_bytes) * u64::from(height),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("vello.ui_texture.read_rgba8"),
+        });
+        encoder.copy_texture_to_buffer(
+            self.texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row_bytes),
+                    rows_per_image: None,
+                },
+            },
+            self.texture.size(),
+        );
+        queue.submit([encoder.finish()]);
+        let slice = buffer.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+        if let Some(recv_result) = block_on_wgpu(device, receiver.receive()) {
+            recv_result?;
+        }
+        let data = slice.get_mapped_range();
+        let mut pixels = Vec::with_capacity(row_bytes as usize * height as usize);
+        for row in data.chunks_exact(padded_row_bytes as usize) {
+            pixels.extend_from_slice(&row[..row_bytes as usize]);
+        }
+        Ok(pixels)
+    }
+}
@@ -4,13 +4,30 @@
 //! Simple helpers for managing wgpu state and surfaces.
 
 use std::future::Future;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use wgpu::{
-    Adapter, Device, Instance, Limits, Queue, Surface, SurfaceConfiguration, SurfaceTarget,
-    Texture, TextureFormat, TextureView, util::TextureBlitter,
+    Adapter, AdapterInfo, Device, Instance, Limits, PipelineCache, Queue, Surface,
+    SurfaceConfiguration, SurfaceTarget, Texture, TextureFormat, TextureView,
+    util::{TextureBlitter, TextureBlitterBuilder},
 };
 
-use crate::{Error, Result};
+use crate::kurbo::{Affine, BezPath, Point, Rect, Shape, Vec2};
+use crate::peniko::{Color, Fill};
+use crate::{AaConfig, AaSupport, Error, Result, Scene};
+
+/// The descriptor for the [`Instance`] of a [`RenderContext`], which can be configured using
+/// wgpu's environment variables.
+fn instance_descriptor() -> wgpu::InstanceDescriptor {
+    wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::from_env().unwrap_or_default(),
+        flags: wgpu::InstanceFlags::from_build_config().with_env(),
+        memory_budget_thresholds: wgpu::MemoryBudgetThresholds::default(),
+        backend_options: wgpu::BackendOptions::from_env_or_default(),
+    }
+}
 
 /// Simple render context that maintains wgpu state for rendering the pipeline.
 pub struct RenderContext {
@@ -30,16 +47,39 @@ impl RenderContext {
         reason = "Creating a wgpu Instance is something which should only be done rarely"
     )]
     pub fn new() -> Self {
-        let backends = wgpu::Backends::from_env().unwrap_or_default();
-        let flags = wgpu::InstanceFlags::from_build_config().with_env();
-        let memory_budget_thresholds = wgpu::MemoryBudgetThresholds::default();
-        let backend_options = wgpu::BackendOptions::from_env_or_default();
-        let instance = Instance::new(&wgpu::InstanceDescriptor {
-            backends,
-            flags,
-            memory_budget_thresholds,
-            backend_options,
-        });
+        let instance = Instance::new(&instance_descriptor());
+        Self {
+            instance,
+            devices: Vec::new(),
+        }
+    }
+
+    /// Creates a new render context, without blocking.
+    ///
+    /// On the web, this checks whether WebGPU is actually available before choosing it, and
+    /// otherwise uses WebGL if wgpu's `webgl` feature is enabled. [`RenderContext::new`] can't
+    /// do this check, as it can only be done asynchronously, so it may choose WebGPU in browsers
+    /// which expose the API without supporting it. On other platforms, this is equivalent to
+    /// [`RenderContext::new`].
+    ///
+    /// All of the methods of `RenderContext` which need to wait for the GPU, such as
+    /// [`create_surface`](Self::create_surface) and [`device`](Self::device), are also `async`.
+    /// On the web, these futures must be run by the browser's event loop, for example using
+    /// `wasm_bindgen_futures::spawn_local`, rather than by blocking with an executor such as
+    /// `pollster`:
+    ///
+    /// ```ignore
+    /// wasm_bindgen_futures::spawn_local(async move {
+    ///     let mut context = RenderContext::new_async().await;
+    ///     let surface = context
+    ///         .create_surface(window, width, height, wgpu::PresentMode::AutoVsync)
+    ///         .await
+    ///         .unwrap();
+    ///     // Send the context and surface back to the event loop, e.g. using a user event.
+    /// });
+    /// ```
+    pub async fn new_async() -> Self {
+        let instance = wgpu::util::new_instance_with_webgpu_detection(&instance_descriptor()).await;
         Self {
             instance,
             devices: Vec::new(),
@@ -77,51 +117,34 @@ impl RenderContext {
             .ok_or(Error::NoCompatibleDevice)?;
 
         let device_handle = &self.devices[dev_id];
-        let capabilities = surface.get_capabilities(&device_handle.adapter);
-        let format = capabilities
-            .formats
-            .into_iter()
-            .find(|it| matches!(it, TextureFormat::Rgba8Unorm | TextureFormat::Bgra8Unorm))
-            .ok_or(Error::UnsupportedSurfaceFormat)?;
-
-        let config = SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format,
+        let mut surface = RenderSurface::from_existing(
+            &device_handle.adapter,
+            &device_handle.device,
+            surface,
             width,
             height,
             present_mode,
-            desired_maximum_frame_latency: 2,
-            alpha_mode: wgpu::CompositeAlphaMode::Auto,
-            view_formats: vec![],
-        };
-        let (target_texture, target_view) = create_targets(width, height, &device_handle.device);
-        let surface = RenderSurface {
-            surface,
-            config,
-            dev_id,
-            format,
-            target_texture,
-            target_view,
-            blitter: TextureBlitter::new(&device_handle.device, format),
-        };
-        self.configure_surface(&surface);
+        )?;
+        surface.dev_id = dev_id;
         Ok(surface)
     }
 
     /// Resizes the surface to the new dimensions.
     ///
+    /// See [`RenderSurface::resize`].
+    ///
     /// # Panics
     ///
     /// If `width` or `height` is zero.
     pub fn resize_surface(&self, surface: &mut RenderSurface<'_>, width: u32, height: u32) {
-        let (texture, view) = create_targets(width, height, &self.devices[surface.dev_id].device);
-        // TODO: Use clever resize semantics to avoid thrashing the memory allocator during a resize
-        // especially important on metal.
-        surface.target_texture = texture;
-        surface.target_view = view;
-        surface.config.width = width;
-        surface.config.height = height;
-        self.configure_surface(surface);
+        surface.resize(&self.devices[surface.dev_id].device, width, height);
+    }
+
+    /// Sets the resolution of the surface's intermediate render target, relative to the surface.
+    ///
+    /// See [`RenderSurface::set_render_scale`].
+    pub fn set_render_scale(&self, surface: &mut RenderSurface<'_>, render_scale: f32) {
+        surface.set_render_scale(&self.devices[surface.dev_id].device, render_scale);
     }
 
     pub fn set_present_mode(
@@ -129,13 +152,26 @@ impl RenderContext {
         surface: &mut RenderSurface<'_>,
         present_mode: wgpu::PresentMode,
     ) {
-        surface.config.present_mode = present_mode;
-        self.configure_surface(surface);
+        surface.set_present_mode(&self.devices[surface.dev_id].device, present_mode);
     }
 
-    fn configure_surface(&self, surface: &RenderSurface<'_>) {
-        let device = &self.devices[surface.dev_id].device;
-        surface.surface.configure(device, &surface.config);
+    /// Adds a device which was created outside of Vello, such as by a game engine, returning
+    /// its device handle id.
+    ///
+    /// The device is then used by [`device`](Self::device) and for the surfaces created by
+    /// this context which it supports, rather than creating another device. It must have been
+    /// created from `adapter`, which should have been created from [`RenderContext::instance`]
+    /// so that it can support the surfaces which this context creates.
+    ///
+    /// To create a surface for a device without using a `RenderContext` at all, use
+    /// [`RenderSurface::from_existing`].
+    pub fn add_device(&mut self, adapter: Adapter, device: Device, queue: Queue) -> usize {
+        self.devices.push(DeviceHandle {
+            adapter,
+            device,
+            queue,
+        });
+        self.devices.len() - 1
     }
 
     /// Finds or creates a compatible device handle id.
@@ -163,7 +199,10 @@ impl RenderContext {
                 .ok()?;
         let features = adapter.features();
         let limits = Limits::default();
-        let maybe_features = wgpu::Features::CLEAR_TEXTURE | wgpu::Features::PIPELINE_CACHE;
+        // Timestamp queries are used by `Renderer::last_frame_gpu_time`.
+        let maybe_features = wgpu::Features::CLEAR_TEXTURE
+            | wgpu::Features::PIPELINE_CACHE
+            | wgpu::Features::TIMESTAMP_QUERY;
         #[cfg(feature = "wgpu-profiler")]
         let maybe_features = maybe_features | wgpu_profiler::GpuProfiler::ALL_WGPU_TIMER_FEATURES;
 
@@ -201,14 +240,39 @@ fn create_targets(width: u32, height: u32, device: &Device) -> (Texture, Texture
         mip_level_count: 1,
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
-        usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+        // `RENDER_ATTACHMENT` allows a post-process shader to be used, see `Renderer::set_post_process`.
+        usage: wgpu::TextureUsages::STORAGE_BINDING
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::RENDER_ATTACHMENT,
         format: TextureFormat::Rgba8Unorm,
-        view_formats: &[],
+        view_formats: &[TextureFormat::Rgba8UnormSrgb],
     });
     let target_view = target_texture.create_view(&wgpu::TextureViewDescriptor::default());
     (target_texture, target_view)
 }
 
+/// Creates the view of `target_texture` which should be used to blit it to a texture of
+/// `target_format`, such as a surface.
+///
+/// Vello writes sRGB encoded colors into its `Rgba8Unorm` render target. Blitting those values
+/// to an sRGB target would encode them a second time, making the output too bright. For sRGB
+/// targets, this instead returns an `Rgba8UnormSrgb` view of `target_texture`, so that the colors
+/// are decoded when sampled and re-encoded when written, and the target ends up with the same
+/// bytes as a non-sRGB target would.
+///
+/// `target_texture` must have been created with `Rgba8UnormSrgb` in its `view_formats` if
+/// `target_format` is an sRGB format.
+/// The [`TextureBlitter`] must be created for `target_format`.
+pub fn blit_source_view(target_texture: &Texture, target_format: TextureFormat) -> TextureView {
+    let format = target_format
+        .is_srgb()
+        .then_some(TextureFormat::Rgba8UnormSrgb);
+    target_texture.create_view(&wgpu::TextureViewDescriptor {
+        format,
+        ..Default::default()
+    })
+}
+
 impl DeviceHandle {
     /// Returns the adapter associated with the device.
     pub fn adapter(&self) -> &Adapter {
@@ -220,11 +284,22 @@ impl DeviceHandle {
 pub struct RenderSurface<'s> {
     pub surface: Surface<'s>,
     pub config: SurfaceConfiguration,
+    /// The id of the device handle in the [`RenderContext`] which created the surface.
+    ///
+    /// This is `usize::MAX` for surfaces created using [`RenderSurface::from_existing`].
     pub dev_id: usize,
     pub format: TextureFormat,
     pub target_texture: Texture,
+    /// The view which Vello should render into.
     pub target_view: TextureView,
+    /// The view of `target_texture` which should be blitted to the surface using `blitter`.
+    ///
+    /// If the surface has an sRGB format, this decodes the colors when sampled, so that the
+    /// surface shows the same colors as a non-sRGB surface would. See [`blit_source_view`].
+    pub blit_view: TextureView,
     pub blitter: TextureBlitter,
+    scale_factor: f64,
+    render_scale: f32,
 }
 
 impl std::fmt::Debug for RenderSurface<'_> {
@@ -236,11 +311,582 @@ impl std::fmt::Debug for RenderSurface<'_> {
             .field("format", &self.format)
             .field("target_texture", &self.target_texture)
             .field("target_view", &self.target_view)
+            .field("blit_view", &self.blit_view)
             .field("blitter", &"(Not Debug)")
+            .field("scale_factor", &self.scale_factor)
+            .field("render_scale", &self.render_scale)
             .finish()
     }
 }
 
+impl<'s> RenderSurface<'s> {
+    /// Creates a render surface for `surface`, using a device which was created outside of
+    /// Vello, such as by a game engine.
+    ///
+    /// This configures the surface and creates the intermediate texture which Vello renders
+    /// into, so that the surface can be rendered to with a [`Renderer`](crate::Renderer)
+    /// created for the same `device`, without creating another device.
+    /// `device` must have been created from `adapter`, which must support `surface`.
+    ///
+    /// The surface isn't associated with a [`RenderContext`], so its `dev_id` is `usize::MAX`,
+    /// and it must not be passed to the methods of a `RenderContext`. Use
+    /// [`resize`](Self::resize), [`set_render_scale`](Self::set_render_scale) and
+    /// [`set_present_mode`](Self::set_present_mode) instead, with the same device.
+    pub fn from_existing(
+        adapter: &Adapter,
+        device: &Device,
+        surface: Surface<'s>,
+        width: u32,
+        height: u32,
+        present_mode: wgpu::PresentMode,
+    ) -> Result<Self> {
+        let capabilities = surface.get_capabilities(adapter);
+        // Prefer formats without sRGB encoding, but fall back to sRGB formats on platforms
+        // which only support those. Either way, the blit keeps the same output colors.
+        let format = capabilities
+            .formats
+            .iter()
+            .find(|it| matches!(it, TextureFormat::Rgba8Unorm | TextureFormat::Bgra8Unorm))
+            .or_else(|| {
+                capabilities.formats.iter().find(|it| {
+                    matches!(
+                        it,
+                        TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8UnormSrgb
+                    )
+                })
+            })
+            .copied()
+            .ok_or(Error::UnsupportedSurfaceFormat)?;
+
+        let config = SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width,
+            height,
+            present_mode,
+            desired_maximum_frame_latency: 2,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+        };
+        let (target_texture, target_view) = create_targets(width, height, device);
+        let blit_view = blit_source_view(&target_texture, format);
+        let surface = RenderSurface {
+            surface,
+            config,
+            dev_id: usize::MAX,
+            format,
+            target_texture,
+            target_view,
+            blit_view,
+            blitter: create_blitter(device, format),
+            scale_factor: 1.0,
+            render_scale: 1.0,
+        };
+        surface.surface.configure(device, &surface.config);
+        Ok(surface)
+    }
+
+    /// Resizes the surface to the new dimensions.
+    ///
+    /// `device` must be the device which the surface was created with.
+    ///
+    /// # Panics
+    ///
+    /// If `width` or `height` is zero.
+    pub fn resize(&mut self, device: &Device, width: u32, height: u32) {
+        let (target_width, target_height) = render_target_size(width, height, self.render_scale);
+        let (texture, view) = create_targets(target_width, target_height, device);
+        // TODO: Use clever resize semantics to avoid thrashing the memory allocator during a resize
+        // especially important on metal.
+        self.blit_view = blit_source_view(&texture, self.format);
+        self.target_texture = texture;
+        self.target_view = view;
+        self.config.width = width;
+        self.config.height = height;
+        self.surface.configure(device, &self.config);
+    }
+
+    /// Sets the resolution of the surface's intermediate render target, relative to the surface.
+    ///
+    /// For example, with a scale of `0.75`, a 1000x800 surface is rendered at 750x600, and
+    /// upscaled with bilinear filtering by the surface's blitter. This reduces the cost of
+    /// rendering on slower GPUs, at the cost of sharpness, and can be adjusted each frame
+    /// for dynamic resolution.
+    ///
+    /// The scene should be rendered with [`RenderSurface::render_transform`] applied, and the
+    /// width and height of [`RenderParams`](crate::RenderParams) should be the size of
+    /// [`RenderSurface::target_texture`]. Blurred rounded rectangles are scaled by the
+    /// transform, so shadows look the same at any scale, just softer.
+    ///
+    /// The scale is clamped to `0.1..=1.0`. Values which are not finite are treated as `1.0`.
+    /// `device` must be the device which the surface was created with.
+    pub fn set_render_scale(&mut self, device: &Device, render_scale: f32) {
+        let render_scale = if render_scale.is_finite() {
+            render_scale.clamp(0.1, 1.0)
+        } else {
+            1.0
+        };
+        if render_scale == self.render_scale {
+            return;
+        }
+        self.render_scale = render_scale;
+        let (width, height) = (self.config.width, self.config.height);
+        self.resize(device, width, height);
+    }
+
+    /// Sets the present mode of the surface.
+    ///
+    /// `device` must be the device which the surface was created with.
+    pub fn set_present_mode(&mut self, device: &Device, present_mode: wgpu::PresentMode) {
+        self.config.present_mode = present_mode;
+        self.surface.configure(device, &self.config);
+    }
+}
+
+impl RenderSurface<'_> {
+    /// The number of device (physical) pixels per CSS (logical) pixel of the surface.
+    ///
+    /// This is `1.0` unless it has been set using [`set_scale_factor`](Self::set_scale_factor).
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the number of device pixels per CSS pixel, such as from winit's
+    /// `Window::scale_factor`.
+    ///
+    /// The surface's size is always in device pixels, so this doesn't resize the surface.
+    /// Values which are not finite and positive are treated as `1.0`.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = if scale_factor.is_finite() && scale_factor > 0.0 {
+            scale_factor
+        } else {
+            1.0
+        };
+    }
+
+    /// The transform from CSS pixels to the surface's device pixels.
+    ///
+    /// Building a scene in CSS pixels and rendering it with this transform keeps sizes,
+    /// offsets and blur radii the same physical size on displays with different pixel
+    /// densities, as blurred rounded rectangles are scaled with their transform.
+    pub fn css_to_device(&self) -> Affine {
+        Affine::scale(self.scale_factor)
+    }
+
+    /// The resolution of the intermediate render target relative to the surface, set using
+    /// [`RenderSurface::set_render_scale`].
+    pub fn render_scale(&self) -> f32 {
+        self.render_scale
+    }
+
+    /// The transform from the surface's pixels to the pixels of the intermediate render target.
+    ///
+    /// This is the identity unless a [render scale](RenderContext::set_render_scale) is set.
+    /// It should be applied to the whole scene, such as by appending it to another scene.
+    pub fn render_transform(&self) -> Affine {
+        let scale_x = f64::from(self.target_texture.width()) / f64::from(self.config.width);
+        let scale_y = f64::from(self.target_texture.height()) / f64::from(self.config.height);
+        Affine::scale_non_uniform(scale_x, scale_y)
+    }
+}
+
+/// Returns the size of the intermediate render target for a surface of the given size.
+pub(crate) fn render_target_size(width: u32, height: u32, render_scale: f32) -> (u32, u32) {
+    let scale = |size: u32| ((size as f32 * render_scale).round() as u32).max(1);
+    (scale(width), scale(height))
+}
+
+/// Creates a blitter which upscales with bilinear filtering, for when a render scale is set.
+fn create_blitter(device: &Device, format: TextureFormat) -> TextureBlitter {
+    TextureBlitterBuilder::new(device, format)
+        .sample_type(wgpu::FilterMode::Linear)
+        .build()
+}
+
+/// Converts a point in CSS (logical) pixels to device (physical) pixels.
+pub fn css_to_device_point(point: Point, scale_factor: f64) -> Point {
+    (point.to_vec2() * scale_factor).to_point()
+}
+
+/// Converts a point in device (physical) pixels to CSS (logical) pixels, such as the
+/// position of the mouse.
+pub fn device_to_css_point(point: Point, scale_factor: f64) -> Point {
+    (point.to_vec2() / scale_factor).to_point()
+}
+
+/// Converts an offset or size in CSS (logical) pixels to device (physical) pixels.
+pub fn css_to_device_vec2(vec: Vec2, scale_factor: f64) -> Vec2 {
+    vec * scale_factor
+}
+
+/// Converts an offset or size in device (physical) pixels to CSS (logical) pixels.
+pub fn device_to_css_vec2(vec: Vec2, scale_factor: f64) -> Vec2 {
+    vec / scale_factor
+}
+
+/// Draws a checkerboard of squares covering `rect`, like the transparency grid of an image
+/// editor, to show the alpha of content drawn over it.
+///
+/// The squares are `cell_size` wide, starting with a `light` square at the top left corner of
+/// `rect`, and alternating with `dark` squares. Squares at the right and bottom edges are cut
+/// off by `rect`. Image editors commonly use white and a light gray, such as `#cccccc`.
+///
+/// This draws two paths, however many squares there are.
+/// Nothing is drawn if `cell_size` isn't positive.
+pub fn draw_transparency_grid(
+    scene: &mut Scene,
+    rect: Rect,
+    cell_size: f64,
+    light: Color,
+    dark: Color,
+) {
+    if cell_size.is_nan() || cell_size <= 0.0 {
+        return;
+    }
+    let rect = rect.abs();
+    scene.fill(Fill::NonZero, Affine::IDENTITY, light, None, &rect);
+    let columns = (rect.width() / cell_size).ceil() as u64;
+    let rows = (rect.height() / cell_size).ceil() as u64;
+    let mut dark_cells = BezPath::new();
+    for row in 0..rows {
+        let y0 = rect.y0 + row as f64 * cell_size;
+        // The dark squares are those where the row and column have different parities.
+        for column in (1 - row % 2..columns).step_by(2) {
+            let x0 = rect.x0 + column as f64 * cell_size;
+            let cell = Rect::new(
+                x0,
+                y0,
+                (x0 + cell_size).min(rect.x1),
+                (y0 + cell_size).min(rect.y1),
+            );
+            dark_cells.extend(cell.path_elements(0.1));
+        }
+    }
+    scene.fill(Fill::NonZero, Affine::IDENTITY, dark, None, &dark_cells);
+}
+
+/// Adapts the render scale and anti-aliasing method to keep frames within a time budget.
+///
+/// Pass the time taken by each frame to [`FramePacer::update`], such as from
+/// [`Renderer::last_frame_gpu_time`](crate::Renderer::last_frame_gpu_time), then render the
+/// next frame using [`FramePacer::antialiasing_method`] and the
+/// [`render_scale`](FramePacer::render_scale), using
+/// [`RenderContext::set_render_scale`].
+///
+/// The control loop works as follows:
+///
+/// 1. The frame times are smoothed with an exponential moving average, so that a single slow
+///    frame doesn't change the quality.
+/// 2. If the average is over the target frame time, quality is lowered. The anti-aliasing
+///    method is lowered first, from 16x to 8x multisampling and then to area anti-aliasing,
+///    as multisampling can be expensive with many overlapping layers and blurs. Once the
+///    cheapest method is in use, the render scale is reduced in proportion to the excess
+///    time, down to the minimum render scale.
+/// 3. If the average is below 70% of the target, quality is raised in the opposite order:
+///    the render scale is increased in steps of 0.1 up to full resolution, and then the
+///    anti-aliasing method is raised.
+/// 4. After each change, the average is discarded and the next few frames are ignored, as
+///    frame times lag behind changes in quality.
+///
+/// The gap between the thresholds for lowering and raising quality stops the quality from
+/// oscillating when the frame time is close to the target.
+#[derive(Clone, Debug)]
+pub struct FramePacer {
+    target: Duration,
+    /// The supported anti-aliasing methods, from the most to the least expensive.
+    antialiasing: Vec<AaConfig>,
+    antialiasing_index: usize,
+    render_scale: f32,
+    min_render_scale: f32,
+    average: Option<f64>,
+    settle_frames: u32,
+}
+
+impl FramePacer {
+    /// The weight of each frame in the moving average of frame times.
+    const SMOOTHING: f64 = 0.25;
+    /// The fraction of the target frame time below which quality is raised.
+    const HEADROOM: f64 = 0.7;
+    /// The number of frames which are ignored after the quality changes.
+    const SETTLE_FRAMES: u32 = 8;
+    /// The amount the render scale is increased by when quality is raised.
+    const SCALE_STEP: f32 = 0.1;
+
+    /// Creates a pacer with the target frame time `target`, such as 16.6ms for 60 frames per
+    /// second.
+    ///
+    /// It starts at full resolution, with the most expensive anti-aliasing method in `support`.
+    pub fn new(target: Duration, support: AaSupport) -> Self {
+        let antialiasing: Vec<AaConfig> = [
+            (support.msaa16, AaConfig::Msaa16),
+            (support.msaa8, AaConfig::Msaa8),
+            (support.area, AaConfig::Area),
+        ]
+        .into_iter()
+        .filter_map(|(supported, method)| supported.then_some(method))
+        .collect();
+        Self {
+            target,
+            antialiasing: if antialiasing.is_empty() {
+                vec![AaConfig::Area]
+            } else {
+                antialiasing
+            },
+            antialiasing_index: 0,
+            render_scale: 1.0,
+            min_render_scale: 0.5,
+            average: None,
+            settle_frames: 0,
+        }
+    }
+
+    /// Sets the lowest render scale which the pacer reduces the resolution to, which is 0.5
+    /// by default.
+    ///
+    /// This is clamped to the range supported by [`RenderContext::set_render_scale`].
+    pub fn with_min_render_scale(mut self, min_render_scale: f32) -> Self {
+        self.min_render_scale = if min_render_scale.is_nan() {
+            1.0
+        } else {
+            min_render_scale.clamp(0.1, 1.0)
+        };
+        self
+    }
+
+    /// The target frame time.
+    pub fn target(&self) -> Duration {
+        self.target
+    }
+
+    /// The anti-aliasing method to render the next frame with.
+    pub fn antialiasing_method(&self) -> AaConfig {
+        self.antialiasing[self.antialiasing_index]
+    }
+
+    /// The render scale to render the next frame at.
+    pub fn render_scale(&self) -> f32 {
+        self.render_scale
+    }
+
+    /// Records the time taken by a frame, and adapts the quality of the following frames.
+    ///
+    /// Returns `true` if the anti-aliasing method or render scale changed.
+    pub fn update(&mut self, frame_time: Duration) -> bool {
+        if self.settle_frames > 0 {
+            self.settle_frames -= 1;
+            return false;
+        }
+        let frame_time = frame_time.as_secs_f64();
+        let average = match self.average {
+            Some(average) => average + Self::SMOOTHING * (frame_time - average),
+            None => frame_time,
+        };
+        self.average = Some(average);
+        let target = self.target.as_secs_f64();
+
+        let changed = if average > target {
+            if self.antialiasing_index + 1 < self.antialiasing.len() {
+                self.antialiasing_index += 1;
+                true
+            } else if self.render_scale > self.min_render_scale {
+                // The cost of rendering is roughly proportional to the number of pixels,
+                // which is the square of the render scale.
+                let scale = self.render_scale * (target / average).sqrt() as f32;
+                self.render_scale = scale
+                    .min(self.render_scale - 0.05)
+                    .max(self.min_render_scale);
+                true
+            } else {
+                false
+            }
+        } else if average < target * Self::HEADROOM {
+            if self.render_scale < 1.0 {
+                self.render_scale = (self.render_scale + Self::SCALE_STEP).min(1.0);
+                true
+            } else if self.antialiasing_index > 0 {
+                self.antialiasing_index -= 1;
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+        if changed {
+            self.average = None;
+            self.settle_frames = Self::SETTLE_FRAMES;
+        }
+        changed
+    }
+}
+
+/// Coalesces redraw requests and limits how often they are made, for applications which only
+/// render when their content changes.
+///
+/// Call [`RedrawScheduler::request`] whenever the content changes, instead of requesting a
+/// redraw from the windowing system directly. Any number of requests made before the next
+/// redraw are coalesced into one. Then, once the pending events have been handled, call
+/// [`RedrawScheduler::poll`], which returns `true` when a redraw should be made, and use
+/// [`RedrawScheduler::next_redraw`] to sleep until the next redraw is due. With winit, this
+/// is done in `ApplicationHandler::about_to_wait`:
+///
+/// ```ignore
+/// fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+///     let now = Instant::now();
+///     if self.redraws.poll(now) {
+///         self.window.request_redraw();
+///     }
+///     match self.redraws.next_redraw(now) {
+///         Some(deadline) => event_loop.set_control_flow(ControlFlow::WaitUntil(deadline)),
+///         None => event_loop.set_control_flow(ControlFlow::Wait),
+///     }
+/// }
+/// ```
+///
+/// Redraws are made at most once per [minimum interval](RedrawScheduler::min_interval), so
+/// a burst of changes, such as from mouse movement, doesn't render faster than the frame
+/// limit. When nothing changes, no redraws are requested, so identical frames are never
+/// rendered and the application can sleep, which saves battery compared to rendering
+/// continuously.
+#[derive(Clone, Debug)]
+pub struct RedrawScheduler {
+    min_interval: Duration,
+    pending: bool,
+    last_redraw: Option<Instant>,
+}
+
+impl RedrawScheduler {
+    /// Creates a scheduler which redraws at most `max_fps` times per second.
+    ///
+    /// A `max_fps` which isn't finite and positive doesn't limit the rate of redraws. A `max_fps`
+    /// so small that its interval can't be represented by a [`Duration`] never redraws after
+    /// the first redraw.
+    pub fn new(max_fps: f64) -> Self {
+        let min_interval = if max_fps.is_finite() && max_fps > 0.0 {
+            Duration::try_from_secs_f64(1.0 / max_fps).unwrap_or(Duration::MAX)
+        } else {
+            Duration::ZERO
+        };
+        Self::with_min_interval(min_interval)
+    }
+
+    /// Creates a scheduler which leaves at least `min_interval` between redraws.
+    pub fn with_min_interval(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            pending: false,
+            last_redraw: None,
+        }
+    }
+
+    /// The shortest time between two redraws.
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Records that the content has changed, so a redraw is needed.
+    pub fn request(&mut self) {
+        self.pending = true;
+    }
+
+    /// Whether a redraw has been requested, but not yet made.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Returns `true` if a redraw should be made at `now`, which is when one has been requested
+    /// and the minimum interval has passed since the last redraw.
+    ///
+    /// When this returns `true`, the request is cleared and `now` is recorded as the time of
+    /// the last redraw.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        if self.next_redraw(now).is_none_or(|due| due > now) {
+            return false;
+        }
+        self.pending = false;
+        self.last_redraw = Some(now);
+        true
+    }
+
+    /// The time at which the pending redraw is due, or `None` if no redraw has been requested.
+    ///
+    /// This is never earlier than `now`. If the minimum interval is so long that the time
+    /// can't be represented by an [`Instant`], the redraw is never due, and `None` is returned.
+    pub fn next_redraw(&self, now: Instant) -> Option<Instant> {
+        if !self.pending {
+            return None;
+        }
+        let due = match self.last_redraw {
+            Some(last_redraw) => last_redraw.checked_add(self.min_interval)?,
+            None => now,
+        };
+        Some(due.max(now))
+    }
+}
+
+/// Loads the pipeline cache for the given device from `directory`, or creates an empty cache if
+/// there isn't one yet.
+///
+/// The cache file is named using [`wgpu::util::pipeline_cache_key`], so caches from a different
+/// adapter or driver version are never loaded. If the cache file is stale or invalid,
+/// an empty cache is created instead, so the pipelines will be recompiled.
+/// Once the renderer has been created, it should be written back using
+/// [`Renderer::save_pipeline_cache`](crate::Renderer::save_pipeline_cache) with the returned path.
+///
+/// Returns `Ok(None)` if the device doesn't support pipeline caches.
+///
+/// # Safety
+///
+/// The directory should only have been written into by
+/// [`Renderer::save_pipeline_cache`](crate::Renderer::save_pipeline_cache) or
+/// [`write_pipeline_cache`].
+/// See [`Device::create_pipeline_cache`] for more details.
+pub unsafe fn load_pipeline_cache(
+    device: &Device,
+    adapter_info: &AdapterInfo,
+    directory: &Path,
+) -> Result<Option<(PipelineCache, PathBuf)>> {
+    if !device.features().contains(wgpu::Features::PIPELINE_CACHE) {
+        return Ok(None);
+    }
+    let Some(cache_key) = wgpu::util::pipeline_cache_key(adapter_info) else {
+        return Ok(None);
+    };
+    let cache_file = directory.join(cache_key);
+    let data = match std::fs::read(&cache_file) {
+        Ok(data) => Some(data),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(Error::PipelineCacheIo(e)),
+    };
+    let cache = unsafe {
+        // Safety: Upheld by the caller.
+        device.create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+            label: Some("vello.pipeline_cache"),
+            data: data.as_deref(),
+            // Recompile with an empty cache if the data is from an incompatible version.
+            fallback: true,
+        })
+    };
+    Ok(Some((cache, cache_file)))
+}
+
+/// Writes the data from `cache` to `path`, replacing any existing cache file.
+///
+/// Returns `Ok(false)` if the backend can't provide the cache's data.
+pub fn write_pipeline_cache(path: &Path, cache: &PipelineCache) -> Result<bool> {
+    let Some(data) = cache.get_data() else {
+        return Ok(false);
+    };
+    // Write to a temporary file first, so that a partially written cache is never loaded.
+    let new_path = path.with_extension("new");
+    std::fs::write(&new_path, data).map_err(Error::PipelineCacheIo)?;
+    if let Err(e) = std::fs::rename(&new_path, path) {
+        drop(std::fs::remove_file(&new_path));
+        return Err(Error::PipelineCacheIo(e));
+    }
+    Ok(true)
+}
+
 struct NullWake;
 
 impl std::task::Wake for NullWake {
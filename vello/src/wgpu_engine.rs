@@ -14,6 +14,7 @@ use wgpu::{
 
 use crate::{
     Error, Result,
+    gpu_timer::GpuTimer,
     low_level::{BufferProxy, Command, ImageProxy, Recording, ResourceId, ResourceProxy, ShaderId},
     recording::BindType,
 };
@@ -41,6 +42,8 @@ pub(crate) struct WgpuEngine {
     /// The `Texture` should have the same size as the `Image`.
     pub(crate) image_overrides: HashMap<u64, wgpu::TexelCopyTextureInfoBase<Texture>>,
     pipeline_cache: Option<PipelineCache>,
+    /// Times each recording, if the device supports timestamp queries.
+    pub(crate) gpu_timer: Option<GpuTimer>,
 }
 
 enum PipelineState {
@@ -160,14 +163,37 @@ impl WgpuEngine {
 
     #[cfg(not(target_arch = "wasm32"))]
     /// Initialise (in parallel) any shaders which are yet to be created
+    ///
+    /// `progress` is called with the label of each shader once it has been created,
+    /// alongside the number of shaders created so far and the total number to create.
     pub fn build_shaders_if_needed(
         &mut self,
         device: &Device,
         num_threads: Option<std::num::NonZeroUsize>,
+        progress: &mut dyn FnMut(&str, usize, usize),
     ) {
         use std::num::NonZeroUsize;
 
         if let Some(mut new_shaders) = self.shaders_to_initialise.take() {
+            let total = new_shaders.len();
+            let mut done = 0;
+            if num_threads == NonZeroUsize::new(1) {
+                // Initialisation was only deferred so that progress could be reported,
+                // so create the shaders on this thread.
+                for it in new_shaders {
+                    let shader = Self::create_compute_pipeline(
+                        device,
+                        it.label,
+                        it.wgsl,
+                        it.entries,
+                        self.pipeline_cache.as_ref(),
+                    );
+                    self.shaders[it.shader_id.0].wgpu = Some(shader);
+                    done += 1;
+                    progress(it.label, done, total);
+                }
+                return;
+            }
             let num_threads = num_threads
                 .map(NonZeroUsize::get)
                 .unwrap_or_else(|| {
@@ -233,11 +259,19 @@ impl WgpuEngine {
 
                 while let Ok((id, value)) = rx.recv() {
                     self.shaders[id.0].wgpu = Some(value);
+                    done += 1;
+                    progress(self.shaders[id.0].label, done, total);
                 }
             });
         }
     }
 
+    /// Returns the label of a shader, and whether it is run on the CPU.
+    pub(crate) fn shader_info(&self, shader_id: ShaderId) -> (&'static str, bool) {
+        let shader = &self.shaders[shader_id.0];
+        (shader.label, shader.cpu.is_some())
+    }
+
     /// Add a shader.
     ///
     /// This function is somewhat limited, it doesn't apply a label, only allows one bind group,
@@ -394,6 +428,10 @@ impl WgpuEngine {
             device.create_command_encoder(&CommandEncoderDescriptor { label: Some(label) });
         #[cfg(feature = "wgpu-profiler")]
         let query = profiler.begin_query(label, &mut encoder);
+        let timed = self
+            .gpu_timer
+            .as_mut()
+            .is_some_and(|timer| timer.begin(&mut encoder));
         for command in &recording.commands {
             match command {
                 Command::Upload(buf_proxy, bytes) => {
@@ -746,6 +784,9 @@ impl WgpuEngine {
                 Command::FreeImage(proxy) => {
                     free_images.insert(proxy.id);
                 }
+                Command::PushDebugGroup(label) => encoder.push_debug_group(label),
+                Command::PopDebugGroup => encoder.pop_debug_group(),
+                Command::InsertDebugMarker(label) => encoder.insert_debug_marker(label),
             }
         }
         #[cfg(feature = "wgpu-profiler")]
@@ -753,7 +794,13 @@ impl WgpuEngine {
         // TODO: This only actually needs to happen once per frame, but run_recording happens two or three times
         #[cfg(feature = "wgpu-profiler")]
         profiler.resolve_queries(&mut encoder);
+        if timed && let Some(timer) = &mut self.gpu_timer {
+            timer.end(&mut encoder, queue);
+        }
         queue.submit(Some(encoder.finish()));
+        if timed && let Some(timer) = &mut self.gpu_timer {
+            timer.map();
+        }
         for id in free_bufs {
             if let Some(buf) = self.bind_map.buf_map.remove(&id)
                 && let MaterializedBuffer::Gpu(gpu_buf) = buf.buffer
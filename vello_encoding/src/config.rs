@@ -9,8 +9,17 @@ use super::{
 };
 use bytemuck::{Pod, Zeroable};
 
-const TILE_WIDTH: u32 = 16;
-const TILE_HEIGHT: u32 = 16;
+/// The width of the tiles used by the coarse and fine rasterization stages, in pixels.
+///
+/// This must be kept in sync with `TILE_WIDTH` in `shader/shared/config.wgsl`.
+pub const TILE_WIDTH: u32 = 16;
+/// The height of the tiles used by the coarse and fine rasterization stages, in pixels.
+///
+/// This must be kept in sync with `TILE_HEIGHT` in `shader/shared/config.wgsl`.
+pub const TILE_HEIGHT: u32 = 16;
+/// The default maximum distance, in pixels, between a curve and the lines which it is
+/// flattened to by the GPU.
+pub const DEFAULT_FLATTEN_TOLERANCE: f32 = 0.25;
 
 // TODO: Obtain these from the vello_shaders crate
 pub(crate) const PATH_REDUCE_WG: u32 = 256;
@@ -151,6 +160,27 @@ pub struct ConfigUniform {
     pub blend_size: u32,
     /// Size of per-tile command list buffer allocation (in `u32`s).
     pub ptcl_size: u32,
+    /// Gamma applied to the coverage of glyph outlines. A value of 1.0 leaves
+    /// the coverage unchanged.
+    pub text_gamma: f32,
+    /// Non-zero if the alpha of blurred rounded rectangles should be converted to
+    /// sample coverage when using MSAA.
+    pub alpha_to_coverage: u32,
+    /// The debug visualization which is output instead of the scene, where `0` is none.
+    ///
+    /// See `vello::DebugView` for the other values.
+    pub debug_view: u32,
+    /// Non-zero if the output should be written with premultiplied alpha.
+    pub premultiplied_output: u32,
+    /// The maximum distance, in pixels, between a curve and the lines which it is
+    /// flattened to.
+    pub flatten_tolerance: f32,
+    /// The horizontal position, in pixels, of the region of the output image which is
+    /// rendered into.
+    pub target_offset_x: u32,
+    /// The vertical position, in pixels, of the region of the output image which is
+    /// rendered into.
+    pub target_offset_y: u32,
 }
 
 /// CPU side setup and configuration.
@@ -188,6 +218,13 @@ impl RenderConfig {
                 segments_size: buffer_sizes.segments.len(),
                 blend_size: buffer_sizes.blend_spill.len(),
                 ptcl_size: buffer_sizes.ptcl.len(),
+                text_gamma: 1.0,
+                alpha_to_coverage: 0,
+                debug_view: 0,
+                premultiplied_output: 0,
+                flatten_tolerance: DEFAULT_FLATTEN_TOLERANCE,
+                target_offset_x: 0,
+                target_offset_y: 0,
                 layout: *layout,
             },
             workgroup_counts,
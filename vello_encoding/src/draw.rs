@@ -48,6 +48,36 @@ impl DrawTag {
     pub const fn info_size(self) -> u32 {
         (self.0 >> 6) & 0xf
     }
+
+    /// Returns the size of the draw data (in u32s) used by this tag.
+    pub const fn scene_size(self) -> u32 {
+        (self.0 >> 2) & 0x7
+    }
+
+    /// Returns the name of this tag, or `None` if it isn't a known tag.
+    pub const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NOP => "NOP",
+            Self::COLOR => "COLOR",
+            Self::LINEAR_GRADIENT => "LINEAR_GRADIENT",
+            Self::RADIAL_GRADIENT => "RADIAL_GRADIENT",
+            Self::SWEEP_GRADIENT => "SWEEP_GRADIENT",
+            Self::IMAGE => "IMAGE",
+            Self::BLUR_RECT => "BLUR_RECT",
+            Self::BEGIN_CLIP => "BEGIN_CLIP",
+            Self::END_CLIP => "END_CLIP",
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Debug for DrawTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "DrawTag::{name}"),
+            None => write!(f, "DrawTag({:#x})", self.0),
+        }
+    }
 }
 
 /// The first word of each draw info stream entry contains the flags.
@@ -57,6 +87,18 @@ impl DrawTag {
 /// `1` represents an even-odd fill.
 pub const DRAW_INFO_FLAGS_FILL_RULE_BIT: u32 = 1;
 
+/// Set in the draw info flags if the path is a glyph outline, in which case
+/// the text gamma is applied to its coverage.
+pub const DRAW_INFO_FLAGS_GLYPH_BIT: u32 = 2;
+
+/// Set in the draw info flags if the path isn't anti-aliased, in which case
+/// its coverage is rounded to 0 or 1.
+pub const DRAW_INFO_FLAGS_ALIASED_BIT: u32 = 4;
+
+/// Set in the draw info flags if the path clears the content under it, in
+/// which case its brush isn't painted.
+pub const DRAW_INFO_FLAGS_CLEAR_BIT: u32 = 8;
+
 /// Draw object bounding box.
 #[derive(Copy, Clone, Pod, Zeroable, Debug, Default)]
 #[repr(C)]
@@ -164,11 +206,26 @@ pub struct DrawImage {
     pub xy: u32,
     /// Packed image dimensions.
     pub width_height: u32,
-    /// Packed quality, extend mode and 8-bit alpha (bits `qqxxyyaaaaaaaa`,
-    /// 18 unused prefix bits).
+    /// Packed color space, format, alpha type, quality, extend mode and 8-bit alpha
+    /// (bits `cftqqxxyyaaaaaaaa`, 15 unused prefix bits).
     pub sample_alpha: u32,
 }
 
+/// The color space which the color channels of an image are encoded in.
+///
+/// Vello composites in the sRGB color space, with gamma-encoded channels, like most
+/// 2D renderers and the colors of [`peniko::Color`]. Images are sampled as sRGB by default.
+/// Images with linear color channels, such as the output of a 3D renderer, are converted
+/// to sRGB as they are sampled, after filtering, so that they aren't composited too dark.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImageColorSpace {
+    /// The color channels are gamma-encoded sRGB, as in most image files.
+    #[default]
+    Srgb = 0,
+    /// The color channels are linear, with the sRGB primaries.
+    LinearSrgb = 1,
+}
+
 /// Draw data for a blurred rounded rectangle.
 #[derive(Clone, Copy, Debug, Default, Zeroable, Pod)]
 #[repr(C)]
@@ -209,6 +266,14 @@ impl DrawBeginClip {
     /// for legacy reasons.
     /// We expect this to change in the future.
     pub const CLIP_BLEND_MODE: u32 = 0x8003;
+    /// The shift of the color matrix index in the resolved `blend_mode`.
+    ///
+    /// When an encoding is resolved, the bits of `blend_mode` above this shift are set to
+    /// one more than the index of the layer's color matrix in the packed scene, or left as
+    /// zero if the layer has no color matrix.
+    pub const COLOR_MATRIX_SHIFT: u32 = 17;
+    /// The maximum number of layers with a color matrix which can be rendered in one scene.
+    pub const MAX_COLOR_MATRICES: usize = (1 << (32 - Self::COLOR_MATRIX_SHIFT)) - 1;
 
     /// Creates new clip draw data for a Porter-Duff blend mode.
     pub fn new(blend_mode: BlendMode, alpha: f32) -> Self {
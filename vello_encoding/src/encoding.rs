@@ -5,19 +5,23 @@ use crate::DrawBeginClip;
 
 use super::{
     DrawBlurRoundedRect, DrawColor, DrawImage, DrawLinearGradient, DrawRadialGradient,
-    DrawSweepGradient, DrawTag, Glyph, GlyphRun, NormalizedCoord, Patch, PathEncoder, PathTag,
-    Style, Transform,
+    DrawSweepGradient, DrawTag, Glyph, GlyphRun, ImageColorSpace, NormalizedCoord, Patch,
+    PathEncoder, PathTag, Style, Transform,
 };
 
 use peniko::color::{DynamicColor, palette};
-use peniko::kurbo::{Shape, Stroke};
+use peniko::kurbo::{Rect, Shape, Stroke};
 use peniko::{
     BrushRef, ColorStop, Extend, Fill, GradientKind, ImageBrushRef, ImageSampler,
-    LinearGradientPosition, RadialGradientPosition, SweepGradientPosition,
+    InterpolationAlphaSpace, LinearGradientPosition, RadialGradientPosition, SweepGradientPosition,
 };
 
 /// Encoded data streams for a scene.
 ///
+/// The streams are uploaded to the GPU unchanged (after resolving late bound
+/// resources), so they can be read by tools which want to inspect a scene.
+/// [`Encoding::draw_objects`] and [`Encoding::dump_layout`] help with decoding them.
+///
 /// # Invariants
 ///
 /// * At least one transform and style must be encoded before any path data
@@ -25,18 +29,32 @@ use peniko::{
 #[derive(Clone, Default)]
 pub struct Encoding {
     /// The path tag stream.
+    ///
+    /// Contains one tag per path segment, plus a [`PathTag::TRANSFORM`] or
+    /// [`PathTag::STYLE`] marker each time the current transform or style changes,
+    /// and a [`PathTag::PATH`] marker at the end of each path.
     pub path_tags: Vec<PathTag>,
     /// The path data stream.
     /// Stores all coordinates on paths.
     /// Stored as `u32` as all comparisons are performed bitwise.
     pub path_data: Vec<u32>,
     /// The draw tag stream.
+    ///
+    /// Contains one tag per path, describing how that path is drawn.
+    /// Clips and layers are a [`DrawTag::BEGIN_CLIP`] and [`DrawTag::END_CLIP`] pair.
     pub draw_tags: Vec<DrawTag>,
     /// The draw data stream.
+    ///
+    /// Each draw tag uses [`DrawTag::scene_size`] consecutive values, in the order of
+    /// `draw_tags`. For example, a [`DrawTag::COLOR`] is followed by a [`DrawColor`].
     pub draw_data: Vec<u32>,
     /// The transform stream.
+    ///
+    /// Each [`PathTag::TRANSFORM`] marker selects the next transform.
     pub transforms: Vec<Transform>,
-    /// The style stream
+    /// The style stream.
+    ///
+    /// Each [`PathTag::STYLE`] marker selects the next style.
     pub styles: Vec<Style>,
     /// Late bound resource data.
     pub resources: Resources,
@@ -48,6 +66,10 @@ pub struct Encoding {
     pub n_clips: u32,
     /// Number of unclosed clips/layers.
     pub n_open_clips: u32,
+    /// Number of end clips which were ignored as there was no open clip to end.
+    ///
+    /// These are reported by [`Encoding::validate`].
+    pub n_unmatched_end_clips: u32,
     /// Flags that capture the current state of the encoding.
     pub flags: u32,
 }
@@ -61,6 +83,11 @@ impl Encoding {
     /// the current style in the stream.
     pub const FORCE_NEXT_STYLE: u32 = 2;
 
+    /// Disables anti-aliasing for the styles which are encoded while this is set.
+    ///
+    /// Unlike the other flags, this is kept until it is cleared or the encoding is reset.
+    pub const ALIASED: u32 = 4;
+
     /// Creates a new encoding.
     pub fn new() -> Self {
         Self::default()
@@ -86,10 +113,24 @@ impl Encoding {
         self.n_path_segments = 0;
         self.n_clips = 0;
         self.n_open_clips = 0;
+        self.n_unmatched_end_clips = 0;
         self.flags = 0;
         self.resources.reset();
     }
 
+    /// Releases any capacity of the encoded streams which isn't in use.
+    ///
+    /// Calling this after [`Encoding::reset`] frees the memory used by the streams.
+    pub fn shrink_to_fit(&mut self) {
+        self.transforms.shrink_to_fit();
+        self.path_tags.shrink_to_fit();
+        self.path_data.shrink_to_fit();
+        self.styles.shrink_to_fit();
+        self.draw_data.shrink_to_fit();
+        self.draw_tags.shrink_to_fit();
+        self.resources.shrink_to_fit();
+    }
+
     /// Appends another encoding to this one with an optional transform.
     pub fn append(&mut self, other: &Self, transform: &Option<Transform>) {
         let glyph_runs_base = {
@@ -126,12 +167,14 @@ impl Encoding {
                         draw_data_offset: offset,
                         stops,
                         extend,
+                        interpolation_alpha_space,
                     } => {
                         let stops = stops.start + stops_base..stops.end + stops_base;
                         Patch::Ramp {
                             draw_data_offset: offset + offsets.draw_data,
                             stops,
                             extend: *extend,
+                            interpolation_alpha_space: *interpolation_alpha_space,
                         }
                     }
                     Patch::GlyphRun { index } => Patch::GlyphRun {
@@ -144,6 +187,13 @@ impl Encoding {
                         image: image.clone(),
                         draw_data_offset: *draw_data_offset + offsets.draw_data,
                     },
+                    Patch::ColorMatrix {
+                        draw_data_offset,
+                        matrix,
+                    } => Patch::ColorMatrix {
+                        draw_data_offset: *draw_data_offset + offsets.draw_data,
+                        matrix: *matrix,
+                    },
                 }));
             self.resources
                 .color_stops
@@ -158,7 +208,10 @@ impl Encoding {
         self.n_path_segments += other.n_path_segments;
         self.n_clips += other.n_clips;
         self.n_open_clips += other.n_open_clips;
-        self.flags = other.flags;
+        self.n_unmatched_end_clips += other.n_unmatched_end_clips;
+        // Whether anti-aliasing is disabled is a setting of this encoding, rather than state
+        // which follows the appended data.
+        self.flags = (other.flags & !Self::ALIASED) | (self.flags & Self::ALIASED);
         if let Some(transform) = *transform {
             self.transforms
                 .extend(other.transforms.iter().map(|x| transform * *x));
@@ -183,11 +236,257 @@ impl Encoding {
         }
     }
 
+    /// Returns an iterator over the draw objects, yielding each draw tag with its draw data.
+    pub fn draw_objects(&self) -> impl Iterator<Item = (DrawTag, &[u32])> + '_ {
+        let mut offset = 0;
+        self.draw_tags.iter().map(move |&tag| {
+            let size = tag.scene_size() as usize;
+            let data = self
+                .draw_data
+                .get(offset..offset + size)
+                .unwrap_or_default();
+            offset += size;
+            (tag, data)
+        })
+    }
+
+    /// Returns a human readable description of the path and draw streams.
+    ///
+    /// This lists each path with the number of segments it contains, and each draw object
+    /// with its draw data, indented by the clip depth. It is intended for debugging, and the
+    /// exact format may change between releases.
+    pub fn dump_layout(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} paths, {} segments, {} transforms, {} styles, {} clips",
+            self.n_paths,
+            self.n_path_segments,
+            self.transforms.len(),
+            self.styles.len(),
+            self.n_clips,
+        );
+        out.push_str("paths:\n");
+        let (mut path_ix, mut segments, mut transform_ix, mut style_ix) = (0, 0, 0_usize, 0_usize);
+        for tag in &self.path_tags {
+            if *tag == PathTag::TRANSFORM {
+                transform_ix += 1;
+            } else if *tag == PathTag::STYLE {
+                style_ix += 1;
+            } else if *tag == PathTag::PATH {
+                let _ = writeln!(
+                    out,
+                    "  {path_ix}: {segments} segments, transform {}, style {}",
+                    transform_ix.saturating_sub(1),
+                    style_ix.saturating_sub(1),
+                );
+                path_ix += 1;
+                segments = 0;
+            } else if tag.is_path_segment() {
+                segments += 1;
+            }
+        }
+        out.push_str("draws:\n");
+        let mut depth = 0_usize;
+        for (ix, (tag, data)) in self.draw_objects().enumerate() {
+            if tag == DrawTag::END_CLIP {
+                depth = depth.saturating_sub(1);
+            }
+            let _ = writeln!(
+                out,
+                "  {ix}: {:indent$}{tag:?} {data:x?}",
+                "",
+                indent = depth * 2
+            );
+            if tag == DrawTag::BEGIN_CLIP {
+                depth += 1;
+            }
+        }
+        out
+    }
+
+    /// Checks the invariants of the encoding, returning a description of the first problem
+    /// found.
+    ///
+    /// This checks that clips and layers are balanced, that the counts of paths, segments and
+    /// clips match the streams, that each transform, style and draw object has its data, and
+    /// that the late bound resources refer to data which exists. Encodings created through
+    /// the encoding methods always pass, except that clips can be left open or ended too many
+    /// times, which is otherwise handled by closing the open clips and ignoring the extra
+    /// ends. This is intended for debugging, as it takes time proportional to the size of the
+    /// encoding.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.n_open_clips != 0 {
+            return Err(format!(
+                "{} clips or layers were begun but not ended",
+                self.n_open_clips
+            ));
+        }
+        if self.n_unmatched_end_clips != 0 {
+            return Err(format!(
+                "{} clips or layers were ended without being begun",
+                self.n_unmatched_end_clips
+            ));
+        }
+        let (mut n_paths, mut n_segments, mut n_transforms, mut n_styles) = (0_usize, 0, 0, 0);
+        let mut path_data_size = 0;
+        for tag in &self.path_tags {
+            if *tag == PathTag::TRANSFORM {
+                n_transforms += 1;
+            } else if *tag == PathTag::STYLE {
+                n_styles += 1;
+            } else if *tag == PathTag::PATH {
+                n_paths += 1;
+            } else if tag.is_path_segment() {
+                n_segments += 1;
+                // Each segment adds its points after the first, which is the end of the
+                // previous segment or the initial move. Points are two values as `f32`, or
+                // one as packed `i16`.
+                let values_per_point = if tag.is_f32() { 2 } else { 1 };
+                path_data_size += tag.path_segment_type().0 as usize * values_per_point;
+            }
+        }
+        if n_paths != self.n_paths as usize {
+            return Err(format!(
+                "{n_paths} paths were encoded, but the count of paths is {}",
+                self.n_paths
+            ));
+        }
+        if n_segments != self.n_path_segments as usize {
+            return Err(format!(
+                "{n_segments} path segments were encoded, but the count of segments is {}",
+                self.n_path_segments
+            ));
+        }
+        if n_transforms != self.transforms.len() {
+            return Err(format!(
+                "{n_transforms} transforms were selected, but {} were encoded",
+                self.transforms.len()
+            ));
+        }
+        if n_styles != self.styles.len() {
+            return Err(format!(
+                "{n_styles} styles were selected, but {} were encoded",
+                self.styles.len()
+            ));
+        }
+        if self.path_data.len() < path_data_size {
+            return Err(format!(
+                "the path segments need at least {path_data_size} values of path data, but there are {}",
+                self.path_data.len()
+            ));
+        }
+        let mut draw_data_size = 0;
+        let (mut n_clips, mut depth) = (0_usize, 0_usize);
+        for (ix, tag) in self.draw_tags.iter().enumerate() {
+            draw_data_size += tag.scene_size() as usize;
+            if *tag == DrawTag::BEGIN_CLIP {
+                n_clips += 1;
+                depth += 1;
+            } else if *tag == DrawTag::END_CLIP {
+                n_clips += 1;
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    format!("draw object {ix} ends a clip, but there is no clip to end")
+                })?;
+            }
+        }
+        if depth != 0 {
+            return Err(format!("{depth} clips in the draw stream are never ended"));
+        }
+        if n_clips != self.n_clips as usize {
+            return Err(format!(
+                "{n_clips} clip draw objects were encoded, but the count of clips is {}",
+                self.n_clips
+            ));
+        }
+        if draw_data_size != self.draw_data.len() {
+            return Err(format!(
+                "the draw objects need {draw_data_size} values of draw data, but there are {}",
+                self.draw_data.len()
+            ));
+        }
+        let resources = &self.resources;
+        for patch in &resources.patches {
+            match patch {
+                Patch::Ramp {
+                    draw_data_offset,
+                    stops,
+                    ..
+                } => {
+                    if *draw_data_offset >= self.draw_data.len() {
+                        return Err(format!(
+                            "a gradient is at draw data offset {draw_data_offset}, which is out of bounds"
+                        ));
+                    }
+                    if stops.end > resources.color_stops.len() {
+                        return Err(format!(
+                            "a gradient uses color stops {stops:?}, but there are {} stops",
+                            resources.color_stops.len()
+                        ));
+                    }
+                }
+                Patch::Image {
+                    draw_data_offset, ..
+                } => {
+                    if *draw_data_offset >= self.draw_data.len() {
+                        return Err(format!(
+                            "an image is at draw data offset {draw_data_offset}, which is out of bounds"
+                        ));
+                    }
+                }
+                Patch::ColorMatrix {
+                    draw_data_offset, ..
+                } => {
+                    if *draw_data_offset >= self.draw_data.len() {
+                        return Err(format!(
+                            "a color matrix is at draw data offset {draw_data_offset}, which is out of bounds"
+                        ));
+                    }
+                }
+                Patch::GlyphRun { index } => {
+                    let Some(run) = resources.glyph_runs.get(*index) else {
+                        return Err(format!(
+                            "glyph run {index} is drawn, but there are {} glyph runs",
+                            resources.glyph_runs.len()
+                        ));
+                    };
+                    if run.glyphs.end > resources.glyphs.len() {
+                        return Err(format!(
+                            "glyph run {index} uses glyphs {:?}, but there are {} glyphs",
+                            run.glyphs,
+                            resources.glyphs.len()
+                        ));
+                    }
+                    if run.normalized_coords.end > resources.normalized_coords.len() {
+                        return Err(format!(
+                            "glyph run {index} uses variation coordinates {:?}, but there are {}",
+                            run.normalized_coords,
+                            resources.normalized_coords.len()
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Encodes a fill style.
     pub fn encode_fill_style(&mut self, fill: Fill) {
         self.encode_style(Style::from_fill(fill));
     }
 
+    /// Encodes a fill style for a path which clears the content under it, in proportion to
+    /// its coverage.
+    ///
+    /// The path should be followed by a [color](Self::encode_color), which isn't painted.
+    pub fn encode_clear_style(&mut self, fill: Fill) {
+        let mut style = Style::from_fill(fill);
+        style.flags_and_miter_limit |= Style::FLAGS_CLEAR_BIT;
+        self.encode_style(style);
+    }
+
     /// Encodes a stroke style.
     ///
     /// Returns false if the stroke had zero width and so couldn't be encoded.
@@ -202,7 +501,10 @@ impl Encoding {
         }
     }
 
-    fn encode_style(&mut self, style: Style) {
+    fn encode_style(&mut self, mut style: Style) {
+        if self.flags & Self::ALIASED != 0 {
+            style.flags_and_miter_limit |= Style::FLAGS_ALIASED_BIT;
+        }
         if self.flags & Self::FORCE_NEXT_STYLE != 0 || self.styles.last() != Some(&style) {
             self.path_tags.push(PathTag::STYLE);
             self.styles.push(style);
@@ -247,6 +549,28 @@ impl Encoding {
         encoder.finish(true) != 0
     }
 
+    /// Encodes an axis-aligned rectangle. If `is_fill` is false, the rectangle is encoded as a
+    /// closed subpath for stroking.
+    ///
+    /// This encodes the same path as [`Self::encode_shape`] would for `rect`, but writes the
+    /// four corners directly rather than going through the [`Shape`] path iterator.
+    /// Returns `true` if a non-zero number of segments were encoded.
+    pub fn encode_rect(&mut self, rect: Rect, is_fill: bool) -> bool {
+        let (x0, y0, x1, y1) = (
+            rect.x0 as f32,
+            rect.y0 as f32,
+            rect.x1 as f32,
+            rect.y1 as f32,
+        );
+        let mut encoder = self.encode_path(is_fill);
+        encoder.move_to(x0, y0);
+        encoder.line_to(x1, y0);
+        encoder.line_to(x1, y1);
+        encoder.line_to(x0, y1);
+        encoder.close();
+        encoder.finish(true) != 0
+    }
+
     /// Encode an empty path.
     ///
     /// This is useful for bookkeeping when a path is absolutely required (for example in
@@ -297,6 +621,7 @@ impl Encoding {
                         gradient.stops.iter().copied(),
                         alpha,
                         gradient.extend,
+                        gradient.interpolation_alpha_space,
                     );
                 }
                 GradientKind::Radial(RadialGradientPosition {
@@ -316,6 +641,7 @@ impl Encoding {
                         gradient.stops.iter().copied(),
                         alpha,
                         gradient.extend,
+                        gradient.interpolation_alpha_space,
                     );
                 }
                 GradientKind::Sweep(SweepGradientPosition {
@@ -334,6 +660,7 @@ impl Encoding {
                         gradient.stops.iter().copied(),
                         alpha,
                         gradient.extend,
+                        gradient.interpolation_alpha_space,
                     );
                 }
             },
@@ -358,8 +685,9 @@ impl Encoding {
         color_stops: impl Iterator<Item = ColorStop>,
         alpha: f32,
         extend: Extend,
+        interpolation_alpha_space: InterpolationAlphaSpace,
     ) {
-        match self.add_ramp(color_stops, alpha, extend) {
+        match self.add_ramp(color_stops, alpha, extend, interpolation_alpha_space) {
             RampStops::Empty => self.encode_color(palette::css::TRANSPARENT),
             RampStops::One(color) => {
                 self.encode_color(color);
@@ -379,6 +707,7 @@ impl Encoding {
         color_stops: impl Iterator<Item = ColorStop>,
         alpha: f32,
         extend: Extend,
+        interpolation_alpha_space: InterpolationAlphaSpace,
     ) {
         // Match Skia's epsilon for radii comparison
         const SKIA_EPSILON: f32 = 1.0 / (1 << 12) as f32;
@@ -386,7 +715,7 @@ impl Encoding {
             self.encode_color(palette::css::TRANSPARENT);
             return;
         }
-        match self.add_ramp(color_stops, alpha, extend) {
+        match self.add_ramp(color_stops, alpha, extend, interpolation_alpha_space) {
             RampStops::Empty => self.encode_color(palette::css::TRANSPARENT),
             RampStops::One(color) => self.encode_color(color),
             RampStops::Many => {
@@ -404,13 +733,14 @@ impl Encoding {
         color_stops: impl Iterator<Item = ColorStop>,
         alpha: f32,
         extend: Extend,
+        interpolation_alpha_space: InterpolationAlphaSpace,
     ) {
         const SKIA_DEGENERATE_THRESHOLD: f32 = 1.0 / (1 << 15) as f32;
         if (gradient.t0 - gradient.t1).abs() < SKIA_DEGENERATE_THRESHOLD {
             self.encode_color(palette::css::TRANSPARENT);
             return;
         }
-        match self.add_ramp(color_stops, alpha, extend) {
+        match self.add_ramp(color_stops, alpha, extend, interpolation_alpha_space) {
             RampStops::Empty => self.encode_color(palette::css::TRANSPARENT),
             RampStops::One(color) => self.encode_color(color),
             RampStops::Many => {
@@ -423,7 +753,41 @@ impl Encoding {
 
     /// Encodes an image brush.
     pub fn encode_image<'b>(&mut self, brush: impl Into<ImageBrushRef<'b>>, alpha: f32) {
+        self.encode_image_with_color_space(brush, alpha, ImageColorSpace::Srgb);
+    }
+
+    /// Encodes an image brush, whose color channels are in `color_space`.
+    pub fn encode_image_with_color_space<'b>(
+        &mut self,
+        brush: impl Into<ImageBrushRef<'b>>,
+        alpha: f32,
+        color_space: ImageColorSpace,
+    ) {
         let brush: ImageBrushRef<'b> = brush.into();
+        let sub_rect = [0, 0, brush.image.width, brush.image.height];
+        self.encode_image_sub_rect(brush, alpha, color_space, sub_rect);
+    }
+
+    /// Encodes an image brush which only samples the texels in `sub_rect` of the image, given
+    /// as `[x, y, width, height]`, such as a single entry of a texture atlas.
+    ///
+    /// The brush behaves as if the sub-rectangle was the whole image, with its top left corner
+    /// at the origin. Sampling, including the extend modes and bilinear filtering, is clamped
+    /// to the sub-rectangle, so texels outside of it never bleed in. The sub-rectangle is
+    /// limited to the bounds of the image.
+    pub fn encode_image_sub_rect<'b>(
+        &mut self,
+        brush: impl Into<ImageBrushRef<'b>>,
+        alpha: f32,
+        color_space: ImageColorSpace,
+        sub_rect: [u32; 4],
+    ) {
+        let brush: ImageBrushRef<'b> = brush.into();
+        let [x, y, width, height] = sub_rect;
+        let x = x.min(brush.image.width);
+        let y = y.min(brush.image.height);
+        let width = width.min(brush.image.width - x);
+        let height = height.min(brush.image.height - y);
         let ImageSampler {
             x_extend,
             y_extend,
@@ -441,9 +805,12 @@ impl Encoding {
         self.draw_tags.push(DrawTag::IMAGE);
         self.draw_data
             .extend_from_slice(bytemuck::cast_slice(bytemuck::bytes_of(&DrawImage {
-                xy: 0,
-                width_height: (brush.image.width << 16) | (brush.image.height & 0xFFFF),
-                sample_alpha: ((brush.image.format as u32) << 15
+                // The origin of the sub-rectangle, which is offset by the location of the image
+                // in the atlas when resolved.
+                xy: (x << 16) | (y & 0xFFFF),
+                width_height: (width << 16) | (height & 0xFFFF),
+                sample_alpha: ((color_space as u32) << 16
+                    | (brush.image.format as u32) << 15
                     | (brush.image.alpha_type as u32) << 14
                     | (quality as u32) << 12
                     | ((x_extend as u32) << 10)
@@ -483,6 +850,23 @@ impl Encoding {
         self.n_open_clips += 1;
     }
 
+    /// Encodes a begin clip command for a layer whose content is transformed by a color matrix
+    /// before it is composited.
+    ///
+    /// The matrix is a row-major 4×5 matrix, as for the SVG `feColorMatrix` filter, which is
+    /// applied to the unpremultiplied colors of the layer.
+    pub fn encode_begin_clip_with_color_matrix(
+        &mut self,
+        parameters: DrawBeginClip,
+        matrix: [f32; 20],
+    ) {
+        self.encode_begin_clip(parameters);
+        self.resources.patches.push(Patch::ColorMatrix {
+            draw_data_offset: self.draw_data.len() - 2,
+            matrix,
+        });
+    }
+
     /// Encodes an end clip command.
     pub fn encode_end_clip(&mut self) {
         if self.n_open_clips > 0 {
@@ -492,6 +876,8 @@ impl Encoding {
             self.n_paths += 1;
             self.n_clips += 1;
             self.n_open_clips -= 1;
+        } else {
+            self.n_unmatched_end_clips += 1;
         }
     }
 
@@ -513,6 +899,7 @@ impl Encoding {
         color_stops: impl Iterator<Item = ColorStop>,
         alpha: f32,
         extend: Extend,
+        interpolation_alpha_space: InterpolationAlphaSpace,
     ) -> RampStops {
         let offset = self.draw_data.len();
         let stops_start = self.resources.color_stops.len();
@@ -532,6 +919,7 @@ impl Encoding {
                     draw_data_offset: offset,
                     stops: stops_start..stops_end,
                     extend,
+                    interpolation_alpha_space,
                 });
                 RampStops::Many
             }
@@ -575,6 +963,14 @@ impl Resources {
         self.glyph_runs.clear();
         self.normalized_coords.clear();
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.patches.shrink_to_fit();
+        self.color_stops.shrink_to_fit();
+        self.glyphs.shrink_to_fit();
+        self.glyph_runs.shrink_to_fit();
+        self.normalized_coords.shrink_to_fit();
+    }
 }
 
 /// Snapshot of offsets for encoded streams.
@@ -607,7 +1003,11 @@ impl StreamOffsets {
 
 #[cfg(test)]
 mod tests {
-    use peniko::{Extend, ImageQuality};
+    use peniko::kurbo::Rect;
+    use peniko::{BlendMode, Color, Extend, Fill, ImageQuality};
+
+    use super::Encoding;
+    use crate::{DrawBeginClip, DrawTag, Transform};
 
     #[test]
     fn ensure_image_quality_values() {
@@ -630,4 +1030,49 @@ mod tests {
             Extend::Pad | Extend::Repeat | Extend::Reflect => {}
         }
     }
+
+    #[test]
+    fn draw_objects_split_draw_data() {
+        let mut encoding = Encoding::new();
+        encoding.encode_transform(Transform::IDENTITY);
+        encoding.encode_fill_style(Fill::NonZero);
+        let rect = Rect::new(0., 0., 10., 10.);
+        encoding.encode_shape(&rect, true);
+        encoding.encode_begin_clip(DrawBeginClip::new(BlendMode::default(), 0.5));
+        encoding.encode_shape(&rect, true);
+        encoding.encode_color(Color::WHITE);
+        encoding.encode_shape(&rect, true);
+        encoding.encode_end_clip();
+
+        let objects = encoding.draw_objects().collect::<Vec<_>>();
+        let tags = objects.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            [DrawTag::BEGIN_CLIP, DrawTag::COLOR, DrawTag::END_CLIP]
+        );
+        assert_eq!(objects[0].1.len(), 2);
+        assert_eq!(objects[1].1, [0xffff_ffff]);
+        assert!(objects[2].1.is_empty());
+
+        let layout = encoding.dump_layout();
+        assert!(layout.contains("0: DrawTag::BEGIN_CLIP"), "{layout}");
+        assert!(
+            layout.contains("1:   DrawTag::COLOR [ffffffff]"),
+            "{layout}"
+        );
+        assert!(layout.contains("2: DrawTag::END_CLIP []"), "{layout}");
+        assert!(
+            layout.contains("0: 4 segments, transform 0, style 0"),
+            "{layout}"
+        );
+    }
+
+    #[test]
+    fn tags_have_readable_debug() {
+        assert_eq!(format!("{:?}", DrawTag::BLUR_RECT), "DrawTag::BLUR_RECT");
+        assert_eq!(format!("{:?}", DrawTag(0x3)), "DrawTag(0x3)");
+        let mut tag = crate::PathTag::LINE_TO_F32;
+        tag.set_subpath_end();
+        assert_eq!(format!("{tag:?}"), "PathTag::LINE_TO_F32 | SUBPATH_END_BIT");
+    }
 }
@@ -49,16 +49,19 @@ mod resolve;
 pub use binning::BinHeader;
 pub use clip::{Clip, ClipBbox, ClipBic, ClipElement};
 pub use config::{
-    BufferSize, BufferSizes, BumpAllocatorMemory, BumpAllocators, ConfigUniform, IndirectCount,
-    RenderConfig, WorkgroupCounts, WorkgroupSize,
+    BufferSize, BufferSizes, BumpAllocatorMemory, BumpAllocators, ConfigUniform,
+    DEFAULT_FLATTEN_TOLERANCE, IndirectCount, RenderConfig, TILE_HEIGHT, TILE_WIDTH,
+    WorkgroupCounts, WorkgroupSize,
 };
 pub use draw::{
-    DRAW_INFO_FLAGS_FILL_RULE_BIT, DrawBbox, DrawBeginClip, DrawBlurRoundedRect, DrawColor,
-    DrawImage, DrawLinearGradient, DrawMonoid, DrawRadialGradient, DrawSweepGradient, DrawTag,
+    DRAW_INFO_FLAGS_ALIASED_BIT, DRAW_INFO_FLAGS_CLEAR_BIT, DRAW_INFO_FLAGS_FILL_RULE_BIT,
+    DRAW_INFO_FLAGS_GLYPH_BIT, DrawBbox, DrawBeginClip, DrawBlurRoundedRect, DrawColor, DrawImage,
+    DrawLinearGradient, DrawMonoid, DrawRadialGradient, DrawSweepGradient, DrawTag,
+    ImageColorSpace,
 };
 pub use encoding::{Encoding, Resources, StreamOffsets};
 pub use glyph::{Glyph, GlyphRun};
-pub use mask::{make_mask_lut, make_mask_lut_16};
+pub use mask::{make_mask_lut, make_mask_lut_16, make_mask_lut_for_pattern};
 pub use math::Transform;
 pub use monoid::Monoid;
 pub use path::{
@@ -10,14 +10,23 @@ const MASK_HEIGHT: usize = 32;
 
 const PATTERN: [u8; 8] = [0, 5, 3, 7, 1, 4, 6, 2];
 
-fn one_mask(slope: f64, mut translation: f64, is_pos: bool) -> u8 {
+fn one_mask(slope: f64, translation: f64, is_pos: bool) -> u8 {
+    let xs = PATTERN.map(|item| (item as f64 + 0.5) * 0.125);
+    one_mask_for_columns(&xs, slope, translation, is_pos) as u8
+}
+
+/// Compute the half-plane mask for samples with the given x positions.
+///
+/// Sample `i` is in row `i` of the pixel, that is at y position `(i + 0.5) / n`,
+/// as the y half-plane masks in fine rasterization assume that.
+fn one_mask_for_columns(xs: &[f64], slope: f64, mut translation: f64, is_pos: bool) -> u16 {
     if is_pos {
         translation = 1. - translation;
     }
+    let n = xs.len() as f64;
     let mut result = 0;
-    for (i, item) in PATTERN.iter().enumerate() {
-        let mut y = (i as f64 + 0.5) * 0.125;
-        let x = (*item as f64 + 0.5) * 0.125;
+    for (i, x) in xs.iter().enumerate() {
+        let mut y = (i as f64 + 0.5) / n;
         if !is_pos {
             y = 1. - y;
         }
@@ -57,22 +66,9 @@ const MASK16_HEIGHT: usize = 64;
 // [D3D11 standard sample pattern]: https://learn.microsoft.com/en-us/windows/win32/api/d3d11/ne-d3d11-d3d11_standard_multisample_quality_levels
 const PATTERN_16: [u8; 16] = [1, 8, 4, 11, 15, 7, 3, 12, 0, 9, 5, 13, 2, 10, 6, 14];
 
-fn one_mask_16(slope: f64, mut translation: f64, is_pos: bool) -> u16 {
-    if is_pos {
-        translation = 1. - translation;
-    }
-    let mut result = 0;
-    for (i, item) in PATTERN_16.iter().enumerate() {
-        let mut y = (i as f64 + 0.5) * 0.0625;
-        let x = (*item as f64 + 0.5) * 0.0625;
-        if !is_pos {
-            y = 1. - y;
-        }
-        if (x - (1.0 - translation)) * (1. - slope) - (y - translation) * slope >= 0. {
-            result |= 1 << i;
-        }
-    }
-    result
+fn one_mask_16(slope: f64, translation: f64, is_pos: bool) -> u16 {
+    let xs = PATTERN_16.map(|item| (item as f64 + 0.5) * 0.0625);
+    one_mask_for_columns(&xs, slope, translation, is_pos)
 }
 
 /// Make a lookup table of half-plane masks.
@@ -96,3 +92,51 @@ pub fn make_mask_lut_16() -> Vec<u8> {
     // bytes per iteration of the above loop.
     bytemuck::cast_slice(&v16).into()
 }
+
+/// Make a lookup table of half-plane masks for custom sample positions.
+///
+/// `pattern` has an `[x, y]` position within the pixel for each sample, where both coordinates
+/// are in the range `0.0..1.0`. It must have 8 or 16 samples, giving a table like
+/// [`make_mask_lut`] or [`make_mask_lut_16`] respectively.
+///
+/// Fine rasterization assumes that the samples are spread evenly over the rows of the pixel,
+/// so there must be exactly one sample in each of the 8 or 16 rows. The x positions can be
+/// chosen freely, and the y positions are moved to the center of their row.
+///
+/// Returns `None` if the pattern doesn't meet these requirements.
+pub fn make_mask_lut_for_pattern(pattern: &[[f32; 2]]) -> Option<Vec<u8>> {
+    let n = pattern.len();
+    if n != 8 && n != 16 {
+        return None;
+    }
+    let mut xs = vec![None; n];
+    for &[x, y] in pattern {
+        if !((0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)) {
+            return None;
+        }
+        let row = ((y * n as f32) as usize).min(n - 1);
+        if xs[row].replace(f64::from(x)).is_some() {
+            return None;
+        }
+    }
+    let xs: Vec<f64> = xs.into_iter().collect::<Option<_>>()?;
+    let (width, height) = if n == 8 {
+        (MASK_WIDTH, MASK_HEIGHT)
+    } else {
+        (MASK16_WIDTH, MASK16_HEIGHT)
+    };
+    let masks = (0..width * height).map(|i| {
+        let half_height = height / 2;
+        let u = i % width;
+        let v = i / width;
+        let is_pos = v >= half_height;
+        let y = ((v % half_height) as f64 + 0.5) * (1.0 / half_height as f64);
+        let x = (u as f64 + 0.5) * (1.0 / width as f64);
+        one_mask_for_columns(&xs, y, x, is_pos)
+    });
+    Some(if n == 8 {
+        masks.map(|mask| mask as u8).collect()
+    } else {
+        masks.flat_map(u16::to_ne_bytes).collect()
+    })
+}
@@ -19,8 +19,8 @@ pub struct Style {
     ///   `FLAGS_*` constants below for more information.
     ///
     ///   ```text
-    ///   flags: |style|fill|join|start cap|end cap|reserved|
-    ///    bits:  0     1    2-3  4-5       6-7     8-15
+    ///   flags: |style|fill|join|start cap|end cap|glyph|aliased|clear|reserved|
+    ///    bits:  0     1    2-3  4-5       6-7     8     9       10    11-15
     ///   ```
     ///
     /// - `miter_limit: u16` - The miter limit for a stroke, encoded in
@@ -66,6 +66,20 @@ impl Style {
 
     pub const FLAGS_START_CAP_MASK: u32 = 0x0C00_0000;
     pub const FLAGS_END_CAP_MASK: u32 = 0x0300_0000;
+
+    /// 1 if the path is a glyph outline. The coverage of glyphs is adjusted
+    /// by the text gamma during fine rasterization.
+    ///
+    /// This is set at resolve time, and is not part of the glyph cache key.
+    pub const FLAGS_GLYPH_BIT: u32 = 0x0080_0000;
+
+    /// 1 if the path isn't anti-aliased. Its coverage is rounded to 0 or 1
+    /// during fine rasterization.
+    pub const FLAGS_ALIASED_BIT: u32 = 0x0040_0000;
+
+    /// 1 if the path clears the content under it to transparent, in proportion
+    /// to its coverage, rather than being painted with its brush.
+    pub const FLAGS_CLEAR_BIT: u32 = 0x0020_0000;
     pub const MITER_LIMIT_MASK: u32 = 0xFFFF;
 
     pub fn from_fill(fill: Fill) -> Self {
@@ -315,6 +329,28 @@ impl PathTag {
     }
 }
 
+impl std::fmt::Debug for PathTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match Self(self.0 & !Self::SUBPATH_END_BIT) {
+            Self::LINE_TO_F32 => "LINE_TO_F32",
+            Self::QUAD_TO_F32 => "QUAD_TO_F32",
+            Self::CUBIC_TO_F32 => "CUBIC_TO_F32",
+            Self::LINE_TO_I16 => "LINE_TO_I16",
+            Self::QUAD_TO_I16 => "QUAD_TO_I16",
+            Self::CUBIC_TO_I16 => "CUBIC_TO_I16",
+            Self::TRANSFORM => "TRANSFORM",
+            Self::PATH => "PATH",
+            Self::STYLE => "STYLE",
+            _ => return write!(f, "PathTag({:#x})", self.0),
+        };
+        write!(f, "PathTag::{name}")?;
+        if self.is_path_segment() && self.is_subpath_end() {
+            write!(f, " | SUBPATH_END_BIT")?;
+        }
+        Ok(())
+    }
+}
+
 /// Monoid for the path tag stream.
 #[derive(Copy, Clone, Pod, Zeroable, Default, Debug)]
 #[repr(C)]
@@ -843,6 +879,7 @@ const EPSILON: f32 = 1e-12;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use peniko::kurbo::BezPath;
 
     #[test]
     fn test_fill_style() {
@@ -875,4 +912,53 @@ mod tests {
             }
         }
     }
+
+    /// Returns the segment types of the stroke cap markers which end each subpath of `path`.
+    fn stroke_cap_markers(path: &BezPath) -> Vec<u8> {
+        let (mut tags, mut data, mut n_segments, mut n_paths) = (vec![], vec![], 0, 0);
+        let mut encoder =
+            PathEncoder::new(&mut tags, &mut data, &mut n_segments, &mut n_paths, false);
+        encoder.path_elements(path.iter());
+        encoder.finish(true);
+        tags.iter()
+            .filter(|tag| tag.is_subpath_end())
+            .map(|tag| tag.path_segment_type().0)
+            .collect()
+    }
+
+    #[test]
+    fn stroke_markers_distinguish_open_and_closed_subpaths() {
+        let mut path = BezPath::new();
+        // Closed triangle.
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.line_to((10.0, 10.0));
+        path.close_path();
+        // Open polyline.
+        path.move_to((20.0, 0.0));
+        path.line_to((30.0, 0.0));
+        path.line_to((30.0, 10.0));
+        // Open polyline which returns to its start point without closing.
+        path.move_to((40.0, 0.0));
+        path.line_to((50.0, 0.0));
+        path.line_to((40.0, 0.0));
+        // Closed subpath which starts without a move, after the previous subpath is closed.
+        path.move_to((60.0, 0.0));
+        path.line_to((70.0, 0.0));
+        path.line_to((70.0, 10.0));
+        path.close_path();
+        path.line_to((80.0, 10.0));
+        assert_eq!(
+            stroke_cap_markers(&path),
+            [
+                // Closed subpaths are marked with a line, to be joined.
+                PathSegmentType::LINE_TO.0,
+                // Open subpaths are marked with a quad, to be capped.
+                PathSegmentType::QUAD_TO.0,
+                PathSegmentType::QUAD_TO.0,
+                PathSegmentType::LINE_TO.0,
+                PathSegmentType::QUAD_TO.0,
+            ]
+        );
+    }
 }
@@ -4,8 +4,8 @@
 use std::collections::HashMap;
 
 use peniko::color::cache_key::CacheKey;
-use peniko::color::{HueDirection, Srgb};
-use peniko::{ColorStop, ColorStops};
+use peniko::color::{AlphaColor, PremulColor, Srgb};
+use peniko::{ColorStop, ColorStops, InterpolationAlphaSpace};
 
 const N_SAMPLES: usize = 512;
 const RETAINED_COUNT: usize = 64;
@@ -21,7 +21,7 @@ pub struct Ramps<'a> {
 #[derive(Default)]
 pub(crate) struct RampCache {
     epoch: u64,
-    map: HashMap<CacheKey<ColorStops>, (u32, u64)>,
+    map: HashMap<(CacheKey<ColorStops>, bool), (u32, u64)>,
     data: Vec<u32>,
 }
 
@@ -35,38 +35,44 @@ impl RampCache {
         }
     }
 
-    pub(crate) fn add(&mut self, stops: &[ColorStop]) -> u32 {
-        if let Some(entry) = self.map.get_mut(&CacheKey(stops.into())) {
+    pub(crate) fn add(
+        &mut self,
+        stops: &[ColorStop],
+        interpolation_alpha_space: InterpolationAlphaSpace,
+    ) -> u32 {
+        let premultiplied = interpolation_alpha_space == InterpolationAlphaSpace::Premultiplied;
+        let key = (CacheKey(stops.into()), premultiplied);
+        if let Some(entry) = self.map.get_mut(&key) {
             entry.1 = self.epoch;
             entry.0
         } else if self.map.len() < RETAINED_COUNT {
             let id = (self.data.len() / N_SAMPLES) as u32;
-            self.data.extend(make_ramp(stops));
-            self.map.insert(CacheKey(stops.into()), (id, self.epoch));
+            self.data.extend(make_ramp(stops, premultiplied));
+            self.map.insert(key, (id, self.epoch));
             id
         } else {
             let mut reuse = None;
-            for (stops, (id, epoch)) in &self.map {
+            for (old_key, (id, epoch)) in &self.map {
                 if *epoch + 2 < self.epoch {
-                    reuse = Some((stops.to_owned(), *id));
+                    reuse = Some((old_key.to_owned(), *id));
                     break;
                 }
             }
-            if let Some((old_stops, id)) = reuse {
-                self.map.remove(&old_stops);
+            if let Some((old_key, id)) = reuse {
+                self.map.remove(&old_key);
                 let start = id as usize * N_SAMPLES;
                 for (dst, src) in self.data[start..start + N_SAMPLES]
                     .iter_mut()
-                    .zip(make_ramp(stops))
+                    .zip(make_ramp(stops, premultiplied))
                 {
                     *dst = src;
                 }
-                self.map.insert(CacheKey(stops.into()), (id, self.epoch));
+                self.map.insert(key, (id, self.epoch));
                 id
             } else {
                 let id = (self.data.len() / N_SAMPLES) as u32;
-                self.data.extend(make_ramp(stops));
-                self.map.insert(CacheKey(stops.into()), (id, self.epoch));
+                self.data.extend(make_ramp(stops, premultiplied));
+                self.map.insert(key, (id, self.epoch));
                 id
             }
         }
@@ -81,7 +87,12 @@ impl RampCache {
     }
 }
 
-fn make_ramp(stops: &[ColorStop]) -> impl Iterator<Item = u32> + '_ {
+/// Samples the colors of `stops`, returning premultiplied RGBA8 colors.
+///
+/// If `premultiplied` is set, the colors are interpolated with premultiplied alpha, as in CSS,
+/// so that the hue of a color is kept when it fades to a transparent color. Otherwise, the
+/// color channels are interpolated separately from the alpha channel.
+fn make_ramp(stops: &[ColorStop], premultiplied: bool) -> impl Iterator<Item = u32> + '_ {
     let mut last_u = 0.0;
     let mut last_c = stops[0].color.to_alpha_color::<Srgb>();
     let mut this_u = last_u;
@@ -102,10 +113,20 @@ fn make_ramp(stops: &[ColorStop]) -> impl Iterator<Item = u32> + '_ {
         }
         let du = this_u - last_u;
         let c = if du < 1e-9 {
-            this_c
+            this_c.premultiply()
         } else {
-            last_c.lerp(this_c, (u - last_u) / du, HueDirection::default())
+            let t = (u - last_u) / du;
+            if premultiplied {
+                let (c0, c1) = (last_c.premultiply(), this_c.premultiply());
+                PremulColor::new(lerp(c0.components, c1.components, t))
+            } else {
+                AlphaColor::<Srgb>::new(lerp(last_c.components, this_c.components, t)).premultiply()
+            }
         };
-        c.premultiply().to_rgba8().to_u32()
+        c.to_rgba8().to_u32()
     })
 }
+
+fn lerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    core::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
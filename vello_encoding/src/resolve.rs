@@ -2,11 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use bytemuck::{Pod, Zeroable};
-use peniko::{Extend, ImageData};
+use peniko::{Extend, ImageData, InterpolationAlphaSpace};
 use std::ops::Range;
 use std::sync::Arc;
 
-use super::{DrawTag, Encoding, PathTag, StreamOffsets, Style, Transform};
+use super::{DrawBeginClip, DrawTag, Encoding, PathTag, StreamOffsets, Style, Transform};
 
 use crate::glyph_cache::GlyphCache;
 use crate::image_cache::{ImageCache, Images};
@@ -36,6 +36,8 @@ pub struct Layout {
     pub transform_base: u32,
     /// Start of style stream.
     pub style_base: u32,
+    /// Start of color matrix stream.
+    pub color_matrix_base: u32,
 }
 
 impl Layout {
@@ -95,6 +97,13 @@ impl Layout {
     /// Returns the style stream.
     pub fn styles<'a>(&self, data: &'a [u8]) -> &'a [Style] {
         let start = self.style_base as usize * 4;
+        let end = self.color_matrix_base as usize * 4;
+        bytemuck::cast_slice(&data[start..end])
+    }
+
+    /// Returns the color matrix stream.
+    pub fn color_matrices<'a>(&self, data: &'a [u8]) -> &'a [[f32; 20]] {
+        let start = self.color_matrix_base as usize * 4;
         bytemuck::cast_slice(&data[start..])
     }
 }
@@ -119,7 +128,7 @@ pub fn resolve_solid_paths_only(encoding: &Encoding, packed: &mut Vec<u8>) -> La
     let SceneBufferSizes {
         buffer_size,
         path_tag_padded,
-    } = SceneBufferSizes::new(encoding, &StreamOffsets::default());
+    } = SceneBufferSizes::new(encoding, &StreamOffsets::default(), 0);
     data.reserve(buffer_size);
     // Path tag stream
     layout.path_tag_base = size_to_words(data.len());
@@ -148,6 +157,8 @@ pub fn resolve_solid_paths_only(encoding: &Encoding, packed: &mut Vec<u8>) -> La
     // Style stream
     layout.style_base = size_to_words(data.len());
     data.extend_from_slice(bytemuck::cast_slice(&encoding.styles));
+    // Color matrix stream
+    layout.color_matrix_base = size_to_words(data.len());
     layout.n_draw_objects = layout.n_paths;
     assert_eq!(buffer_size, data.len());
     layout
@@ -161,6 +172,7 @@ pub struct Resolver {
     ramp_cache: RampCache,
     image_cache: ImageCache,
     pending_images: Vec<PendingImage>,
+    color_matrices: Vec<[f32; 20]>,
     patches: Vec<ResolvedPatch>,
 }
 
@@ -194,7 +206,7 @@ impl Resolver {
         let SceneBufferSizes {
             buffer_size,
             path_tag_padded,
-        } = SceneBufferSizes::new(encoding, &patch_sizes);
+        } = SceneBufferSizes::new(encoding, &patch_sizes, self.color_matrices.len());
         data.reserve(buffer_size);
         // Path tag stream
         layout.path_tag_base = size_to_words(data.len());
@@ -289,7 +301,10 @@ impl Resolver {
                             ));
                         }
                         if let Some((x, y)) = self.pending_images[*index].xy {
-                            let xy = (x << 16) | y;
+                            // The encoded value is the origin of the sampled sub-rectangle
+                            // within the image.
+                            let origin = encoding.draw_data[*draw_data_offset];
+                            let xy = ((x + (origin >> 16)) << 16) | (y + (origin & 0xFFFF));
                             data.extend_from_slice(bytemuck::bytes_of(&xy));
                             pos = *draw_data_offset + 1;
                         } else {
@@ -301,6 +316,20 @@ impl Resolver {
                             pos = *draw_data_offset + 2;
                         }
                     }
+                    ResolvedPatch::ColorMatrix {
+                        draw_data_offset,
+                        index,
+                    } => {
+                        if pos < *draw_data_offset {
+                            data.extend_from_slice(bytemuck::cast_slice(
+                                &encoding.draw_data[pos..*draw_data_offset],
+                            ));
+                        }
+                        let blend_mode = encoding.draw_data[*draw_data_offset]
+                            | (index + 1) << DrawBeginClip::COLOR_MATRIX_SHIFT;
+                        data.extend_from_slice(bytemuck::bytes_of(&blend_mode));
+                        pos = *draw_data_offset + 1;
+                    }
                 }
             }
             if pos < stream.len() {
@@ -372,7 +401,14 @@ impl Resolver {
                         pos = stream_offset;
                     }
                     for glyph in &self.glyphs[glyphs.clone()] {
-                        data.extend_from_slice(bytemuck::cast_slice(&glyph.styles));
+                        for style in &glyph.styles {
+                            let style = Style {
+                                flags_and_miter_limit: style.flags_and_miter_limit
+                                    | Style::FLAGS_GLYPH_BIT,
+                                ..*style
+                            };
+                            data.extend_from_slice(bytemuck::bytes_of(&style));
+                        }
                     }
                 }
             }
@@ -380,6 +416,9 @@ impl Resolver {
                 data.extend_from_slice(bytemuck::cast_slice(&stream[pos..]));
             }
         }
+        // Color matrix stream
+        layout.color_matrix_base = size_to_words(data.len());
+        data.extend_from_slice(bytemuck::cast_slice(&self.color_matrices));
         self.glyphs.clear();
         layout.n_draw_objects = layout.n_paths;
         assert_eq!(buffer_size, data.len());
@@ -392,6 +431,7 @@ impl Resolver {
         self.glyph_cache.maintain();
         self.image_cache.clear();
         self.pending_images.clear();
+        self.color_matrices.clear();
         self.patches.clear();
         let mut sizes = StreamOffsets::default();
         let resources = &encoding.resources;
@@ -401,8 +441,12 @@ impl Resolver {
                     draw_data_offset,
                     stops,
                     extend,
+                    interpolation_alpha_space,
                 } => {
-                    let ramp_id = self.ramp_cache.add(&resources.color_stops[stops.clone()]);
+                    let ramp_id = self.ramp_cache.add(
+                        &resources.color_stops[stops.clone()],
+                        *interpolation_alpha_space,
+                    );
                     self.patches.push(ResolvedPatch::Ramp {
                         draw_data_offset: *draw_data_offset + sizes.draw_data,
                         ramp_id,
@@ -481,6 +525,21 @@ impl Resolver {
                         draw_data_offset: *draw_data_offset + sizes.draw_data,
                     });
                 }
+                Patch::ColorMatrix {
+                    draw_data_offset,
+                    matrix,
+                } => {
+                    // Layers beyond the maximum are composited without their color matrix.
+                    if self.color_matrices.len() == DrawBeginClip::MAX_COLOR_MATRICES {
+                        continue;
+                    }
+                    let index = self.color_matrices.len() as u32;
+                    self.color_matrices.push(*matrix);
+                    self.patches.push(ResolvedPatch::ColorMatrix {
+                        draw_data_offset: *draw_data_offset + sizes.draw_data,
+                        index,
+                    });
+                }
             }
         }
         sizes
@@ -524,6 +583,8 @@ pub enum Patch {
         stops: Range<usize>,
         /// Extend mode for the gradient.
         extend: Extend,
+        /// Whether the colors are interpolated with premultiplied alpha.
+        interpolation_alpha_space: InterpolationAlphaSpace,
     },
     /// Glyph run resource.
     GlyphRun {
@@ -537,6 +598,13 @@ pub enum Patch {
         /// Underlying image data.
         image: ImageData,
     },
+    /// Color matrix of a layer.
+    ColorMatrix {
+        /// Offset to the blend mode of the layer in the draw data stream.
+        draw_data_offset: usize,
+        /// Row-major 4×5 color matrix.
+        matrix: [f32; 20],
+    },
 }
 
 /// Image to be allocated in the atlas.
@@ -577,6 +645,12 @@ enum ResolvedPatch {
         /// Offset to the atlas location in the draw data stream.
         draw_data_offset: usize,
     },
+    ColorMatrix {
+        /// Offset to the blend mode of the layer in the draw data stream.
+        draw_data_offset: usize,
+        /// Index of the color matrix in the color matrix stream.
+        index: u32,
+    },
 }
 
 struct SceneBufferSizes {
@@ -587,9 +661,9 @@ struct SceneBufferSizes {
 }
 
 impl SceneBufferSizes {
-    /// Computes common scene buffer sizes for the given encoding and patch
-    /// stream sizes.
-    fn new(encoding: &Encoding, patch_sizes: &StreamOffsets) -> Self {
+    /// Computes common scene buffer sizes for the given encoding, patch
+    /// stream sizes and number of color matrices.
+    fn new(encoding: &Encoding, patch_sizes: &StreamOffsets, n_color_matrices: usize) -> Self {
         let n_path_tags =
             encoding.path_tags.len() + patch_sizes.path_tags + encoding.n_open_clips as usize;
         let path_tag_padded = align_up(n_path_tags, 4 * crate::config::PATH_REDUCE_WG);
@@ -601,7 +675,8 @@ impl SceneBufferSizes {
             )
             + slice_size_in_bytes(&encoding.draw_data, patch_sizes.draw_data)
             + slice_size_in_bytes(&encoding.transforms, patch_sizes.transforms)
-            + slice_size_in_bytes(&encoding.styles, patch_sizes.styles);
+            + slice_size_in_bytes(&encoding.styles, patch_sizes.styles)
+            + n_color_matrices * size_of::<[f32; 20]>();
         Self {
             buffer_size,
             path_tag_padded,
@@ -0,0 +1,16 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(missing_docs, reason = "Not needed for benchmarks")]
+
+use criterion::{criterion_group, criterion_main};
+use vello_scene_bench::encode;
+
+criterion_group!(
+    benches,
+    encode::rounded_rects,
+    encode::rects,
+    encode::box_shadows,
+    encode::prepared_paths
+);
+criterion_main!(benches);
@@ -0,0 +1,174 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+use vello::kurbo::{Affine, BezPath, RoundedRect, Stroke};
+use vello::peniko::{Color, Fill};
+use vello::{BoxShadowInstance, Scene};
+
+const NUM_CARDS: usize = 1000;
+const NUM_SEGMENTS: usize = 1000;
+const NUM_DRAWS: usize = 100;
+
+fn cards() -> Vec<(RoundedRect, Color)> {
+    (0..NUM_CARDS)
+        .map(|i| {
+            let x = (i % 40) as f64 * 50.0;
+            let y = (i / 40) as f64 * 40.0;
+            let rect = RoundedRect::new(x, y, x + 45.0, y + 35.0, 6.0);
+            let shade = u8::try_from(i % 256).unwrap();
+            (rect, Color::from_rgba8(shade, 128, 255 - shade, 255))
+        })
+        .collect()
+}
+
+pub fn rounded_rects(c: &mut Criterion) {
+    let cards = cards();
+    let mut g = c.benchmark_group("rounded_rects");
+    let mut scene = Scene::new();
+    g.bench_function("fill_per_card", |b| {
+        b.iter(|| {
+            scene.reset();
+            for (rect, color) in &cards {
+                scene.fill(Fill::NonZero, Affine::IDENTITY, *color, None, rect);
+            }
+            black_box(&scene);
+        });
+    });
+    g.bench_function("fill_rounded_rects", |b| {
+        b.iter(|| {
+            scene.reset();
+            scene.fill_rounded_rects(Affine::IDENTITY, &cards);
+            black_box(&scene);
+        });
+    });
+}
+
+pub fn rects(c: &mut Criterion) {
+    let rects = cards()
+        .into_iter()
+        .map(|(rect, color)| (rect.rect(), color))
+        .collect::<Vec<_>>();
+    let stroke = Stroke::new(1.0);
+    let mut g = c.benchmark_group("rects");
+    let mut scene = Scene::new();
+    g.bench_function("fill_and_stroke_shape", |b| {
+        b.iter(|| {
+            scene.reset();
+            for (rect, color) in &rects {
+                scene.fill(Fill::NonZero, Affine::IDENTITY, *color, None, rect);
+                scene.stroke(&stroke, Affine::IDENTITY, *color, None, rect);
+            }
+            black_box(&scene);
+        });
+    });
+    g.bench_function("fill_rect_and_stroke_rect", |b| {
+        b.iter(|| {
+            scene.reset();
+            for (rect, color) in &rects {
+                scene.fill_rect(Affine::IDENTITY, *rect, *color);
+                scene.stroke_rect(&stroke, Affine::IDENTITY, *rect, *color);
+            }
+            black_box(&scene);
+        });
+    });
+}
+
+pub fn box_shadows(c: &mut Criterion) {
+    let shadows = cards()
+        .into_iter()
+        .map(|(rect, _)| BoxShadowInstance {
+            transform: Affine::translate((0.0, 4.0)),
+            rect: rect.rect(),
+            color: Color::from_rgba8(0, 0, 0, 96),
+            radius: rect.radii().top_left,
+            std_dev: 4.0,
+        })
+        .collect::<Vec<_>>();
+    let mut g = c.benchmark_group("box_shadows");
+    let mut scene = Scene::new();
+    g.bench_function("draw_blurred_rounded_rect_in", |b| {
+        b.iter(|| {
+            scene.reset();
+            for shadow in &shadows {
+                let shape = shadow.rect.inflate(10.0, 10.0);
+                scene.draw_blurred_rounded_rect_in(
+                    &shape,
+                    shadow.transform,
+                    shadow.rect,
+                    shadow.color,
+                    shadow.radius,
+                    shadow.std_dev,
+                );
+            }
+            black_box(&scene);
+        });
+    });
+    g.bench_function("draw_box_shadows_batch", |b| {
+        b.iter(|| {
+            scene.reset();
+            scene.draw_box_shadows_batch(&shadows);
+            black_box(&scene);
+        });
+    });
+}
+
+/// A closed path with a mixture of line, quadratic and cubic segments.
+fn complex_path() -> BezPath {
+    let mut path = BezPath::new();
+    path.move_to((0.0, 0.0));
+    for i in 0..NUM_SEGMENTS {
+        let x = i as f64;
+        let y = if i % 2 == 0 { 10.0 } else { -10.0 };
+        match i % 3 {
+            0 => path.line_to((x, y)),
+            1 => path.quad_to((x - 0.5, 2.0 * y), (x, y)),
+            _ => path.curve_to((x - 0.75, -y), (x - 0.25, 2.0 * y), (x, y)),
+        }
+    }
+    path.close_path();
+    path
+}
+
+pub fn prepared_paths(c: &mut Criterion) {
+    let path = complex_path();
+    let prepared = Scene::prepare_path(&path, 0.1);
+    let stroke = Stroke::new(2.0);
+    let color = Color::from_rgba8(40, 120, 200, 255);
+    let mut g = c.benchmark_group("prepared_paths");
+    let mut scene = Scene::new();
+    g.bench_function("fill_and_stroke", |b| {
+        b.iter(|| {
+            scene.reset();
+            for i in 0..NUM_DRAWS {
+                let transform = Affine::translate((0.0, i as f64 * 30.0));
+                scene.fill(Fill::NonZero, transform, color, None, &path);
+                scene.stroke(&stroke, transform, color, None, &path);
+            }
+            black_box(&scene);
+        });
+    });
+    g.bench_function("fill_and_stroke_combined", |b| {
+        b.iter(|| {
+            scene.reset();
+            for i in 0..NUM_DRAWS {
+                let transform = Affine::translate((0.0, i as f64 * 30.0));
+                scene.fill_and_stroke(Fill::NonZero, &stroke, transform, color, color, &path);
+            }
+            black_box(&scene);
+        });
+    });
+    g.bench_function("fill_and_stroke_prepared", |b| {
+        b.iter(|| {
+            scene.reset();
+            for i in 0..NUM_DRAWS {
+                let transform = Affine::translate((0.0, i as f64 * 30.0));
+                scene.fill_prepared(Fill::NonZero, transform, color, None, &prepared);
+                scene.stroke_prepared(&stroke, transform, color, None, &prepared);
+            }
+            black_box(&scene);
+        });
+    });
+}
@@ -0,0 +1,8 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Benchmarks of encoding scenes on the CPU.
+
+#![allow(missing_docs, reason = "Not needed for benchmarks")]
+
+pub mod encode;
@@ -49,6 +49,9 @@ var<storage, read_write> ptcl: array<u32>;
 const WG_SIZE = 256u;
 const N_SLICE = WG_SIZE / 32u;
 
+// The value of `config.debug_view` for the paint order view, matching fine.wgsl.
+const DEBUG_VIEW_PAINT_ORDER = 4u;
+
 var<workgroup> sh_bitmaps: array<array<atomic<u32>, N_TILE>, N_SLICE>;
 var<workgroup> sh_part_count: array<u32, WG_SIZE>;
 var<workgroup> sh_part_offsets: array<u32, WG_SIZE>;
@@ -95,8 +98,12 @@ fn write_path(tile: Tile, tile_ix: u32, draw_flags: u32) {
         tiles[tile_ix].segment_count_or_ix = ~seg_ix;
         alloc_cmd(4u);
         ptcl[cmd_offset] = CMD_FILL;
+        // The low three bits hold the fill rule, whether the text gamma applies, and whether
+        // the path is aliased.
         let even_odd = (draw_flags & DRAW_INFO_FLAGS_FILL_RULE_BIT) != 0u;
-        let size_and_rule = (n_segs << 1u) | u32(even_odd);
+        let is_glyph = (draw_flags & DRAW_INFO_FLAGS_GLYPH_BIT) != 0u;
+        let is_aliased = (draw_flags & DRAW_INFO_FLAGS_ALIASED_BIT) != 0u;
+        let size_and_rule = (n_segs << 3u) | (u32(is_aliased) << 2u) | (u32(is_glyph) << 1u) | u32(even_odd);
         let fill = CmdFill(size_and_rule, seg_ix, tile.backdrop);
         ptcl[cmd_offset + 1u] = fill.size_and_rule;
         ptcl[cmd_offset + 2u] = fill.seg_data;
@@ -116,6 +123,12 @@ fn write_color(color: CmdColor) {
     cmd_offset += 2u;
 }
 
+fn write_clear() {
+    alloc_cmd(1u);
+    ptcl[cmd_offset] = CMD_CLEAR;
+    cmd_offset += 1u;
+}
+
 fn write_grad(ty: u32, index: u32, info_offset: u32) {
     alloc_cmd(3u);
     ptcl[cmd_offset] = ty;
@@ -138,11 +151,19 @@ fn write_begin_clip() {
 }
 
 fn write_end_clip(end_clip: CmdEndClip) {
-    alloc_cmd(3u);
+    let color_matrix_ix = end_clip.blend >> END_CLIP_COLOR_MATRIX_SHIFT;
+    let size = select(3u, 23u, color_matrix_ix != 0u);
+    alloc_cmd(size);
     ptcl[cmd_offset] = CMD_END_CLIP;
     ptcl[cmd_offset + 1u] = end_clip.blend;
     ptcl[cmd_offset + 2u] = bitcast<u32>(end_clip.alpha);
-    cmd_offset += 3u;
+    if color_matrix_ix != 0u {
+        let matrix_base = config.color_matrix_base + (color_matrix_ix - 1u) * 20u;
+        for (var i = 0u; i < 20u; i += 1u) {
+            ptcl[cmd_offset + 3u + i] = scene[matrix_base + i];
+        }
+    }
+    cmd_offset += size;
 }
 
 fn write_blurred_rounded_rect(color: CmdColor, info_offset: u32) {
@@ -375,11 +396,23 @@ fn main(
             if clip_zero_depth == 0u {
                 let tile_ix = sh_tile_base[el_ix] + sh_tile_stride[el_ix] * tile_y + tile_x;
                 let tile = tiles[tile_ix];
+                // In the paint order debug view, every brush is replaced with a color holding
+                // its draw object index, which fine turns into a heatmap.
+                let is_brush = (drawtag & 1u) == 0u && drawtag != DRAWTAG_NOP;
+                if config.debug_view == DEBUG_VIEW_PAINT_ORDER && is_brush {
+                    write_path(tile, tile_ix, draw_flags);
+                    write_color(CmdColor(drawobj_ix + 1u));
+                    continue;
+                }
                 switch drawtag {
                     case DRAWTAG_FILL_COLOR: {
                         write_path(tile, tile_ix, draw_flags);
-                        let rgba_color = scene[dd];
-                        write_color(CmdColor(rgba_color));
+                        if (draw_flags & DRAW_INFO_FLAGS_CLEAR_BIT) != 0u {
+                            write_clear();
+                        } else {
+                            let rgba_color = scene[dd];
+                            write_color(CmdColor(rgba_color));
+                        }
                     }
                     case DRAWTAG_BLURRED_ROUNDED_RECT: {
                         write_path(tile, tile_ix, draw_flags);
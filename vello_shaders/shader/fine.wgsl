@@ -31,6 +31,13 @@ const IMAGE_QUALITY_HIGH = 2u;
 
 const LUMINANCE_MASK_LAYER = 0x10000u;
 
+// Values of `config.debug_view`, matching `DebugView` on the CPU.
+const DEBUG_VIEW_NONE = 0u;
+const DEBUG_VIEW_COVERAGE = 1u;
+const DEBUG_VIEW_WINDING = 2u;
+const DEBUG_VIEW_TILES = 3u;
+const DEBUG_VIEW_PAINT_ORDER = 4u;
+
 @group(0) @binding(2)
 var<storage> ptcl: array<u32>;
 
@@ -55,6 +62,7 @@ var image_atlas: texture_2d<f32>;
 const MASK_LUT_INDEX: u32 = 8;
 
 #ifdef msaa8
+const SAMPLE_COUNT = 8.0;
 const MASK_WIDTH = 32u;
 const MASK_HEIGHT = 32u;
 const SH_SAMPLES_SIZE = 512u;
@@ -65,6 +73,7 @@ var<storage> mask_lut: array<u32, 256u>;
 #endif
 
 #ifdef msaa16
+const SAMPLE_COUNT = 16.0;
 const MASK_WIDTH = 64u;
 const MASK_HEIGHT = 64u;
 const SH_SAMPLES_SIZE = 1024u;
@@ -151,7 +160,7 @@ fn fill_path_ms(fill: CmdFill, local_id: vec2<u32>, result: ptr<function, array<
         fill_path_ms_evenodd(fill, local_id, result);
         return;
     }
-    let n_segs = fill.size_and_rule >> 1u;
+    let n_segs = fill.size_and_rule >> 3u;
     let th_ix = local_id.y * (TILE_WIDTH / PIXELS_PER_THREAD) + local_id.x;
     // Initialize winding number arrays to a winding number of 0, which is 0x80 in an
     // 8 bit biased signed integer encoding.
@@ -509,7 +518,7 @@ fn fill_path_ms(fill: CmdFill, local_id: vec2<u32>, result: ptr<function, array<
 //
 // TODO: factor some logic out to reduce code duplication.
 fn fill_path_ms_evenodd(fill: CmdFill, local_id: vec2<u32>, result: ptr<function, array<f32, PIXELS_PER_THREAD>>) {
-    let n_segs = fill.size_and_rule >> 1u;
+    let n_segs = fill.size_and_rule >> 3u;
     let th_ix = local_id.y * (TILE_WIDTH / PIXELS_PER_THREAD) + local_id.x;
     if th_ix < TILE_HEIGHT {
         if th_ix == 0u {
@@ -813,7 +822,8 @@ fn read_image(cmd_ix: u32) -> CmdImage {
     let width_height = info[info_offset + 7u];
     let sample_alpha = info[info_offset + 8u];
     let alpha = f32(sample_alpha & 0xFFu) / 255.0;
-    let format = sample_alpha >> 15u;
+    let color_space = sample_alpha >> 16u;
+    let format = (sample_alpha >> 15u) & 0x1u;
     let alpha_type = (sample_alpha >> 14u) & 0x1u;
     let quality = (sample_alpha >> 12u) & 0x3u;
     let x_extend = (sample_alpha >> 10u) & 0x3u;
@@ -823,7 +833,7 @@ fn read_image(cmd_ix: u32) -> CmdImage {
     let y = f32(xy & 0xffffu);
     let width = f32(width_height >> 16u);
     let height = f32(width_height & 0xffffu);
-    return CmdImage(matrx, xlat, vec2(x, y), vec2(width, height), format, x_extend, y_extend, quality, alpha, alpha_type);
+    return CmdImage(matrx, xlat, vec2(x, y), vec2(width, height), format, x_extend, y_extend, quality, alpha, alpha_type, color_space);
 }
 
 fn read_end_clip(cmd_ix: u32) -> CmdEndClip {
@@ -832,6 +842,29 @@ fn read_end_clip(cmd_ix: u32) -> CmdEndClip {
     return CmdEndClip(blend, alpha);
 }
 
+fn read_color_matrix_row(ix: u32) -> vec4<f32> {
+    return bitcast<vec4<f32>>(vec4(ptcl[ix], ptcl[ix + 1u], ptcl[ix + 2u], ptcl[ix + 3u]));
+}
+
+fn read_color_matrix(ix: u32) -> CmdColorMatrix {
+    let weights = mat4x4(
+        read_color_matrix_row(ix),
+        read_color_matrix_row(ix + 5u),
+        read_color_matrix_row(ix + 10u),
+        read_color_matrix_row(ix + 15u)
+    );
+    let offset = bitcast<vec4<f32>>(vec4(ptcl[ix + 4u], ptcl[ix + 9u], ptcl[ix + 14u], ptcl[ix + 19u]));
+    return CmdColorMatrix(weights, offset);
+}
+
+// Applies a color matrix to a premultiplied color, returning a premultiplied color.
+// The matrix applies to unpremultiplied colors, and its results are clamped to [0, 1].
+fn apply_color_matrix(color: vec4<f32>, matrix: CmdColorMatrix) -> vec4<f32> {
+    let unpremultiplied = vec4(unpremultiply(color), color.a);
+    let result = clamp(unpremultiplied * matrix.weights + matrix.offset, vec4(0.0), vec4(1.0));
+    return vec4(result.rgb * result.a, result.a);
+}
+
 const PIXEL_FORMAT_RGBA: u32 = 0u;
 const PIXEL_FORMAT_BGRA: u32 = 1u;
 // Normalises subpixel order loaded from an image, based on the image's format.
@@ -861,6 +894,25 @@ fn maybe_premul_alpha(pixel: vec4f, alpha_type: u32) -> vec4f {
     }
 }
 
+const COLOR_SPACE_SRGB: u32 = 0u;
+const COLOR_SPACE_LINEAR_SRGB: u32 = 1u;
+// Converts a premultiplied pixel sampled from an image to sRGB, in which colors are composited.
+fn image_color_space(pixel: vec4f, color_space: u32) -> vec4f {
+    switch color_space {
+        case COLOR_SPACE_LINEAR_SRGB: {
+            if pixel.a == 0.0 {
+                return pixel;
+            }
+            let rgb = pixel.rgb / pixel.a;
+            let srgb = select(1.055 * pow(rgb, vec3(1.0 / 2.4)) - 0.055, 12.92 * rgb, rgb <= vec3(0.0031308));
+            return vec4(srgb * pixel.a, pixel.a);
+        }
+        case COLOR_SPACE_SRGB, default: {
+            return pixel;
+        }
+    }
+}
+
 const EXTEND_PAD: u32 = 0u;
 const EXTEND_REPEAT: u32 = 1u;
 const EXTEND_REFLECT: u32 = 2u;
@@ -873,6 +925,8 @@ fn extend_mode_normalized(t: f32, mode: u32) -> f32 {
             return fract(t);
         }
         case EXTEND_REFLECT, default: {
+            // A triangle wave, which mirrors the ramp at each integer. This is continuous,
+            // so the last stop isn't repeated at the reflection point.
             return abs(t - 2.0 * round(0.5 * t));
         }
     }
@@ -892,6 +946,33 @@ fn extend_mode(t: f32, mode: u32, max: f32) -> f32 {
     }
 }
 
+// The coverage of a rounded rectangle with no blur, from its signed distance field.
+//
+// The distance is converted to device pixels using the gradient of the distance field,
+// so that the edge is anti-aliased over one pixel under any transform. This is used for
+// analytic circles and ellipses, which don't need to be flattened.
+fn sharp_rounded_rect_coverage(blur: CmdBlurRect, p: vec2<f32>) -> f32 {
+    let half_size = vec2(blur.width, blur.height) * 0.5;
+    let radius = min(blur.radius, min(half_size.x, half_size.y));
+    let q = abs(p) - (half_size - radius);
+    let outside = max(q, vec2(0.0));
+    let d = length(outside) + min(max(q.x, q.y), 0.0) - radius;
+    var grad: vec2<f32>;
+    if outside.x > 0.0 || outside.y > 0.0 {
+        grad = normalize(outside);
+    } else if q.x > q.y {
+        grad = vec2(1.0, 0.0);
+    } else {
+        grad = vec2(0.0, 1.0);
+    }
+    grad *= select(vec2(1.0), vec2(-1.0), p < vec2(0.0));
+    // `matrx` maps device coordinates to local coordinates, so by the chain rule, its columns
+    // give the gradient of the distance in device space.
+    let device_grad = vec2(dot(blur.matrx.xy, grad), dot(blur.matrx.zw, grad));
+    let local_per_pixel = max(length(device_grad), 1e-9);
+    return clamp(0.5 - d / local_per_pixel, 0.0, 1.0);
+}
+
 const PIXELS_PER_THREAD = 4u;
 
 #ifndef msaa
@@ -903,7 +984,7 @@ const PIXELS_PER_THREAD = 4u;
 //
 // FIXME: This should return an array when https://github.com/gfx-rs/naga/issues/1930 is fixed.
 fn fill_path(fill: CmdFill, xy: vec2<f32>, result: ptr<function, array<f32, PIXELS_PER_THREAD>>) {
-    let n_segs = fill.size_and_rule >> 1u;
+    let n_segs = fill.size_and_rule >> 3u;
     let even_odd = (fill.size_and_rule & 1u) != 0u;
     var area: array<f32, PIXELS_PER_THREAD>;
     let backdrop_f = f32(fill.backdrop);
@@ -943,6 +1024,11 @@ fn fill_path(fill: CmdFill, xy: vec2<f32>, result: ptr<function, array<f32, PIXE
             area[i] += y_edge;
         }
     }
+    if config.debug_view == DEBUG_VIEW_WINDING {
+        // Keep the signed winding number (integrated over the pixel) for the debug view.
+        *result = area;
+        return;
+    }
     if even_odd {
         // even-odd winding rule
         for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
@@ -960,6 +1046,17 @@ fn fill_path(fill: CmdFill, xy: vec2<f32>, result: ptr<function, array<f32, PIXE
 
 #endif
 
+// Adjust the coverage of a glyph outline by the configured text gamma.
+//
+// Gamma values above 1 increase partial coverage, which makes small text appear heavier,
+// while values below 1 make it appear lighter.
+fn apply_text_gamma(area: ptr<function, array<f32, PIXELS_PER_THREAD>>) {
+    let inv_gamma = 1.0 / config.text_gamma;
+    for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+        (*area)[i] = pow((*area)[i], inv_gamma);
+    }
+}
+
 // The X size should be 16 / PIXELS_PER_THREAD
 @compute @workgroup_size(4, 16)
 fn main(
@@ -983,6 +1080,8 @@ fn main(
     var blend_stack: array<array<u32, PIXELS_PER_THREAD>, BLEND_STACK_SPLIT>;
     var clip_depth = 0u;
     var area: array<f32, PIXELS_PER_THREAD>;
+    // The accumulated coverage or latest winding number, for the debug views.
+    var debug_value: array<f32, PIXELS_PER_THREAD>;
     var cmd_ix = tile_ix * PTCL_INITIAL_ALLOC;
     let blend_offset = ptcl[cmd_ix];
     cmd_ix += 1u;
@@ -999,7 +1098,31 @@ fn main(
                 fill_path_ms(fill, local_id.xy, &area);
 #else
                 fill_path(fill, local_xy, &area);
+                if config.debug_view == DEBUG_VIEW_WINDING {
+                    for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+                        if abs(area[i]) > 1e-3 {
+                            debug_value[i] = area[i];
+                        }
+                        // The raw winding number isn't a valid coverage value.
+                        area[i] = 0.0;
+                    }
+                }
 #endif
+                if config.debug_view == DEBUG_VIEW_COVERAGE {
+                    for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+                        debug_value[i] += area[i] * (1.0 - debug_value[i]);
+                    }
+                }
+                let is_glyph = (fill.size_and_rule & 2u) != 0u;
+                if is_glyph && config.text_gamma != 1.0 {
+                    apply_text_gamma(&area);
+                }
+                let is_aliased = (fill.size_and_rule & 4u) != 0u;
+                if is_aliased {
+                    for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+                        area[i] = select(0.0, 1.0, area[i] >= 0.5);
+                    }
+                }
                 cmd_ix += 4u;
             }
             case CMD_SOLID: {
@@ -1010,6 +1133,14 @@ fn main(
             }
             case CMD_COLOR: {
                 let color = read_color(cmd_ix);
+                if config.debug_view == DEBUG_VIEW_PAINT_ORDER {
+                    // Coarse has replaced the color with the draw object index plus one.
+                    for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+                        if area[i] >= 0.5 {
+                            debug_value[i] = f32(color.rgba_color);
+                        }
+                    }
+                }
                 let fg = unpack4x8unorm(color.rgba_color);
                 for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
                     let fg_i = fg * area[i];
@@ -1017,6 +1148,12 @@ fn main(
                 }
                 cmd_ix += 2u;
             }
+            case CMD_CLEAR: {
+                for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
+                    rgba[i] *= 1.0 - area[i];
+                }
+                cmd_ix += 1u;
+            }
             case CMD_BEGIN_CLIP: {
                 if clip_depth < BLEND_STACK_SPLIT {
                     for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
@@ -1037,6 +1174,12 @@ fn main(
             }
             case CMD_END_CLIP: {
                 let end_clip = read_end_clip(cmd_ix);
+                let blend = end_clip.blend & ((1u << END_CLIP_COLOR_MATRIX_SHIFT) - 1u);
+                let has_color_matrix = (end_clip.blend >> END_CLIP_COLOR_MATRIX_SHIFT) != 0u;
+                var color_matrix: CmdColorMatrix;
+                if has_color_matrix {
+                    color_matrix = read_color_matrix(cmd_ix + 3u);
+                }
                 clip_depth -= 1u;
                 for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
                     var bg_rgba: u32;
@@ -1049,8 +1192,12 @@ fn main(
                         bg_rgba = blend_spill[local_blend_start + i];
                     }
                     let bg = unpack4x8unorm(bg_rgba);
-                    let fg = rgba[i] * area[i] * end_clip.alpha;
-                    if end_clip.blend == LUMINANCE_MASK_LAYER {
+                    var layer = rgba[i];
+                    if has_color_matrix {
+                        layer = apply_color_matrix(layer, color_matrix);
+                    }
+                    let fg = layer * area[i] * end_clip.alpha;
+                    if blend == LUMINANCE_MASK_LAYER {
                         // TODO: Does this case apply more generally?
                         // See https://github.com/linebender/vello/issues/1061
                         // TODO: How do we handle anti-aliased edges here?
@@ -1062,10 +1209,10 @@ fn main(
                         let luminance = clamp(svg_lum(unpremultiply(fg)) * fg.a, 0.0, 1.0);
                         rgba[i] = bg * luminance;
                     } else {
-                        rgba[i] = blend_mix_compose(bg, fg, end_clip.blend);
+                        rgba[i] = blend_mix_compose(bg, fg, blend);
                     }
                 }
-                cmd_ix += 3u;
+                cmd_ix += select(3u, 23u, has_color_matrix);
             }
             case CMD_JUMP: {
                 cmd_ix = ptcl[cmd_ix + 1u];
@@ -1114,8 +1261,21 @@ fn main(
                     let d_pos = pow(pow(x1, exponent) + pow(y1, exponent), inv_exponent);
                     let d_neg = min(max(x0, y0), 0.0);
                     let d = d_pos + d_neg - r1;
-                    let alpha = scale * (erf7(inv_std_dev * (min_edge + d)) - erf7(inv_std_dev * d));
+                    var alpha = scale * (erf7(inv_std_dev * (min_edge + d)) - erf7(inv_std_dev * d));
+                    if blur.std_dev <= 0.0 {
+                        alpha = sharp_rounded_rect_coverage(blur, local_xy);
+                    }
 
+#ifdef msaa
+                    if config.alpha_to_coverage != 0u {
+                        // Emulate alpha-to-coverage: the alpha selects a number of samples, which
+                        // overlap the samples covered by the path rather than attenuating them.
+                        let coverage = min(round(alpha * SAMPLE_COUNT) / SAMPLE_COUNT, area[i]);
+                        let fg_i = blur_rgba * coverage;
+                        rgba[i] = rgba[i] * (1.0 - fg_i.a) + fg_i;
+                        continue;
+                    }
+#endif
                     let fg_rgba = blur_rgba * alpha;
                     let fg_i = fg_rgba * area[i];
                     rgba[i] = rgba[i] * (1.0 - fg_i.a) + fg_i;
@@ -1228,7 +1388,7 @@ fn main(
                                 // TODO: If the image couldn't be added to the atlas (i.e. was too big), this isn't robust
                                 let atlas_uv_clamped = clamp(atlas_uv, image.atlas_offset, atlas_max);
                                 // Nearest neighbor sampling
-                                let fg_rgba = maybe_premul_alpha(textureLoad(image_atlas, vec2<i32>(atlas_uv_clamped), 0), image.alpha_type);
+                                let fg_rgba = image_color_space(maybe_premul_alpha(textureLoad(image_atlas, vec2<i32>(atlas_uv_clamped), 0), image.alpha_type), image.color_space);
                                 let fg_i = pixel_format(fg_rgba * area[i] * image.alpha, image.format);
                                 rgba[i] = rgba[i] * (1.0 - fg_i.a) + fg_i;
                             }
@@ -1255,7 +1415,7 @@ fn main(
                                 let c = maybe_premul_alpha(textureLoad(image_atlas, vec2<i32>(uv_quad.zy), 0), image.alpha_type);
                                 let d = maybe_premul_alpha(textureLoad(image_atlas, vec2<i32>(uv_quad.zw), 0), image.alpha_type);
                                 // Bilinear sampling
-                                let fg_rgba = mix(mix(a, b, uv_frac.y), mix(c, d, uv_frac.y), uv_frac.x);
+                                let fg_rgba = image_color_space(mix(mix(a, b, uv_frac.y), mix(c, d, uv_frac.y), uv_frac.x), image.color_space);
                                 let fg_i = pixel_format(fg_rgba * area[i] * image.alpha, image.format);
                                 rgba[i] = rgba[i] * (1.0 - fg_i.a) + fg_i;
                             }
@@ -1271,12 +1431,44 @@ fn main(
     for (var i = 0u; i < PIXELS_PER_THREAD; i += 1u) {
         let coords = xy_uint + vec2(i, 0u);
         if coords.x < config.target_width && coords.y < config.target_height {
-            let fg = rgba[i];
+            var fg = rgba[i];
+            switch config.debug_view {
+                case DEBUG_VIEW_COVERAGE: {
+                    fg = vec4(vec3(debug_value[i]), 1.0);
+                }
+                case DEBUG_VIEW_WINDING: {
+                    // Positive winding numbers are shown in green, and negative in red,
+                    // with each unit a quarter of the full brightness.
+                    let w = clamp(debug_value[i] * 0.25, -1.0, 1.0);
+                    fg = vec4(max(-w, 0.0), max(w, 0.0), 0.0, 1.0);
+                }
+                case DEBUG_VIEW_TILES: {
+                    // Outline the top and left edges of each tile.
+                    if (local_id.x == 0u && i == 0u) || local_id.y == 0u {
+                        fg = mix(fg, vec4(1.0, 0.0, 1.0, 1.0), 0.5);
+                    }
+                }
+                case DEBUG_VIEW_PAINT_ORDER: {
+                    // Later draws run from blue, through green, to red. Uncovered pixels are black.
+                    fg = vec4(0.0, 0.0, 0.0, 1.0);
+                    if debug_value[i] > 0.0 {
+                        let t = (debug_value[i] - 1.0) / max(f32(config.n_drawobj) - 1.0, 1.0);
+                        let s = 2.0 * t - 1.0;
+                        fg = vec4(max(s, 0.0), 1.0 - abs(s), max(-s, 0.0), 1.0);
+                    }
+                }
+                default: {}
+            }
             // let fg = base_color * (1.0 - foreground.a) + foreground;
-            // Max with a small epsilon to avoid NaNs
-            let a_inv = 1.0 / max(fg.a, 1e-6);
-            let rgba_sep = vec4(fg.rgb * a_inv, fg.a);
-            textureStore(output, vec2<i32>(coords), rgba_sep);
+            let out_coords = vec2<i32>(coords + vec2(config.target_offset_x, config.target_offset_y));
+            if config.premultiplied_output != 0u {
+                textureStore(output, out_coords, fg);
+            } else {
+                // Max with a small epsilon to avoid NaNs
+                let a_inv = 1.0 / max(fg.a, 1e-6);
+                let rgba_sep = vec4(fg.rgb * a_inv, fg.a);
+                textureStore(output, out_coords, rgba_sep);
+            }
         }
     } 
 }
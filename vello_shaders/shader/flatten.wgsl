@@ -373,7 +373,7 @@ fn flatten_euler(
         return;
     }
 
-    let tol = 0.25;
+    let tol = config.flatten_tolerance;
     var t0_u = 0u;
     var dt = 1.0;
     var last_p = p0;
@@ -498,7 +498,7 @@ fn flatten_arc(
     var r = begin - center;
 
     let MIN_THETA = 0.0001;
-    let tol = 0.25;
+    let tol = config.flatten_tolerance;
     let radius = max(tol, length(p0 - transform_apply(transform, center)));
     let theta = max(MIN_THETA, 2. * acos(1. - tol / radius));
 
@@ -845,7 +845,11 @@ fn main(
     let out = &path_bboxes[path_ix];
     let style_flags = scene[config.style_base + style_ix];
     // The fill bit is always set to 0 for strokes which represents a non-zero fill.
-    let draw_flags = select(DRAW_INFO_FLAGS_FILL_RULE_BIT, 0u, (style_flags & STYLE_FLAGS_FILL) == 0u);
+    let fill_rule_flag = select(DRAW_INFO_FLAGS_FILL_RULE_BIT, 0u, (style_flags & STYLE_FLAGS_FILL) == 0u);
+    let glyph_flag = select(0u, DRAW_INFO_FLAGS_GLYPH_BIT, (style_flags & STYLE_FLAGS_GLYPH) != 0u);
+    let aliased_flag = select(0u, DRAW_INFO_FLAGS_ALIASED_BIT, (style_flags & STYLE_FLAGS_ALIASED) != 0u);
+    let clear_flag = select(0u, DRAW_INFO_FLAGS_CLEAR_BIT, (style_flags & STYLE_FLAGS_CLEAR) != 0u);
+    let draw_flags = fill_rule_flag | glyph_flag | aliased_flag | clear_flag;
     if (tag.tag_byte & PATH_TAG_PATH) != 0u {
         (*out).draw_flags = draw_flags;
         (*out).trans_ix = trans_ix;
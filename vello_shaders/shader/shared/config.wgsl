@@ -30,6 +30,7 @@ struct Config {
 
     transform_base: u32,
     style_base: u32,
+    color_matrix_base: u32,
 
     // Sizes of bump allocated buffers (in element size units)
     lines_size: u32,
@@ -39,9 +40,29 @@ struct Config {
     segments_size: u32,
     blend_size: u32,
     ptcl_size: u32,
+
+    // Gamma applied to the coverage of glyph outlines, 1.0 for no adjustment.
+    text_gamma: f32,
+
+    // Non-zero to convert the alpha of blurred rects to MSAA sample coverage.
+    alpha_to_coverage: u32,
+
+    // The debug visualization to output instead of the scene, see `DebugView`.
+    debug_view: u32,
+
+    // Non-zero to write the output with premultiplied alpha.
+    premultiplied_output: u32,
+
+    // The maximum distance in pixels between a curve and its flattened lines.
+    flatten_tolerance: f32,
+
+    // The position in pixels of the region of the output image which is rendered into.
+    target_offset_x: u32,
+    target_offset_y: u32,
 }
 
 // Geometry of tiles and bins
+// The tile size must be kept in sync with `TILE_WIDTH` and `TILE_HEIGHT` in `vello_encoding`.
 
 const TILE_WIDTH = 16u;
 const TILE_HEIGHT = 16u;
@@ -30,6 +30,12 @@ const DRAWTAG_END_CLIP = 0x21u;
 /// draw object stream but get used after the draw objects have been reduced on the GPU.
 /// 0 represents a non-zero fill. 1 represents an even-odd fill.
 const DRAW_INFO_FLAGS_FILL_RULE_BIT = 1u;
+/// Set if the draw object is a glyph outline, so the text gamma applies to its coverage.
+const DRAW_INFO_FLAGS_GLYPH_BIT = 2u;
+/// Set if the draw object isn't anti-aliased, so its coverage is rounded to 0 or 1.
+const DRAW_INFO_FLAGS_ALIASED_BIT = 4u;
+/// Set if the draw object clears the content under it, rather than painting its brush.
+const DRAW_INFO_FLAGS_CLEAR_BIT = 8u;
 
 fn draw_monoid_identity() -> DrawMonoid {
     return DrawMonoid();
@@ -39,6 +39,10 @@ const STYLE_FLAGS_JOIN_BEVEL: u32 = 0u;
 const STYLE_FLAGS_JOIN_MITER: u32 = 0x10000000u;
 const STYLE_FLAGS_JOIN_ROUND: u32 = 0x20000000u;
 
+const STYLE_FLAGS_GLYPH: u32 = 0x00800000u;
+const STYLE_FLAGS_ALIASED: u32 = 0x00400000u;
+const STYLE_FLAGS_CLEAR: u32 = 0x00200000u;
+
 // TODO: Declare the remaining STYLE flags here.
 
 fn tag_monoid_identity() -> TagMonoid {
@@ -23,6 +23,7 @@ const CMD_BEGIN_CLIP = 10u;
 const CMD_END_CLIP = 11u;
 const CMD_JUMP = 12u;
 const CMD_BLUR_RECT = 13u;
+const CMD_CLEAR = 14u;
 
 // The individual PTCL structs are written here, but read/write is by
 // hand in the relevant shaders
@@ -103,9 +104,22 @@ struct CmdImage {
     quality: u32,
     alpha: f32,
     alpha_type: u32,
+    color_space: u32,
 }
 
 struct CmdEndClip {
     blend: u32,
     alpha: f32,
 }
+
+// The bits of `CmdEndClip.blend` from this shift up are non-zero if the layer has a color
+// matrix, in which case the 20 values of the matrix follow the command.
+// This must be kept in sync with `DrawBeginClip::COLOR_MATRIX_SHIFT` in `vello_encoding`.
+const END_CLIP_COLOR_MATRIX_SHIFT = 17u;
+
+// A row-major 4x5 color matrix, as for the SVG `feColorMatrix` filter.
+struct CmdColorMatrix {
+    // Each column is a row of the matrix, without its offset.
+    weights: mat4x4<f32>,
+    offset: vec4<f32>,
+}
@@ -4,13 +4,15 @@
 use std::cmp::max;
 
 use vello_encoding::{
-    BinHeader, BumpAllocators, ConfigUniform, DRAW_INFO_FLAGS_FILL_RULE_BIT, DrawMonoid, DrawTag,
-    Path, Tile,
+    BinHeader, BumpAllocators, ConfigUniform, DRAW_INFO_FLAGS_ALIASED_BIT,
+    DRAW_INFO_FLAGS_CLEAR_BIT, DRAW_INFO_FLAGS_FILL_RULE_BIT, DRAW_INFO_FLAGS_GLYPH_BIT,
+    DrawBeginClip, DrawMonoid, DrawTag, Path, Tile,
 };
 
 use super::{
-    CMD_BEGIN_CLIP, CMD_BLUR_RECT, CMD_COLOR, CMD_END, CMD_END_CLIP, CMD_FILL, CMD_IMAGE, CMD_JUMP,
-    CMD_LIN_GRAD, CMD_RAD_GRAD, CMD_SOLID, CMD_SWEEP_GRAD, CpuBinding, PTCL_INITIAL_ALLOC,
+    CMD_BEGIN_CLIP, CMD_BLUR_RECT, CMD_CLEAR, CMD_COLOR, CMD_END, CMD_END_CLIP, CMD_FILL,
+    CMD_IMAGE, CMD_JUMP, CMD_LIN_GRAD, CMD_RAD_GRAD, CMD_SOLID, CMD_SWEEP_GRAD, CpuBinding,
+    PTCL_INITIAL_ALLOC,
 };
 
 // Tiles per bin
@@ -25,6 +27,9 @@ const BLEND_STACK_SPLIT: u32 = 4;
 const TILE_WIDTH: u32 = 16;
 const TILE_HEIGHT: u32 = 16;
 
+// The value of `debug_view` for `vello::DebugView::PaintOrder`
+const DEBUG_VIEW_PAINT_ORDER: u32 = 4;
+
 const PTCL_INCREMENT: u32 = 256;
 const PTCL_HEADROOM: u32 = 2;
 
@@ -84,7 +89,12 @@ impl TileState {
             self.alloc_cmd(4, config, bump, ptcl);
             self.write(ptcl, 0, CMD_FILL);
             let even_odd = (draw_flags & DRAW_INFO_FLAGS_FILL_RULE_BIT) != 0;
-            let size_and_rule = (n_segs << 1) | (even_odd as u32);
+            let is_glyph = (draw_flags & DRAW_INFO_FLAGS_GLYPH_BIT) != 0;
+            let is_aliased = (draw_flags & DRAW_INFO_FLAGS_ALIASED_BIT) != 0;
+            let size_and_rule = (n_segs << 3)
+                | ((is_aliased as u32) << 2)
+                | ((is_glyph as u32) << 1)
+                | (even_odd as u32);
             self.write(ptcl, 1, size_and_rule);
             self.write(ptcl, 2, seg_ix);
             self.write(ptcl, 3, tile.backdrop as u32);
@@ -109,6 +119,12 @@ impl TileState {
         self.cmd_offset += 2;
     }
 
+    fn write_clear(&mut self, config: &ConfigUniform, bump: &mut BumpAllocators, ptcl: &mut [u32]) {
+        self.alloc_cmd(1, config, bump, ptcl);
+        self.write(ptcl, 0, CMD_CLEAR);
+        self.cmd_offset += 1;
+    }
+
     fn write_image(
         &mut self,
         config: &ConfigUniform,
@@ -169,14 +185,23 @@ impl TileState {
         config: &ConfigUniform,
         bump: &mut BumpAllocators,
         ptcl: &mut [u32],
+        scene: &[u32],
         blend: u32,
         alpha: f32,
     ) {
-        self.alloc_cmd(3, config, bump, ptcl);
+        let color_matrix_ix = blend >> DrawBeginClip::COLOR_MATRIX_SHIFT;
+        let size = if color_matrix_ix != 0 { 23 } else { 3 };
+        self.alloc_cmd(size, config, bump, ptcl);
         self.write(ptcl, 0, CMD_END_CLIP);
         self.write(ptcl, 1, blend);
         self.write(ptcl, 2, f32::to_bits(alpha));
-        self.cmd_offset += 3;
+        if color_matrix_ix != 0 {
+            let matrix_base = config.layout.color_matrix_base + (color_matrix_ix - 1) * 20;
+            for i in 0..20 {
+                self.write(ptcl, 3 + i, scene[(matrix_base + i) as usize]);
+            }
+        }
+        self.cmd_offset += size;
     }
 }
 
@@ -283,10 +308,27 @@ fn coarse_main(
                     let include_tile = n_segs != 0 || (backdrop_clear == is_clip) || is_blend;
                     if include_tile {
                         match DrawTag(drawtag) {
+                            // In the paint order debug view, every brush is replaced with a
+                            // color holding its draw object index, which fine turns into a heatmap.
+                            DrawTag::COLOR
+                            | DrawTag::IMAGE
+                            | DrawTag::LINEAR_GRADIENT
+                            | DrawTag::RADIAL_GRADIENT
+                            | DrawTag::SWEEP_GRADIENT
+                            | DrawTag::BLUR_RECT
+                                if config.debug_view == DEBUG_VIEW_PAINT_ORDER =>
+                            {
+                                tile_state.write_path(config, bump, ptcl, tile, draw_flags);
+                                tile_state.write_color(config, bump, ptcl, *drawobj_ix + 1);
+                            }
                             DrawTag::COLOR => {
                                 tile_state.write_path(config, bump, ptcl, tile, draw_flags);
-                                let rgba_color = scene[dd as usize];
-                                tile_state.write_color(config, bump, ptcl, rgba_color);
+                                if (draw_flags & DRAW_INFO_FLAGS_CLEAR_BIT) != 0 {
+                                    tile_state.write_clear(config, bump, ptcl);
+                                } else {
+                                    let rgba_color = scene[dd as usize];
+                                    tile_state.write_color(config, bump, ptcl, rgba_color);
+                                }
                             }
                             DrawTag::IMAGE => {
                                 tile_state.write_path(config, bump, ptcl, tile, draw_flags);
@@ -356,7 +398,7 @@ fn coarse_main(
                                 tile_state.write_path(config, bump, ptcl, tile, draw_flags);
                                 let blend = scene[dd as usize];
                                 let alpha = f32::from_bits(scene[dd as usize + 1]);
-                                tile_state.write_end_clip(config, bump, ptcl, blend, alpha);
+                                tile_state.write_end_clip(config, bump, ptcl, scene, blend, alpha);
                                 render_blend_depth -= 1;
                             }
                             _ => todo!(),
@@ -3,7 +3,9 @@
 
 use vello_encoding::{ConfigUniform, PathSegment, Tile};
 
-use super::{CMD_COLOR, CMD_END, CMD_FILL, CMD_JUMP, CMD_SOLID, CpuTexture, PTCL_INITIAL_ALLOC};
+use super::{
+    CMD_CLEAR, CMD_COLOR, CMD_END, CMD_FILL, CMD_JUMP, CMD_SOLID, CpuTexture, PTCL_INITIAL_ALLOC,
+};
 
 // These should also move into a common area
 const TILE_WIDTH: usize = 16;
@@ -49,7 +51,7 @@ fn pack4x8unorm(x: [f32; 4]) -> u32 {
 }
 
 fn fill_path(area: &mut [f32], segments: &[PathSegment], fill: &CmdFill, x_tile: f32, y_tile: f32) {
-    let n_segs = fill.size_and_rule >> 1;
+    let n_segs = fill.size_and_rule >> 3;
     let even_odd = (fill.size_and_rule & 1) != 0;
     let backdrop_f = fill.backdrop as f32;
     for a in area.iter_mut() {
@@ -144,6 +146,19 @@ fn fine_main(
                     let x0 = (tile_x as usize * TILE_WIDTH) as f32;
                     let y0 = (tile_y as usize * TILE_HEIGHT) as f32;
                     fill_path(&mut area, segments, &fill, x0, y0);
+                    let is_glyph = (fill.size_and_rule & 2) != 0;
+                    if is_glyph && config.text_gamma != 1.0 {
+                        let inv_gamma = config.text_gamma.recip();
+                        for a in area.iter_mut() {
+                            *a = a.powf(inv_gamma);
+                        }
+                    }
+                    let is_aliased = (fill.size_and_rule & 4) != 0;
+                    if is_aliased {
+                        for a in area.iter_mut() {
+                            *a = if *a >= 0.5 { 1.0 } else { 0.0 };
+                        }
+                    }
                     cmd_ix += 4;
                 }
                 CMD_SOLID => {
@@ -163,6 +178,14 @@ fn fine_main(
                     }
                     cmd_ix += 2;
                 }
+                CMD_CLEAR => {
+                    for i in 0..TILE_SIZE {
+                        for j in 0..4 {
+                            rgba[i][j] *= 1.0 - area[i];
+                        }
+                    }
+                    cmd_ix += 1;
+                }
                 CMD_JUMP => {
                     cmd_ix = ptcl[(cmd_ix + 1) as usize];
                 }
@@ -12,7 +12,8 @@ use super::{
 };
 use vello_encoding::math::f16_to_f32;
 use vello_encoding::{
-    BumpAllocators, ConfigUniform, DRAW_INFO_FLAGS_FILL_RULE_BIT, LineSoup, Monoid, PathBbox,
+    BumpAllocators, ConfigUniform, DRAW_INFO_FLAGS_ALIASED_BIT, DRAW_INFO_FLAGS_CLEAR_BIT,
+    DRAW_INFO_FLAGS_FILL_RULE_BIT, DRAW_INFO_FLAGS_GLYPH_BIT, LineSoup, Monoid, PathBbox,
     PathMonoid, PathTag, Style,
 };
 
@@ -162,6 +163,7 @@ fn flatten_arc(
     center: Vec2,
     angle: f32,
     transform: &Transform,
+    tol: f32,
     line_ix: &mut usize,
     lines: &mut [LineSoup],
     bbox: &mut IntBbox,
@@ -170,7 +172,6 @@ fn flatten_arc(
 
     let mut p0 = transform.apply(begin);
     let mut r = begin - center;
-    let tol: f32 = 0.25;
     let radius = tol.max((p0 - transform.apply(center)).length());
     let theta = (2. * (1. - tol / radius).acos()).max(MIN_THETA);
 
@@ -207,6 +208,7 @@ fn flatten_euler(
     offset: f32,
     start_p: Vec2,
     end_p: Vec2,
+    tol: f32,
     line_ix: &mut usize,
     lines: &mut [LineSoup],
     bbox: &mut IntBbox,
@@ -248,7 +250,6 @@ fn flatten_euler(
         return;
     }
 
-    let tol: f32 = 0.25;
     let mut t0_u: u32 = 0;
     let mut dt: f32 = 1.;
     let mut last_p = p0;
@@ -391,6 +392,7 @@ fn draw_cap(
     cap1: Vec2,
     offset_tangent: Vec2,
     transform: &Transform,
+    tol: f32,
     line_ix: &mut usize,
     lines: &mut [LineSoup],
     bbox: &mut IntBbox,
@@ -403,6 +405,7 @@ fn draw_cap(
             point,
             std::f32::consts::PI,
             transform,
+            tol,
             line_ix,
             lines,
             bbox,
@@ -433,6 +436,7 @@ fn draw_join(
     n_prev: Vec2,
     n_next: Vec2,
     transform: &Transform,
+    tol: f32,
     line_ix: &mut usize,
     lines: &mut [LineSoup],
     bbox: &mut IntBbox,
@@ -494,6 +498,7 @@ fn draw_join(
                 p0,
                 cr.atan2(d).abs(),
                 transform,
+                tol,
                 line_ix,
                 lines,
                 bbox,
@@ -673,6 +678,7 @@ fn flatten_main(
     lines: &mut [LineSoup],
 ) {
     let mut line_ix = 0;
+    let tol = config.flatten_tolerance;
     let pathtags = &scene[config.layout.path_tag_base as usize..];
     let pathdata = &scene[config.layout.path_data_base as usize..];
 
@@ -685,11 +691,27 @@ fn flatten_main(
         let style_flags = scene[(config.layout.style_base.wrapping_add(style_ix)) as usize];
         if (tag.tag_byte & PATH_TAG_PATH) != 0 {
             let out = &mut path_bboxes[path_ix as usize];
-            out.draw_flags = if (style_flags & Style::FLAGS_FILL_BIT) == 0 {
+            let fill_rule_flag = if (style_flags & Style::FLAGS_FILL_BIT) == 0 {
                 0
             } else {
                 DRAW_INFO_FLAGS_FILL_RULE_BIT
             };
+            let glyph_flag = if (style_flags & Style::FLAGS_GLYPH_BIT) == 0 {
+                0
+            } else {
+                DRAW_INFO_FLAGS_GLYPH_BIT
+            };
+            let aliased_flag = if (style_flags & Style::FLAGS_ALIASED_BIT) == 0 {
+                0
+            } else {
+                DRAW_INFO_FLAGS_ALIASED_BIT
+            };
+            let clear_flag = if (style_flags & Style::FLAGS_CLEAR_BIT) == 0 {
+                0
+            } else {
+                DRAW_INFO_FLAGS_CLEAR_BIT
+            };
+            out.draw_flags = fill_rule_flag | glyph_flag | aliased_flag | clear_flag;
             out.trans_ix = trans_ix;
         }
 
@@ -720,6 +742,7 @@ fn flatten_main(
                             pts.p0 + n,
                             -offset_tangent,
                             &transform,
+                            tol,
                             &mut line_ix,
                             lines,
                             &mut bbox,
@@ -772,6 +795,7 @@ fn flatten_main(
                         offset,
                         pts.p0 + n_start,
                         pts.p3 + n_prev,
+                        tol,
                         &mut line_ix,
                         lines,
                         &mut bbox,
@@ -783,6 +807,7 @@ fn flatten_main(
                         -offset,
                         pts.p0 - n_start,
                         pts.p3 - n_prev,
+                        tol,
                         &mut line_ix,
                         lines,
                         &mut bbox,
@@ -798,6 +823,7 @@ fn flatten_main(
                             n_prev,
                             n_next,
                             &transform,
+                            tol,
                             &mut line_ix,
                             lines,
                             &mut bbox,
@@ -812,6 +838,7 @@ fn flatten_main(
                             pts.p3 - n_prev,
                             offset_tangent,
                             &transform,
+                            tol,
                             &mut line_ix,
                             lines,
                             &mut bbox,
@@ -826,6 +853,7 @@ fn flatten_main(
                     /*offset*/ 0.,
                     pts.p0,
                     pts.p3,
+                    tol,
                     &mut line_ix,
                     lines,
                     &mut bbox,
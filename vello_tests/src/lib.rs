@@ -41,7 +41,10 @@ use vello::wgpu::{
     self, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Extent3d, TexelCopyBufferInfo,
     TextureDescriptor, TextureFormat, TextureUsages,
 };
-use vello::{AaConfig, RendererOptions, Scene, util::RenderContext, util::block_on_wgpu};
+use vello::{
+    AaConfig, ClearMode, DebugView, RendererOptions, Scene, util::RenderContext,
+    util::block_on_wgpu,
+};
 
 mod compare;
 mod snapshot;
@@ -58,6 +61,11 @@ pub struct TestParams {
     pub use_cpu: bool,
     pub name: String,
     pub anti_aliasing: AaConfig,
+    pub text_gamma: f32,
+    pub alpha_to_coverage: bool,
+    pub debug: DebugView,
+    pub premultiplied_alpha: bool,
+    pub flatten_tolerance: Option<f64>,
 }
 
 impl TestParams {
@@ -69,6 +77,11 @@ impl TestParams {
             use_cpu: false,
             name: name.into(),
             anti_aliasing: AaConfig::Area,
+            text_gamma: 1.0,
+            alpha_to_coverage: false,
+            debug: DebugView::None,
+            premultiplied_alpha: false,
+            flatten_tolerance: None,
         }
     }
 }
@@ -118,6 +131,9 @@ pub async fn get_scene_image(
             num_init_threads: NonZeroUsize::new(1),
             antialiasing_support: std::iter::once(params.anti_aliasing).collect(),
             pipeline_cache: None,
+            msaa_sample_pattern: None,
+            debug_validation: false,
+            intermediate_budget_bytes: None,
         },
     )
     .or_else(|_| bail!("Got non-Send/Sync error from creating renderer"))?;
@@ -128,6 +144,13 @@ pub async fn get_scene_image(
         width,
         height,
         antialiasing_method: params.anti_aliasing,
+        text_gamma: params.text_gamma,
+        alpha_to_coverage: params.alpha_to_coverage,
+        debug: params.debug,
+        premultiplied_alpha: params.premultiplied_alpha,
+        flatten_tolerance: params.flatten_tolerance,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
     };
     let size = Extent3d {
         width,
@@ -192,8 +215,11 @@ pub async fn get_scene_image(
         format: ImageFormat::Rgba8,
         width,
         height,
-        // TODO: Confirm
-        alpha_type: ImageAlphaType::Alpha,
+        alpha_type: if params.premultiplied_alpha {
+            ImageAlphaType::AlphaPremultiplied
+        } else {
+            ImageAlphaType::Alpha
+        },
     };
     Ok(image)
 }
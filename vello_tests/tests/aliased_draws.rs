@@ -0,0 +1,94 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for disabling anti-aliasing of individual draws using [`Scene::set_antialiasing`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Stroke};
+use vello::peniko::{Color, Fill, color::palette};
+use vello_encoding::Style;
+use vello_tests::TestParams;
+
+fn is_aliased(style: &Style) -> bool {
+    style.flags_and_miter_limit & Style::FLAGS_ALIASED_BIT != 0
+}
+
+#[test]
+fn aliased_flag_only_applies_while_disabled() {
+    let circle = Circle::new((20.0, 20.0), 10.0);
+    let mut scene = Scene::new();
+    assert!(scene.antialiasing(), "anti-aliasing is enabled by default");
+    scene.set_antialiasing(false);
+    assert!(!scene.antialiasing(), "anti-aliasing should be disabled");
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &circle,
+    );
+    scene.set_antialiasing(true);
+    scene.stroke(
+        &Stroke::new(2.0),
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &circle,
+    );
+    let styles = &scene.encoding().styles;
+    assert_eq!(styles.len(), 2, "the fill and stroke have separate styles");
+    assert!(is_aliased(&styles[0]), "the fill should be aliased");
+    assert!(!is_aliased(&styles[1]), "the stroke should be anti-aliased");
+
+    scene.set_antialiasing(false);
+    scene.reset();
+    assert!(
+        scene.antialiasing(),
+        "resetting enables anti-aliasing again"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn aliased_fill_has_hard_edges_next_to_smooth_stroke() {
+    let mut fill = Scene::new();
+    fill.set_antialiasing(false);
+    fill.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Circle::new((32.0, 32.0), 20.3),
+    );
+    let mut stroke = Scene::new();
+    stroke.stroke(
+        &Stroke::new(3.0),
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Circle::new((32.0, 32.0), 25.3),
+    );
+    let mut params = TestParams::new("aliased_draws", 64, 64);
+    params.base_color = Some(Color::TRANSPARENT);
+
+    let fill = vello_tests::render_then_debug_sync(&fill, &params).unwrap();
+    let alphas = || fill.data.data().chunks_exact(4).map(|pixel| pixel[3]);
+    assert!(
+        alphas().all(|alpha| alpha == 0 || alpha == 255),
+        "an aliased fill should only fully cover or not cover each pixel"
+    );
+    assert!(
+        alphas().any(|alpha| alpha == 255),
+        "the fill should be drawn"
+    );
+
+    let stroke = vello_tests::render_then_debug_sync(&stroke, &params).unwrap();
+    assert!(
+        stroke
+            .data
+            .data()
+            .chunks_exact(4)
+            .any(|pixel| (1..255).contains(&pixel[3])),
+        "the stroke should still be anti-aliased"
+    );
+}
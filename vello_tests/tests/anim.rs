@@ -0,0 +1,110 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the easing curves and tweens in [`vello::anim`].
+
+use vello::anim::{Animatable, Easing, Tween};
+use vello::kurbo::Vec2;
+use vello::peniko::color::palette;
+
+#[track_caller]
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "Expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn css_curves_match_reference_values() {
+    // Reference values computed by bisecting the Bézier curves to high precision.
+    let cases = [
+        (Easing::EASE, [0.408_510_59, 0.802_403_39, 0.960_458_98]),
+        (Easing::EASE_IN, [0.093_464_65, 0.315_356_81, 0.621_861_87]),
+        (Easing::EASE_OUT, [0.378_138_13, 0.684_643_19, 0.906_535_35]),
+        (Easing::EASE_IN_OUT, [0.129_161_93, 0.5, 0.870_838_07]),
+    ];
+    for (easing, expected) in cases {
+        for (x, expected) in [0.25, 0.5, 0.75].into_iter().zip(expected) {
+            assert_close(easing.eval(x), expected);
+        }
+        assert_close(easing.eval(0.0), 0.0);
+        assert_close(easing.eval(1.0), 1.0);
+    }
+}
+
+#[test]
+fn cubic_bezier_with_flat_sections() {
+    // The curve is flat in x near the ends, where Newton's method converges poorly.
+    let easing = Easing::cubic_bezier(1.0, 0.0, 0.0, 1.0);
+    assert_close(easing.eval(0.5), 0.5);
+    assert!(easing.eval(0.01) < 0.01, "Should start slowly");
+    assert!(easing.eval(0.99) > 0.99, "Should end slowly");
+    assert_close(Easing::cubic_bezier(0.0, 0.0, 1.0, 1.0).eval(0.3), 0.3);
+}
+
+#[test]
+fn spring_overshoots_then_settles() {
+    let easing = Easing::Spring {
+        stiffness: 100.0,
+        damping: 10.0,
+    };
+    assert_close(easing.eval(0.1), 0.340_299_85);
+    assert_close(easing.eval(0.25), 1.023_359_58);
+    assert_close(easing.eval(0.5), 1.074_590_57);
+    assert_close(easing.eval(1.0), 1.0);
+
+    let critical = Easing::Spring {
+        stiffness: 100.0,
+        damping: 20.0,
+    };
+    let overdamped = Easing::Spring {
+        stiffness: 100.0,
+        damping: 40.0,
+    };
+    for i in 1..10 {
+        let t = f64::from(i) / 10.0;
+        assert!(
+            critical.eval(t) < 1.0,
+            "Critically damped spring shouldn't overshoot"
+        );
+        assert!(
+            overdamped.eval(t) < critical.eval(t),
+            "Overdamped spring should be slower than critically damped"
+        );
+    }
+}
+
+#[test]
+fn tween_ticks_to_target() {
+    let mut tween = Tween::new(0.0, 10.0, 1.0, Easing::Linear);
+    assert_close(tween.tick(0.25), 2.5);
+    assert_close(tween.tick(0.25), 5.0);
+    assert!(!tween.is_finished(), "Tween ended early");
+    assert_close(tween.tick(10.0), 10.0);
+    assert!(tween.is_finished(), "Tween should have ended");
+
+    tween.retarget(20.0);
+    assert_close(tween.value(), 10.0);
+    assert_close(tween.tick(0.5), 15.0);
+}
+
+#[test]
+fn tween_animates_vectors_and_colors() {
+    let mut offset = Tween::new(Vec2::ZERO, Vec2::new(4.0, -8.0), 2.0, Easing::Linear);
+    assert_eq!(offset.tick(1.0), Vec2::new(2.0, -4.0));
+
+    let color = palette::css::BLACK.interpolate(&palette::css::WHITE, 0.5);
+    let [r, g, b, a] = color.components;
+    assert!(
+        (r - 0.5).abs() < 1e-6 && r == g && g == b,
+        "Unexpected {color:?}"
+    );
+    assert!((a - 1.0).abs() < 1e-6, "Alpha should be unchanged");
+    // Colors don't extrapolate when a curve overshoots.
+    let overshoot = palette::css::BLACK.interpolate(&palette::css::WHITE, 1.5);
+    assert!(
+        overshoot.difference(palette::css::WHITE) < 1e-6,
+        "Unexpected {overshoot:?}"
+    );
+}
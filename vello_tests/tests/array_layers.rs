@@ -0,0 +1,140 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for rendering into the layers of a texture array, using [`Renderer::array_layer_target`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::util::RenderContext;
+use vello::wgpu::{self, Device, Queue, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const SIZE: u32 = 16;
+
+fn create_texture(device: &Device, layers: u32, usage: TextureUsages) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: layers,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage,
+        view_formats: &[],
+    })
+}
+
+fn solid_scene(color: Color) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        color,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)),
+    );
+    scene
+}
+
+/// Reads the center pixel of `layer` by copying it into a separate texture.
+fn read_layer(
+    renderer: &Renderer,
+    device: &Device,
+    queue: &Queue,
+    array: &wgpu::Texture,
+    layer: u32,
+) -> Color {
+    let single = create_texture(device, 1, TextureUsages::COPY_DST | TextureUsages::COPY_SRC);
+    let mut encoder = device.create_command_encoder(&Default::default());
+    encoder.copy_texture_to_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: array,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: 0,
+                y: 0,
+                z: layer,
+            },
+            aspect: wgpu::TextureAspect::All,
+        },
+        single.as_image_copy(),
+        wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit([encoder.finish()]);
+    renderer
+        .read_pixel(device, queue, &single, SIZE / 2, SIZE / 2)
+        .unwrap()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn render_into_array_layers() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let queue = &context.devices[device_id].queue;
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let array = create_texture(
+        device,
+        2,
+        TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+    );
+    let params = RenderParams {
+        base_color: palette::css::BLACK,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    let colors = [palette::css::RED, palette::css::BLUE];
+    for (layer, color) in (0..).zip(colors) {
+        let view = renderer
+            .array_layer_target(&array, layer, params.clear_mode)
+            .unwrap();
+        renderer
+            .render_to_texture(device, queue, &solid_scene(color), &view, &params)
+            .unwrap();
+    }
+    for (layer, color) in (0..).zip(colors) {
+        let pixel = read_layer(&renderer, device, queue, &array, layer);
+        assert_eq!(pixel.to_rgba8(), color.to_rgba8(), "layer {layer}");
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn layer_out_of_bounds_is_an_error() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let array = create_texture(device, 2, TextureUsages::STORAGE_BINDING);
+    let result = renderer.array_layer_target(&array, 2, ClearMode::Clear);
+    assert!(
+        matches!(
+            result,
+            Err(Error::ArrayLayerOutOfBounds {
+                layer: 2,
+                layers: 2
+            })
+        ),
+        "Unexpected result {:?}",
+        result.err()
+    );
+}
@@ -0,0 +1,144 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that batched drawing methods on [`Scene`] match their unbatched equivalents.
+
+use vello::kurbo::{Affine, RoundedRect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::{BoxShadowInstance, Scene};
+use vello_tests::TestParams;
+
+fn cards() -> Vec<(RoundedRect, Color)> {
+    (0..12)
+        .map(|i| {
+            let x = (i % 4) as f64 * 35.0 + 5.0;
+            let y = (i / 4) as f64 * 30.0 + 5.0;
+            let color = if i % 2 == 0 {
+                palette::css::ORANGE
+            } else {
+                palette::css::TEAL.with_alpha(0.5)
+            };
+            (RoundedRect::new(x, y, x + 40.0, y + 25.0, 5.0), color)
+        })
+        .collect()
+}
+
+#[test]
+fn fill_rounded_rects_matches_fill_encoding() {
+    let cards = cards();
+    let transform = Affine::translate((3.0, 4.0));
+    let mut individual = Scene::new();
+    for (rect, color) in &cards {
+        individual.fill(Fill::NonZero, transform, *color, None, rect);
+    }
+    let mut batched = Scene::new();
+    batched.fill_rounded_rects(transform, &cards);
+    let (individual, batched) = (individual.encoding(), batched.encoding());
+    assert!(
+        individual.path_tags == batched.path_tags,
+        "Path tags differ"
+    );
+    assert_eq!(individual.path_data, batched.path_data);
+    assert!(
+        individual.draw_tags == batched.draw_tags,
+        "Draw tags differ"
+    );
+    assert_eq!(individual.draw_data, batched.draw_data);
+    assert_eq!(individual.transforms, batched.transforms);
+    assert_eq!(individual.styles, batched.styles);
+}
+
+#[test]
+fn fill_rounded_rects_empty_is_noop() {
+    let mut scene = Scene::new();
+    scene.fill_rounded_rects(Affine::IDENTITY, &[]);
+    assert!(scene.encoding().is_empty());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn fill_rounded_rects_renders_like_fill() {
+    let cards = cards();
+    let mut individual = Scene::new();
+    for (rect, color) in &cards {
+        individual.fill(Fill::NonZero, Affine::IDENTITY, *color, None, rect);
+    }
+    let mut batched = Scene::new();
+    batched.fill_rounded_rects(Affine::IDENTITY, &cards);
+    let params = TestParams::new("fill_rounded_rects", 150, 100);
+    let expected = vello_tests::render_then_debug_sync(&individual, &params).unwrap();
+    let actual = vello_tests::render_then_debug_sync(&batched, &params).unwrap();
+    assert!(
+        expected.data.data() == actual.data.data(),
+        "Batched rounded rects should render identically to individual fills"
+    );
+}
+
+fn shadows() -> Vec<BoxShadowInstance> {
+    cards()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (rect, color))| BoxShadowInstance {
+            transform: Affine::translate((2.0, 3.0)),
+            rect: rect.rect(),
+            color: color.with_alpha(0.6),
+            radius: rect.radii().top_left,
+            std_dev: 1.0 + i as f64 * 0.5,
+        })
+        .collect()
+}
+
+#[test]
+fn draw_box_shadows_batch_matches_individual_encoding() {
+    let shadows = shadows();
+    let mut individual = Scene::new();
+    for shadow in &shadows {
+        individual.draw_blurred_rounded_rect(
+            shadow.transform,
+            shadow.rect,
+            shadow.color,
+            shadow.radius,
+            shadow.std_dev,
+        );
+    }
+    let mut batched = Scene::new();
+    batched.draw_box_shadows_batch(&shadows);
+    let (individual, batched) = (individual.encoding(), batched.encoding());
+    assert!(
+        individual.path_tags == batched.path_tags,
+        "Path tags differ"
+    );
+    assert_eq!(individual.path_data, batched.path_data);
+    assert!(
+        individual.draw_tags == batched.draw_tags,
+        "Draw tags differ"
+    );
+    assert_eq!(individual.draw_data, batched.draw_data);
+    assert_eq!(individual.transforms, batched.transforms);
+    assert_eq!(individual.styles, batched.styles);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn draw_box_shadows_batch_renders_like_individual() {
+    let shadows = shadows();
+    let mut individual = Scene::new();
+    for shadow in &shadows {
+        individual.draw_blurred_rounded_rect(
+            shadow.transform,
+            shadow.rect,
+            shadow.color,
+            shadow.radius,
+            shadow.std_dev,
+        );
+    }
+    let mut batched = Scene::new();
+    batched.draw_box_shadows_batch(&shadows);
+    let params = TestParams::new("draw_box_shadows_batch", 150, 100);
+    let expected = vello_tests::render_then_debug_sync(&individual, &params).unwrap();
+    let actual = vello_tests::render_then_debug_sync(&batched, &params).unwrap();
+    assert!(
+        expected.data.data() == actual.data.data(),
+        "Batched shadows should render identically to individual shadows"
+    );
+}
@@ -0,0 +1,156 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for blurred rounded rectangles with degenerate standard deviations.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Rect, RoundedRect};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+const RECT: Rect = Rect::new(8.5, 10.0, 52.0, 44.25);
+
+fn assert_same_encoding(a: &Scene, b: &Scene) {
+    let (a, b) = (a.encoding(), b.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.draw_data, b.draw_data);
+    assert_eq!(a.transforms, b.transforms);
+}
+
+fn plain_fill() -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::rotate(0.1),
+        palette::css::BLUE,
+        None,
+        &RoundedRect::from_rect(RECT, 6.0),
+    );
+    scene
+}
+
+#[test]
+fn zero_std_dev_is_a_plain_fill() {
+    let mut blurred = Scene::new();
+    blurred.draw_blurred_rounded_rect(Affine::rotate(0.1), RECT, palette::css::BLUE, 6.0, 0.0);
+    assert_same_encoding(&blurred, &plain_fill());
+
+    let mut batched = Scene::new();
+    batched.draw_box_shadows_batch(&[vello::BoxShadowInstance {
+        transform: Affine::rotate(0.1),
+        rect: RECT,
+        color: palette::css::BLUE,
+        radius: 6.0,
+        std_dev: 0.0,
+    }]);
+    assert_same_encoding(&batched, &plain_fill());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn zero_std_dev_renders_as_plain_fill() {
+    let mut blurred = Scene::new();
+    blurred.draw_blurred_rounded_rect(Affine::rotate(0.1), RECT, palette::css::BLUE, 6.0, 0.0);
+    let params = TestParams::new("zero_std_dev", 64, 64);
+    let blurred = vello_tests::render_then_debug_sync(&blurred, &params).unwrap();
+    let plain = vello_tests::render_then_debug_sync(&plain_fill(), &params).unwrap();
+    assert_eq!(blurred.data.data(), plain.data.data());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn zero_std_dev_in_clip_shape_is_crisp() {
+    let mut scene = Scene::new();
+    // The clip shape isn't a rectangle, so this can't be replaced with a plain fill.
+    scene.draw_blurred_rounded_rect_in(
+        &Circle::new((32.0, 32.0), 20.0),
+        Affine::IDENTITY,
+        RECT,
+        palette::css::WHITE,
+        6.0,
+        0.0,
+    );
+    let image =
+        vello_tests::render_then_debug_sync(&scene, &TestParams::new("zero_std_dev_in", 64, 64))
+            .unwrap();
+    let pixel = |x: usize, y: usize| image.data.data()[(y * 64 + x) * 4];
+    // Inside both the rectangle and the circle.
+    assert_eq!(pixel(32, 32), 255);
+    // Inside the rectangle, but outside the circle.
+    assert_eq!(pixel(10, 12), 0);
+    // Inside the circle, but outside the rectangle.
+    assert_eq!(pixel(32, 48), 0);
+}
+
+#[test]
+fn negative_std_dev_is_clamped_to_zero() {
+    for std_dev in [-3.0, -f64::MIN_POSITIVE, f64::NAN, f64::NEG_INFINITY] {
+        let mut blurred = Scene::new();
+        blurred.draw_blurred_rounded_rect(
+            Affine::rotate(0.1),
+            RECT,
+            palette::css::BLUE,
+            6.0,
+            std_dev,
+        );
+        assert_same_encoding(&blurred, &plain_fill());
+
+        let mut clipped = Scene::new();
+        clipped.draw_blurred_rounded_rect_in(
+            &Circle::new((32.0, 32.0), 20.0),
+            Affine::IDENTITY,
+            RECT,
+            palette::css::WHITE,
+            6.0,
+            std_dev,
+        );
+        let mut zero = Scene::new();
+        zero.draw_blurred_rounded_rect_in(
+            &Circle::new((32.0, 32.0), 20.0),
+            Affine::IDENTITY,
+            RECT,
+            palette::css::WHITE,
+            6.0,
+            0.0,
+        );
+        assert_same_encoding(&clipped, &zero);
+    }
+}
+
+#[test]
+fn extreme_std_dev_is_clamped() {
+    let transform = Affine::scale(4.0);
+    let max = Scene::max_blur_std_dev(transform);
+    assert_eq!(
+        max,
+        Scene::MAX_BLUR_STD_DEV / 4.0,
+        "the limit is in device pixels"
+    );
+    for std_dev in [max * 2.0, 1e30, f64::MAX] {
+        let mut extreme = Scene::new();
+        extreme.draw_blurred_rounded_rect(transform, RECT, palette::css::BLUE, 6.0, std_dev);
+        let mut clamped = Scene::new();
+        clamped.draw_blurred_rounded_rect(transform, RECT, palette::css::BLUE, 6.0, max);
+        assert_same_encoding(&extreme, &clamped);
+    }
+
+    let mask = Scene::new().draw_shadow_mask(RECT.size(), 6.0, 1e30);
+    let limit = (RECT.width() + 5.0 * Scene::MAX_BLUR_STD_DEV).ceil() as u32;
+    assert_eq!(mask.width, limit, "the mask is limited by the clamped blur");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn extreme_std_dev_renders() {
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, palette::css::BLUE, 6.0, 1e30);
+    let params = TestParams::new("extreme_std_dev", 64, 64);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    assert_eq!(
+        image.data.data().len(),
+        64 * 64 * 4,
+        "the scene should render"
+    );
+}
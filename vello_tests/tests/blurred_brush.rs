@@ -0,0 +1,136 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for blurred rounded rectangles and glows drawn with brushes other than a solid color.
+
+use vello::kurbo::{Affine, Rect, RoundedRect};
+use vello::peniko::{Gradient, color::palette};
+use vello::{GlowKind, Scene};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+const RECT: Rect = Rect::new(12.0, 12.0, 52.0, 52.0);
+
+fn gradient() -> Gradient {
+    Gradient::new_linear((RECT.x0, 0.0), (RECT.x1, 0.0))
+        .with_stops([palette::css::RED, palette::css::BLUE])
+}
+
+#[test]
+fn solid_color_is_a_single_draw() {
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, palette::css::RED, 4.0, 3.0);
+    assert_eq!(scene.encoding().draw_tags, [DrawTag::BLUR_RECT]);
+}
+
+#[test]
+fn gradient_is_masked_by_the_blur() {
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, &gradient(), 4.0, 3.0);
+    assert_eq!(
+        scene.encoding().draw_tags,
+        [
+            DrawTag::BEGIN_CLIP,
+            DrawTag::BLUR_RECT,
+            DrawTag::BEGIN_CLIP,
+            DrawTag::LINEAR_GRADIENT,
+            DrawTag::END_CLIP,
+            DrawTag::END_CLIP,
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn blurred_gradient_has_the_coverage_of_a_blurred_color() {
+    let mut gradient_scene = Scene::new();
+    gradient_scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, &gradient(), 4.0, 3.0);
+    let mut color_scene = Scene::new();
+    color_scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, palette::css::RED, 4.0, 3.0);
+
+    let params = TestParams::new("blurred_gradient", 64, 64);
+    let gradient_image = vello_tests::render_then_debug_sync(&gradient_scene, &params).unwrap();
+    let color_image = vello_tests::render_then_debug_sync(&color_scene, &params).unwrap();
+    let gradient_pixels = gradient_image.data.data();
+    let color_pixels = color_image.data.data();
+    for (gradient_pixel, color_pixel) in gradient_pixels
+        .chunks_exact(4)
+        .zip(color_pixels.chunks_exact(4))
+    {
+        assert!(
+            gradient_pixel[3].abs_diff(color_pixel[3]) <= 1,
+            "the alpha of {gradient_pixel:?} differs from {color_pixel:?}"
+        );
+    }
+
+    let pixel = |x: usize, y: usize| &gradient_pixels[(y * 64 + x) * 4..][..4];
+    // The gradient is sampled at full resolution, so it fades from red to blue across the rect.
+    let left = pixel(16, 32);
+    let right = pixel(48, 32);
+    assert!(left[0] > left[2], "{left:?} should be mostly red");
+    assert!(right[2] > right[0], "{right:?} should be mostly blue");
+    // The edges are blurred, as for a solid color.
+    let edge = pixel(12, 32);
+    assert!(
+        edge[3] > 64 && edge[3] < 192,
+        "{edge:?} should be partly covered"
+    );
+}
+
+fn radial_gradient() -> Gradient {
+    Gradient::new_radial(RECT.center(), (0.5 * RECT.width()) as f32)
+        .with_stops([palette::css::ORANGE, palette::css::BLUE])
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn radial_gradient_shadow_varies_in_hue() {
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(Affine::IDENTITY, RECT, &radial_gradient(), 4.0, 3.0);
+    let params = TestParams::new("radial_gradient_shadow", 64, 64);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixels = image.data.data();
+    let pixel = |x: usize, y: usize| &pixels[(y * 64 + x) * 4..][..4];
+    let center = pixel(32, 32);
+    assert!(
+        center[0] > center[2],
+        "{center:?} should be warm at the center"
+    );
+    for edge in [pixel(14, 32), pixel(32, 14), pixel(50, 50)] {
+        assert!(
+            edge[2] > edge[0],
+            "{edge:?} should be cool towards the edges"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn glow_accepts_gradient() {
+    let shape = RoundedRect::from_rect(RECT.inflate(-8.0, -8.0), 4.0);
+    let gradient = Gradient::new_linear((0.0, 0.0), (64.0, 0.0))
+        .with_stops([palette::css::RED, palette::css::BLUE]);
+    let mut scene = Scene::new();
+    scene.draw_glow_rounded_rect(
+        Affine::IDENTITY,
+        shape,
+        &gradient,
+        4.0,
+        3.0,
+        GlowKind::Outer,
+    );
+    let params = TestParams::new("gradient_glow", 64, 64);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixels = image.data.data();
+    let pixel = |x: usize, y: usize| &pixels[(y * 64 + x) * 4..][..4];
+    let left = pixel(16, 32);
+    let right = pixel(48, 32);
+    assert!(left[0] > left[2], "{left:?} should be mostly red");
+    assert!(right[2] > right[0], "{right:?} should be mostly blue");
+    let inside = pixel(32, 32);
+    assert_eq!(
+        inside[..3],
+        [0, 0, 0],
+        "an outer glow doesn't cover the shape"
+    );
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the `brush_transform` of [`Scene::fill`], which moves the brush independently
+//! of the shape.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{
+    Extend, Fill, ImageAlphaType, ImageBrush, ImageData, ImageFormat, ImageQuality,
+};
+use vello_tests::TestParams;
+
+const WIDTH: u32 = 32;
+const HEIGHT: u32 = 8;
+
+/// A repeating image with a red pixel followed by a blue pixel, scaled up to 8 pixels each.
+fn stripes() -> ImageBrush {
+    let blob: Vec<u8> = vec![255, 0, 0, 255, 0, 0, 255, 255];
+    ImageBrush::new(ImageData {
+        data: blob.into(),
+        format: ImageFormat::Rgba8,
+        width: 2,
+        height: 1,
+        alpha_type: ImageAlphaType::Alpha,
+    })
+    .with_quality(ImageQuality::Low)
+    .with_extend(Extend::Repeat)
+}
+
+/// Fills the whole target with the stripes, scrolled by `offset` pixels.
+fn scene(offset: f64) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &stripes(),
+        Some(Affine::translate((offset, 0.0)) * Affine::scale(8.0)),
+        &Rect::new(0.0, 0.0, f64::from(WIDTH), f64::from(HEIGHT)),
+    );
+    scene
+}
+
+fn red_at(image: &ImageData, x: u32) -> bool {
+    let index = usize::try_from((HEIGHT / 2 * WIDTH + x) * 4).unwrap();
+    let pixel = &image.data.data()[index..index + 4];
+    assert!(
+        pixel[0].abs_diff(pixel[2]) > 250,
+        "Expected red or blue at {x}, got {pixel:?}"
+    );
+    pixel[0] > pixel[2]
+}
+
+#[test]
+fn scrolling_only_changes_brush() {
+    let still = scene(0.0);
+    let scrolled = scene(8.0);
+    assert_eq!(
+        still.encoding().path_data,
+        scrolled.encoding().path_data,
+        "The shape shouldn't move"
+    );
+    assert_ne!(
+        still.encoding().transforms,
+        scrolled.encoding().transforms,
+        "The brush should move"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn image_scrolls_within_static_rect() {
+    let params = TestParams::new("brush_transform", WIDTH, HEIGHT);
+    for (frame, offset) in [(0, 0.0), (1, 8.0), (2, 16.0)] {
+        let image = vello_tests::render_then_debug_sync(&scene(offset), &params).unwrap();
+        for stripe in 0..WIDTH / 8 {
+            // The stripe under each pixel moves by one for every frame.
+            let expected = (stripe + frame) % 2 == 0;
+            assert_eq!(
+                red_at(&image, stripe * 8 + 4),
+                expected,
+                "Unexpected stripe {stripe} with an offset of {offset}"
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Canvas`], which should encode the same commands as drawing into a [`Scene`] directly.
+
+use vello::Scene;
+use vello::canvas::Canvas;
+use vello::kurbo::{Affine, Circle, Line, Rect, RoundedRect, Stroke};
+use vello::peniko::{Fill, color::palette};
+
+fn assert_same_encoding(a: &Scene, b: &Scene) {
+    let (a, b) = (a.encoding(), b.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.draw_data, b.draw_data);
+    assert_eq!(a.transforms, b.transforms);
+    assert_eq!(a.styles, b.styles);
+}
+
+#[test]
+fn canvas_matches_scene_calls() {
+    let mut canvas_scene = Scene::new();
+    let mut canvas = Canvas::new(&mut canvas_scene);
+    canvas.translate((10.0, 20.0));
+    canvas.rect(0.0, 0.0, 100.0, 40.0).fill(palette::css::TEAL);
+    canvas.set_line_width(3.0);
+    canvas
+        .rounded_rect(5.0, 5.0, 30.0, 20.0, 4.0)
+        .fill(palette::css::RED)
+        .stroke(palette::css::WHITE);
+    canvas.scale(2.0, 2.0);
+    canvas
+        .line((0.0, 0.0), (10.0, 10.0))
+        .stroke(palette::css::BLUE);
+
+    let mut direct = Scene::new();
+    let transform = Affine::translate((10.0, 20.0));
+    direct.fill(
+        Fill::NonZero,
+        transform,
+        palette::css::TEAL,
+        None,
+        &Rect::new(0.0, 0.0, 100.0, 40.0),
+    );
+    let rounded_rect = RoundedRect::new(5.0, 5.0, 35.0, 25.0, 4.0);
+    direct.fill(
+        Fill::NonZero,
+        transform,
+        palette::css::RED,
+        None,
+        &rounded_rect,
+    );
+    direct.stroke(
+        &Stroke::new(3.0),
+        transform,
+        palette::css::WHITE,
+        None,
+        &rounded_rect,
+    );
+    direct.stroke(
+        &Stroke::new(3.0),
+        transform * Affine::scale(2.0),
+        palette::css::BLUE,
+        None,
+        &Line::new((0.0, 0.0), (10.0, 10.0)),
+    );
+    assert_same_encoding(&canvas_scene, &direct);
+}
+
+#[test]
+fn paint_uses_styles_and_save_restores_them() {
+    let mut canvas_scene = Scene::new();
+    let mut canvas = Canvas::new(&mut canvas_scene);
+    canvas.set_fill_style(palette::css::ORANGE);
+    canvas.set_stroke_style(palette::css::BLACK);
+    canvas.save();
+    canvas.rotate(0.5);
+    canvas.clear_fill_style();
+    canvas.set_fill_rule(Fill::EvenOdd);
+    canvas.circle((0.0, 0.0), 10.0).paint();
+    canvas.restore();
+    canvas.circle((0.0, 0.0), 10.0).paint();
+    // Restoring without a matching save has no effect.
+    canvas.restore();
+    assert_eq!(canvas.transform(), Affine::IDENTITY);
+
+    let circle = Circle::new((0.0, 0.0), 10.0);
+    let mut direct = Scene::new();
+    direct.stroke(
+        &Stroke::new(1.0),
+        Affine::rotate(0.5),
+        palette::css::BLACK,
+        None,
+        &circle,
+    );
+    direct.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::ORANGE,
+        None,
+        &circle,
+    );
+    direct.stroke(
+        &Stroke::new(1.0),
+        Affine::IDENTITY,
+        palette::css::BLACK,
+        None,
+        &circle,
+    );
+    assert_same_encoding(&canvas_scene, &direct);
+}
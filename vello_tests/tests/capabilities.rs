@@ -0,0 +1,50 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::capabilities`].
+
+use vello::util::RenderContext;
+use vello::wgpu;
+use vello::{AaConfig, AaSupport, Renderer, RendererOptions};
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn capabilities_match_device() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let device = &device_handle.device;
+    let adapter = device_handle.adapter();
+    let antialiasing: AaSupport = [AaConfig::Area, AaConfig::Msaa16].into_iter().collect();
+    let renderer = Renderer::new(
+        device,
+        RendererOptions {
+            antialiasing_support: antialiasing,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let caps = renderer.capabilities(adapter, device);
+    assert_eq!(caps.antialiasing, antialiasing);
+    assert_eq!(
+        caps.max_texture_size,
+        device.limits().max_texture_dimension_2d
+    );
+    assert!(caps.max_texture_size >= 2048);
+    let features = device.features();
+    assert_eq!(
+        caps.timestamps,
+        features.contains(wgpu::Features::TIMESTAMP_QUERY)
+    );
+    assert_eq!(
+        caps.subgroup_ops,
+        features.contains(wgpu::Features::SUBGROUP)
+    );
+    assert_eq!(
+        caps.float_targets,
+        adapter
+            .get_texture_format_features(wgpu::TextureFormat::Rgba32Float)
+            .flags
+            .contains(wgpu::TextureFormatFeatureFlags::BLENDABLE)
+    );
+}
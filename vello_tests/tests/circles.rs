@@ -0,0 +1,193 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_circle`] and [`Scene::fill_ellipse`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Ellipse, Point};
+use vello::peniko::{Color, Fill, Gradient, color::palette};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+fn gradient() -> Gradient {
+    Gradient::new_linear((0.0, 0.0), (60.0, 0.0))
+        .with_stops([palette::css::TEAL, palette::css::ORANGE])
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn fill_circle_matches_fill() {
+    let transform = Affine::translate((3.5, 1.25));
+    let mut convenience = Scene::new();
+    convenience.fill_circle(transform, &gradient(), (30.0, 30.0), 24.5);
+    let mut generic = Scene::new();
+    generic.fill(
+        Fill::NonZero,
+        transform,
+        &gradient(),
+        None,
+        &Circle::new((30.0, 30.0), 24.5),
+    );
+    assert_eq!(
+        convenience.encoding().path_data,
+        generic.encoding().path_data
+    );
+
+    let params = TestParams::new("fill_circle", 64, 64);
+    let convenience = vello_tests::render_then_debug_sync(&convenience, &params).unwrap();
+    let generic = vello_tests::render_then_debug_sync(&generic, &params).unwrap();
+    assert_eq!(convenience.data.data(), generic.data.data());
+}
+
+#[test]
+fn fill_ellipse_matches_fill() {
+    let mut convenience = Scene::new();
+    convenience.fill_ellipse(Affine::rotate(0.3), &gradient(), (40.0, 20.0), (30.0, 12.0));
+    let mut generic = Scene::new();
+    generic.fill(
+        Fill::NonZero,
+        Affine::rotate(0.3),
+        &gradient(),
+        None,
+        &Ellipse::new((40.0, 20.0), (30.0, 12.0), 0.0),
+    );
+    let (a, b) = (convenience.encoding(), generic.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.draw_data, b.draw_data);
+    assert_eq!(a.transforms, b.transforms);
+}
+
+#[test]
+fn solid_circle_is_not_flattened() {
+    let mut analytic = Scene::new();
+    analytic.fill_circle(Affine::IDENTITY, palette::css::WHITE, (256.0, 256.0), 250.0);
+    let mut path = Scene::new();
+    path.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Circle::new((256.0, 256.0), 250.0),
+    );
+    // The analytic circle is bounded by a rectangle, whose coverage is computed from the
+    // circle's signed distance rather than from the Béziers of the circle.
+    assert_eq!(analytic.encoding().draw_tags, [DrawTag::BLUR_RECT]);
+    assert_eq!(path.encoding().draw_tags, [DrawTag::COLOR]);
+}
+
+#[test]
+fn degenerate_ellipse_is_filled_as_path() {
+    let mut analytic = Scene::new();
+    analytic.fill_ellipse(
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        (10.0, 10.0),
+        (5.0, 0.0),
+    );
+    let mut path = Scene::new();
+    path.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Ellipse::new((10.0, 10.0), (5.0, 0.0), 0.0),
+    );
+    assert_eq!(analytic.encoding().draw_tags, path.encoding().draw_tags);
+    assert_eq!(analytic.encoding().path_data, path.encoding().path_data);
+
+    let mut nan = Scene::new();
+    nan.fill_circle(Affine::IDENTITY, palette::css::WHITE, (f64::NAN, 10.0), 5.0);
+    assert!(
+        nan.encoding().is_empty(),
+        "A circle with a NaN center shouldn't be drawn"
+    );
+}
+
+/// Draws a white circle into a scene prepared by `setup`, using both [`Scene::fill_circle`]
+/// and [`Scene::fill`], and asserts that they encode the same path.
+fn assert_filled_as_path(setup: impl Fn(&mut Scene)) {
+    let circle = Circle::new((20.0, 20.0), 12.0);
+    let mut analytic = Scene::new();
+    setup(&mut analytic);
+    analytic.fill_circle(
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        circle.center,
+        circle.radius,
+    );
+    let mut path = Scene::new();
+    setup(&mut path);
+    path.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &circle,
+    );
+    let (a, b) = (analytic.encoding(), path.encoding());
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.path_data, b.path_data);
+}
+
+#[test]
+fn aliased_circle_is_filled_as_path() {
+    assert_filled_as_path(|scene| scene.set_antialiasing(false));
+}
+
+/// Renders `scene` in white on black, and returns the largest difference between the
+/// rendered coverage and the coverage of `inside`, estimated by supersampling each pixel.
+fn max_coverage_error(scene: &Scene, size: u32, inside: impl Fn(Point) -> bool) -> f64 {
+    const SAMPLES: u32 = 16;
+    let mut params = TestParams::new("analytic_coverage", size, size);
+    params.base_color = Some(Color::BLACK);
+    let image = vello_tests::render_then_debug_sync(scene, &params).unwrap();
+    let data = image.data.data();
+    let mut max_error: f64 = 0.0;
+    for y in 0..size {
+        for x in 0..size {
+            let mut covered = 0;
+            for sy in 0..SAMPLES {
+                for sx in 0..SAMPLES {
+                    let p = Point::new(
+                        f64::from(x) + (f64::from(sx) + 0.5) / f64::from(SAMPLES),
+                        f64::from(y) + (f64::from(sy) + 0.5) / f64::from(SAMPLES),
+                    );
+                    covered += u32::from(inside(p));
+                }
+            }
+            let expected = f64::from(covered) / f64::from(SAMPLES * SAMPLES);
+            let ix = usize::try_from((y * size + x) * 4).unwrap();
+            let actual = f64::from(data[ix]) / 255.0;
+            max_error = max_error.max((actual - expected).abs());
+        }
+    }
+    max_error
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn large_circle_coverage_is_accurate() {
+    let center = Point::new(256.0, 256.0);
+    let radius = 250.0;
+    let mut scene = Scene::new();
+    scene.fill_circle(Affine::IDENTITY, palette::css::WHITE, center, radius);
+    let error = max_coverage_error(&scene, 512, |p| p.distance(center) < radius);
+    assert!(error < 0.08, "coverage error {error} is too large");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn rotated_ellipse_coverage_is_accurate() {
+    let center = Point::new(128.0, 128.0);
+    let transform = Affine::rotate_about(0.5, center);
+    let mut scene = Scene::new();
+    scene.fill_ellipse(transform, palette::css::WHITE, center, (100.0, 60.0));
+    let inverse = transform.inverse();
+    let error = max_coverage_error(&scene, 256, |p| {
+        let local = inverse * p - center;
+        (local.x / 100.0).powi(2) + (local.y / 60.0).powi(2) < 1.0
+    });
+    assert!(error < 0.1, "coverage error {error} is too large");
+}
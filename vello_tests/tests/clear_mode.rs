@@ -0,0 +1,146 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for accumulating renders into a target, using [`ClearMode::None`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::low_level::DebugLayers;
+use vello::peniko::{Color, Fill, color::palette};
+use vello::util::{RenderContext, block_on_wgpu};
+use vello::wgpu::{self, TextureUsages};
+use vello::{AaConfig, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene};
+
+const SIZE: u32 = 16;
+
+fn params(base_color: Color, clear_mode: ClearMode, premultiplied_alpha: bool) -> RenderParams {
+    RenderParams {
+        base_color,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode,
+    }
+}
+
+/// Renders a white rectangle with an alpha of `0.3` into a new target for each of `params`,
+/// returning the alpha of the center of the target after each render.
+///
+/// When `async_pipeline` is set, the scene is rendered using
+/// [`Renderer::render_to_texture_async`] instead.
+fn accumulate(params: &[RenderParams], async_pipeline: bool) -> Vec<f32> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let queue = &context.devices[device_id].queue;
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING
+            | TextureUsages::RENDER_ATTACHMENT
+            | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE.with_alpha(0.3),
+        None,
+        &Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)),
+    );
+    params
+        .iter()
+        .map(|params| {
+            if async_pipeline {
+                #[expect(deprecated, reason = "The async pipeline should also accumulate")]
+                let render = renderer.render_to_texture_async(
+                    device,
+                    queue,
+                    &scene,
+                    &view,
+                    params,
+                    DebugLayers::none(),
+                );
+                block_on_wgpu(device, render).unwrap();
+            } else {
+                renderer
+                    .render_to_texture(device, queue, &scene, &view, params)
+                    .unwrap();
+            }
+            let pixel = renderer
+                .read_pixel(device, queue, &texture, SIZE / 2, SIZE / 2)
+                .unwrap();
+            pixel.components[3]
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn renders_accumulate() {
+    for premultiplied_alpha in [false, true] {
+        let accumulating = || {
+            params(
+                palette::css::TRANSPARENT,
+                ClearMode::None,
+                premultiplied_alpha,
+            )
+        };
+        let alphas = accumulate(&[accumulating(), accumulating()], false);
+        assert!(
+            (alphas[0] - 0.3).abs() < 0.01,
+            "Expected the first render to have an alpha of 0.3, got {}",
+            alphas[0]
+        );
+        assert!(
+            (alphas[1] - 0.51).abs() < 0.01,
+            "Expected the second render to accumulate to 0.51, got {} (premultiplied: {premultiplied_alpha})",
+            alphas[1]
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn clear_replaces_contents() {
+    let alphas = accumulate(
+        &[
+            params(palette::css::TRANSPARENT, ClearMode::None, false),
+            params(palette::css::TRANSPARENT, ClearMode::Clear, false),
+        ],
+        false,
+    );
+    assert!(
+        (alphas[1] - 0.3).abs() < 0.01,
+        "Expected the target to be cleared, got {}",
+        alphas[1]
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn async_renders_accumulate() {
+    let accumulating = || params(palette::css::TRANSPARENT, ClearMode::None, false);
+    let alphas = accumulate(&[accumulating(), accumulating()], true);
+    assert!(
+        (alphas[1] - 0.51).abs() < 0.01,
+        "Expected the second async render to accumulate to 0.51, got {}",
+        alphas[1]
+    );
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the [`ColorExt`] helpers.
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::color::{ColorSpaceTag, palette};
+use vello::peniko::{Color, Fill};
+use vello::{ColorExt, Scene};
+use vello_tests::TestParams;
+
+const SPACES: [ColorSpaceTag; 3] = [
+    ColorSpaceTag::Srgb,
+    ColorSpaceTag::LinearSrgb,
+    ColorSpaceTag::Oklab,
+];
+
+fn assert_close(actual: Color, expected: Color) {
+    let difference = actual
+        .components
+        .iter()
+        .zip(expected.components)
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0_f32, f32::max);
+    assert!(
+        difference < 1e-4,
+        "Expected {expected:?}, got {actual:?} (difference of {difference})"
+    );
+}
+
+#[test]
+fn lerp_in_endpoints() {
+    let a = palette::css::RED;
+    let b = palette::css::BLUE.with_alpha(0.5);
+    for space in SPACES {
+        assert_close(a.lerp_in(b, 0.0, space), a);
+        assert_close(a.lerp_in(b, 1.0, space), b);
+    }
+}
+
+#[test]
+fn lerp_in_same_color_round_trips() {
+    let color = Color::new([0.2, 0.4, 0.6, 0.8]);
+    for space in SPACES {
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            assert_close(color.lerp_in(color, t, space), color);
+        }
+    }
+}
+
+#[test]
+fn lerp_in_linear_is_brighter_than_srgb() {
+    let a = palette::css::BLACK;
+    let b = palette::css::WHITE;
+    let srgb = a.lerp_in(b, 0.5, ColorSpaceTag::Srgb);
+    let linear = a.lerp_in(b, 0.5, ColorSpaceTag::LinearSrgb);
+    assert_close(srgb, Color::new([0.5, 0.5, 0.5, 1.0]));
+    assert!(
+        linear.components[0] > srgb.components[0],
+        "Interpolating in linear light should avoid a muddy midpoint"
+    );
+}
+
+#[test]
+fn lerp_in_fades_alpha() {
+    let a = palette::css::RED;
+    let b = a.with_alpha(0.0);
+    let mid = a.lerp_in(b, 0.5, ColorSpaceTag::Oklab);
+    assert!((mid.components[3] - 0.5).abs() < 1e-4);
+    // Interpolation is premultiplied, so the color itself is unchanged.
+    assert_close(mid, a.with_alpha(0.5));
+}
+
+#[test]
+fn premultiplied_round_trips() {
+    let color = Color::new([0.2, 0.4, 0.6, 0.5]);
+    let premultiplied = color.to_premultiplied();
+    assert_eq!(premultiplied, [0.1, 0.2, 0.3, 0.5]);
+    assert_close(Color::from_premultiplied(premultiplied), color);
+    assert_eq!(palette::css::WHITE.to_premultiplied(), [1.0; 4]);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn premultiplied_half_alpha_composites() {
+    // Half-transparent red, which is straight `[1.0, 0.0, 0.0, 0.5]`.
+    let color = Color::from_premultiplied([0.5, 0.0, 0.0, 0.5]);
+    assert_close(color, Color::new([1.0, 0.0, 0.0, 0.5]));
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        color,
+        None,
+        &Rect::new(0.0, 0.0, 4.0, 4.0),
+    );
+    let params = TestParams {
+        base_color: Some(palette::css::WHITE),
+        ..TestParams::new("premultiplied_half_alpha", 4, 4)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = &image.data.data()[..4];
+    // Over white, this is `src + (1 - src_alpha) * white` in each channel.
+    assert_eq!(pixel[0], 255, "Unexpected red in {pixel:?}");
+    assert!(pixel[1].abs_diff(128) <= 1, "Unexpected green in {pixel:?}");
+    assert!(pixel[2].abs_diff(128) <= 1, "Unexpected blue in {pixel:?}");
+    assert_eq!(pixel[3], 255, "Unexpected alpha in {pixel:?}");
+}
@@ -0,0 +1,308 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for color matrix layers, pushed using [`Scene::push_color_matrix_layer`] and
+//! [`Scene::push_adjust_layer`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{
+    Color, Fill, Gradient, ImageAlphaType, ImageBrush, ImageData, ImageFormat, Mix, color::palette,
+};
+use vello::{Adjust, ColorMatrix, Scene};
+use vello_encoding::{DrawBeginClip, Resolver};
+use vello_tests::TestParams;
+
+const RECT: Rect = Rect::new(0.0, 0.0, 64.0, 16.0);
+
+fn luminance(color: Color) -> f32 {
+    let [r, g, b, _] = color.components;
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+#[test]
+fn grayscale_gives_luminance() {
+    let gray = ColorMatrix::grayscale(1.0);
+    for color in [
+        palette::css::RED,
+        palette::css::LIME,
+        palette::css::ORANGE,
+        palette::css::TEAL.with_alpha(0.5),
+    ] {
+        let [r, g, b, a] = gray.apply(color).components;
+        let expected = luminance(color);
+        for channel in [r, g, b] {
+            assert!(
+                (channel - expected).abs() < 1e-5,
+                "{channel} should be {expected} for {color:?}"
+            );
+        }
+        assert_eq!(a, color.components[3], "alpha is unchanged");
+    }
+    assert_eq!(
+        ColorMatrix::grayscale(0.0).apply(palette::css::ORANGE),
+        palette::css::ORANGE,
+        "no grayscale leaves colors unchanged"
+    );
+}
+
+#[test]
+fn matrices_compose_in_order() {
+    let color = Color::new([0.2, 0.5, 0.9, 1.0]);
+    let sepia = ColorMatrix::sepia(1.0);
+    let hue = ColorMatrix::hue_rotate(90.0);
+    let composed = sepia.then(&hue).apply(color);
+    let sequential = hue.apply(sepia.apply(color));
+    for (a, b) in composed.components.iter().zip(sequential.components) {
+        assert!(
+            (a - b).abs() < 1e-5,
+            "{composed:?} should match {sequential:?}"
+        );
+    }
+}
+
+#[test]
+fn color_matrix_layers_are_resolved_in_order() {
+    let sepia = ColorMatrix::sepia(1.0);
+    let gray = ColorMatrix::grayscale(1.0);
+    let mut appended = Scene::new();
+    appended.push_color_matrix_layer(gray, Affine::IDENTITY, &RECT);
+    appended.pop_layer();
+    let mut scene = Scene::new();
+    scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &RECT);
+    scene.pop_layer();
+    scene.push_color_matrix_layer(sepia, Affine::IDENTITY, &RECT);
+    scene.pop_layer();
+    scene.append(&appended, None);
+
+    let mut resolver = Resolver::new();
+    let mut packed = Vec::new();
+    let (layout, _, _) = resolver.resolve(scene.encoding(), &mut packed);
+    let matrices = layout.color_matrices(&packed);
+    assert_eq!(matrices, [sepia.0, gray.0]);
+    let blend_modes: Vec<u32> = layout
+        .draw_data(&packed)
+        .chunks_exact(2)
+        .map(|clip| clip[0] >> DrawBeginClip::COLOR_MATRIX_SHIFT)
+        .collect();
+    assert_eq!(
+        blend_modes,
+        [0, 1, 2],
+        "Each layer with a color matrix should refer to its matrix"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn grayscale_gradient_renders_luminance() {
+    let gradient = Gradient::new_linear((0.0, 0.0), (64.0, 0.0)).with_stops([
+        palette::css::RED,
+        palette::css::LIME,
+        palette::css::BLUE,
+    ]);
+    let mut scene = Scene::new();
+    scene.push_color_matrix_layer(ColorMatrix::grayscale(1.0), Affine::IDENTITY, &RECT);
+    scene.fill(Fill::NonZero, Affine::IDENTITY, &gradient, None, &RECT);
+    scene.pop_layer();
+    let params = TestParams::new("grayscale_gradient", 64, 16);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let mut levels = Vec::new();
+    for pixel in image.data.data().chunks_exact(4).take(64) {
+        let [r, g, b, _] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+        assert!(
+            r.abs_diff(g) <= 1 && g.abs_diff(b) <= 1,
+            "{pixel:?} should be gray"
+        );
+        levels.push(g);
+    }
+    // Green is brighter than red and blue, so the middle of the gradient is lightest.
+    let expected = |color: Color| (luminance(color) * 255.0).round() as u8;
+    assert!(
+        levels[32] > levels[0] && levels[32] > levels[63],
+        "{levels:?} should peak in the middle"
+    );
+    assert!(
+        levels[0].abs_diff(expected(palette::css::RED)) <= 2,
+        "the start should be the luminance of red, got {}",
+        levels[0]
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn color_matrix_applies_to_images() {
+    let red = [255_u8, 0, 0, 255];
+    let image = ImageBrush::new(ImageData {
+        data: red.repeat(4).into(),
+        format: ImageFormat::Rgba8,
+        width: 2,
+        height: 2,
+        alpha_type: ImageAlphaType::Alpha,
+    });
+    let mut scene = Scene::new();
+    scene.push_color_matrix_layer(ColorMatrix::grayscale(1.0), Affine::IDENTITY, &RECT);
+    scene.draw_image(&image, Affine::scale(8.0));
+    scene.pop_layer();
+    let params = TestParams::new("color_matrix_image", 64, 16);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = &image.data.data()[..4];
+    let gray = (luminance(palette::css::RED) * 255.0).round() as u8;
+    for channel in &pixel[..3] {
+        assert!(
+            channel.abs_diff(gray) <= 1,
+            "{pixel:?} should be the luminance of red"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn color_matrix_applies_to_the_blended_layer() {
+    // Sets the alpha of every pixel in the layer to 1, so the translucent red rectangle drawn
+    // in the layer becomes opaque red, and the rest of the layer becomes opaque black.
+    let matrix = [
+        1.0, 0.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 0.0, 0.0, 1.0, //
+    ];
+    let mut scene = Scene::new();
+    scene.push_color_matrix_layer(matrix, Affine::IDENTITY, &RECT);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED.with_alpha(0.5),
+        None,
+        &Rect::new(0.0, 0.0, 32.0, 16.0),
+    );
+    scene.pop_layer();
+    let params = TestParams::new("color_matrix_blended_layer", 64, 16);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let data = image.data.data();
+    assert_eq!(&data[..4], [255, 0, 0, 255], "the red half");
+    assert_eq!(&data[48 * 4..49 * 4], [0, 0, 0, 255], "the empty half");
+}
+
+fn assert_close(actual: Color, expected: [f32; 4]) {
+    for (a, b) in actual.components.iter().zip(expected) {
+        assert!((a - b).abs() < 1e-5, "{actual:?} should be {expected:?}");
+    }
+}
+
+#[test]
+fn adjust_parameters_match_css_filters() {
+    let color = Color::new([0.8, 0.4, 0.2, 0.5]);
+    let adjust = |adjust: Adjust| ColorMatrix::from(adjust).apply(color);
+    assert_close(
+        adjust(Adjust {
+            brightness: 0.5,
+            ..Adjust::default()
+        }),
+        [0.4, 0.2, 0.1, 0.5],
+    );
+    assert_close(
+        adjust(Adjust {
+            contrast: 2.0,
+            ..Adjust::default()
+        }),
+        [1.0, 0.3, 0.0, 0.5],
+    );
+    let gray = 0.213 * 0.8 + 0.715 * 0.4 + 0.072 * 0.2;
+    assert_close(
+        adjust(Adjust {
+            saturation: 0.0,
+            ..Adjust::default()
+        }),
+        [gray, gray, gray, 0.5],
+    );
+    assert_close(
+        adjust(Adjust {
+            hue_rotate: 360.0,
+            ..Adjust::default()
+        }),
+        color.components,
+    );
+    assert_close(adjust(Adjust::default()), color.components);
+    // Grays have no hue to rotate.
+    assert_close(
+        ColorMatrix::from(Adjust {
+            hue_rotate: 120.0,
+            ..Adjust::default()
+        })
+        .apply(Color::new([0.3, 0.3, 0.3, 1.0])),
+        [0.3, 0.3, 0.3, 1.0],
+    );
+}
+
+#[test]
+fn adjust_layer_is_a_color_matrix_layer() {
+    let adjust = Adjust {
+        brightness: 0.5,
+        ..Adjust::default()
+    };
+    let mut scene = Scene::new();
+    scene.push_layer(Fill::NonZero, Mix::Normal, 0.5, Affine::IDENTITY, &RECT);
+    scene.push_adjust_layer(adjust, Affine::IDENTITY, &RECT);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &RECT,
+    );
+    scene.pop_layer();
+    scene.pop_layer();
+
+    let mut expected = Scene::new();
+    expected.push_layer(Fill::NonZero, Mix::Normal, 0.5, Affine::IDENTITY, &RECT);
+    expected.push_color_matrix_layer(adjust, Affine::IDENTITY, &RECT);
+    expected.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &RECT,
+    );
+    expected.pop_layer();
+    expected.pop_layer();
+    assert_eq!(scene.encoding().draw_data, expected.encoding().draw_data);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn adjust_layer_desaturates_the_blended_layer() {
+    // The red and blue rectangles overlap in the middle, where they blend to purple before
+    // the layer is desaturated.
+    let mut scene = Scene::new();
+    scene.push_adjust_layer(
+        Adjust {
+            saturation: 0.0,
+            ..Adjust::default()
+        },
+        Affine::IDENTITY,
+        &RECT,
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 40.0, 16.0),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLUE.with_alpha(0.5),
+        None,
+        &Rect::new(24.0, 0.0, 64.0, 16.0),
+    );
+    scene.pop_layer();
+    let params = TestParams::new("adjust_layer_desaturates", 64, 16);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    for x in [0, 32, 56] {
+        let pixel = &image.data.data()[x * 4..x * 4 + 4];
+        assert!(
+            pixel[0].abs_diff(pixel[1]) <= 1 && pixel[1].abs_diff(pixel[2]) <= 1,
+            "{pixel:?} at {x} should be gray"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Colormap`].
+
+use vello::Colormap;
+use vello::peniko::Gradient;
+
+/// The largest difference, out of 255, allowed from the canonical tables.
+const TOLERANCE: i16 = 6;
+
+fn assert_close(colormap: Colormap, t: f32, expected: [u8; 3]) {
+    let [r, g, b, a] = colormap.eval(t).to_rgba8().to_u8_array();
+    assert_eq!(a, 255);
+    for (actual, expected) in [r, g, b].into_iter().zip(expected) {
+        assert!(
+            (i16::from(actual) - i16::from(expected)).abs() <= TOLERANCE,
+            "{colormap:?} at {t}: got {:?}, expected {expected:?}",
+            [r, g, b]
+        );
+    }
+}
+
+#[test]
+fn viridis_matches_table() {
+    assert_close(Colormap::Viridis, 0.0, [68, 1, 84]);
+    assert_close(Colormap::Viridis, 0.25, [59, 82, 139]);
+    assert_close(Colormap::Viridis, 0.5, [33, 144, 141]);
+    assert_close(Colormap::Viridis, 0.75, [94, 201, 98]);
+    assert_close(Colormap::Viridis, 1.0, [253, 231, 37]);
+}
+
+#[test]
+fn magma_and_plasma_match_tables() {
+    assert_close(Colormap::Magma, 0.0, [0, 0, 4]);
+    assert_close(Colormap::Magma, 0.5, [183, 55, 121]);
+    assert_close(Colormap::Magma, 1.0, [252, 253, 191]);
+    assert_close(Colormap::Plasma, 0.0, [13, 8, 135]);
+    assert_close(Colormap::Plasma, 0.5, [204, 71, 120]);
+    assert_close(Colormap::Plasma, 1.0, [240, 249, 33]);
+}
+
+#[test]
+fn turbo_is_dark_at_ends_and_green_in_middle() {
+    let [r, g, b, _] = Colormap::Turbo.eval(0.5).to_rgba8().to_u8_array();
+    assert!(g > r && g > b, "{:?}", [r, g, b]);
+    for t in [0.0, 1.0] {
+        let [r, g, b, _] = Colormap::Turbo.eval(t).to_rgba8().to_u8_array();
+        assert!(
+            u16::from(r) + u16::from(g) + u16::from(b) < 255,
+            "{:?}",
+            [r, g, b]
+        );
+    }
+}
+
+#[test]
+fn out_of_range_is_clamped() {
+    for colormap in [
+        Colormap::Viridis,
+        Colormap::Magma,
+        Colormap::Plasma,
+        Colormap::Turbo,
+    ] {
+        assert_eq!(colormap.eval(-1.0), colormap.eval(0.0));
+        assert_eq!(colormap.eval(f32::NAN), colormap.eval(0.0));
+        assert_eq!(colormap.eval(2.0), colormap.eval(1.0));
+    }
+}
+
+#[test]
+fn gradient_stops() {
+    let gradient = Gradient::new_linear((0.0, 0.0), (256.0, 0.0)).with_stops(Colormap::Viridis);
+    let stops = &gradient.stops;
+    assert_eq!(stops.len(), Colormap::STOPS);
+    assert_eq!(stops.first().unwrap().offset, 0.0);
+    assert_eq!(stops.last().unwrap().offset, 1.0);
+    assert!(stops.windows(2).all(|pair| pair[0].offset < pair[1].offset));
+}
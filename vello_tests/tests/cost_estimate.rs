@@ -0,0 +1,118 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::estimate_gpu_cost`].
+
+use vello::kurbo::{Affine, Circle, Rect};
+use vello::peniko::{Fill, Mix, color::palette};
+use vello::{CostEstimate, Scene};
+
+const CLIP: Rect = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+fn blur(scene: &mut Scene, transform: Affine) {
+    // A 10x20 rectangle, with a blur extent of 2.5 standard deviations on each side.
+    scene.draw_blurred_rounded_rect(
+        transform,
+        Rect::new(0.0, 0.0, 10.0, 20.0),
+        palette::css::BLACK,
+        2.0,
+        1.0,
+    );
+}
+
+#[test]
+fn empty_scene_costs_nothing() {
+    let estimate = Scene::new().estimate_gpu_cost();
+    assert_eq!(estimate, CostEstimate::default());
+    assert_eq!(estimate.score(), 0.0);
+}
+
+#[test]
+fn counts_draws_and_layers() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Circle::new((50.0, 50.0), 20.0),
+    );
+    scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &CLIP);
+    scene.push_layer(Fill::NonZero, Mix::Multiply, 1.0, Affine::IDENTITY, &CLIP);
+    scene.push_layer(Fill::NonZero, Mix::Normal, 0.5, Affine::IDENTITY, &CLIP);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &CLIP,
+    );
+    scene.pop_layer();
+    scene.pop_layer();
+    scene.pop_layer();
+    scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &CLIP);
+    scene.pop_layer();
+
+    let estimate = scene.estimate_gpu_cost();
+    assert_eq!(estimate.draws, 2);
+    assert_eq!(estimate.layers, 4);
+    assert_eq!(estimate.composited_layers, 2);
+    assert_eq!(estimate.max_layer_depth, 3);
+    assert_eq!(estimate.blurs, 0);
+    assert!(estimate.score() > 0.0);
+}
+
+#[test]
+fn blur_area_uses_the_transform() {
+    let mut scene = Scene::new();
+    blur(&mut scene, Affine::IDENTITY);
+    let estimate = scene.estimate_gpu_cost();
+    assert_eq!(estimate.blurs, 1);
+    assert_eq!(estimate.blur_area, 15.0 * 25.0);
+
+    let mut scaled = Scene::new();
+    blur(
+        &mut scaled,
+        Affine::scale(2.0).then_translate((40.0, 3.0).into()),
+    );
+    assert_eq!(scaled.estimate_gpu_cost().blur_area, 4.0 * 15.0 * 25.0);
+}
+
+#[test]
+fn blurs_in_composited_layers_cost_more() {
+    let mut plain = Scene::new();
+    blur(&mut plain, Affine::IDENTITY);
+
+    let mut clipped = Scene::new();
+    clipped.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &CLIP);
+    blur(&mut clipped, Affine::IDENTITY);
+    clipped.pop_layer();
+
+    let mut nested = Scene::new();
+    nested.push_layer(Fill::NonZero, Mix::Normal, 0.5, Affine::IDENTITY, &CLIP);
+    nested.push_layer(Fill::NonZero, Mix::Normal, 0.5, Affine::IDENTITY, &CLIP);
+    blur(&mut nested, Affine::IDENTITY);
+    nested.pop_layer();
+    nested.pop_layer();
+    // A blur after the layers are popped isn't affected by them.
+    blur(&mut nested, Affine::IDENTITY);
+
+    let plain = plain.estimate_gpu_cost().blur_area;
+    assert_eq!(clipped.estimate_gpu_cost().blur_area, plain);
+    assert_eq!(nested.estimate_gpu_cost().blur_area, 3.0 * plain + plain);
+}
+
+#[test]
+fn deeply_nested_layers_score_highly() {
+    let mut scene = Scene::new();
+    for _ in 0..50 {
+        scene.push_layer(Fill::NonZero, Mix::Normal, 0.9, Affine::IDENTITY, &CLIP);
+        blur(&mut scene, Affine::scale(10.0));
+    }
+    for _ in 0..50 {
+        scene.pop_layer();
+    }
+    let estimate = scene.estimate_gpu_cost();
+    assert_eq!(estimate.max_layer_depth, 50);
+    assert!(estimate.score() > 10.0, "{estimate:?}");
+}
@@ -0,0 +1,97 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::with_cull_rect`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Rect, Stroke};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+const VIEWPORT: Rect = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+#[test]
+fn offscreen_fill_is_not_encoded() {
+    let mut scene = Scene::new().with_cull_rect(VIEWPORT);
+    scene.fill(
+        Fill::NonZero,
+        Affine::translate((500.0, 0.0)),
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 50.0, 50.0),
+    );
+    scene.draw_blurred_rounded_rect(
+        Affine::translate((0.0, -300.0)),
+        Rect::new(0.0, 0.0, 50.0, 50.0),
+        palette::css::BLACK,
+        5.0,
+        10.0,
+    );
+    let encoding = scene.encoding();
+    assert!(encoding.is_empty());
+    assert!(encoding.draw_tags.is_empty());
+    assert_eq!(encoding.n_paths, 0);
+}
+
+#[test]
+fn partially_visible_shapes_are_kept() {
+    let mut scene = Scene::new().with_cull_rect(VIEWPORT);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Circle::new((-20.0, 50.0), 25.0),
+    );
+    assert_eq!(scene.encoding().n_paths, 1);
+}
+
+#[test]
+fn stroke_outside_cull_rect_is_kept_if_it_reaches_inside() {
+    let mut scene = Scene::new().with_cull_rect(VIEWPORT);
+    // The path is outside the viewport, but the stroke extends into it.
+    let line = Rect::new(-10.0, 10.0, -5.0, 90.0);
+    scene.stroke(
+        &Stroke::new(20.0),
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &line,
+    );
+    assert_eq!(scene.encoding().n_paths, 1);
+    scene.stroke(
+        &Stroke::new(2.0),
+        Affine::translate((-50.0, 0.0)),
+        palette::css::RED,
+        None,
+        &line,
+    );
+    assert_eq!(scene.encoding().n_paths, 1);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn culling_does_not_change_output() {
+    let draw = |scene: &mut Scene| {
+        for i in 0..20 {
+            let x = f64::from(i) * 30.0 - 200.0;
+            scene.fill(
+                Fill::NonZero,
+                Affine::translate((x, x * 0.5)),
+                palette::css::TEAL,
+                None,
+                &Circle::new((0.0, 0.0), 20.0),
+            );
+        }
+    };
+    let mut culled = Scene::new().with_cull_rect(VIEWPORT);
+    draw(&mut culled);
+    let mut full = Scene::new();
+    draw(&mut full);
+    assert!(culled.encoding().n_paths < full.encoding().n_paths);
+
+    let params = TestParams::new("cull_rect", 100, 100);
+    let culled = vello_tests::render_then_debug_sync(&culled, &params).unwrap();
+    let full = vello_tests::render_then_debug_sync(&full, &params).unwrap();
+    assert_eq!(culled.data.data(), full.data.data());
+}
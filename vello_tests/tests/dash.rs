@@ -0,0 +1,101 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the dashing of strokes on curved shapes.
+//!
+//! [`Scene::stroke`](vello::Scene::stroke) expands dashes on the CPU using [`kurbo::dash`],
+//! with the same tolerance as used here.
+
+use std::f64::consts::PI;
+
+use vello::kurbo::{
+    self, CubicBez, Line, ParamCurveArclen, PathEl, Point, QuadBez, RoundedRect, Shape,
+};
+
+/// The tolerance used when dashing in `Scene::stroke`.
+const SHAPE_TOLERANCE: f64 = 0.01;
+
+/// A dash produced by [`kurbo::dash`].
+struct Dash {
+    length: f64,
+    has_line: bool,
+    has_curve: bool,
+}
+
+/// Returns the dashes for `shape`, measuring the arc length of each.
+fn dashes(shape: &impl Shape, offset: f64, pattern: &[f64]) -> Vec<Dash> {
+    let mut dashes: Vec<Dash> = vec![];
+    let mut start = Point::ZERO;
+    let mut last = Point::ZERO;
+    for el in kurbo::dash(shape.path_elements(SHAPE_TOLERANCE), offset, pattern) {
+        if let PathEl::MoveTo(p) = el {
+            dashes.push(Dash {
+                length: 0.0,
+                has_line: false,
+                has_curve: false,
+            });
+            (start, last) = (p, p);
+            continue;
+        }
+        let dash = dashes.last_mut().expect("Dashes start with a `MoveTo`");
+        let (length, end, is_curve) = match el {
+            PathEl::LineTo(p) => (Line::new(last, p).arclen(1e-9), p, false),
+            PathEl::QuadTo(p1, p2) => (QuadBez::new(last, p1, p2).arclen(1e-9), p2, true),
+            PathEl::CurveTo(p1, p2, p3) => (CubicBez::new(last, p1, p2, p3).arclen(1e-9), p3, true),
+            PathEl::ClosePath => (Line::new(last, start).arclen(1e-9), start, false),
+            PathEl::MoveTo(_) => unreachable!(),
+        };
+        dash.length += length;
+        dash.has_line |= !is_curve && length > 1e-6;
+        dash.has_curve |= is_curve && length > 1e-6;
+        last = end;
+    }
+    dashes
+}
+
+#[test]
+fn rounded_rect_dashes_are_continuous_across_corners() {
+    let shape = RoundedRect::new(0.0, 0.0, 100.0, 60.0, 20.0);
+    let pattern = [7.0, 5.0];
+    let dashes = dashes(&shape, 0.0, &pattern);
+
+    // The first and last dashes can be cut short where the path starts and ends.
+    let interior = &dashes[1..dashes.len() - 1];
+    for (i, dash) in interior.iter().enumerate() {
+        assert!(
+            (dash.length - pattern[0]).abs() < 1e-2,
+            "Dash {} has length {}, expected {}",
+            i + 1,
+            dash.length,
+            pattern[0]
+        );
+    }
+    assert!(
+        interior.iter().any(|dash| dash.has_line && dash.has_curve),
+        "Expected a dash to run from a straight edge into a corner"
+    );
+}
+
+#[test]
+fn rounded_rect_dashes_cover_perimeter() {
+    let (width, height, radius) = (100.0, 60.0, 20.0);
+    let shape = RoundedRect::new(0.0, 0.0, width, height, radius);
+    let perimeter = 2.0 * (width + height) - 8.0 * radius + 2.0 * PI * radius;
+    // A pattern which divides the perimeter exactly, so the dash phase is the same at the
+    // end of the path as at its start.
+    let count = 24.0;
+    let period = perimeter / count;
+    let pattern = [period * 0.5, period * 0.5];
+    let dashes = dashes(&shape, 0.0, &pattern);
+    let total: f64 = dashes.iter().map(|dash| dash.length).sum();
+    assert!(
+        (total - perimeter * 0.5).abs() < 0.05,
+        "Dashes cover {total}, expected {}",
+        perimeter * 0.5
+    );
+    assert!(
+        dashes.len() == 24 || dashes.len() == 25,
+        "Expected 24 dashes, got {}",
+        dashes.len()
+    );
+}
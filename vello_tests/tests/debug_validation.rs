@@ -0,0 +1,146 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Encoding::validate`] and [`RendererOptions::debug_validation`].
+//!
+//! [`Encoding::validate`]: vello_encoding::Encoding::validate
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, Gradient, Mix, color::palette};
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const SIZE: u32 = 32;
+
+/// A scene with nested layers, like the `inner_shadow` example.
+fn nested_layers(pops: usize) -> Scene {
+    let rect = Rect::new(4.0, 4.0, 28.0, 28.0);
+    let mut scene = Scene::new();
+    scene.push_layer(Fill::NonZero, Mix::Normal, 1.0, Affine::IDENTITY, &rect);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &Gradient::new_linear((0.0, 0.0), (32.0, 0.0))
+            .with_stops([palette::css::RED, palette::css::BLUE]),
+        None,
+        &rect,
+    );
+    scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &rect.inset(-4.0));
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::GREEN,
+        None,
+        &rect,
+    );
+    for _ in 0..pops {
+        scene.pop_layer();
+    }
+    scene
+}
+
+#[test]
+fn balanced_scene_is_valid() {
+    assert_eq!(nested_layers(2).encoding().validate(), Ok(()));
+}
+
+#[test]
+fn unpopped_layer_is_invalid() {
+    let error = nested_layers(1).encoding().validate().unwrap_err();
+    assert!(
+        error.contains("1 clips or layers were begun but not ended"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn extra_pop_is_invalid() {
+    let mut scene = nested_layers(3);
+    let error = scene.encoding().validate().unwrap_err();
+    assert!(
+        error.contains("1 clips or layers were ended without being begun"),
+        "Unexpected error: {error}"
+    );
+    scene.reset();
+    assert_eq!(
+        scene.encoding().validate(),
+        Ok(()),
+        "Resetting the scene should clear the extra pop"
+    );
+}
+
+#[test]
+fn extra_pop_is_kept_when_appended() {
+    let mut scene = Scene::new();
+    scene.append(&nested_layers(3), Some(Affine::translate((8.0, 8.0))));
+    assert!(
+        scene.encoding().validate().is_err(),
+        "The extra pop of the appended scene should be reported"
+    );
+}
+
+fn render(scene: &Scene, debug_validation: bool) -> vello::Result<()> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let options = RendererOptions::builder()
+        .debug_validation(debug_validation)
+        .build()?;
+    let mut renderer = Renderer::new(device, options)?;
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let params = RenderParams {
+        base_color: Color::BLACK,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer.render_to_texture(device, queue, scene, &view, &params)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn renderer_reports_invalid_scene() {
+    let result = render(&nested_layers(1), true);
+    assert!(
+        matches!(&result, Err(Error::InvalidScene(message)) if message.contains("not ended")),
+        "Expected an invalid scene error, got {result:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn renderer_renders_valid_scene_with_validation() {
+    render(&nested_layers(2), true).expect("A balanced scene should render without errors");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn renderer_closes_layers_without_validation() {
+    render(&nested_layers(1), false)
+        .expect("Open layers should be closed when validation is disabled");
+}
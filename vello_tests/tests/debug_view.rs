@@ -0,0 +1,155 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`DebugView`].
+
+use vello::kurbo::{Affine, BezPath, Rect, Shape};
+use vello::peniko::{Fill, ImageData, color::palette};
+use vello::{DebugView, Scene};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+
+fn render(scene: &Scene, debug: DebugView) -> ImageData {
+    let params = TestParams {
+        debug,
+        ..TestParams::new("debug_view", SIZE, SIZE)
+    };
+    vello_tests::render_then_debug_sync(scene, &params).unwrap()
+}
+
+fn pixel(image: &ImageData, x: u32, y: u32) -> [u8; 4] {
+    let index = usize::try_from((y * SIZE + x) * 4).unwrap();
+    image.data.data()[index..index + 4].try_into().unwrap()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn coverage_ignores_brush() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED.with_alpha(0.5),
+        None,
+        &Rect::new(0.0, 0.0, 32.0, 64.0),
+    );
+    let image = render(&scene, DebugView::Coverage);
+    assert_eq!(pixel(&image, 16, 32), [255, 255, 255, 255]);
+    assert_eq!(pixel(&image, 48, 32), [0, 0, 0, 255]);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn winding_shows_overlap_and_direction() {
+    // Two overlapping rectangles in the same direction, as a single path, and a third
+    // rectangle in the opposite direction.
+    let mut path = BezPath::new();
+    path.extend(Rect::new(0.0, 0.0, 24.0, 32.0).path_elements(0.1));
+    path.extend(Rect::new(12.0, 0.0, 36.0, 32.0).path_elements(0.1));
+    let reversed = Rect::new(0.0, 40.0, 24.0, 64.0)
+        .to_path(0.1)
+        .reverse_subpaths();
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &path,
+    );
+    scene.fill(
+        Fill::EvenOdd,
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &reversed,
+    );
+    let image = render(&scene, DebugView::Winding);
+
+    let once = pixel(&image, 6, 16);
+    let twice = pixel(&image, 18, 16);
+    let negative = pixel(&image, 12, 52);
+    // Winding numbers are shown in either the red or green channel, depending on their sign.
+    let channel = if once[0] > 0 { 0 } else { 1 };
+    assert!(
+        once[channel].abs_diff(64) <= 1 && once[1 - channel] == 0,
+        "Expected a winding number of 1, got {once:?}"
+    );
+    assert!(
+        twice[channel].abs_diff(128) <= 1 && twice[1 - channel] == 0,
+        "Expected a winding number of 2, got {twice:?}"
+    );
+    assert!(
+        negative[1 - channel].abs_diff(64) <= 1 && negative[channel] == 0,
+        "Expected a winding number of -1, got {negative:?}"
+    );
+    assert_eq!(pixel(&image, 48, 16), [0, 0, 0, 255]);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn tiles_outline_tile_edges() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Rect::new(0.0, 0.0, 64.0, 64.0),
+    );
+    let normal = render(&scene, DebugView::None);
+    let tiles = render(&scene, DebugView::Tiles);
+    for (x, y) in [(0, 8), (16, 5), (5, 16), (32, 32)] {
+        assert_ne!(
+            pixel(&tiles, x, y),
+            pixel(&normal, x, y),
+            "Expected the edge of a tile at ({x}, {y})"
+        );
+    }
+    for (x, y) in [(8, 8), (17, 17), (40, 50)] {
+        assert_eq!(
+            pixel(&tiles, x, y),
+            pixel(&normal, x, y),
+            "Expected ({x}, {y}) to be unchanged"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "debug_layers")]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn paint_order_shows_topmost_draw() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Rect::new(0.0, 0.0, 40.0, 64.0),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE.with_alpha(0.5),
+        None,
+        &Rect::new(24.0, 0.0, 64.0, 32.0),
+    );
+    let image = render(&scene, DebugView::PaintOrder);
+    // The first draw is blue and the last is red, regardless of their brushes.
+    for (x, y) in [(8, 16), (32, 48)] {
+        assert_eq!(
+            pixel(&image, x, y),
+            [0, 0, 255, 255],
+            "Expected the first draw at ({x}, {y})"
+        );
+    }
+    for (x, y) in [(32, 16), (48, 16)] {
+        assert_eq!(
+            pixel(&image, x, y),
+            [255, 0, 0, 255],
+            "Expected the second draw at ({x}, {y})"
+        );
+    }
+    assert_eq!(pixel(&image, 48, 48), [0, 0, 0, 255]);
+}
@@ -0,0 +1,176 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that non-finite and empty geometry is skipped, rather than encoded.
+
+use vello::kurbo::{Affine, Circle, Line, Rect, RoundedRect, Stroke};
+use vello::peniko::{Fill, color::palette};
+use vello::{BoxShadowInstance, Scene};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+const NAN_RECTS: [Rect; 3] = [
+    Rect::new(f64::NAN, 0.0, 10.0, 10.0),
+    Rect::new(0.0, 0.0, f64::INFINITY, 10.0),
+    Rect::new(0.0, f64::NEG_INFINITY, 10.0, 10.0),
+];
+
+const EMPTY_RECTS: [Rect; 3] = [
+    Rect::new(4.0, 4.0, 4.0, 20.0),
+    Rect::new(4.0, 4.0, 20.0, 4.0),
+    Rect::new(0.0, 0.0, 0.0, 0.0),
+];
+
+fn nan_transform() -> Affine {
+    Affine::new([1.0, 0.0, 0.0, f64::NAN, 0.0, 0.0])
+}
+
+fn fill(scene: &mut Scene, transform: Affine, rect: Rect) {
+    scene.fill(Fill::NonZero, transform, palette::css::RED, None, &rect);
+}
+
+#[test]
+fn non_finite_fills_are_skipped() {
+    let mut scene = Scene::new();
+    for rect in NAN_RECTS {
+        fill(&mut scene, Affine::IDENTITY, rect);
+    }
+    fill(&mut scene, nan_transform(), Rect::new(0.0, 0.0, 10.0, 10.0));
+    scene.fill_rounded_rects(
+        Affine::IDENTITY,
+        &[(RoundedRect::from_rect(NAN_RECTS[0], 2.0), palette::css::RED)],
+    );
+    assert!(scene.encoding().draw_tags.is_empty());
+}
+
+#[test]
+fn empty_fills_are_skipped() {
+    let mut scene = Scene::new();
+    for rect in EMPTY_RECTS {
+        fill(&mut scene, Affine::IDENTITY, rect);
+    }
+    assert!(scene.encoding().draw_tags.is_empty());
+
+    // Negative sizes still have area, and are drawn as a normal rectangle.
+    fill(
+        &mut scene,
+        Affine::IDENTITY,
+        Rect::new(20.0, 20.0, 4.0, 4.0),
+    );
+    assert_eq!(scene.encoding().draw_tags, [DrawTag::COLOR]);
+}
+
+#[test]
+fn non_finite_strokes_are_skipped() {
+    let mut scene = Scene::new();
+    for rect in NAN_RECTS {
+        scene.stroke(
+            &Stroke::new(2.0),
+            Affine::IDENTITY,
+            palette::css::RED,
+            None,
+            &rect,
+        );
+    }
+    for width in [f64::NAN, f64::INFINITY] {
+        scene.stroke(
+            &Stroke::new(width),
+            Affine::IDENTITY,
+            palette::css::RED,
+            None,
+            &Circle::new((10.0, 10.0), 5.0),
+        );
+    }
+    assert!(scene.encoding().draw_tags.is_empty());
+}
+
+#[test]
+fn lines_are_stroked() {
+    let mut scene = Scene::new();
+    scene.stroke(
+        &Stroke::new(2.0),
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Line::new((4.0, 10.0), (40.0, 10.0)),
+    );
+    assert_eq!(scene.encoding().draw_tags, [DrawTag::COLOR]);
+}
+
+#[test]
+fn degenerate_blurs_are_skipped() {
+    let mut scene = Scene::new();
+    let rects = NAN_RECTS
+        .into_iter()
+        .chain(EMPTY_RECTS)
+        .chain([Rect::new(20.0, 20.0, 4.0, 4.0)]);
+    for rect in rects {
+        scene.draw_blurred_rounded_rect(Affine::IDENTITY, rect, palette::css::RED, 4.0, 3.0);
+    }
+    let rect = Rect::new(4.0, 4.0, 20.0, 20.0);
+    scene.draw_blurred_rounded_rect(Affine::IDENTITY, rect, palette::css::RED, f64::NAN, 3.0);
+    scene.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        rect,
+        palette::css::RED,
+        4.0,
+        f64::INFINITY,
+    );
+    scene.draw_blurred_rounded_rect(nan_transform(), rect, palette::css::RED, 4.0, 3.0);
+    assert!(scene.encoding().draw_tags.is_empty());
+}
+
+#[test]
+fn degenerate_box_shadows_are_skipped() {
+    let shadow = BoxShadowInstance {
+        transform: Affine::IDENTITY,
+        rect: Rect::new(4.0, 4.0, 20.0, 20.0),
+        color: palette::css::RED,
+        radius: 4.0,
+        std_dev: 3.0,
+    };
+    let mut shadows = vec![
+        BoxShadowInstance {
+            transform: nan_transform(),
+            ..shadow
+        },
+        BoxShadowInstance {
+            radius: f64::NAN,
+            ..shadow
+        },
+        shadow,
+    ];
+    for rect in NAN_RECTS.into_iter().chain(EMPTY_RECTS) {
+        shadows.push(BoxShadowInstance { rect, ..shadow });
+    }
+    let mut batched = Scene::new();
+    batched.draw_box_shadows_batch(&shadows);
+    let mut expected = Scene::new();
+    expected.draw_box_shadows_batch(&[shadow]);
+    assert_eq!(
+        batched.encoding().draw_tags,
+        expected.encoding().draw_tags,
+        "Only the valid shadow should be drawn"
+    );
+    assert_eq!(batched.encoding().draw_data, expected.encoding().draw_data);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn degenerate_geometry_renders_nothing() {
+    let rect = Rect::new(8.0, 8.0, 24.0, 24.0);
+    let mut expected = Scene::new();
+    fill(&mut expected, Affine::IDENTITY, rect);
+
+    let mut scene = Scene::new();
+    for rect in NAN_RECTS.into_iter().chain(EMPTY_RECTS) {
+        fill(&mut scene, Affine::IDENTITY, rect);
+        scene.draw_blurred_rounded_rect(Affine::IDENTITY, rect, palette::css::BLUE, 4.0, 3.0);
+    }
+    fill(&mut scene, Affine::IDENTITY, rect);
+
+    let params = TestParams::new("degenerate_geometry", 32, 32);
+    let expected = vello_tests::render_then_debug_sync(&expected, &params).unwrap();
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    assert_eq!(image.data.data(), expected.data.data());
+}
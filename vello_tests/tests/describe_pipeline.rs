@@ -0,0 +1,99 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::describe_pipeline`].
+
+use vello::util::RenderContext;
+use vello::{AaConfig, AaSupport, PostProcessShader, Renderer, RendererOptions};
+
+fn renderer(use_cpu: bool) -> (RenderContext, Renderer) {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let options = RendererOptions::builder()
+        .use_cpu(use_cpu)
+        .antialiasing_support(AaSupport::area_only())
+        .build()
+        .unwrap();
+    let renderer = Renderer::new(&context.devices[device_id].device, options).unwrap();
+    (context, renderer)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn passes_run_in_order_with_dependencies() {
+    let (_context, renderer) = renderer(false);
+    let passes = renderer.describe_pipeline(AaConfig::Area);
+    let position = |label: &str| {
+        passes
+            .iter()
+            .position(|pass| pass.label == label)
+            .unwrap_or_else(|| panic!("{label} should be described"))
+    };
+    assert!(
+        position("vello.flatten") < position("vello.tile_alloc"),
+        "paths are flattened before tiles are allocated"
+    );
+    assert!(
+        position("vello.tile_alloc") < position("vello.coarse"),
+        "tiles are allocated before coarse rasterization"
+    );
+    assert!(
+        position("vello.clip_leaf") < position("vello.coarse"),
+        "clip passes should be described"
+    );
+    let fine = passes.last().unwrap();
+    assert_eq!(
+        fine.label, "vello.fine_area",
+        "fine rasterization runs last"
+    );
+    assert!(
+        fine.resources.contains(&"vello.ptcl_buf"),
+        "fine rasterization reads the output of coarse rasterization"
+    );
+    assert!(
+        fine.resources.contains(&"vello.target"),
+        "fine rasterization writes the target"
+    );
+    assert!(
+        passes[position("vello.coarse")]
+            .resources
+            .contains(&"vello.ptcl_buf"),
+        "coarse rasterization writes the per-tile command list"
+    );
+    assert!(
+        passes.iter().all(|pass| !pass.cpu),
+        "all passes run on the GPU by default"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn cpu_passes_and_post_process_are_described() {
+    let (_context, mut renderer) = renderer(true);
+    let passes = renderer.describe_pipeline(AaConfig::Area);
+    let coarse = passes
+        .iter()
+        .find(|pass| pass.label == "vello.coarse")
+        .unwrap();
+    assert!(coarse.cpu, "coarse rasterization runs on the CPU");
+    assert!(
+        !passes.last().unwrap().cpu,
+        "fine rasterization always runs on the GPU"
+    );
+
+    renderer.set_post_process(PostProcessShader::new(
+        "@fragment fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> { \
+         return textureSample(source_texture, source_sampler, in.uv); }",
+    ));
+    let with_post_process = renderer.describe_pipeline(AaConfig::Area);
+    assert_eq!(
+        with_post_process.len(),
+        passes.len() + 1,
+        "post-processing adds one pass"
+    );
+    assert_eq!(
+        with_post_process.last().unwrap().label,
+        "vello.post_process",
+        "post-processing runs last"
+    );
+}
@@ -0,0 +1,89 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that a [`Renderer`] can only be used with the device it was created with.
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const SIZE: u32 = 16;
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn other_device_or_queue_is_rejected() {
+    let context = RenderContext::new();
+    let adapter = pollster::block_on(wgpu::util::initialize_adapter_from_env_or_default(
+        &context.instance,
+        None,
+    ))
+    .expect("No compatible adapter found");
+    let (device, queue) =
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+    let (other_device, other_queue) =
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+
+    let mut renderer = Renderer::new(&device, RendererOptions::default()).unwrap();
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)),
+    );
+    let params = RenderParams {
+        base_color: Color::BLACK,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+
+    let result = renderer.render_to_texture(&other_device, &other_queue, &scene, &view, &params);
+    assert!(
+        matches!(result, Err(Error::DeviceMismatch)),
+        "Rendering with another device should fail, got {result:?}"
+    );
+    renderer
+        .render_to_texture(&device, &queue, &scene, &view, &params)
+        .expect("Rendering with the renderer's device should succeed");
+    let result = renderer.render_to_texture(&device, &other_queue, &scene, &view, &params);
+    assert!(
+        matches!(result, Err(Error::DeviceMismatch)),
+        "Rendering with another queue should fail, got {result:?}"
+    );
+
+    let pixel = renderer
+        .read_pixel(&device, &queue, &target, SIZE / 2, SIZE / 2)
+        .unwrap();
+    assert!(
+        pixel.difference(palette::css::RED) < 0.01,
+        "The scene should still be rendered, got {pixel:?}"
+    );
+}
@@ -0,0 +1,34 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that [`Renderer::new`] fails clearly on devices which can't run compute shaders.
+
+use vello::util::RenderContext;
+use vello::wgpu;
+use vello::{Error, Renderer, RendererOptions};
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn webgl2_limits_are_rejected() {
+    let context = RenderContext::new();
+    let adapter = pollster::block_on(wgpu::util::initialize_adapter_from_env_or_default(
+        &context.instance,
+        None,
+    ))
+    .expect("No compatible adapter found");
+    // Request a device with the limits of WebGL2, which has no compute shaders, to simulate
+    // running on that backend.
+    let (device, _queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+        label: None,
+        required_features: wgpu::Features::empty(),
+        required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
+        ..Default::default()
+    }))
+    .unwrap();
+    let result = Renderer::new(&device, RendererOptions::default());
+    assert!(
+        matches!(result, Err(Error::ComputeShadersUnsupported)),
+        "Unexpected result {:?}",
+        result.err()
+    );
+}
@@ -0,0 +1,118 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_with_alpha`] and [`Scene::stroke_with_alpha`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Rect, Stroke};
+use vello::peniko::{Fill, Gradient, Mix, color::palette};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+const ALPHA: f32 = 0.4;
+
+fn gradient() -> Gradient {
+    Gradient::new_linear((8.0, 0.0), (56.0, 0.0))
+        .with_stops([palette::css::RED, palette::css::BLUE])
+}
+
+#[test]
+fn alpha_does_not_push_a_layer() {
+    let mut scene = Scene::new();
+    scene.fill_with_alpha(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        ALPHA,
+        None,
+        &Rect::new(0.0, 0.0, 10.0, 10.0),
+    );
+    scene.stroke_with_alpha(
+        &Stroke::new(2.0),
+        Affine::IDENTITY,
+        &gradient(),
+        ALPHA,
+        None,
+        &Circle::new((5.0, 5.0), 4.0),
+    );
+    assert_eq!(
+        scene.encoding().draw_tags,
+        [DrawTag::COLOR, DrawTag::LINEAR_GRADIENT]
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn alpha_matches_layer_opacity() {
+    let circle = Circle::new((20.0, 20.0), 14.0);
+    let rect = Rect::new(8.0, 40.0, 56.0, 56.0);
+    let stroke = Stroke::new(3.0);
+    let bounds = Rect::new(0.0, 0.0, 64.0, 64.0);
+
+    let mut alpha_scene = Scene::new();
+    alpha_scene.fill_with_alpha(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        ALPHA,
+        None,
+        &circle,
+    );
+    alpha_scene.fill_with_alpha(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient(),
+        ALPHA,
+        None,
+        &rect,
+    );
+    alpha_scene.stroke_with_alpha(
+        &stroke,
+        Affine::IDENTITY,
+        palette::css::LIME,
+        ALPHA,
+        None,
+        &Circle::new((46.0, 20.0), 10.0),
+    );
+
+    // The same shapes, each faded by its own layer.
+    let mut layer_scene = Scene::new();
+    layer_scene.push_layer(Fill::NonZero, Mix::Normal, ALPHA, Affine::IDENTITY, &bounds);
+    layer_scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &circle,
+    );
+    layer_scene.pop_layer();
+    layer_scene.push_layer(Fill::NonZero, Mix::Normal, ALPHA, Affine::IDENTITY, &bounds);
+    layer_scene.fill(Fill::NonZero, Affine::IDENTITY, &gradient(), None, &rect);
+    layer_scene.pop_layer();
+    layer_scene.push_layer(Fill::NonZero, Mix::Normal, ALPHA, Affine::IDENTITY, &bounds);
+    layer_scene.stroke(
+        &stroke,
+        Affine::IDENTITY,
+        palette::css::LIME,
+        None,
+        &Circle::new((46.0, 20.0), 10.0),
+    );
+    layer_scene.pop_layer();
+
+    let params = TestParams::new("draw_alpha", 64, 64);
+    let alpha_image = vello_tests::render_then_debug_sync(&alpha_scene, &params).unwrap();
+    let layer_image = vello_tests::render_then_debug_sync(&layer_scene, &params).unwrap();
+    for (alpha_pixel, layer_pixel) in alpha_image
+        .data
+        .data()
+        .chunks_exact(4)
+        .zip(layer_image.data.data().chunks_exact(4))
+    {
+        for (a, b) in alpha_pixel.iter().zip(layer_pixel) {
+            assert!(
+                a.abs_diff(*b) <= 1,
+                "{alpha_pixel:?} differs from {layer_pixel:?}"
+            );
+        }
+    }
+}
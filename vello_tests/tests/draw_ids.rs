@@ -0,0 +1,116 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for tagging draws with ids using [`Scene::draw_with_id`].
+
+use vello::kurbo::{Affine, Circle, Rect, Stroke};
+use vello::peniko::{Fill, color::palette};
+use vello::{DrawId, Scene};
+
+const RECT: Rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+
+#[test]
+fn ids_are_attached_to_their_draws() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RECT,
+    );
+    scene.fill_with_id(
+        7,
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RECT,
+    );
+    scene.stroke_with_id(
+        9,
+        &Stroke::new(1.0),
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &Circle::new((5.0, 5.0), 4.0),
+    );
+    assert_eq!(
+        scene.draw_ids(),
+        [DrawId { id: 7, draws: 1..2 }, DrawId { id: 9, draws: 2..3 }]
+    );
+    assert_eq!(scene.draw_id(0), None, "the first draw has no id");
+    assert_eq!(scene.draw_id(1), Some(7));
+    assert_eq!(scene.draw_id(2), Some(9));
+    assert_eq!(scene.draw_id(3), None, "there is no fourth draw");
+
+    scene.reset();
+    assert!(scene.draw_ids().is_empty(), "resetting clears the ids");
+}
+
+#[test]
+fn culled_draws_are_not_tagged() {
+    let mut scene = Scene::new();
+    scene.set_cull_rect(Some(Rect::new(100.0, 100.0, 200.0, 200.0)));
+    scene.fill_with_id(
+        1,
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RECT,
+    );
+    assert!(scene.draw_ids().is_empty(), "nothing was drawn");
+}
+
+#[test]
+fn nested_ids_take_precedence() {
+    let mut scene = Scene::new();
+    scene.draw_with_id(1, |scene| {
+        scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &RECT);
+        scene.fill_with_id(
+            2,
+            Fill::NonZero,
+            Affine::IDENTITY,
+            palette::css::RED,
+            None,
+            &RECT,
+        );
+        scene.pop_layer();
+    });
+    assert_eq!(
+        scene.draw_ids(),
+        [
+            DrawId { id: 1, draws: 0..1 },
+            DrawId { id: 2, draws: 1..2 },
+            DrawId { id: 1, draws: 2..3 },
+        ]
+    );
+}
+
+#[test]
+fn ids_survive_append_and_patches() {
+    let mut child = Scene::new();
+    child.fill_with_id(
+        3,
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RECT,
+    );
+    let mut parent = Scene::new();
+    parent.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RECT,
+    );
+    parent.append(&child, Some(Affine::translate((20.0, 0.0))));
+    assert_eq!(parent.draw_ids(), [DrawId { id: 3, draws: 1..2 }]);
+
+    let mut patched = child.clone();
+    patched.apply_patch(parent.diff(&child)).unwrap();
+    assert_eq!(patched.draw_ids(), parent.draw_ids());
+}
@@ -0,0 +1,98 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`edge_quality`], and of the anti-aliasing methods using it.
+
+use vello::kurbo::{Affine, BezPath, Line};
+use vello::peniko::{Fill, ImageAlphaType, ImageData, ImageFormat, color::palette};
+use vello::testing::edge_quality;
+use vello::{AaConfig, Scene};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+
+/// A 16x16 image with white on the left and black on the right, where the column at `x = 8`
+/// has the brightness `middle`.
+fn vertical_edge(middle: u8) -> ImageData {
+    let mut data = Vec::new();
+    for _ in 0..16 {
+        for x in 0..16 {
+            let value = match x {
+                ..8 => 255,
+                8 => middle,
+                _ => 0,
+            };
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+    ImageData {
+        data: data.into(),
+        format: ImageFormat::Rgba8,
+        width: 16,
+        height: 16,
+        alpha_type: ImageAlphaType::Alpha,
+    }
+}
+
+#[test]
+fn exact_edge_scores_higher_than_aliased() {
+    let edge = Line::new((8.5, 0.0), (8.5, 16.0));
+    let exact = edge_quality(&vertical_edge(128), edge);
+    let aliased = edge_quality(&vertical_edge(255), edge);
+    assert!(exact > 0.99, "Expected an exact edge, got {exact}");
+    assert!(
+        (aliased - 0.71).abs() < 0.01,
+        "Expected an error of half a pixel in a third of the pixels, got {aliased}"
+    );
+    // The metric doesn't depend on the direction of the edge.
+    let reversed = edge_quality(&vertical_edge(128), Line::new(edge.p1, edge.p0));
+    assert!((reversed - exact).abs() < 1e-9);
+    assert_eq!(
+        edge_quality(&vertical_edge(255), Line::new((2.5, 0.0), (2.5, 16.0))),
+        0.0,
+        "No edge should be found"
+    );
+}
+
+/// Renders a white triangle on black with a diagonal edge, returning the quality of the edge.
+fn diagonal_edge_quality(anti_aliasing: AaConfig, aliased: bool) -> f64 {
+    let mut triangle = BezPath::new();
+    triangle.move_to((0.0, 0.0));
+    triangle.line_to((64.0, 0.0));
+    triangle.line_to((0.0, 50.0));
+    triangle.close_path();
+    let mut scene = Scene::new();
+    scene.set_antialiasing(!aliased);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &triangle,
+    );
+    let mut params = TestParams::new("edge_quality", SIZE, SIZE);
+    params.base_color = Some(palette::css::BLACK);
+    params.anti_aliasing = anti_aliasing;
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    edge_quality(&image, Line::new((8.0, 43.75), (56.0, 6.25)))
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn msaa16_scores_higher_than_aliased() {
+    let aliased = diagonal_edge_quality(AaConfig::Area, true);
+    let msaa16 = diagonal_edge_quality(AaConfig::Msaa16, false);
+    let area = diagonal_edge_quality(AaConfig::Area, false);
+    assert!(
+        msaa16 > aliased,
+        "MSAA16 ({msaa16}) should score higher than aliased output ({aliased})"
+    );
+    assert!(
+        area > aliased,
+        "Area anti-aliasing ({area}) should score higher than aliased output ({aliased})"
+    );
+    assert!(
+        area > 0.95,
+        "Expected area anti-aliasing to be exact, got {area}"
+    );
+}
@@ -0,0 +1,91 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for Material Design elevation shadows, drawn using [`Scene::draw_elevation_shadow`].
+
+use vello::kurbo::{Affine, Rect, RoundedRect, Vec2};
+use vello::peniko::color::palette;
+use vello::{BoxShadowInstance, Scene};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+const RECT: Rect = Rect::new(16.0, 16.0, 48.0, 48.0);
+
+#[test]
+fn elevation_8_matches_material_spec() {
+    let shape = RoundedRect::from_rect(RECT, 4.0);
+    let [ambient, key] = BoxShadowInstance::material_elevation(Affine::IDENTITY, shape, 8.0);
+    // The key shadow is `0 2px 3px rgba(0, 0, 0, 0.3)`.
+    assert_eq!(key.rect, RECT + Vec2::new(0.0, 2.0), "key shadow offset");
+    assert_eq!(key.std_dev, 1.5, "key shadow blur");
+    assert_eq!(key.radius, 4.0, "key shadow radius");
+    assert_eq!(
+        key.color,
+        palette::css::BLACK.with_alpha(0.3),
+        "key shadow color"
+    );
+    // The ambient shadow is `0 6px 10px 4px rgba(0, 0, 0, 0.15)`.
+    assert_eq!(
+        ambient.rect,
+        RECT.inflate(4.0, 4.0) + Vec2::new(0.0, 6.0),
+        "ambient shadow offset and spread"
+    );
+    assert_eq!(ambient.std_dev, 5.0, "ambient shadow blur");
+    assert_eq!(ambient.radius, 8.0, "ambient shadow radius");
+    assert_eq!(
+        ambient.color,
+        palette::css::BLACK.with_alpha(0.15),
+        "ambient shadow color"
+    );
+}
+
+#[test]
+fn elevations_between_levels_are_interpolated() {
+    let shape = RoundedRect::from_rect(RECT, 0.0);
+    // 7 dp is halfway between the levels at 6 dp and 8 dp.
+    let [ambient, key] = BoxShadowInstance::material_elevation(Affine::IDENTITY, shape, 7.0);
+    assert_eq!(key.rect, RECT + Vec2::new(0.0, 1.5), "key shadow offset");
+    assert_eq!(key.std_dev, 1.5, "key shadow blur");
+    assert_eq!(ambient.std_dev, 4.5, "ambient shadow blur");
+    assert_eq!(ambient.radius, 0.0, "square corners stay square");
+
+    let [_, highest] = BoxShadowInstance::material_elevation(Affine::IDENTITY, shape, 12.0);
+    let [_, higher] = BoxShadowInstance::material_elevation(Affine::IDENTITY, shape, 100.0);
+    assert_eq!(highest, higher, "elevations above 12 dp are clamped");
+}
+
+#[test]
+fn zero_elevation_draws_nothing() {
+    let shape = RoundedRect::from_rect(RECT, 4.0);
+    let mut scene = Scene::new();
+    scene.draw_elevation_shadow(Affine::IDENTITY, shape, 0.0);
+    scene.draw_elevation_shadow(Affine::IDENTITY, shape, f64::NAN);
+    assert!(scene.encoding().is_empty(), "nothing should be drawn");
+    scene.draw_elevation_shadow(Affine::IDENTITY, shape, 3.0);
+    assert_eq!(
+        scene.encoding().draw_tags,
+        [DrawTag::BLUR_RECT, DrawTag::BLUR_RECT],
+        "the ambient and key shadows are each a blurred rect"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn shadow_is_below_surface() {
+    let shape = RoundedRect::from_rect(RECT, 4.0);
+    let mut scene = Scene::new();
+    scene.draw_elevation_shadow(Affine::IDENTITY, shape, 8.0);
+    let mut params = TestParams::new("elevation_shadow", 64, 64);
+    params.base_color = Some(palette::css::WHITE);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let data = image.data.data();
+    let red = |x: usize, y: usize| data[(y * 64 + x) * 4];
+    assert!(
+        red(32, 50) < red(32, 14),
+        "the shadow should be darker below the surface than above it"
+    );
+    assert!(
+        red(32, 32) < 255,
+        "the shadow should be drawn under the surface"
+    );
+}
@@ -0,0 +1,103 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for using a device created outside of Vello, with [`RenderContext::add_device`].
+//!
+//! [`RenderSurface::from_existing`](vello::util::RenderSurface::from_existing) needs a window,
+//! so can't be tested here. It is what [`RenderContext::create_render_surface`] uses to create
+//! surfaces for the devices in the context, including added devices.
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{AaConfig, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene};
+
+const SIZE: u32 = 16;
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn added_device_is_used_by_context() {
+    let mut context = RenderContext::new();
+    // Create the device as an engine would, without going through the context.
+    let adapter = pollster::block_on(wgpu::util::initialize_adapter_from_env_or_default(
+        &context.instance,
+        None,
+    ))
+    .expect("No compatible adapter found");
+    let (device, queue) =
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+    let dev_id = context.add_device(adapter, device.clone(), queue);
+    assert_eq!(
+        pollster::block_on(context.device(None)),
+        Some(dev_id),
+        "The added device should be found"
+    );
+    assert_eq!(
+        context.devices.len(),
+        1,
+        "No other device should have been created"
+    );
+
+    // Render using the context's handle, to check that it shares the engine's device.
+    let device_handle = &context.devices[dev_id];
+    let mut renderer = Renderer::new(&device, RendererOptions::default()).unwrap();
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)),
+    );
+    let params = RenderParams {
+        base_color: Color::BLACK,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer
+        .render_to_texture(
+            &device_handle.device,
+            &device_handle.queue,
+            &scene,
+            &view,
+            &params,
+        )
+        .expect("Rendering with the added device should succeed");
+    let pixel = renderer
+        .read_pixel(
+            &device_handle.device,
+            &device_handle.queue,
+            &target,
+            SIZE / 2,
+            SIZE / 2,
+        )
+        .unwrap();
+    assert!(
+        pixel.difference(palette::css::RED) < 0.01,
+        "The scene should be rendered into the engine's texture, got {pixel:?}"
+    );
+}
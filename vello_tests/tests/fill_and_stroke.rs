@@ -0,0 +1,70 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_and_stroke`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Rect, Shape, Stroke};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+#[test]
+fn matches_separate_calls() {
+    let path = Circle::new((20.0, 20.0), 12.0).to_path(0.1);
+    let stroke = Stroke::new(3.0);
+    let transform = Affine::rotate(0.3);
+    let mut expected = Scene::new();
+    expected.fill(Fill::EvenOdd, transform, palette::css::RED, None, &path);
+    expected.stroke(&stroke, transform, palette::css::BLUE, None, &path);
+    let mut scene = Scene::new();
+    scene.fill_and_stroke(
+        Fill::EvenOdd,
+        &stroke,
+        transform,
+        palette::css::RED,
+        palette::css::BLUE,
+        &path,
+    );
+    let (actual, expected) = (scene.encoding(), expected.encoding());
+    assert_eq!(actual.draw_tags, expected.draw_tags);
+    assert_eq!(actual.draw_data, expected.draw_data);
+    assert_eq!(actual.path_tags, expected.path_tags);
+    assert_eq!(actual.path_data, expected.path_data);
+    assert_eq!(actual.transforms, expected.transforms);
+    assert_eq!(
+        actual.transforms.len(),
+        1,
+        "The transform should be shared by both draws"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn stroke_is_drawn_over_fill() {
+    let mut scene = Scene::new();
+    scene.fill_and_stroke(
+        Fill::NonZero,
+        &Stroke::new(8.0),
+        Affine::IDENTITY,
+        palette::css::RED,
+        palette::css::BLUE,
+        &Rect::new(8.0, 8.0, 24.0, 24.0),
+    );
+    let params = TestParams::new("fill_and_stroke", 32, 32);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = |x: u32, y: u32| {
+        let index = usize::try_from((y * 32 + x) * 4).unwrap();
+        <[u8; 4]>::try_from(&image.data.data()[index..index + 4]).unwrap()
+    };
+    assert_eq!(pixel(16, 16), [255, 0, 0, 255], "Expected the fill inside");
+    assert_eq!(
+        pixel(10, 16),
+        [0, 0, 255, 255],
+        "Expected the stroke over the fill"
+    );
+    assert_eq!(
+        pixel(6, 16),
+        [0, 0, 255, 255],
+        "Expected the stroke outside"
+    );
+}
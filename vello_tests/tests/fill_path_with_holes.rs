@@ -0,0 +1,70 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_path_with_holes`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Circle, Shape};
+use vello::peniko::color::palette;
+use vello_encoding::Style;
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+
+/// An annulus whose outer and inner circles are both drawn in the same direction, so that
+/// the non-zero rule would fill the hole.
+fn annulus(reverse_inner: bool) -> BezPath {
+    let mut path = Circle::new((32.0, 32.0), 24.0).to_path(0.1);
+    let inner = Circle::new((32.0, 32.0), 12.0).to_path(0.1);
+    if reverse_inner {
+        path.extend(inner.reverse_subpaths());
+    } else {
+        path.extend(inner);
+    }
+    path
+}
+
+#[test]
+fn uses_even_odd() {
+    let mut scene = Scene::new();
+    scene.fill_path_with_holes(Affine::IDENTITY, palette::css::WHITE, &annulus(false));
+    let styles = &scene.encoding().styles;
+    assert_eq!(styles.len(), 1, "one path should be encoded");
+    assert_eq!(
+        styles[0].flags_and_miter_limit & Style::FLAGS_FILL_BIT,
+        Style::FLAGS_FILL_BIT,
+        "the path should be filled using the even-odd rule"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn annulus_has_empty_center_regardless_of_winding() {
+    for reverse_inner in [false, true] {
+        let mut scene = Scene::new();
+        scene.fill_path_with_holes(
+            Affine::IDENTITY,
+            palette::css::WHITE,
+            &annulus(reverse_inner),
+        );
+        let params = TestParams::new("fill_path_with_holes", SIZE, SIZE);
+        let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+        let data = image.data.data();
+        let red = |x: u32, y: u32| data[usize::try_from((y * SIZE + x) * 4).unwrap()];
+        assert_eq!(
+            red(32, 32),
+            0,
+            "the center should be a hole (reversed inner circle: {reverse_inner})"
+        );
+        assert_eq!(
+            red(32, 14),
+            255,
+            "the ring should be filled (reversed inner circle: {reverse_inner})"
+        );
+        assert_eq!(
+            red(2, 2),
+            0,
+            "outside the ring should be empty (reversed inner circle: {reverse_inner})"
+        );
+    }
+}
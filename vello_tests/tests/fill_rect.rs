@@ -0,0 +1,101 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_rect`] and [`Scene::stroke_rect`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect, Stroke};
+use vello::peniko::{Color, Fill, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 32;
+
+fn transforms() -> [Affine; 3] {
+    [
+        Affine::IDENTITY,
+        Affine::translate((3.5, -2.0)),
+        Affine::rotate_about(0.3, (16.0, 16.0)).then_scale(1.5),
+    ]
+}
+
+#[test]
+fn fill_rect_matches_fill() {
+    let rect = Rect::new(4.0, 6.0, 20.0, 18.0);
+    for transform in transforms() {
+        let mut expected = Scene::new();
+        expected.fill(Fill::NonZero, transform, palette::css::RED, None, &rect);
+        let mut actual = Scene::new();
+        actual.fill_rect(transform, rect, palette::css::RED);
+        assert_eq!(
+            actual.encoding().path_data,
+            expected.encoding().path_data,
+            "The fast path should encode the same path with {transform:?}"
+        );
+        assert_eq!(actual.encoding().path_tags, expected.encoding().path_tags);
+        assert_eq!(actual.encoding().draw_data, expected.encoding().draw_data);
+        assert_eq!(actual.encoding().transforms, expected.encoding().transforms);
+    }
+}
+
+#[test]
+fn stroke_rect_matches_stroke() {
+    let rect = Rect::new(4.0, 6.0, 20.0, 18.0);
+    let stroke = Stroke::new(3.0);
+    for transform in transforms() {
+        let mut expected = Scene::new();
+        expected.stroke(&stroke, transform, palette::css::RED, None, &rect);
+        let mut actual = Scene::new();
+        actual.stroke_rect(&stroke, transform, rect, palette::css::RED);
+        assert_eq!(
+            actual.encoding().path_data,
+            expected.encoding().path_data,
+            "The fast path should encode the same path with {transform:?}"
+        );
+        assert_eq!(actual.encoding().path_tags, expected.encoding().path_tags);
+        assert_eq!(actual.encoding().styles, expected.encoding().styles);
+        assert_eq!(actual.encoding().transforms, expected.encoding().transforms);
+    }
+}
+
+#[test]
+fn empty_rects_are_skipped() {
+    let mut scene = Scene::new();
+    scene.fill_rect(
+        Affine::IDENTITY,
+        Rect::new(4.0, 4.0, 4.0, 20.0),
+        palette::css::RED,
+    );
+    scene.stroke_rect(
+        &Stroke::new(0.0),
+        Affine::IDENTITY,
+        Rect::new(4.0, 4.0, 20.0, 20.0),
+        palette::css::RED,
+    );
+    assert!(
+        scene.encoding().is_empty(),
+        "Rects without area and zero width strokes shouldn't be encoded"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn transformed_fill_rect_renders() {
+    let mut scene = Scene::new();
+    // A 16x8 rect, rotated a quarter turn about the center of the target, becomes 8x16.
+    let transform = Affine::rotate_about(std::f64::consts::FRAC_PI_2, (16.0, 16.0));
+    scene.fill_rect(
+        transform,
+        Rect::new(8.0, 12.0, 24.0, 20.0),
+        palette::css::RED,
+    );
+    let mut params = TestParams::new("fill_rect_transformed", SIZE, SIZE);
+    params.base_color = Some(Color::TRANSPARENT);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let alpha = |x: usize, y: usize| image.data.data()[(y * SIZE as usize + x) * 4 + 3];
+    assert_eq!(alpha(16, 10), 255, "The rotated rect should cover (16, 10)");
+    assert_eq!(
+        alpha(10, 16),
+        0,
+        "The rotated rect shouldn't cover (10, 16), which the unrotated rect would"
+    );
+}
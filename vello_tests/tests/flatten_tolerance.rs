@@ -0,0 +1,78 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the tolerance used to flatten curves, and
+//! [`RenderParams::flatten_tolerance`](vello::RenderParams::flatten_tolerance).
+
+use std::f64::consts::PI;
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+const RADIUS: f64 = 28.0;
+
+fn circle_scene(transform: Affine, circle: Circle) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(Fill::NonZero, transform, palette::css::WHITE, None, &circle);
+    scene
+}
+
+fn render(scene: &Scene, name: &str, flatten_tolerance: Option<f64>) -> Vec<u8> {
+    let params = TestParams {
+        flatten_tolerance,
+        ..TestParams::new(name, SIZE, SIZE)
+    };
+    let image = vello_tests::render_then_debug_sync(scene, &params).unwrap();
+    image.data.data().to_vec()
+}
+
+/// The area covered by the white fill, in pixels.
+fn coverage(pixels: &[u8]) -> f64 {
+    pixels
+        .chunks_exact(4)
+        .map(|pixel| f64::from(pixel[0]) / 255.0)
+        .sum()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn zoomed_curves_are_flattened_in_device_space() {
+    // A small circle scaled up ten times is flattened with as many lines as a large circle,
+    // so the two render the same.
+    let zoomed = circle_scene(Affine::scale(10.0), Circle::new((3.2, 3.2), RADIUS / 10.0));
+    let large = circle_scene(Affine::IDENTITY, Circle::new((32.0, 32.0), RADIUS));
+    let zoomed = render(&zoomed, "flatten_zoomed", None);
+    let large = render(&large, "flatten_large", None);
+    for (a, b) in zoomed.iter().zip(&large) {
+        assert!(a.abs_diff(*b) <= 2, "{a} and {b} differ");
+    }
+    let area = PI * RADIUS * RADIUS;
+    assert!((coverage(&zoomed) - area).abs() < 0.01 * area);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn larger_tolerance_gives_visible_facets() {
+    let scene = circle_scene(Affine::IDENTITY, Circle::new((32.0, 32.0), RADIUS));
+    let smooth = coverage(&render(&scene, "flatten_default", None));
+    let coarse = coverage(&render(&scene, "flatten_coarse", Some(3.0)));
+    // The flattened polygon is inside the circle, so it covers less, by roughly
+    // the perimeter times two thirds of the tolerance.
+    assert!(
+        coarse < smooth - 100.0,
+        "coarse coverage {coarse} should be less than {smooth}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn invalid_tolerance_uses_default() {
+    let scene = circle_scene(Affine::IDENTITY, Circle::new((32.0, 32.0), RADIUS));
+    let expected = render(&scene, "flatten_default", None);
+    for tolerance in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(render(&scene, "flatten_invalid", Some(tolerance)), expected);
+    }
+}
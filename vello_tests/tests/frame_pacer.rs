@@ -0,0 +1,184 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`FramePacer`] and [`Renderer::last_frame_gpu_time`](vello::Renderer::last_frame_gpu_time).
+
+use std::time::Duration;
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Fill, color::palette};
+use vello::util::{FramePacer, RenderContext};
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, AaSupport, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const TARGET: Duration = Duration::from_millis(16);
+const SLOW: Duration = Duration::from_millis(40);
+const FAST: Duration = Duration::from_millis(4);
+
+/// Feeds `frame_time` to the pacer until the quality changes, returning whether it did.
+fn step(pacer: &mut FramePacer, frame_time: Duration) -> bool {
+    (0..100).any(|_| pacer.update(frame_time))
+}
+
+#[test]
+fn slow_frames_lower_antialiasing_then_resolution() {
+    let mut pacer = FramePacer::new(TARGET, AaSupport::all());
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Msaa16);
+    assert_eq!(pacer.render_scale(), 1.0);
+
+    assert!(
+        step(&mut pacer, SLOW),
+        "slow frames should lower the quality"
+    );
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Msaa8);
+    assert!(
+        step(&mut pacer, SLOW),
+        "slow frames should lower the quality"
+    );
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Area);
+    assert_eq!(pacer.render_scale(), 1.0);
+
+    assert!(
+        step(&mut pacer, SLOW),
+        "slow frames should lower the quality"
+    );
+    assert!(pacer.render_scale() < 1.0, "{}", pacer.render_scale());
+    while step(&mut pacer, SLOW) {}
+    assert_eq!(pacer.render_scale(), 0.5);
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Area);
+}
+
+#[test]
+fn fast_frames_raise_resolution_then_antialiasing() {
+    let mut pacer = FramePacer::new(TARGET, AaSupport::all()).with_min_render_scale(0.3);
+    while step(&mut pacer, SLOW) {}
+    assert_eq!(pacer.render_scale(), 0.3);
+
+    assert!(
+        step(&mut pacer, FAST),
+        "fast frames should raise the quality"
+    );
+    assert!(
+        (pacer.render_scale() - 0.4).abs() < 1e-6,
+        "{}",
+        pacer.render_scale()
+    );
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Area);
+    while pacer.render_scale() < 1.0 {
+        assert!(
+            step(&mut pacer, FAST),
+            "fast frames should raise the quality"
+        );
+        assert_eq!(pacer.antialiasing_method(), AaConfig::Area);
+    }
+    while step(&mut pacer, FAST) {}
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Msaa16);
+    assert_eq!(pacer.render_scale(), 1.0);
+}
+
+#[test]
+fn frames_within_budget_keep_the_quality() {
+    let mut pacer = FramePacer::new(TARGET, AaSupport::all());
+    // Above the threshold for raising quality, but below the target.
+    assert!(
+        !step(&mut pacer, Duration::from_millis(14)),
+        "quality should not change"
+    );
+    // A single slow frame is smoothed out.
+    assert!(
+        !pacer.update(Duration::from_millis(20)),
+        "quality should not change"
+    );
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Msaa16);
+}
+
+#[test]
+fn frames_after_a_change_are_ignored() {
+    let mut pacer = FramePacer::new(TARGET, AaSupport::all());
+    assert!(
+        pacer.update(SLOW),
+        "a slow first frame should lower the quality"
+    );
+    for _ in 0..8 {
+        assert!(
+            !pacer.update(SLOW),
+            "frames should be ignored while settling"
+        );
+    }
+    assert!(pacer.update(SLOW), "slow frames should lower the quality");
+}
+
+#[test]
+fn only_supported_antialiasing_is_used() {
+    let mut pacer = FramePacer::new(TARGET, AaSupport::area_only()).with_min_render_scale(1.0);
+    assert_eq!(pacer.antialiasing_method(), AaConfig::Area);
+    assert!(
+        !step(&mut pacer, SLOW),
+        "there is no cheaper quality to use"
+    );
+    assert_eq!(pacer.render_scale(), 1.0);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn gpu_time_is_measured() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: 32,
+            height: 32,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 32.0, 32.0),
+    );
+    let params = RenderParams {
+        base_color: palette::css::BLACK,
+        width: 32,
+        height: 32,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+
+    assert_eq!(renderer.last_frame_gpu_time(), None);
+    for _ in 0..3 {
+        renderer
+            .render_to_texture(device, queue, &scene, &view, &params)
+            .unwrap();
+        device.poll(wgpu::PollType::wait_indefinitely()).unwrap();
+    }
+    if device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+        assert!(
+            renderer.last_frame_gpu_time().is_some(),
+            "renders should be timed when timestamps are supported"
+        );
+    } else {
+        assert_eq!(renderer.last_frame_gpu_time(), None);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::draw_glow_rounded_rect`].
+
+use vello::kurbo::{Affine, RoundedRect};
+use vello::peniko::color::palette;
+use vello::{GlowKind, Scene};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 128;
+
+/// Renders a glow around a square from 32 to 96, and returns the red channel of the column
+/// through its center, from the top of the image.
+fn glow_column(kind: GlowKind) -> Vec<u8> {
+    let mut scene = Scene::new();
+    scene.draw_glow_rounded_rect(
+        Affine::IDENTITY,
+        RoundedRect::new(32.0, 32.0, 96.0, 96.0, 8.0),
+        palette::css::WHITE,
+        2.0,
+        4.0,
+        kind,
+    );
+    let image =
+        vello_tests::render_then_debug_sync(&scene, &TestParams::new("glow", SIZE, SIZE)).unwrap();
+    let data = image.data.data();
+    (0..SIZE)
+        .map(|y| data[usize::try_from((y * SIZE + SIZE / 2) * 4).unwrap()])
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn outer_glow_is_outside_shape() {
+    let column = glow_column(GlowKind::Outer);
+    // Nothing is drawn inside the shape.
+    assert!(column[32..96].iter().all(|&v| v == 0), "{column:?}");
+    // The glow is brightest next to the shape, and fades away from it.
+    assert!(column[31] > 127, "{column:?}");
+    assert!(column[31] > column[26]);
+    assert!(column[26] > column[22]);
+    // The blur is cut off at `spread + 2.5 * std_dev`.
+    assert!(column[..16].iter().all(|&v| v == 0), "{column:?}");
+    // The glow is symmetric about the center of the shape.
+    for (top, bottom) in column[..32].iter().zip(column[96..].iter().rev()) {
+        assert!(top.abs_diff(*bottom) <= 1, "{column:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn inner_glow_is_inside_shape() {
+    let column = glow_column(GlowKind::Inner);
+    // Nothing is drawn outside the shape.
+    assert!(column[..32].iter().all(|&v| v == 0), "{column:?}");
+    assert!(column[96..].iter().all(|&v| v == 0), "{column:?}");
+    // The glow is brightest at the edge of the shape, and fades towards its center.
+    assert!(column[32] > 127, "{column:?}");
+    assert!(column[32] > column[37]);
+    assert!(column[37] > column[41]);
+    assert!(column[56..72].iter().all(|&v| v < 2), "{column:?}");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn inner_and_outer_glows_meet_at_edge() {
+    let outer = glow_column(GlowKind::Outer);
+    let inner = glow_column(GlowKind::Inner);
+    let outer_extent = outer[..32].iter().filter(|&&v| v > 0).count();
+    let inner_extent = inner[32..64].iter().filter(|&&v| v > 0).count();
+    // With the same blur and spread, both glows extend a similar distance from the edge.
+    assert!(
+        outer_extent.abs_diff(inner_extent) <= 3,
+        "{outer_extent} {inner_extent}"
+    );
+}
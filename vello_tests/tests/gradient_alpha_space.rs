@@ -0,0 +1,60 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that gradients are interpolated in the alpha space they request.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, Gradient, InterpolationAlphaSpace, color::palette};
+use vello_tests::TestParams;
+
+const WIDTH: u32 = 64;
+
+/// Renders a red to transparent gradient over a transparent background, returning the
+/// unpremultiplied color of the pixel in the middle of the gradient.
+fn midpoint(space: InterpolationAlphaSpace) -> [u8; 4] {
+    let mut scene = Scene::new();
+    let gradient = Gradient::new_linear((0.0, 0.0), (f64::from(WIDTH), 0.0))
+        .with_stops([palette::css::RED, palette::css::TRANSPARENT])
+        .with_interpolation_alpha_space(space);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(WIDTH), 4.0),
+    );
+    let mut params = TestParams::new("gradient_alpha_space", WIDTH, 4);
+    params.base_color = Some(Color::TRANSPARENT);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let x = WIDTH as usize / 2;
+    image.data.data()[x * 4..x * 4 + 4].try_into().unwrap()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn premultiplied_gradient_keeps_hue_towards_transparent() {
+    let [r, g, b, a] = midpoint(InterpolationAlphaSpace::Premultiplied);
+    assert!(
+        (100..156).contains(&a),
+        "The midpoint should be half transparent, got alpha {a}"
+    );
+    assert!(
+        r >= 250 && g == 0 && b == 0,
+        "The midpoint should still be fully red, got ({r}, {g}, {b})"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn unpremultiplied_gradient_darkens_towards_transparent() {
+    let [r, g, b, a] = midpoint(InterpolationAlphaSpace::Unpremultiplied);
+    assert!(
+        (100..156).contains(&a),
+        "The midpoint should be half transparent, got alpha {a}"
+    );
+    assert!(
+        (100..156).contains(&r) && g == 0 && b == 0,
+        "The midpoint should be interpolated towards the black of the transparent stop, got ({r}, {g}, {b})"
+    );
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that reflected and repeated gradients are continuous where they wrap.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Extend, Fill, Gradient, color::palette};
+use vello_tests::TestParams;
+
+const WIDTH: u32 = 128;
+/// The length of the gradient, so that the ramp wraps three times across the target.
+const PERIOD: f64 = 32.0;
+
+/// Renders a black to white gradient with the given extend mode, returning the red channel
+/// of each pixel in the first row.
+fn render(extend: Extend) -> Vec<u8> {
+    let mut scene = Scene::new();
+    let gradient = Gradient::new_linear((0.0, 0.0), (PERIOD, 0.0))
+        .with_stops([palette::css::BLACK, palette::css::WHITE])
+        .with_extend(extend);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(WIDTH), 4.0),
+    );
+    let params = TestParams::new("gradient_extend", WIDTH, 4);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    image.data.data()[..WIDTH as usize * 4]
+        .chunks_exact(4)
+        .map(|pixel| pixel[0])
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn reflect_is_continuous_at_boundaries() {
+    let row = render(Extend::Reflect);
+    for (x, &value) in row.iter().enumerate() {
+        // The gradient is a triangle wave, sampled at the pixel centre.
+        let t = (x as f64 + 0.5) / PERIOD;
+        let triangle = (t - 2.0 * (0.5 * t).round()).abs();
+        let expected = triangle * 255.0;
+        assert!(
+            (f64::from(value) - expected).abs() <= 2.0,
+            "Pixel {x} is {value}, expected {expected}"
+        );
+    }
+    // The ramp is mirrored, rather than repeating its last stop, at the reflection points.
+    for boundary in [32, 64, 96] {
+        let (before, after) = (row[boundary - 1], row[boundary]);
+        assert!(
+            before.abs_diff(after) <= 1,
+            "Seam at x = {boundary}: {before} then {after}"
+        );
+        assert!(
+            row[boundary - 2].abs_diff(row[boundary + 1]) <= 1,
+            "Reflection at x = {boundary} isn't symmetric"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn repeat_wraps_from_end_to_start() {
+    let row = render(Extend::Repeat);
+    for boundary in [32, 64, 96] {
+        assert!(
+            row[boundary - 1] > 240 && row[boundary] < 15,
+            "Expected a hard edge at x = {boundary}, got {} then {}",
+            row[boundary - 1],
+            row[boundary]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::draw_image_with_color_space`].
+
+use vello::kurbo::Affine;
+use vello::peniko::{Color, ImageAlphaType, ImageBrush, ImageData, ImageFormat, color::palette};
+use vello::{ImageColorSpace, Scene};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 8;
+
+/// A single pixel image with the given color channels and alpha.
+fn image(value: u8, alpha: u8) -> ImageBrush {
+    let blob: Vec<u8> = vec![value, value, value, alpha];
+    ImageBrush::new(ImageData {
+        data: blob.into(),
+        format: ImageFormat::Rgba8,
+        width: 1,
+        height: 1,
+        alpha_type: ImageAlphaType::Alpha,
+    })
+}
+
+/// Renders `image` scaled to cover the target, over `base_color`, and returns the red channel
+/// of the center pixel.
+fn render(image: &ImageBrush, color_space: ImageColorSpace, base_color: Color) -> u8 {
+    let mut scene = Scene::new();
+    scene.draw_image_with_color_space(image, color_space, Affine::scale(f64::from(SIZE)));
+    let params = TestParams {
+        base_color: Some(base_color),
+        ..TestParams::new("image_color_space", SIZE, SIZE)
+    };
+    let rendered = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let center = usize::try_from(SIZE / 2 * SIZE + SIZE / 2).unwrap();
+    rendered.data.data()[center * 4]
+}
+
+#[test]
+fn srgb_matches_draw_image() {
+    let image = image(55, 255);
+    let mut expected = Scene::new();
+    expected.draw_image(&image, Affine::scale(2.0));
+    let mut scene = Scene::new();
+    scene.draw_image_with_color_space(&image, ImageColorSpace::Srgb, Affine::scale(2.0));
+    assert_eq!(scene.encoding().draw_tags, expected.encoding().draw_tags);
+    assert_eq!(scene.encoding().draw_data, expected.encoding().draw_data);
+    assert_eq!(scene.encoding().path_data, expected.encoding().path_data);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn linear_image_is_converted_to_srgb() {
+    // A linear value of 55 / 255 is the same as an sRGB value of about 128 / 255.
+    let image = image(55, 255);
+    let linear = render(&image, ImageColorSpace::LinearSrgb, palette::css::BLACK);
+    assert!(linear.abs_diff(128) <= 1, "{linear} should be about 128");
+    let srgb = render(&image, ImageColorSpace::Srgb, palette::css::BLACK);
+    assert_eq!(srgb, 55);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn translucent_linear_image_composites_in_srgb() {
+    // The color is converted before it is composited over the background, so a half
+    // transparent mid-gray is halfway between the gray and white.
+    let image = image(55, 128);
+    let composited = render(&image, ImageColorSpace::LinearSrgb, palette::css::WHITE);
+    let expected = (128.0 * 128.0 + 255.0 * 127.0) / 255.0;
+    assert!(
+        (f64::from(composited) - expected).abs() <= 2.0,
+        "{composited} should be about {expected}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn transparent_linear_image_draws_nothing() {
+    let image = image(200, 0);
+    let composited = render(&image, ImageColorSpace::LinearSrgb, palette::css::WHITE);
+    assert_eq!(composited, 255);
+}
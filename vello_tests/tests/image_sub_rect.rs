@@ -0,0 +1,103 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::draw_image_sub_rect`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{ImageAlphaType, ImageBrush, ImageData, ImageFormat, ImageQuality};
+use vello_tests::TestParams;
+
+/// An atlas with two 2x1 entries side by side, the first red and the second blue.
+fn atlas() -> ImageBrush {
+    let red = [255, 0, 0, 255];
+    let blue = [0, 0, 255, 255];
+    let blob: Vec<u8> = [red, red, blue, blue].concat();
+    ImageBrush::new(ImageData {
+        data: blob.into(),
+        format: ImageFormat::Rgba8,
+        width: 4,
+        height: 1,
+        alpha_type: ImageAlphaType::Alpha,
+    })
+    .with_quality(ImageQuality::Medium)
+}
+
+#[test]
+fn sub_rect_is_encoded() {
+    let mut scene = Scene::new();
+    scene.draw_image_sub_rect(
+        &atlas(),
+        Rect::new(2.0, 0.0, 6.0, 1.0),
+        true,
+        Affine::IDENTITY,
+    );
+    let draw_data = &scene.encoding().draw_data;
+    // The origin of the entry, and its size, limited to the atlas.
+    assert_eq!(draw_data[0], 2 << 16, "Unexpected origin");
+    assert_eq!(draw_data[1], (2 << 16) | 1, "Unexpected size");
+
+    let mut empty = Scene::new();
+    empty.draw_image_sub_rect(
+        &atlas(),
+        Rect::new(4.0, 0.0, 8.0, 1.0),
+        true,
+        Affine::IDENTITY,
+    );
+    assert!(
+        empty.encoding().is_empty(),
+        "An empty sub-rect shouldn't be drawn"
+    );
+}
+
+#[test]
+fn sub_rect_without_atlas_safe_samples_whole_image() {
+    let mut scene = Scene::new();
+    scene.draw_image_sub_rect(
+        &atlas(),
+        Rect::new(2.0, 0.0, 4.0, 1.0),
+        false,
+        Affine::IDENTITY,
+    );
+    let draw_data = &scene.encoding().draw_data;
+    assert_eq!(draw_data[0], 0, "The whole image should be sampled");
+    assert_eq!(draw_data[1], (4 << 16) | 1, "Unexpected size");
+}
+
+#[test]
+fn degenerate_transform_is_skipped() {
+    let mut scene = Scene::new();
+    scene.draw_image_sub_rect(
+        &atlas(),
+        Rect::new(0.0, 0.0, 2.0, 1.0),
+        true,
+        Affine::scale(f64::NAN),
+    );
+    assert!(
+        scene.encoding().is_empty(),
+        "A non-finite transform shouldn't be encoded"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn atlas_entry_does_not_bleed() {
+    let mut scene = Scene::new();
+    scene.draw_image_sub_rect(
+        &atlas(),
+        Rect::new(0.0, 0.0, 2.0, 1.0),
+        true,
+        Affine::scale(8.0),
+    );
+    let params = TestParams::new("image_sub_rect", 16, 8);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    for x in 0..16 {
+        let index = usize::try_from((4 * 16 + x) * 4).unwrap();
+        let pixel = &image.data.data()[index..index + 4];
+        assert_eq!(
+            pixel,
+            [255, 0, 0, 255],
+            "Expected only the red entry at {x}, with no bleeding from its neighbor"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`RendererOptions::intermediate_budget_bytes`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, Mix, color::palette};
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+/// The target is 4×4 tiles, so each layer nested beyond the fourth could need 16 KiB.
+const SIZE: u32 = 64;
+const SPILLED_LAYER_BYTES: u64 = 16 * 1024;
+
+/// A scene with `depth` nested layers.
+fn nested_layers(depth: usize) -> Scene {
+    let mut scene = Scene::new();
+    for i in 0..depth {
+        let rect = Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)).inset(-(i as f64));
+        scene.push_layer(Fill::NonZero, Mix::Multiply, 1.0, Affine::IDENTITY, &rect);
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            palette::css::WHITE,
+            None,
+            &rect,
+        );
+    }
+    for _ in 0..depth {
+        scene.pop_layer();
+    }
+    scene
+}
+
+fn render(scene: &Scene, budget: Option<u64>) -> vello::Result<()> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let options = RendererOptions::builder()
+        .intermediate_budget_bytes(budget)
+        .build()?;
+    let mut renderer = Renderer::new(device, options)?;
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let params = RenderParams {
+        base_color: Color::BLACK,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer.render_to_texture(device, queue, scene, &view, &params)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn shallow_layers_need_no_budget() {
+    render(&nested_layers(4), Some(0))
+        .expect("Layers nested four deep should render without intermediate memory");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn deep_layers_within_budget_render() {
+    render(&nested_layers(8), Some(4 * SPILLED_LAYER_BYTES))
+        .expect("Four spilled layers should fit in a budget for four layers");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn deep_layers_over_budget_report_layer() {
+    let result = render(&nested_layers(8), Some(2 * SPILLED_LAYER_BYTES));
+    match result {
+        Err(Error::OutOfIntermediateMemory {
+            layer,
+            required,
+            budget,
+        }) => {
+            assert_eq!(
+                layer, 6,
+                "The seventh layer is the first which needs a third spilled layer"
+            );
+            assert_eq!(required, 3 * SPILLED_LAYER_BYTES, "Unexpected requirement");
+            assert_eq!(budget, 2 * SPILLED_LAYER_BYTES, "Unexpected budget");
+        }
+        result => panic!("Expected an out of intermediate memory error, got {result:?}"),
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn deep_layers_without_budget_render() {
+    render(&nested_layers(8), None).expect("The default buffer should fit eight layers");
+}
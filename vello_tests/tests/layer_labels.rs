@@ -0,0 +1,131 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::push_layer_labeled`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{BlendMode, Compose, Fill, Mix, color::palette};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+fn labeled_scene() -> Scene {
+    let mut scene = Scene::new();
+    let rect = Rect::new(0.0, 0.0, 40.0, 40.0);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &rect,
+    );
+    scene.push_layer_labeled(
+        "outer",
+        Fill::NonZero,
+        BlendMode::new(Mix::Normal, Compose::SrcOver),
+        1.0,
+        Affine::IDENTITY,
+        &rect,
+    );
+    scene.push_layer_labeled(
+        "inner",
+        Fill::NonZero,
+        Compose::DestOut,
+        1.0,
+        Affine::IDENTITY,
+        &rect,
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLACK,
+        None,
+        &rect,
+    );
+    scene.pop_layer();
+    scene.pop_layer();
+    scene
+}
+
+#[test]
+fn labels_refer_to_begin_clip() {
+    let scene = labeled_scene();
+    let labels = scene.layer_labels();
+    assert_eq!(
+        labels.iter().map(|l| l.label.as_str()).collect::<Vec<_>>(),
+        ["outer", "inner"]
+    );
+    for label in labels {
+        assert!(
+            scene.encoding().draw_tags[label.draw_index] == DrawTag::BEGIN_CLIP,
+            "Label {} doesn't refer to a layer",
+            label.label
+        );
+    }
+}
+
+#[test]
+fn append_offsets_labels() {
+    let child = labeled_scene();
+    let mut scene = labeled_scene();
+    let offset = scene.encoding().draw_tags.len();
+    scene.append(&child, None);
+    let labels = scene.layer_labels();
+    assert_eq!(labels.len(), 4);
+    assert_eq!(
+        labels[2].draw_index,
+        child.layer_labels()[0].draw_index + offset
+    );
+    assert_eq!(
+        labels[3].draw_index,
+        child.layer_labels()[1].draw_index + offset
+    );
+
+    scene.reset();
+    assert!(scene.layer_labels().is_empty());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn labeled_layers_render_like_unlabeled() {
+    let rect = Rect::new(0.0, 0.0, 40.0, 40.0);
+    let mut unlabeled = Scene::new();
+    unlabeled.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &rect,
+    );
+    unlabeled.push_layer(
+        Fill::NonZero,
+        BlendMode::new(Mix::Normal, Compose::SrcOver),
+        1.0,
+        Affine::IDENTITY,
+        &rect,
+    );
+    unlabeled.push_layer(
+        Fill::NonZero,
+        Compose::DestOut,
+        1.0,
+        Affine::IDENTITY,
+        &rect,
+    );
+    unlabeled.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLACK,
+        None,
+        &rect,
+    );
+    unlabeled.pop_layer();
+    unlabeled.pop_layer();
+
+    let params = TestParams::new("labeled_layers", 40, 40);
+    let labeled = vello_tests::render_then_debug_sync(&labeled_scene(), &params).unwrap();
+    let unlabeled = vello_tests::render_then_debug_sync(&unlabeled, &params).unwrap();
+    assert!(
+        labeled.data.data() == unlabeled.data.data(),
+        "Labels changed the rendered result"
+    );
+}
@@ -0,0 +1,208 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::render_to_texture_with_mips`].
+
+use vello::kurbo::{Affine, Circle, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::util::{RenderContext, block_on_wgpu};
+use vello::wgpu::{self, Device, Queue, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const SIZE: u32 = 32;
+
+fn create_texture(device: &Device, usage: TextureUsages) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 3,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage,
+        view_formats: &[],
+    })
+}
+
+fn params(base_color: Color) -> RenderParams {
+    RenderParams {
+        base_color,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    }
+}
+
+/// A scene with anti-aliased edges, partially transparent content, and single pixel stripes.
+fn scene() -> Scene {
+    let mut scene = Scene::new();
+    for x in (0..SIZE).step_by(2) {
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            palette::css::ORANGE,
+            None,
+            &Rect::new(f64::from(x), 0.0, f64::from(x) + 1.0, f64::from(SIZE)),
+        );
+    }
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::CRIMSON,
+        None,
+        &Circle::new((10.0, 12.0), 7.3),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::TEAL.with_alpha(0.6),
+        None,
+        &Circle::new((20.0, 20.0), 9.7),
+    );
+    scene
+}
+
+/// Reads back mip `level` of `texture`, as tightly packed RGBA8 rows.
+fn read_level(device: &Device, queue: &Queue, texture: &wgpu::Texture, level: u32) -> Vec<u8> {
+    let size = SIZE >> level;
+    let padded_byte_width = (size * 4).next_multiple_of(256);
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: u64::from(padded_byte_width) * u64::from(size),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&Default::default());
+    encoder.copy_texture_to_buffer(
+        wgpu::TexelCopyTextureInfo {
+            texture,
+            mip_level: level,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_byte_width),
+                rows_per_image: None,
+            },
+        },
+        wgpu::Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit([encoder.finish()]);
+    let slice = buffer.slice(..);
+    let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+    slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+    block_on_wgpu(device, receiver.receive())
+        .unwrap()
+        .expect("Failed to map the readback buffer");
+    let data = slice.get_mapped_range();
+    let row_bytes = usize::try_from(size * 4).unwrap();
+    data.chunks(usize::try_from(padded_byte_width).unwrap())
+        .flat_map(|row| &row[..row_bytes])
+        .copied()
+        .collect()
+}
+
+/// Averages each 2x2 block of the straight alpha `image`, with premultiplied alpha.
+fn box_downsample(image: &[u8], size: usize) -> Vec<f32> {
+    let half = size / 2;
+    let mut result = Vec::with_capacity(half * half * 4);
+    for y in 0..half {
+        for x in 0..half {
+            let mut sum = [0.0_f32; 4];
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let index = ((2 * y + dy) * size + 2 * x + dx) * 4;
+                let texel = &image[index..index + 4];
+                let alpha = f32::from(texel[3]) / 255.0;
+                for channel in 0..3 {
+                    sum[channel] += f32::from(texel[channel]) / 255.0 * alpha;
+                }
+                sum[3] += alpha;
+            }
+            let alpha = sum[3] / 4.0;
+            for channel in &sum[..3] {
+                result.push(channel / 4.0 / alpha.max(1e-6));
+            }
+            result.push(alpha);
+        }
+    }
+    result
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn mip_levels_are_box_downsampled() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let queue = &context.devices[device_id].queue;
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let texture = create_texture(
+        device,
+        TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_SRC,
+    );
+    renderer
+        .render_to_texture_with_mips(
+            device,
+            queue,
+            &scene(),
+            &texture,
+            &params(palette::css::TRANSPARENT),
+        )
+        .unwrap();
+    for level in 1..3 {
+        let size = usize::try_from(SIZE >> (level - 1)).unwrap();
+        let previous = read_level(device, queue, &texture, level - 1);
+        let actual = read_level(device, queue, &texture, level);
+        let expected = box_downsample(&previous, size);
+        for (index, (actual, expected)) in actual.iter().zip(&expected).enumerate() {
+            let actual = f32::from(*actual) / 255.0;
+            assert!(
+                (actual - expected).abs() <= 2.0 / 255.0,
+                "Level {level} differs at channel {index}: expected {expected}, got {actual}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn missing_usages_are_an_error() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let queue = &context.devices[device_id].queue;
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let texture = create_texture(device, TextureUsages::STORAGE_BINDING);
+    let result = renderer.render_to_texture_with_mips(
+        device,
+        queue,
+        &scene(),
+        &texture,
+        &params(palette::css::BLACK),
+    );
+    assert!(
+        matches!(result, Err(Error::IncompatibleTarget { .. })),
+        "Unexpected result {:?}",
+        result.err()
+    );
+}
@@ -0,0 +1,60 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that strokes of paths with both open and closed subpaths cap only the open ones.
+
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Cap, Join, Stroke};
+use vello::peniko::{Color, color::palette};
+use vello_tests::TestParams;
+
+const WIDTH: u32 = 64;
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn open_subpaths_are_capped_and_closed_subpaths_are_joined() {
+    let mut path = BezPath::new();
+    // A closed square, which starts and ends at its top left corner.
+    path.move_to((8.0, 8.0));
+    path.line_to((24.0, 8.0));
+    path.line_to((24.0, 24.0));
+    path.line_to((8.0, 24.0));
+    path.close_path();
+    // An open polyline, as from an SVG path without a `Z` command.
+    path.move_to((40.0, 8.0));
+    path.line_to((56.0, 8.0));
+    path.line_to((56.0, 24.0));
+    let stroke = Stroke::new(8.0)
+        .with_caps(Cap::Square)
+        .with_join(Join::Bevel);
+    let mut scene = Scene::new();
+    scene.stroke(&stroke, Affine::IDENTITY, palette::css::WHITE, None, &path);
+
+    let mut params = TestParams::new("mixed_subpaths", WIDTH, 32);
+    params.base_color = Some(Color::TRANSPARENT);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let alpha = |x: u32, y: u32| image.data.data()[((y * WIDTH + x) * 4 + 3) as usize];
+
+    // Square caps extend the open polyline by half the stroke width at both ends.
+    assert_eq!(
+        alpha(37, 8),
+        255,
+        "the open subpath should have a start cap"
+    );
+    assert_eq!(
+        alpha(56, 26),
+        255,
+        "the open subpath should have an end cap"
+    );
+    // Caps at the start of the square would cover its outer corner, which the bevel cuts off.
+    assert_eq!(
+        alpha(5, 5),
+        0,
+        "the closed subpath should be joined where it starts"
+    );
+    assert_eq!(
+        alpha(5, 5),
+        alpha(26, 26),
+        "all corners of the closed subpath should be joined in the same way"
+    );
+}
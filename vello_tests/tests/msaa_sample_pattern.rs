@@ -0,0 +1,124 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for custom MSAA sample positions, set using [`RendererOptions::msaa_sample_pattern`].
+
+use vello::{Error, RendererOptions};
+use vello_encoding::{make_mask_lut, make_mask_lut_16, make_mask_lut_for_pattern};
+
+/// The default 8 sample pattern, with sample `i` in row `i`.
+fn default_pattern_8() -> Vec<[f32; 2]> {
+    [0, 5, 3, 7, 1, 4, 6, 2]
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| [(x as f32 + 0.5) / 8.0, (i as f32 + 0.5) / 8.0])
+        .collect()
+}
+
+/// The default 16 sample pattern, with sample `i` in row `i`.
+fn default_pattern_16() -> Vec<[f32; 2]> {
+    [1, 8, 4, 11, 15, 7, 3, 12, 0, 9, 5, 13, 2, 10, 6, 14]
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| [(x as f32 + 0.5) / 16.0, (i as f32 + 0.5) / 16.0])
+        .collect()
+}
+
+#[test]
+fn default_patterns_match_default_luts() {
+    assert_eq!(
+        make_mask_lut_for_pattern(&default_pattern_8()),
+        Some(make_mask_lut()),
+        "the default 8 sample pattern should give the default table"
+    );
+    assert_eq!(
+        make_mask_lut_for_pattern(&default_pattern_16()),
+        Some(make_mask_lut_16()),
+        "the default 16 sample pattern should give the default table"
+    );
+}
+
+#[test]
+fn sample_order_and_position_within_row_are_irrelevant() {
+    let mut pattern = default_pattern_8();
+    pattern.reverse();
+    for sample in &mut pattern {
+        // Stay within the same row.
+        sample[1] += 0.04;
+    }
+    assert_eq!(
+        make_mask_lut_for_pattern(&pattern),
+        Some(make_mask_lut()),
+        "samples should be sorted into rows, and moved to the row's center"
+    );
+}
+
+#[test]
+fn custom_pattern_changes_lut() {
+    // A rotated grid, with two samples in each of the four columns.
+    let pattern: Vec<[f32; 2]> = [1, 5, 3, 7, 0, 4, 2, 6]
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| [(x as f32 + 0.5) / 8.0, (i as f32 + 0.5) / 8.0])
+        .collect();
+    let lut = make_mask_lut_for_pattern(&pattern).expect("the pattern is valid");
+    assert_eq!(
+        lut.len(),
+        make_mask_lut().len(),
+        "the table should have the same size"
+    );
+    assert_ne!(
+        lut,
+        make_mask_lut(),
+        "the table should use the custom pattern"
+    );
+}
+
+#[test]
+fn invalid_patterns_are_rejected() {
+    let mut two_in_one_row = default_pattern_8();
+    two_in_one_row[1][1] = two_in_one_row[0][1];
+    let mut out_of_range = default_pattern_8();
+    out_of_range[3][0] = 1.0;
+    let mut nan = default_pattern_16();
+    nan[5][1] = f32::NAN;
+    let four_samples = &default_pattern_8()[..4];
+    for (pattern, reason) in [
+        (&two_in_one_row[..], "two samples in one row"),
+        (&out_of_range[..], "a sample outside the pixel"),
+        (&nan[..], "a NaN position"),
+        (four_samples, "an unsupported number of samples"),
+        (&[][..], "no samples"),
+    ] {
+        assert!(
+            make_mask_lut_for_pattern(pattern).is_none(),
+            "a pattern with {reason} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn builder_sets_pattern() {
+    let options = RendererOptions::builder()
+        .msaa_sample_pattern(Some(default_pattern_16()))
+        .build()
+        .unwrap();
+    assert_eq!(options.msaa_sample_pattern, Some(default_pattern_16()));
+    assert!(
+        RendererOptions::default().msaa_sample_pattern.is_none(),
+        "the default positions are used by default"
+    );
+}
+
+#[test]
+fn builder_rejects_invalid_pattern() {
+    let mut two_in_one_row = default_pattern_8();
+    two_in_one_row[1][1] = two_in_one_row[0][1];
+    let result = RendererOptions::builder()
+        .msaa_sample_pattern(Some(two_in_one_row))
+        .build();
+    assert!(
+        matches!(result, Err(Error::InvalidRendererOptions(_))),
+        "a pattern with two samples in one row should be rejected when building the options"
+    );
+}
@@ -0,0 +1,73 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::stroke_with_scale_mode`] with [`ScaleMode::Screen`].
+
+use vello::kurbo::{Affine, Line, Stroke};
+use vello::peniko::color::palette;
+use vello::{ScaleMode, Scene};
+use vello_tests::TestParams;
+
+fn dashed() -> Stroke {
+    Stroke::new(4.0).with_dashes(0.0, [10.0, 10.0])
+}
+
+/// Strokes a line which is at the same place on screen for every `scale`.
+fn scaled_line(scale_mode: ScaleMode, scale: f64) -> Scene {
+    let mut scene = Scene::new();
+    scene.stroke_with_scale_mode(
+        &dashed(),
+        scale_mode,
+        Affine::scale(scale),
+        palette::css::WHITE,
+        None,
+        &Line::new((5.0 / scale, 32.0 / scale), (125.0 / scale, 32.0 / scale)),
+    );
+    scene
+}
+
+/// Returns the lengths of the runs of covered pixels along the center of the line.
+fn dash_lengths(scene: &Scene) -> Vec<usize> {
+    let params = TestParams::new("non_scaling_stroke", 128, 64);
+    let image = vello_tests::render_then_debug_sync(scene, &params).unwrap();
+    let row = &image.data.data()[32 * 128 * 4..33 * 128 * 4];
+    let mut lengths = vec![];
+    let mut run = 0;
+    for pixel in row.chunks_exact(4) {
+        if pixel[0] > 127 {
+            run += 1;
+        } else if run > 0 {
+            lengths.push(run);
+            run = 0;
+        }
+    }
+    if run > 0 {
+        lengths.push(run);
+    }
+    lengths
+}
+
+#[test]
+fn screen_mode_ignores_transform_scale() {
+    let reference = scaled_line(ScaleMode::World, 1.0);
+    let screen = scaled_line(ScaleMode::Screen, 2.0);
+    let (a, b) = (reference.encoding(), screen.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.styles, b.styles);
+
+    // In world mode, the dashes are scaled along with the line.
+    let world = scaled_line(ScaleMode::World, 2.0);
+    assert_ne!(world.encoding().path_data, screen.encoding().path_data);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn screen_mode_keeps_dash_length() {
+    for scale in [1.0, 2.0, 0.5] {
+        let lengths = dash_lengths(&scaled_line(ScaleMode::Screen, scale));
+        assert_eq!(lengths, vec![10; 6], "scale {scale}");
+    }
+    let world = dash_lengths(&scaled_line(ScaleMode::World, 2.0));
+    assert_eq!(world, vec![20; 3]);
+}
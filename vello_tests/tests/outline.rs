@@ -0,0 +1,92 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::draw_outline`].
+
+use vello::kurbo::{Affine, Point, Rect, RoundedRect, Shape};
+use vello::peniko::color::palette;
+use vello::{OutlineStyle, Scene};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 96;
+const SHAPE: Rect = Rect::new(24.0, 24.0, 72.0, 72.0);
+const WIDTH: f64 = 4.0;
+const OFFSET: f64 = 3.0;
+
+/// Renders an outline of a square, and returns the coverage of each pixel, with the pixel's
+/// center.
+fn render_outline(style: OutlineStyle) -> Vec<(Point, u8)> {
+    let mut scene = Scene::new();
+    scene.draw_outline(
+        Affine::IDENTITY,
+        RoundedRect::from_rect(SHAPE, 6.0),
+        palette::css::WHITE,
+        WIDTH,
+        OFFSET,
+        style,
+    );
+    let image =
+        vello_tests::render_then_debug_sync(&scene, &TestParams::new("outline", SIZE, SIZE))
+            .unwrap();
+    image
+        .data
+        .data()
+        .chunks_exact(4)
+        .enumerate()
+        .map(|(i, pixel)| {
+            let i = u32::try_from(i).unwrap();
+            let center = Point::new(f64::from(i % SIZE) + 0.5, f64::from(i / SIZE) + 0.5);
+            (center, pixel[0])
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn outline_is_outside_shape_at_offset() {
+    for style in [
+        OutlineStyle::Solid,
+        OutlineStyle::Dashed,
+        OutlineStyle::Dotted,
+    ] {
+        let pixels = render_outline(style);
+        // The area between the shape and the outline, whose corners are rounded like the outline.
+        let gap = RoundedRect::from_rect(SHAPE.inflate(OFFSET - 0.5, OFFSET - 0.5), 6.0 + OFFSET);
+        let outer = SHAPE.inflate(OFFSET + WIDTH + 0.5, OFFSET + WIDTH + 0.5);
+        for &(center, value) in &pixels {
+            if gap.contains(center) || !outer.contains(center) {
+                assert_eq!(value, 0, "{style:?} drew at {center:?}");
+            }
+        }
+        assert!(pixels.iter().any(|&(_, value)| value > 250), "{style:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn solid_outline_is_continuous() {
+    let pixels = render_outline(OutlineStyle::Solid);
+    // The top side of the outline is fully covered, so a row of pixels in it is too.
+    let y = SHAPE.y0 - OFFSET - 0.5 * WIDTH + 0.5;
+    for x in 30..66 {
+        let (_, value) = pixels
+            .iter()
+            .find(|(center, _)| center.x == f64::from(x) + 0.5 && (center.y - y).abs() < 0.25)
+            .unwrap();
+        assert_eq!(*value, 255, "gap at x = {x}");
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn dashed_outline_has_gaps() {
+    let pixels = render_outline(OutlineStyle::Dashed);
+    let y = SHAPE.y0 - OFFSET - 0.5 * WIDTH + 0.5;
+    let row: Vec<u8> = pixels
+        .iter()
+        .filter(|(center, _)| (center.y - y).abs() < 0.25 && (30.0..66.0).contains(&center.x))
+        .map(|&(_, value)| value)
+        .collect();
+    assert!(row.contains(&0));
+    assert!(row.contains(&255));
+}
@@ -0,0 +1,148 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`vello::path::boolean`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, PathEl, Point, Rect, RoundedRect, Shape};
+use vello::path::{BoolOp, boolean};
+use vello::peniko::{BlendMode, Color, Compose, Fill, Mix};
+use vello_tests::TestParams;
+
+const A: Rect = Rect::new(0.0, 0.0, 20.0, 20.0);
+const B: Rect = Rect::new(10.0, 10.0, 30.0, 30.0);
+
+/// The vertices of each polygon in `path`.
+fn polygons(path: &BezPath) -> Vec<Vec<Point>> {
+    let mut polygons = Vec::new();
+    for el in path.elements() {
+        match el {
+            PathEl::MoveTo(p) => polygons.push(vec![*p]),
+            PathEl::LineTo(p) => polygons.last_mut().unwrap().push(*p),
+            PathEl::ClosePath => {}
+            _ => panic!("{el:?} should be a line"),
+        }
+    }
+    polygons
+}
+
+fn assert_area(path: &BezPath, expected: f64) {
+    assert!(
+        (path.area().abs() - expected).abs() < 1e-9,
+        "{} should be {expected}",
+        path.area()
+    );
+}
+
+#[test]
+fn union_of_overlapping_rects() {
+    let union = boolean(&A, &B, BoolOp::Union);
+    let polygons = polygons(&union);
+    assert_eq!(polygons.len(), 1);
+    let mut vertices = polygons[0].clone();
+    vertices.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    assert_eq!(
+        vertices,
+        [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 20.0),
+            Point::new(10.0, 20.0),
+            Point::new(10.0, 30.0),
+            Point::new(20.0, 0.0),
+            Point::new(20.0, 10.0),
+            Point::new(30.0, 10.0),
+            Point::new(30.0, 30.0),
+        ]
+    );
+    assert_area(&union, 700.0);
+    assert!(union.contains(Point::new(5.0, 5.0)), "inside A");
+    assert!(union.contains(Point::new(25.0, 25.0)), "inside B");
+    assert!(!union.contains(Point::new(25.0, 5.0)), "outside both");
+}
+
+#[test]
+fn other_operations_on_overlapping_rects() {
+    let intersection = boolean(&A, &B, BoolOp::Intersection);
+    assert_eq!(polygons(&intersection).len(), 1);
+    assert_area(&intersection, 100.0);
+    assert_eq!(
+        intersection.bounding_box(),
+        Rect::new(10.0, 10.0, 20.0, 20.0)
+    );
+
+    let difference = boolean(&A, &B, BoolOp::Difference);
+    assert_area(&difference, 300.0);
+    assert!(!difference.contains(Point::new(15.0, 15.0)), "removed by B");
+
+    let xor = boolean(&A, &B, BoolOp::Xor);
+    assert_area(&xor, 600.0);
+    assert!(!xor.contains(Point::new(15.0, 15.0)), "inside both");
+}
+
+#[test]
+fn disjoint_and_adjacent_rects() {
+    let far = Rect::new(40.0, 0.0, 50.0, 10.0);
+    assert_eq!(polygons(&boolean(&A, &far, BoolOp::Union)).len(), 2);
+    assert!(boolean(&A, &far, BoolOp::Intersection).is_empty());
+
+    // Rectangles which share an edge merge into one rectangle.
+    let adjacent = Rect::new(20.0, 0.0, 40.0, 20.0);
+    let union = boolean(&A, &adjacent, BoolOp::Union);
+    assert_eq!(polygons(&union).len(), 1);
+    assert_eq!(polygons(&union)[0].len(), 4);
+    assert_area(&union, 800.0);
+}
+
+#[test]
+fn ring_has_a_hole() {
+    let outer = RoundedRect::new(0.0, 0.0, 100.0, 60.0, 12.0);
+    let inner = RoundedRect::new(10.0, 10.0, 90.0, 50.0, 6.0);
+    let ring = boolean(&outer, &inner, BoolOp::Difference);
+    assert_eq!(polygons(&ring).len(), 2);
+    let expected = outer.area() - inner.area();
+    assert!(
+        (ring.area().abs() - expected).abs() < 1.0,
+        "{} should be about {expected}",
+        ring.area()
+    );
+    assert!(!ring.contains(Point::new(50.0, 30.0)), "inside the hole");
+    assert!(ring.contains(Point::new(5.0, 30.0)), "inside the ring");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn ring_matches_dest_out() {
+    let outer = RoundedRect::new(4.0, 4.0, 60.0, 44.0, 10.0);
+    let inner = RoundedRect::new(14.0, 14.0, 50.0, 34.0, 4.0);
+
+    let mut layered = Scene::new();
+    layered.push_layer(
+        Fill::NonZero,
+        BlendMode::new(Mix::Normal, Compose::SrcOver),
+        1.0,
+        Affine::IDENTITY,
+        &outer,
+    );
+    layered.fill(Fill::NonZero, Affine::IDENTITY, Color::WHITE, None, &outer);
+    layered.push_layer(
+        Fill::NonZero,
+        Compose::DestOut,
+        1.0,
+        Affine::IDENTITY,
+        &inner,
+    );
+    layered.fill(Fill::NonZero, Affine::IDENTITY, Color::WHITE, None, &inner);
+    layered.pop_layer();
+    layered.pop_layer();
+
+    let mut filled = Scene::new();
+    let ring = boolean(&outer, &inner, BoolOp::Difference);
+    filled.fill(Fill::NonZero, Affine::IDENTITY, Color::WHITE, None, &ring);
+
+    let params = TestParams::new("path_boolean_ring", 64, 48);
+    let layered = vello_tests::render_then_debug_sync(&layered, &params).unwrap();
+    let filled = vello_tests::render_then_debug_sync(&filled, &params).unwrap();
+    for (a, b) in layered.data.data().iter().zip(filled.data.data()) {
+        assert!(a.abs_diff(*b) <= 8, "{a} differs from {b}");
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for drawing with perspective transforms, using [`Scene::fill_perspective`] and
+//! [`Scene::stroke_perspective`].
+
+use vello::Perspective;
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Point, Rect, Shape, Stroke};
+use vello::peniko::{Color, Fill, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+
+fn assert_near(actual: Point, expected: Point, context: &str) {
+    assert!(
+        (actual - expected).hypot() < 1e-9,
+        "{context}: expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn rect_to_quad_maps_corners() {
+    let rect = Rect::new(16.0, 16.0, 48.0, 48.0);
+    let quad = [
+        Point::new(24.0, 16.0),
+        Point::new(40.0, 16.0),
+        Point::new(56.0, 48.0),
+        Point::new(8.0, 48.0),
+    ];
+    let perspective = Perspective::rect_to_quad(rect, quad).unwrap();
+    let corners = [
+        Point::new(16.0, 16.0),
+        Point::new(48.0, 16.0),
+        Point::new(48.0, 48.0),
+        Point::new(16.0, 48.0),
+    ];
+    for (corner, expected) in corners.into_iter().zip(quad) {
+        let actual = perspective.apply(corner).unwrap();
+        assert_near(actual, expected, "Corners should map to the quad");
+    }
+    // Perspective maps the center of the rectangle to the intersection of the diagonals,
+    // which is closer to the short edge than the midpoint of the quad.
+    let center = perspective.apply(rect.center()).unwrap();
+    assert!(
+        center.y < 32.0,
+        "The center should be pulled towards the far edge, got {center:?}"
+    );
+}
+
+#[test]
+fn rect_to_quad_rejects_degenerate_input() {
+    let quad = [
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ];
+    assert!(
+        Perspective::rect_to_quad(Rect::new(0.0, 0.0, 0.0, 1.0), quad).is_none(),
+        "A rectangle without area can't be mapped"
+    );
+    let collinear = [
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 1.0),
+    ];
+    assert!(
+        Perspective::rect_to_quad(Rect::new(0.0, 0.0, 1.0, 1.0), collinear).is_none(),
+        "A quad with three collinear corners can't be mapped to"
+    );
+}
+
+#[test]
+fn tilt_keeps_center_and_narrows_far_edge() {
+    let center = Point::new(32.0, 32.0);
+    let perspective = Perspective::tilt_x(0.5, center, 100.0);
+    assert_near(
+        perspective.apply(center).unwrap(),
+        center,
+        "The center of a tilt should stay in place",
+    );
+    let width_at = |y: f64| {
+        let left = perspective.apply(Point::new(0.0, y)).unwrap();
+        let right = perspective.apply(Point::new(64.0, y)).unwrap();
+        right.x - left.x
+    };
+    assert!(
+        width_at(0.0) > width_at(64.0),
+        "A positive tilt should bring the top edge closer, so make it wider"
+    );
+}
+
+#[test]
+fn fill_is_warped_to_the_quad() {
+    let rect = Rect::new(16.0, 16.0, 48.0, 48.0);
+    let quad = [
+        Point::new(24.0, 16.0),
+        Point::new(40.0, 16.0),
+        Point::new(56.0, 48.0),
+        Point::new(8.0, 48.0),
+    ];
+    let perspective = Perspective::rect_to_quad(rect, quad).unwrap();
+    let mut warped = Scene::new();
+    warped.fill_perspective(
+        Fill::NonZero,
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &rect,
+    );
+    let mut expected = Scene::new();
+    let mut path = BezPath::new();
+    path.move_to(quad[0]);
+    for corner in &quad[1..] {
+        path.line_to(*corner);
+    }
+    path.close_path();
+    expected.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &path,
+    );
+    let (warped, expected) = (warped.encoding(), expected.encoding());
+    assert_eq!(warped.path_data, expected.path_data);
+    assert_eq!(warped.draw_data, expected.draw_data);
+}
+
+#[test]
+fn shapes_behind_the_viewer_are_skipped() {
+    let rect = Rect::new(0.0, 0.0, 64.0, 64.0);
+    // With the viewer this close to the plane, a steep tilt brings the top edge behind the
+    // viewer.
+    let perspective = Perspective::tilt_x(1.4, rect.center(), 16.0);
+    let mut scene = Scene::new();
+    scene.fill_perspective(
+        Fill::NonZero,
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &rect,
+    );
+    scene.stroke_perspective(
+        &Stroke::new(4.0),
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &rect,
+    );
+    assert!(
+        scene.encoding().is_empty(),
+        "Nothing should be drawn for a shape which crosses the plane of the viewer"
+    );
+}
+
+#[test]
+fn stroke_is_filled_as_warped_outline() {
+    let rect = Rect::new(16.0, 16.0, 48.0, 48.0);
+    let perspective = Perspective::tilt_y(0.5, rect.center(), 100.0);
+    let style = Stroke::new(4.0);
+    let mut stroked = Scene::new();
+    stroked.stroke_perspective(
+        &style,
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &rect,
+    );
+    assert_eq!(stroked.encoding().n_paths, 1);
+    let outline = vello::kurbo::stroke(
+        rect.path_elements(0.01),
+        &style,
+        &vello::kurbo::StrokeOpts::default(),
+        0.01,
+    );
+    let mut filled = Scene::new();
+    filled.fill_perspective(
+        Fill::NonZero,
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &outline,
+    );
+    assert_eq!(stroked.encoding().path_data, filled.encoding().path_data);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn rect_drawn_as_trapezoid() {
+    let rect = Rect::new(16.0, 16.0, 48.0, 48.0);
+    let quad = [
+        Point::new(24.0, 16.0),
+        Point::new(40.0, 16.0),
+        Point::new(56.0, 48.0),
+        Point::new(8.0, 48.0),
+    ];
+    let perspective = Perspective::rect_to_quad(rect, quad).unwrap();
+    let mut scene = Scene::new();
+    scene.fill_perspective(
+        Fill::NonZero,
+        perspective,
+        Affine::IDENTITY,
+        palette::css::RED,
+        &rect,
+    );
+    let mut params = TestParams::new("perspective_trapezoid", SIZE, SIZE);
+    params.base_color = Some(Color::TRANSPARENT);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let alpha = |x: usize, y: usize| image.data.data()[(y * SIZE as usize + x) * 4 + 3];
+    for (x, y) in [(11, 46), (52, 46), (32, 18)] {
+        assert_eq!(
+            alpha(x, y),
+            255,
+            "({x}, {y}) is inside the trapezoid, so should be covered"
+        );
+    }
+    for (x, y) in [(18, 18), (45, 18), (4, 46), (60, 46)] {
+        assert_eq!(
+            alpha(x, y),
+            0,
+            "({x}, {y}) is outside the trapezoid, so should not be covered"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`RenderParams::premultiplied_alpha`](vello::RenderParams::premultiplied_alpha).
+
+use vello::Scene;
+use vello::kurbo::{Affine, RoundedRect};
+use vello::peniko::{Color, Fill, ImageAlphaType, ImageData, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 64;
+
+/// A semi-transparent rounded rect with a soft shadow, so that there are many partially
+/// transparent pixels.
+fn scene() -> Scene {
+    let mut scene = Scene::new();
+    let card = RoundedRect::new(12.3, 14.7, 50.1, 47.9, 9.0);
+    scene.draw_blurred_rounded_rect(
+        Affine::translate((0.0, 3.0)),
+        card.rect(),
+        Color::BLACK.with_alpha(0.4),
+        9.0,
+        4.0,
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        Color::from_rgba8(0x20, 0x80, 0xf0, 0xb0),
+        None,
+        &card,
+    );
+    scene
+}
+
+fn render(base_color: Color, premultiplied_alpha: bool) -> ImageData {
+    let params = TestParams {
+        base_color: Some(base_color),
+        premultiplied_alpha,
+        ..TestParams::new("premultiplied_output", SIZE, SIZE)
+    };
+    vello_tests::render_then_debug_sync(&scene(), &params).unwrap()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn premultiplied_output_composites_over_background() {
+    let layer = render(palette::css::TRANSPARENT, true);
+    assert_eq!(layer.alpha_type, ImageAlphaType::AlphaPremultiplied);
+    let expected = render(palette::css::RED, false);
+    let red = [255_u8, 0, 0, 255];
+
+    for (i, (src, expected)) in layer
+        .data
+        .data()
+        .chunks_exact(4)
+        .zip(expected.data.data().chunks_exact(4))
+        .enumerate()
+    {
+        let alpha = src[3];
+        assert!(
+            src[..3].iter().all(|&c| c <= alpha),
+            "Pixel {i} ({src:?}) isn't premultiplied"
+        );
+        // Composite over red using the premultiplied "over" operator.
+        let inv_alpha = 1.0 - f32::from(alpha) / 255.0;
+        for c in 0..4 {
+            let composited = f32::from(src[c]) + f32::from(red[c]) * inv_alpha;
+            assert!(
+                (composited - f32::from(expected[c])).abs() <= 2.0,
+                "Channel {c} of pixel {i} composites to {composited}, expected {}",
+                expected[c]
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn straight_and_premultiplied_agree_on_opaque_pixels() {
+    let straight = render(palette::css::WHITE, false);
+    let premultiplied = render(palette::css::WHITE, true);
+    assert_eq!(straight.data.data(), premultiplied.data.data());
+}
@@ -0,0 +1,167 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::prepare_path`], [`Scene::fill_prepared`] and [`Scene::stroke_prepared`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Circle, Rect, Stroke};
+use vello::peniko::{Fill, Gradient, color::palette};
+use vello_tests::TestParams;
+
+fn assert_same_encoding(a: &Scene, b: &Scene) {
+    let (a, b) = (a.encoding(), b.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.draw_data, b.draw_data);
+    assert_eq!(a.transforms, b.transforms);
+    assert_eq!(a.styles, b.styles);
+    assert_eq!(a.n_paths, b.n_paths);
+    assert_eq!(a.n_path_segments, b.n_path_segments);
+}
+
+fn path() -> BezPath {
+    let mut path = BezPath::new();
+    path.move_to((4.0, 4.0));
+    path.line_to((40.0, 8.0));
+    path.quad_to((56.0, 30.0), (36.0, 52.0));
+    path.curve_to((20.0, 60.0), (10.0, 40.0), (8.0, 24.0));
+    path.close_path();
+    // An open subpath, which is closed when filled but not when stroked.
+    path.move_to((20.0, 20.0));
+    path.line_to((30.0, 30.0));
+    path
+}
+
+fn gradient() -> Gradient {
+    Gradient::new_linear((0.0, 0.0), (60.0, 0.0))
+        .with_stops([palette::css::TEAL, palette::css::ORANGE])
+}
+
+#[test]
+fn prepared_draws_match_direct_draws() {
+    let prepared = Scene::prepare_path(&path(), 0.1);
+    let stroke = Stroke::new(3.0);
+    let brush_transform = Some(Affine::rotate(0.3));
+    let mut direct = Scene::new();
+    let mut cached = Scene::new();
+    for i in 0..3 {
+        let transform = Affine::translate((f64::from(i) * 10.0, 2.0));
+        direct.fill(
+            Fill::EvenOdd,
+            transform,
+            &gradient(),
+            brush_transform,
+            &path(),
+        );
+        direct.stroke(&stroke, transform, palette::css::RED, None, &path());
+        cached.fill_prepared(
+            Fill::EvenOdd,
+            transform,
+            &gradient(),
+            brush_transform,
+            &prepared,
+        );
+        cached.stroke_prepared(&stroke, transform, palette::css::RED, None, &prepared);
+    }
+    assert_same_encoding(&direct, &cached);
+}
+
+#[test]
+fn dashed_stroke_matches_direct_stroke() {
+    let prepared = Scene::prepare_path(&path(), 0.1);
+    let stroke = Stroke::new(2.0).with_dashes(1.0, [4.0, 2.0]);
+    let mut direct = Scene::new();
+    direct.stroke(&stroke, Affine::IDENTITY, palette::css::RED, None, &path());
+    let mut cached = Scene::new();
+    cached.stroke_prepared(
+        &stroke,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &prepared,
+    );
+    assert_same_encoding(&direct, &cached);
+}
+
+#[test]
+fn shapes_are_converted_with_the_tolerance() {
+    let circle = Circle::new((32.0, 32.0), 20.0);
+    let prepared = Scene::prepare_path(&circle, 0.1);
+    assert_eq!(prepared.path(), &circle.to_path(0.1));
+    assert_eq!(prepared.bounds(), circle.to_path(0.1).bounding_box());
+}
+
+#[test]
+fn empty_path_draws_nothing() {
+    let prepared = Scene::prepare_path(&BezPath::new(), 0.1);
+    let mut scene = Scene::new();
+    scene.fill_prepared(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &prepared,
+    );
+    scene.stroke_prepared(
+        &Stroke::new(1.0),
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &prepared,
+    );
+    assert!(scene.encoding().draw_tags.is_empty());
+    assert_eq!(scene.encoding().n_paths, 0);
+}
+
+#[test]
+fn prepared_paths_are_culled() {
+    let prepared = Scene::prepare_path(&path(), 0.1);
+    let mut scene = Scene::new().with_cull_rect(Rect::new(0.0, 0.0, 64.0, 64.0));
+    let offscreen = Affine::translate((200.0, 0.0));
+    scene.fill_prepared(Fill::NonZero, offscreen, palette::css::RED, None, &prepared);
+    scene.stroke_prepared(
+        &Stroke::new(1.0),
+        offscreen,
+        palette::css::RED,
+        None,
+        &prepared,
+    );
+    assert!(scene.encoding().draw_tags.is_empty());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn prepared_path_renders_like_direct_path() {
+    let prepared = Scene::prepare_path(&path(), 0.1);
+    let stroke = Stroke::new(2.0);
+    let mut direct = Scene::new();
+    direct.fill(Fill::NonZero, Affine::IDENTITY, &gradient(), None, &path());
+    direct.stroke(
+        &stroke,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &path(),
+    );
+    let mut cached = Scene::new();
+    cached.fill_prepared(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient(),
+        None,
+        &prepared,
+    );
+    cached.stroke_prepared(
+        &stroke,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &prepared,
+    );
+
+    let params = TestParams::new("prepared_path", 64, 64);
+    let direct = vello_tests::render_then_debug_sync(&direct, &params).unwrap();
+    let cached = vello_tests::render_then_debug_sync(&cached, &params).unwrap();
+    assert_eq!(direct.data.data(), cached.data.data());
+}
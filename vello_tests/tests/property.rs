@@ -11,11 +11,13 @@
     clippy::allow_attributes_without_reason
 )]
 
+use scenes::SimpleText;
 use vello::kurbo::{Affine, Rect};
 use vello::peniko::color::palette::css::TRANSPARENT;
 use vello::peniko::{Brush, Color, ImageFormat, color::palette};
 use vello::peniko::{ImageAlphaType, ImageData, ImageSampler};
-use vello::{AaConfig, Scene};
+use vello::{AaConfig, DebugView, Scene};
+use vello_encoding::DrawTag;
 use vello_tests::TestParams;
 
 fn simple_square(use_cpu: bool) {
@@ -183,6 +185,11 @@ fn premultiplied_image() {
         use_cpu: false,
         name: "bgra".into(),
         anti_aliasing: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
     };
     let scene_image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
     assert_eq!(scene_image.format, ImageFormat::Rgba8);
@@ -195,3 +202,254 @@ fn premultiplied_image() {
         }
     }
 }
+
+/// Renders white text onto a transparent background with the given text gamma,
+/// returning the summed alpha of every pixel.
+fn text_coverage(text_gamma: f32) -> u64 {
+    let mut scene = Scene::new();
+    let mut text = SimpleText::new();
+    text.add(
+        &mut scene,
+        None,
+        12.0,
+        None,
+        Affine::translate((4.0, 16.0)),
+        "Gamma",
+    );
+    let params = TestParams {
+        base_color: Some(TRANSPARENT),
+        text_gamma,
+        ..TestParams::new(format!("text_gamma_{}", (text_gamma * 10.0) as u32), 64, 24)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    image
+        .data
+        .data()
+        .chunks_exact(4)
+        .map(|pixel| u64::from(pixel[3]))
+        .sum()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn text_gamma_orders_glyph_coverage() {
+    let gammas = [0.5, 1.0, 1.8, 2.2];
+    let coverages = gammas.map(text_coverage);
+    for (gamma, coverage) in gammas.windows(2).zip(coverages.windows(2)) {
+        assert!(
+            coverage[0] < coverage[1],
+            "Text at gamma {} should be lighter than at gamma {}, got coverage {} and {}",
+            gamma[0],
+            gamma[1],
+            coverage[0],
+            coverage[1],
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn text_gamma_ignores_shapes() {
+    let mut scene = Scene::new();
+    scene.fill(
+        vello::peniko::Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Rect::new(2.25, 2.5, 17.75, 13.5),
+    );
+    let render = |text_gamma| {
+        let params = TestParams {
+            base_color: Some(TRANSPARENT),
+            text_gamma,
+            ..TestParams::new("text_gamma_shapes", 20, 16)
+        };
+        vello_tests::render_then_debug_sync(&scene, &params).unwrap()
+    };
+    let linear = render(1.0);
+    let adjusted = render(2.2);
+    assert_eq!(
+        linear.data.data(),
+        adjusted.data.data(),
+        "Text gamma should not change the coverage of non-glyph shapes"
+    );
+}
+
+fn clear_region(use_cpu: bool) {
+    let mut scene = Scene::new();
+    scene.fill(
+        vello::peniko::Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0., 0., 100., 100.),
+    );
+    scene.clear_region(&Rect::new(25., 25., 75., 75.), Affine::IDENTITY);
+    let params = TestParams {
+        use_cpu,
+        base_color: Some(palette::css::BLUE),
+        ..TestParams::new("clear_region", 100, 100)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    for (i, pixel) in image.data.data().chunks_exact(4).enumerate() {
+        let (x, y) = (i % 100, i / 100);
+        let inside = (25..75).contains(&x) && (25..75).contains(&y);
+        if inside {
+            assert_eq!(pixel, [0, 0, 0, 0], "Pixel ({x}, {y}) should be cleared");
+        } else {
+            assert_eq!(
+                pixel,
+                [255, 0, 0, 255],
+                "Pixel ({x}, {y}) should be unchanged"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn clear_region_gpu() {
+    clear_region(false);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn clear_region_cpu() {
+    clear_region(true);
+}
+
+#[test]
+fn clear_region_is_a_single_draw() {
+    let mut scene = Scene::new();
+    scene.clear_region(&Rect::new(25., 25., 75., 75.), Affine::IDENTITY);
+    assert_eq!(scene.encoding().draw_tags, [DrawTag::COLOR]);
+    assert_eq!(scene.encoding().n_open_clips, 0);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn clear_region_only_clears_the_current_layer() {
+    let rect = Rect::new(0., 0., 32., 32.);
+    let mut scene = Scene::new();
+    scene.fill(
+        vello::peniko::Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &rect,
+    );
+    scene.push_layer(
+        vello::peniko::Fill::NonZero,
+        vello::peniko::Mix::Normal,
+        1.0,
+        Affine::IDENTITY,
+        &rect,
+    );
+    scene.fill(
+        vello::peniko::Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &rect,
+    );
+    // Half of the pixels in the last column are covered, so are half cleared.
+    scene.clear_region(&Rect::new(16., 0., 32.5, 32.), Affine::IDENTITY);
+    scene.pop_layer();
+    let params = TestParams::new("clear_region_layer", 33, 32);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = |x: usize| &image.data.data()[x * 4..x * 4 + 4];
+    assert_eq!(
+        pixel(8),
+        [0, 0, 255, 255],
+        "The layer is kept outside the region"
+    );
+    assert_eq!(
+        pixel(24),
+        [255, 0, 0, 255],
+        "The content below the layer shows through the region"
+    );
+    assert_eq!(
+        pixel(32),
+        [0, 0, 0, 255],
+        "Nothing is drawn outside the layer"
+    );
+}
+
+fn soft_edge(anti_aliasing: AaConfig, alpha_to_coverage: bool) -> ImageData {
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        Rect::new(20., 20., 44., 44.),
+        palette::css::WHITE,
+        4.,
+        6.,
+    );
+    let params = TestParams {
+        base_color: Some(TRANSPARENT),
+        anti_aliasing,
+        alpha_to_coverage,
+        ..TestParams::new("alpha_to_coverage", 64, 64)
+    };
+    vello_tests::render_then_debug_sync(&scene, &params).unwrap()
+}
+
+/// Whether every alpha value in `image` is a whole number of sixteenths.
+fn alpha_is_quantized(image: &ImageData) -> bool {
+    image.data.data().chunks_exact(4).all(|pixel| {
+        let samples = f32::from(pixel[3]) / 255. * 16.;
+        (samples - samples.round()).abs() < 0.1
+    })
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn alpha_to_coverage_quantizes_soft_edges() {
+    let smooth = soft_edge(AaConfig::Msaa16, false);
+    let quantized = soft_edge(AaConfig::Msaa16, true);
+    assert!(!alpha_is_quantized(&smooth));
+    assert!(alpha_is_quantized(&quantized));
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn alpha_to_coverage_ignored_with_area() {
+    let smooth = soft_edge(AaConfig::Area, false);
+    let quantized = soft_edge(AaConfig::Area, true);
+    assert_eq!(smooth.data.data(), quantized.data.data());
+}
+
+/// Straight alpha images are premultiplied before filtering, so transparent pixels
+/// don't darken the edges of opaque content.
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn straight_alpha_image_edges_have_no_fringe() {
+    let mut scene = Scene::new();
+    // An opaque white pixel next to a transparent black one.
+    let blob: Vec<u8> = vec![255, 255, 255, 255, 0, 0, 0, 0];
+    let image = vello::peniko::ImageBrush {
+        image: ImageData {
+            data: blob.into(),
+            format: ImageFormat::Rgba8,
+            width: 2,
+            height: 1,
+            alpha_type: ImageAlphaType::Alpha,
+        },
+        sampler: ImageSampler {
+            quality: vello::peniko::ImageQuality::Medium,
+            ..Default::default()
+        },
+    };
+    scene.draw_image(&image, Affine::scale(16.));
+    let params = TestParams {
+        base_color: Some(palette::css::WHITE),
+        ..TestParams::new("straight_alpha_fringe", 32, 16)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    for (i, pixel) in image.data.data().chunks_exact(4).enumerate() {
+        let (x, y) = (i % 32, i / 32);
+        assert!(
+            pixel.iter().all(|&c| c >= 254),
+            "Pixel ({x}, {y}) is {pixel:?}, but should be white"
+        );
+    }
+}
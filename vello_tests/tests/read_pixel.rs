@@ -0,0 +1,129 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::read_pixel`].
+
+use vello::kurbo::{Affine, Rect, RoundedRect};
+use vello::peniko::{BlendMode, Color, Compose, Fill, Mix};
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 160;
+
+/// The color of the face of the button in the `inner_shadow` example.
+const FACE_COLOR: Color = Color::new([0.00, 0.48, 1.00, 1.0]);
+
+/// A face with an inset shadow, drawn in the same way as the `inner_shadow` example.
+fn inset_shadow_scene() -> Scene {
+    let mut scene = Scene::new();
+    let face = Rect::new(20., 20., 180., 140.);
+    let shape = RoundedRect::from_rect(face, 8.);
+    scene.fill(Fill::NonZero, Affine::IDENTITY, FACE_COLOR, None, &shape);
+
+    let blend = BlendMode::new(Mix::Normal, Compose::SrcOver);
+    scene.push_layer(Fill::NonZero, blend, 1.0, Affine::IDENTITY, &shape);
+    scene.draw_blurred_rounded_rect_in(
+        &shape,
+        Affine::IDENTITY,
+        face.inflate(40., 40.),
+        Color::BLACK.with_alpha(0.46),
+        8.,
+        6.,
+    );
+    scene.push_layer(
+        Fill::NonZero,
+        Compose::DestOut,
+        1.0,
+        Affine::IDENTITY,
+        &shape,
+    );
+    scene.draw_blurred_rounded_rect_in(
+        &shape,
+        Affine::IDENTITY,
+        Rect::new(22., 26., 178., 142.),
+        Color::BLACK,
+        8.,
+        6.,
+    );
+    scene.pop_layer();
+    scene.pop_layer();
+    scene
+}
+
+/// Renders the inset shadow scene, then reads the pixel at `(x, y)`.
+fn pick(x: u32, y: u32) -> vello::Result<Color> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: WIDTH,
+            height: HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let params = RenderParams {
+        base_color: Color::new([0.12, 0.12, 0.12, 1.0]),
+        width: WIDTH,
+        height: HEIGHT,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer
+        .render_to_texture(device, queue, &inset_shadow_scene(), &view, &params)
+        .unwrap();
+    renderer.read_pixel(device, queue, &target, x, y)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn picks_face_color() {
+    let color = pick(100, 80).unwrap();
+    assert!(
+        color.difference(FACE_COLOR) < 0.01,
+        "Expected the face color, got {color:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn picks_shadow_near_top_edge() {
+    // The shadow is offset downwards, so it is darkest just inside the top edge.
+    let color = pick(100, 22).unwrap();
+    let [_, g, b, _] = color.components;
+    let [_, face_g, face_b, _] = FACE_COLOR.components;
+    assert!(
+        g < face_g && b < face_b,
+        "Expected the shadow to darken the face, got {color:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn out_of_bounds_pixel_is_an_error() {
+    let result = pick(WIDTH, 0);
+    assert!(
+        matches!(result, Err(Error::PixelOutOfBounds(x, 0)) if x == WIDTH),
+        "Unexpected result {result:?}"
+    );
+}
@@ -0,0 +1,138 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`RedrawScheduler`].
+
+use std::time::{Duration, Instant};
+
+use vello::util::RedrawScheduler;
+
+const MILLISECOND: Duration = Duration::from_millis(1);
+
+/// Requests and polls every millisecond for one second, returning the number of redraws.
+fn redraws_in_one_second(scheduler: &mut RedrawScheduler, request: bool) -> usize {
+    let start = Instant::now();
+    (0..1000)
+        .filter(|&ms| {
+            if request {
+                scheduler.request();
+            }
+            scheduler.poll(start + MILLISECOND * ms)
+        })
+        .count()
+}
+
+#[test]
+fn continuous_requests_are_limited_to_max_fps() {
+    let mut scheduler = RedrawScheduler::new(60.0);
+    let redraws = redraws_in_one_second(&mut scheduler, true);
+    assert!(
+        redraws <= 60,
+        "At most 60 redraws should be made in a second, got {redraws}"
+    );
+    assert!(
+        redraws >= 55,
+        "Continuous requests should redraw close to the limit, got {redraws}"
+    );
+}
+
+#[test]
+fn no_requests_make_no_redraws() {
+    let mut scheduler = RedrawScheduler::new(60.0);
+    assert_eq!(redraws_in_one_second(&mut scheduler, false), 0);
+    assert_eq!(scheduler.next_redraw(Instant::now()), None);
+}
+
+#[test]
+fn requests_are_coalesced() {
+    let mut scheduler = RedrawScheduler::new(60.0);
+    let now = Instant::now();
+    for _ in 0..10 {
+        scheduler.request();
+    }
+    assert!(
+        scheduler.poll(now),
+        "The first request should redraw at once"
+    );
+    assert!(
+        !scheduler.poll(now + Duration::from_secs(1)),
+        "All of the requests should have been handled by one redraw"
+    );
+}
+
+#[test]
+fn request_within_interval_is_delayed() {
+    let mut scheduler = RedrawScheduler::with_min_interval(Duration::from_millis(20));
+    let start = Instant::now();
+    scheduler.request();
+    assert!(scheduler.poll(start));
+
+    let now = start + Duration::from_millis(5);
+    scheduler.request();
+    assert!(
+        !scheduler.poll(now),
+        "A redraw within the minimum interval should be delayed"
+    );
+    let due = scheduler.next_redraw(now).unwrap();
+    assert_eq!(due, start + Duration::from_millis(20));
+    assert!(
+        scheduler.is_pending(),
+        "The request should still be pending"
+    );
+    assert!(
+        scheduler.poll(due),
+        "The redraw should be made once it's due"
+    );
+    assert!(!scheduler.is_pending());
+}
+
+#[test]
+fn invalid_max_fps_is_unlimited() {
+    for max_fps in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        let scheduler = RedrawScheduler::new(max_fps);
+        assert_eq!(
+            scheduler.min_interval(),
+            Duration::ZERO,
+            "A max FPS of {max_fps} shouldn't limit redraws"
+        );
+    }
+}
+
+#[test]
+fn tiny_max_fps_does_not_overflow() {
+    for max_fps in [f64::MIN_POSITIVE, 1e-300] {
+        let scheduler = RedrawScheduler::new(max_fps);
+        assert_eq!(
+            scheduler.min_interval(),
+            Duration::MAX,
+            "A max FPS of {max_fps} should saturate the interval"
+        );
+    }
+}
+
+#[test]
+fn unrepresentable_due_time_is_never() {
+    let mut scheduler = RedrawScheduler::with_min_interval(Duration::MAX);
+    let now = Instant::now();
+    scheduler.request();
+    assert!(
+        scheduler.poll(now),
+        "The first request should redraw at once"
+    );
+
+    scheduler.request();
+    let later = now + Duration::from_secs(1);
+    assert_eq!(
+        scheduler.next_redraw(later),
+        None,
+        "A redraw which can't be scheduled should never be due"
+    );
+    assert!(
+        !scheduler.poll(later),
+        "A redraw which can't be scheduled should never be made"
+    );
+    assert!(
+        scheduler.is_pending(),
+        "The request should still be pending"
+    );
+}
@@ -0,0 +1,16 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for creating a [`RenderContext`] asynchronously.
+
+use vello::util::RenderContext;
+use vello::{Renderer, RendererOptions};
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn new_async_finds_device() {
+    let mut context = pollster::block_on(RenderContext::new_async());
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    Renderer::new(device, RendererOptions::default()).unwrap();
+}
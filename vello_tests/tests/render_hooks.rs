@@ -0,0 +1,236 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the hooks for running custom GPU work in a [`Renderer`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Fill, color::palette};
+use vello::util::{RenderContext, block_on_wgpu};
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, PostProcessShader, RenderParams, Renderer, RendererOptions,
+    Scene,
+};
+
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 64;
+
+type RenderFn<'a> = dyn FnOnce(&mut Renderer, &wgpu::Device, &wgpu::Queue, &Scene, &wgpu::TextureView, &RenderParams)
+    + 'a;
+
+/// Renders a red square over a blue background, returning the pixels at the centre and corner.
+fn render(post_process: Option<(PostProcessShader, f32)>) -> ([u8; 4], [u8; 4]) {
+    render_using(Box::new(|renderer, device, queue, scene, view, params| {
+        if let Some((shader, time)) = post_process {
+            renderer.set_post_process(shader);
+            renderer.set_post_process_time(Some(time));
+        }
+        renderer
+            .render_to_texture(device, queue, scene, view, params)
+            .unwrap();
+    }))
+}
+
+/// Renders the scene described in [`render`] using `render_fn`.
+fn render_using(render_fn: Box<RenderFn<'_>>) -> ([u8; 4], [u8; 4]) {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(16., 16., 48., 48.),
+    );
+    let size = wgpu::Extent3d {
+        width: WIDTH,
+        height: HEIGHT,
+        depth_or_array_layers: 1,
+    };
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING
+            | TextureUsages::RENDER_ATTACHMENT
+            | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let params = RenderParams {
+        base_color: palette::css::BLUE,
+        width: WIDTH,
+        height: HEIGHT,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    render_fn(&mut renderer, device, queue, &scene, &view, &params);
+    let bytes_per_row = WIDTH * 4;
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: u64::from(bytes_per_row * HEIGHT),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.copy_texture_to_buffer(
+        target.as_image_copy(),
+        wgpu::TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit([encoder.finish()]);
+    let slice = buffer.slice(..);
+    let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+    slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+    block_on_wgpu(device, receiver.receive()).unwrap().unwrap();
+    let data = slice.get_mapped_range();
+    let pixel = |x: u32, y: u32| {
+        let start = (y * bytes_per_row + x * 4) as usize;
+        <[u8; 4]>::try_from(&data[start..start + 4]).unwrap()
+    };
+    (pixel(32, 32), pixel(2, 2))
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn post_process_inverts_colors() {
+    let shader = PostProcessShader::new(
+        "@fragment
+        fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+            let color = textureSample(source_texture, source_sampler, in.uv);
+            return vec4(1.0 - color.rgb, color.a);
+        }",
+    );
+    assert_eq!(render(None), ([255, 0, 0, 255], [0, 0, 255, 255]));
+    let (centre, corner) = render(Some((shader, 0.0)));
+    assert_eq!(centre, [0, 255, 255, 255], "Red should be inverted to cyan");
+    assert_eq!(
+        corner,
+        [255, 255, 0, 255],
+        "Blue should be inverted to yellow"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn post_process_receives_uniforms() {
+    // Writes the resolution and time into the output, so that they can be checked.
+    let shader = PostProcessShader::new(
+        "@fragment
+        fn main(in: VertexOutput) -> @location(0) vec4<f32> {
+            return vec4(uniforms.resolution / 255.0, uniforms.time / 255.0, 1.0);
+        }",
+    )
+    .with_entry_point("main");
+    let (centre, corner) = render(Some((shader, 128.0)));
+    assert_eq!(centre, [64, 64, 128, 255]);
+    assert_eq!(
+        corner, centre,
+        "Uniforms should be the same across the target"
+    );
+}
+
+/// The shader from the "Animated fills" section of the `PostProcessShader` docs.
+const ANIMATED_FILL: &str = "
+    const KEY: vec3<f32> = vec3(1.0, 0.0, 0.0);
+
+    @fragment
+    fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+        let color = textureSample(source_texture, source_sampler, in.uv);
+        // How much of this pixel is covered by the keyed fill.
+        let key = 1.0 - clamp(distance(color.rgb, KEY) * 4.0, 0.0, 1.0);
+        // A band of light which sweeps across the target once per second.
+        let phase = fract(uniforms.time) * 2.0 - in.uv.x;
+        let shimmer = vec3(1.0, 0.5 + 0.5 * cos(phase * 6.2831853), 0.0);
+        return vec4(mix(color.rgb, shimmer, key), color.a);
+    }
+";
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn post_process_animates_keyed_fill() {
+    let (start, corner) = render(Some((PostProcessShader::new(ANIMATED_FILL), 0.25)));
+    let (later, later_corner) = render(Some((PostProcessShader::new(ANIMATED_FILL), 0.5)));
+    assert_eq!(
+        corner,
+        [0, 0, 255, 255],
+        "The background shouldn't be keyed"
+    );
+    assert_eq!(later_corner, corner, "The background shouldn't be animated");
+    assert_eq!(start[0], 255);
+    assert_ne!(start, later, "The fill should change over time");
+}
+
+fn clear_to_green(encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("clear_to_green"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            depth_slice: None,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color::GREEN),
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn render_to_texture_with_before_is_overwritten() {
+    let pixels = render_using(Box::new(|renderer, device, queue, scene, view, params| {
+        renderer
+            .render_to_texture_with(
+                device,
+                queue,
+                scene,
+                view,
+                params,
+                clear_to_green,
+                |_, _| {},
+            )
+            .unwrap();
+    }));
+    assert_eq!(pixels, ([255, 0, 0, 255], [0, 0, 255, 255]));
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn render_to_texture_with_after_draws_over_scene() {
+    let pixels = render_using(Box::new(|renderer, device, queue, scene, view, params| {
+        renderer
+            .render_to_texture_with(
+                device,
+                queue,
+                scene,
+                view,
+                params,
+                |_, _| {},
+                clear_to_green,
+            )
+            .unwrap();
+    }));
+    assert_eq!(pixels, ([0, 255, 0, 255], [0, 255, 0, 255]));
+}
@@ -0,0 +1,81 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that scenes rendered at a reduced resolution, as with
+//! [`RenderContext::set_render_scale`](vello::util::RenderContext::set_render_scale),
+//! match the full resolution render.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect, RoundedRect};
+use vello::peniko::{Color, Fill, ImageData, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 128;
+
+fn scene() -> Scene {
+    let mut scene = Scene::new();
+    let card = RoundedRect::new(32.0, 32.0, 96.0, 88.0, 12.0);
+    scene.draw_blurred_rounded_rect(
+        Affine::translate((0.0, 6.0)),
+        card.rect(),
+        Color::BLACK.with_alpha(0.5),
+        12.0,
+        8.0,
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &card,
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::ORANGE,
+        None,
+        &Rect::new(48.0, 48.0, 80.0, 64.0),
+    );
+    scene
+}
+
+fn render(scene: &Scene, size: u32) -> ImageData {
+    let params = TestParams {
+        base_color: Some(palette::css::LIGHT_GRAY),
+        ..TestParams::new("render_scale", size, size)
+    };
+    vello_tests::render_then_debug_sync(scene, &params).unwrap()
+}
+
+fn channel(image: &ImageData, x: u32, y: u32, c: u32) -> f64 {
+    let index = usize::try_from((y * image.width + x) * 4 + c).unwrap();
+    f64::from(image.data.data()[index])
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn half_scale_matches_downsampled_full_scale() {
+    let full = render(&scene(), SIZE);
+    let mut scaled = Scene::new();
+    scaled.append(&scene(), Some(Affine::scale(0.5)));
+    let half = render(&scaled, SIZE / 2);
+
+    for y in 0..SIZE / 2 {
+        for x in 0..SIZE / 2 {
+            for c in 0..4 {
+                // Area anti-aliasing is a box filter, so each pixel of the half scale render
+                // should be the average of the corresponding pixels at full scale.
+                let average = (channel(&full, 2 * x, 2 * y, c)
+                    + channel(&full, 2 * x + 1, 2 * y, c)
+                    + channel(&full, 2 * x, 2 * y + 1, c)
+                    + channel(&full, 2 * x + 1, 2 * y + 1, c))
+                    / 4.0;
+                let actual = channel(&half, x, y, c);
+                assert!(
+                    (actual - average).abs() <= 4.0,
+                    "Channel {c} of ({x}, {y}) is {actual}, expected {average}"
+                );
+            }
+        }
+    }
+}
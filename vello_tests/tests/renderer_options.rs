@@ -0,0 +1,214 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of [`Renderer`] creation and its [`RendererOptions`].
+
+use std::num::NonZeroUsize;
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Fill, color::palette};
+use vello::util::{RenderContext, load_pipeline_cache};
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, AaSupport, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions,
+    Scene,
+};
+
+#[test]
+fn builder_sets_options() {
+    let options = RendererOptions::builder()
+        .use_cpu(true)
+        .antialiasing_support(AaSupport::area_only())
+        .num_init_threads(NonZeroUsize::new(2))
+        .build()
+        .unwrap();
+    assert!(options.use_cpu);
+    assert_eq!(options.antialiasing_support, AaSupport::area_only());
+    assert_eq!(options.num_init_threads, NonZeroUsize::new(2));
+    assert!(options.pipeline_cache.is_none());
+}
+
+#[test]
+fn builder_defaults_match_default() {
+    let built = RendererOptions::builder().build().unwrap();
+    let default = RendererOptions::default();
+    assert_eq!(built.use_cpu, default.use_cpu);
+    assert_eq!(built.antialiasing_support, default.antialiasing_support);
+    assert_eq!(built.num_init_threads, default.num_init_threads);
+    assert_eq!(built.msaa_sample_pattern, default.msaa_sample_pattern);
+    assert_eq!(built.debug_validation, default.debug_validation);
+    assert_eq!(
+        built.intermediate_budget_bytes,
+        default.intermediate_budget_bytes
+    );
+}
+
+#[test]
+fn builder_rejects_no_antialiasing() {
+    let no_aa = std::iter::empty::<AaConfig>().collect::<AaSupport>();
+    let result = RendererOptions::builder()
+        .antialiasing_support(no_aa)
+        .build();
+    assert!(
+        matches!(result, Err(Error::InvalidRendererOptions(_))),
+        "Options without any anti-aliasing method should be rejected"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn new_with_progress_reports_every_pipeline() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    for num_init_threads in [NonZeroUsize::new(1), None] {
+        let options = RendererOptions::builder()
+            .antialiasing_support(AaSupport::area_only())
+            .num_init_threads(num_init_threads)
+            .build()
+            .unwrap();
+        let mut reports = Vec::new();
+        Renderer::new_with_progress(device, options, |stage, done, total| {
+            reports.push((stage.to_string(), done, total));
+        })
+        .unwrap();
+        assert!(!reports.is_empty(), "Progress should be reported");
+        for (i, (stage, done, total)) in reports.iter().enumerate() {
+            assert_eq!(*done, i + 1, "Stage {stage} reported out of order");
+            assert_eq!(
+                *total,
+                reports.len(),
+                "Stage {stage} reported the wrong total"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn single_init_thread_renders() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let options = RendererOptions::builder()
+        .num_init_threads(NonZeroUsize::new(1))
+        .build()
+        .unwrap();
+    let mut renderer = Renderer::new(device, options).unwrap();
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: 16,
+            height: 16,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 8.0, 16.0),
+    );
+    // Render with every anti-aliasing method, as each has its own fine pipeline.
+    for antialiasing_method in [AaConfig::Area, AaConfig::Msaa8, AaConfig::Msaa16] {
+        let params = RenderParams {
+            base_color: palette::css::BLUE,
+            width: 16,
+            height: 16,
+            antialiasing_method,
+            text_gamma: 1.0,
+            alpha_to_coverage: false,
+            debug: DebugView::None,
+            premultiplied_alpha: false,
+            flatten_tolerance: None,
+            viewport: None,
+            clear_mode: ClearMode::Clear,
+        };
+        renderer
+            .render_to_texture(device, queue, &scene, &view, &params)
+            .unwrap();
+        let inside = renderer.read_pixel(device, queue, &target, 4, 8).unwrap();
+        let outside = renderer.read_pixel(device, queue, &target, 12, 8).unwrap();
+        assert!(
+            inside.difference(palette::css::RED) < 0.01,
+            "Expected red with {antialiasing_method:?}, got {inside:?}"
+        );
+        assert!(
+            outside.difference(palette::css::BLUE) < 0.01,
+            "Expected the base color with {antialiasing_method:?}, got {outside:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn pipeline_cache_round_trips() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let directory = std::env::temp_dir().join(format!("vello_cache_test_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let info = device_handle.adapter().get_info();
+    // Safety: The directory is only written to by `save_pipeline_cache`.
+    let Some((cache, file)) =
+        (unsafe { load_pipeline_cache(&device_handle.device, &info, &directory) }).unwrap()
+    else {
+        // Pipeline caches aren't supported on this device.
+        return;
+    };
+    let options = RendererOptions {
+        pipeline_cache: Some(cache),
+        ..Default::default()
+    };
+    let renderer = Renderer::new(&device_handle.device, options).unwrap();
+    if renderer.save_pipeline_cache(&file).unwrap() {
+        assert!(
+            file.exists(),
+            "Saved pipeline cache should be written to disk"
+        );
+        // Safety: As above.
+        let reloaded =
+            unsafe { load_pipeline_cache(&device_handle.device, &info, &directory) }.unwrap();
+        assert_eq!(reloaded.map(|(_, path)| path), Some(file));
+    }
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn save_pipeline_cache_without_cache_is_noop() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let renderer = Renderer::new(
+        device,
+        RendererOptions {
+            use_cpu: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let path = std::env::temp_dir().join("vello_cache_test_missing");
+    assert!(!renderer.save_pipeline_cache(&path).unwrap());
+    assert!(!path.exists());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn tile_size_is_16x16() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    assert_eq!(renderer.tile_size(), (16, 16));
+}
@@ -0,0 +1,84 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::fill_rounded_rect_with_border`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, RoundedRect};
+use vello::peniko::{Color, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 32;
+
+/// Renders a 24x24 box with a blue fill and a red border over a transparent background,
+/// returning the row through the middle of the box.
+fn middle_row(border_width: f64) -> Vec<[u8; 4]> {
+    let mut scene = Scene::new();
+    scene.fill_rounded_rect_with_border(
+        Affine::IDENTITY,
+        RoundedRect::new(4.0, 4.0, 28.0, 28.0, 6.0),
+        palette::css::BLUE,
+        palette::css::RED,
+        border_width,
+    );
+    let mut params = TestParams::new("rounded_rect_border", SIZE, SIZE);
+    params.base_color = Some(Color::TRANSPARENT);
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let row = SIZE as usize / 2 * SIZE as usize * 4;
+    image.data.data()[row..row + SIZE as usize * 4]
+        .chunks_exact(4)
+        .map(|pixel| pixel.try_into().unwrap())
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn border_edges_are_not_blended_with_fill() {
+    let row = middle_row(4.0);
+    for x in [4, 7, 24, 27] {
+        assert_eq!(
+            row[x],
+            [255, 0, 0, 255],
+            "Pixel {x} is on the border, so should be only the border color"
+        );
+    }
+    for x in [8, 16, 23] {
+        assert_eq!(
+            row[x],
+            [0, 0, 255, 255],
+            "Pixel {x} is inside the border, so should be only the fill color"
+        );
+    }
+    assert_eq!(row[3][3], 0, "Pixel 3 is outside the box");
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn fractional_border_has_no_seam() {
+    // The inner edge of the border is in the middle of pixel 7, so the border is
+    // anti-aliased over the fill there, without letting the background through.
+    let row = middle_row(3.5);
+    let [r, g, b, a] = row[7];
+    assert_eq!(
+        a, 255,
+        "The edge between the border and fill should be opaque"
+    );
+    assert!(
+        r > 100 && b > 100 && g == 0,
+        "Pixel 7 should be half border and half fill, got ({r}, {g}, {b})"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn zero_border_is_only_fill() {
+    let row = middle_row(0.0);
+    assert_eq!(row[4], [0, 0, 255, 255]);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn wide_border_covers_fill() {
+    let row = middle_row(20.0);
+    assert_eq!(row[16], [255, 0, 0, 255]);
+}
@@ -0,0 +1,79 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that rounded rectangles with a different radius for each corner are
+//! rasterized with the correct geometry.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Point, Rect, RoundedRect, RoundedRectRadii};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 100;
+
+/// The signed distance from `p` to the rounded rectangle, which is negative inside.
+///
+/// This is only exact near the boundary, which is all that these tests need.
+fn signed_distance(rect: Rect, radii: RoundedRectRadii, p: Point) -> f64 {
+    let corners = [
+        (radii.top_left, Point::new(rect.x0, rect.y0), (1., 1.)),
+        (radii.top_right, Point::new(rect.x1, rect.y0), (-1., 1.)),
+        (radii.bottom_right, Point::new(rect.x1, rect.y1), (-1., -1.)),
+        (radii.bottom_left, Point::new(rect.x0, rect.y1), (1., -1.)),
+    ];
+    for (radius, corner, (sx, sy)) in corners {
+        let center = Point::new(corner.x + sx * radius, corner.y + sy * radius);
+        // Whether `p` is in the square between the corner and the center of its arc.
+        let in_corner = (p.x - center.x) * sx < 0. && (p.y - center.y) * sy < 0.;
+        if radius > 0. && in_corner {
+            return p.distance(center) - radius;
+        }
+    }
+    let dx = (rect.x0 - p.x).max(p.x - rect.x1);
+    let dy = (rect.y0 - p.y).max(p.y - rect.y1);
+    dx.max(dy)
+}
+
+fn independent_radii(use_cpu: bool) {
+    let rect = Rect::new(10., 10., 90., 90.);
+    let radii = RoundedRectRadii::new(0., 20., 5., 40.);
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RoundedRect::from_rect(rect, radii),
+    );
+    let params = TestParams {
+        use_cpu,
+        base_color: Some(palette::css::TRANSPARENT),
+        ..TestParams::new("independent_radii", SIZE, SIZE)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    for (i, pixel) in image.data.data().chunks_exact(4).enumerate() {
+        let i = u32::try_from(i).unwrap();
+        let (x, y) = (i % SIZE, i / SIZE);
+        let center = Point::new(f64::from(x) + 0.5, f64::from(y) + 0.5);
+        let distance = signed_distance(rect, radii, center);
+        let alpha = pixel[3];
+        // Pixels which the boundary doesn't pass through must be fully covered or empty.
+        if distance < -1. {
+            assert_eq!(alpha, 255, "Pixel ({x}, {y}) should be inside the shape");
+        } else if distance > 1. {
+            assert_eq!(alpha, 0, "Pixel ({x}, {y}) should be outside the shape");
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn independent_radii_gpu() {
+    independent_radii(false);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn independent_radii_cpu() {
+    independent_radii(true);
+}
@@ -0,0 +1,52 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the CSS pixel to device pixel conversions in [`vello::util`].
+
+use vello::kurbo::{Point, Vec2};
+use vello::util::{
+    css_to_device_point, css_to_device_vec2, device_to_css_point, device_to_css_vec2,
+};
+
+#[test]
+fn unit_scale_is_identity() {
+    let point = Point::new(12.5, -3.0);
+    let offset = Vec2::new(0.0, 4.0);
+    assert_eq!(css_to_device_point(point, 1.0), point);
+    assert_eq!(device_to_css_point(point, 1.0), point);
+    assert_eq!(css_to_device_vec2(offset, 1.0), offset);
+    assert_eq!(device_to_css_vec2(offset, 1.0), offset);
+}
+
+#[test]
+fn double_scale() {
+    assert_eq!(
+        css_to_device_point(Point::new(12.5, -3.0), 2.0),
+        Point::new(25.0, -6.0)
+    );
+    assert_eq!(
+        device_to_css_point(Point::new(25.0, -6.0), 2.0),
+        Point::new(12.5, -3.0)
+    );
+    // A shadow offset of 4 CSS pixels is 8 device pixels on a 2x display.
+    assert_eq!(
+        css_to_device_vec2(Vec2::new(0.0, 4.0), 2.0),
+        Vec2::new(0.0, 8.0)
+    );
+    assert_eq!(
+        device_to_css_vec2(Vec2::new(0.0, 8.0), 2.0),
+        Vec2::new(0.0, 4.0)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let point = Point::new(101.0, 37.0);
+    for scale in [1.0, 1.25, 1.5, 2.0, 3.0] {
+        let round_tripped = device_to_css_point(css_to_device_point(point, scale), scale);
+        assert!(
+            (round_tripped - point).hypot() < 1e-9,
+            "Round trip at scale {scale} gave {round_tripped:?}"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::merge_fragments`], which merges scenes encoded on separate threads.
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{
+    Fill, Gradient, ImageAlphaType, ImageBrush, ImageData, ImageFormat, color::palette,
+};
+use vello_tests::TestParams;
+
+/// Draws the part of the test scene in the `i`th column.
+fn draw_column(scene: &mut Scene, i: u32) {
+    let x = f64::from(i) * 16.0;
+    let gradient = Gradient::new_linear((x, 0.0), (x + 16.0, 0.0)).with_stops(if i % 2 == 0 {
+        [palette::css::RED, palette::css::BLUE]
+    } else {
+        [palette::css::LIME, palette::css::YELLOW]
+    });
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient,
+        None,
+        &Rect::new(x, 0.0, x + 16.0, 32.0),
+    );
+    let shade = u8::try_from(i * 30).unwrap();
+    let image = ImageBrush::new(ImageData {
+        data: vec![shade, 255 - shade, 128, 255].into(),
+        format: ImageFormat::Rgba8,
+        width: 1,
+        height: 1,
+        alpha_type: ImageAlphaType::Alpha,
+    });
+    scene.draw_image(
+        &image,
+        Affine::translate((x + 4.0, 36.0)) * Affine::scale(8.0),
+    );
+    scene.push_layer_labeled(
+        format!("column {i}"),
+        Fill::NonZero,
+        vello::peniko::Mix::Normal,
+        1.0,
+        Affine::IDENTITY,
+        &Rect::new(x, 48.0, x + 8.0, 64.0),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Rect::new(x, 48.0, x + 16.0, 64.0),
+    );
+    scene.pop_layer();
+}
+
+fn threaded_scene() -> Scene {
+    let fragments: Vec<Scene> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..4)
+            .map(|i| {
+                s.spawn(move || {
+                    let mut fragment = Scene::new();
+                    draw_column(&mut fragment, i);
+                    fragment
+                })
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+    let mut scene = Scene::new();
+    scene.merge_fragments(fragments);
+    scene
+}
+
+#[test]
+fn merged_resources_are_remapped() {
+    let scene = threaded_scene();
+    let encoding = scene.encoding();
+    // Each fragment's gradient has its own color stops, which are all kept.
+    assert_eq!(encoding.resources.color_stops.len(), 8);
+    assert_eq!(encoding.resources.patches.len(), 8);
+    let labels: Vec<_> = scene
+        .layer_labels()
+        .iter()
+        .map(|label| (label.label.as_str(), label.draw_index))
+        .collect();
+    for (i, (label, draw_index)) in labels.iter().enumerate() {
+        assert_eq!(*label, format!("column {i}"));
+        assert_eq!(
+            encoding.draw_tags[*draw_index],
+            vello_encoding::DrawTag::BEGIN_CLIP
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn merged_fragments_match_single_thread() {
+    let mut sequential = Scene::new();
+    for i in 0..4 {
+        draw_column(&mut sequential, i);
+    }
+    let params = TestParams::new("merged_fragments", 64, 64);
+    let sequential = vello_tests::render_then_debug_sync(&sequential, &params).unwrap();
+    let merged = vello_tests::render_then_debug_sync(&threaded_scene(), &params).unwrap();
+    assert_eq!(sequential.data.data(), merged.data.data());
+}
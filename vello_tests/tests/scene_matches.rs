@@ -0,0 +1,50 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A sample of using [`vello::testing`] to snapshot test a scene.
+
+use std::path::Path;
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Fill, color::palette};
+use vello::testing::assert_scene_matches;
+
+fn snapshot_path(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots/smoke")
+        .join(name)
+        .with_extension("png")
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn filled_square_matches_reference() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::BLUE,
+        None,
+        &Rect::from_center_size((10., 10.), (6., 6.)),
+    );
+    // This is the same scene as in the `filled_square` smoke test, so we reuse its snapshot.
+    assert_scene_matches(&scene, snapshot_path("filled_square"), 0.01);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+#[should_panic(expected = "pixels differ")]
+fn different_scene_fails() {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::from_center_size((10., 10.), (6., 6.)),
+    );
+    let reference = std::env::temp_dir().join("vello_testing_different_scene.png");
+    std::fs::copy(snapshot_path("filled_square"), &reference).unwrap();
+    assert_scene_matches(&scene, &reference, 0.01);
+}
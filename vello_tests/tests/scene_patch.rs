@@ -0,0 +1,185 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::diff`] and [`Scene::apply_patch`].
+
+use std::sync::Arc;
+
+use vello::kurbo::{Affine, Circle, Rect, Stroke};
+use vello::peniko::{
+    Blob, Fill, FontData, Gradient, ImageAlphaType, ImageBrush, ImageData, ImageFormat, Mix,
+    color::palette,
+};
+use vello::{Error, Glyph, Scene};
+use vello_encoding::Encoding;
+use vello_tests::TestParams;
+
+const ROBOTO_FONT: &[u8] = include_bytes!("../../examples/assets/roboto/Roboto-Regular.ttf");
+
+fn image() -> ImageBrush {
+    let blob: Vec<u8> = vec![255, 0, 0, 255, 0, 0, 255, 255];
+    ImageBrush::new(ImageData {
+        data: blob.into(),
+        format: ImageFormat::Rgba8,
+        width: 2,
+        height: 1,
+        alpha_type: ImageAlphaType::Alpha,
+    })
+}
+
+/// A frame of an animation, in which one circle moves and the rest of the scene is static.
+fn frame(t: f64, image: &ImageBrush) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::WHITE,
+        None,
+        &Rect::new(0.0, 0.0, 64.0, 64.0),
+    );
+    let gradient = Gradient::new_linear((0.0, 0.0), (64.0, 0.0))
+        .with_stops([palette::css::RED, palette::css::BLUE]);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        &gradient,
+        None,
+        &Rect::new(4.0, 4.0, 60.0, 20.0),
+    );
+    scene.push_layer(
+        Fill::NonZero,
+        Mix::Multiply,
+        0.8,
+        Affine::IDENTITY,
+        &Rect::new(0.0, 24.0, 64.0, 64.0),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::translate((t, 0.0)),
+        palette::css::GREEN,
+        None,
+        &Circle::new((10.0, 40.0), 8.0),
+    );
+    scene.stroke(
+        &Stroke::new(2.0),
+        Affine::IDENTITY,
+        palette::css::BLACK,
+        None,
+        &Circle::new((40.0, 44.0), 10.0),
+    );
+    scene.pop_layer();
+    scene.draw_image(image, Affine::translate((40.0, 56.0)));
+    scene
+}
+
+fn assert_same_encoding(a: &Encoding, b: &Encoding) {
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+    assert_eq!(a.draw_data, b.draw_data);
+    assert_eq!(a.transforms, b.transforms);
+    assert_eq!(a.styles, b.styles);
+    assert_eq!(a.resources.color_stops, b.resources.color_stops);
+    assert_eq!(a.resources.patches.len(), b.resources.patches.len());
+    assert_eq!(
+        (a.n_paths, a.n_path_segments, a.n_clips, a.n_open_clips),
+        (b.n_paths, b.n_path_segments, b.n_clips, b.n_open_clips)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let image = image();
+    let previous = frame(5.0, &image);
+    let current = frame(12.0, &image);
+    let patch = current.diff(&previous);
+    assert!(!patch.is_replacement());
+    // Only the moving circle is stored in the patch.
+    assert_eq!(patch.new_draws(), 1);
+    assert_eq!(patch.copied_draws(), current.encoding().draw_tags.len() - 1);
+
+    let mut client = previous.clone();
+    client.apply_patch(patch).unwrap();
+    assert_same_encoding(client.encoding(), current.encoding());
+}
+
+#[test]
+fn round_trip_with_reordered_and_removed_draws() {
+    let image = image();
+    let previous = frame(5.0, &image);
+    let mut current = Scene::new();
+    current.append(&frame(5.0, &image), Some(Affine::translate((4.0, 0.0))));
+    current.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 8.0, 8.0),
+    );
+    current.draw_image(&image, Affine::translate((40.0, 56.0)));
+
+    let mut client = previous.clone();
+    client.apply_patch(current.diff(&previous)).unwrap();
+    assert_same_encoding(client.encoding(), current.encoding());
+
+    // Patching to an empty scene, and back again.
+    let empty = Scene::new();
+    let mut client = previous.clone();
+    client.apply_patch(empty.diff(&previous)).unwrap();
+    assert_same_encoding(client.encoding(), empty.encoding());
+    client.apply_patch(previous.diff(&empty)).unwrap();
+    assert_same_encoding(client.encoding(), previous.encoding());
+}
+
+#[test]
+fn patch_for_a_different_scene_is_rejected() {
+    let image = image();
+    let previous = frame(5.0, &image);
+    let patch = frame(12.0, &image).diff(&previous);
+
+    let mut other = frame(4.0, &image);
+    let encoding = other.encoding().clone();
+    assert!(matches!(
+        other.apply_patch(patch),
+        Err(Error::ScenePatchMismatch)
+    ));
+    assert_same_encoding(other.encoding(), &encoding);
+}
+
+#[test]
+fn glyph_runs_replace_the_scene() {
+    let font = FontData::new(Blob::new(Arc::new(ROBOTO_FONT)), 0);
+    let previous = frame(5.0, &image());
+    let mut current = Scene::new();
+    current.draw_glyphs(&font).font_size(16.0).draw(
+        Fill::NonZero,
+        [Glyph {
+            id: 40,
+            x: 4.0,
+            y: 20.0,
+        }]
+        .into_iter(),
+    );
+
+    let patch = current.diff(&previous);
+    assert!(patch.is_replacement());
+    let mut client = previous.clone();
+    client.apply_patch(patch).unwrap();
+    assert_same_encoding(client.encoding(), current.encoding());
+    assert_eq!(client.encoding().resources.glyph_runs.len(), 1);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn patched_scene_renders_the_same() {
+    let image = image();
+    let previous = frame(5.0, &image);
+    let current = frame(12.0, &image);
+    let mut client = previous.clone();
+    client.apply_patch(current.diff(&previous)).unwrap();
+
+    let params = TestParams::new("scene_patch", 64, 64);
+    let expected = vello_tests::render_then_debug_sync(&current, &params).unwrap();
+    let patched = vello_tests::render_then_debug_sync(&client, &params).unwrap();
+    assert_eq!(patched.data.data(), expected.data.data());
+}
@@ -0,0 +1,47 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the memory behavior of [`Scene::reset`] and [`Scene::reset_and_shrink`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, Circle};
+use vello::peniko::{Fill, color::palette};
+
+fn populated_scene() -> Scene {
+    let mut scene = Scene::new();
+    for i in 0..100 {
+        scene.fill(
+            Fill::NonZero,
+            Affine::translate((f64::from(i), 0.0)),
+            palette::css::RED,
+            None,
+            &Circle::new((10.0, 10.0), 5.0),
+        );
+    }
+    scene
+}
+
+#[test]
+fn reset_retains_capacity() {
+    let mut scene = populated_scene();
+    let path_data = scene.encoding().path_data.capacity();
+    let draw_data = scene.encoding().draw_data.capacity();
+    scene.reset();
+    assert!(scene.encoding().is_empty());
+    assert_eq!(scene.encoding().path_data.capacity(), path_data);
+    assert_eq!(scene.encoding().draw_data.capacity(), draw_data);
+}
+
+#[test]
+fn reset_and_shrink_releases_capacity() {
+    let mut scene = populated_scene();
+    scene.reset_and_shrink();
+    let encoding = scene.encoding();
+    assert!(encoding.is_empty());
+    assert_eq!(encoding.path_tags.capacity(), 0);
+    assert_eq!(encoding.path_data.capacity(), 0);
+    assert_eq!(encoding.draw_tags.capacity(), 0);
+    assert_eq!(encoding.draw_data.capacity(), 0);
+    assert_eq!(encoding.transforms.capacity(), 0);
+    assert_eq!(encoding.styles.capacity(), 0);
+}
@@ -0,0 +1,78 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::draw_shadow_mask`].
+
+use vello::kurbo::{Affine, Rect, Size, Vec2};
+use vello::peniko::Color;
+use vello::{Scene, ShadowMask};
+use vello_tests::TestParams;
+
+#[test]
+fn mask_layout() {
+    let mut scene = Scene::new();
+    let mask = scene.draw_shadow_mask(Size::new(40.0, 20.0), 4.0, 2.0);
+    assert_eq!(
+        mask,
+        ShadowMask {
+            width: 50,
+            height: 30,
+            offset: Vec2::new(5.0, 5.0),
+            slice_inset: 14.0,
+        }
+    );
+    assert_eq!(scene.encoding().draw_tags.len(), 1);
+
+    // The slices don't overlap in small masks.
+    let mask = Scene::new().draw_shadow_mask(Size::new(4.0, 4.0), 8.0, 1.0);
+    assert_eq!((mask.width, mask.height), (9, 9));
+    assert_eq!(mask.slice_inset, 4.5);
+
+    let mut scene = Scene::new();
+    let mask = scene.draw_shadow_mask(Size::new(f64::NAN, 4.0), 2.0, 1.0);
+    assert_eq!(mask.width, 0);
+    assert!(scene.encoding().draw_tags.is_empty());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn mask_is_only_shadow_coverage() {
+    let size = Size::new(40.0, 24.0);
+    let (radius, std_dev) = (6.0, 4.0);
+    let mut scene = Scene::new();
+    let mask = scene.draw_shadow_mask(size, radius, std_dev);
+
+    // The same shadow in white, positioned in the same way.
+    let mut reference = Scene::new();
+    reference.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        Rect::from_origin_size(mask.offset.to_point(), size),
+        Color::WHITE,
+        radius,
+        std_dev,
+    );
+
+    for premultiplied_alpha in [false, true] {
+        let mut params = TestParams::new("shadow_mask", mask.width, mask.height);
+        params.base_color = Some(Color::TRANSPARENT);
+        params.premultiplied_alpha = premultiplied_alpha;
+        let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+        let reference = vello_tests::render_then_debug_sync(&reference, &params).unwrap();
+        let mut partial = 0;
+        for (pixel, expected) in image
+            .data
+            .data()
+            .chunks_exact(4)
+            .zip(reference.data.data().chunks_exact(4))
+        {
+            assert_eq!(pixel[..3], [0, 0, 0], "the mask should have no color");
+            assert_eq!(pixel[3], expected[3], "the alpha should be the coverage");
+            if (1..255).contains(&pixel[3]) {
+                partial += 1;
+            }
+        }
+        assert!(partial > 0, "the shadow should be blurred");
+        // The corners of the mask are outside of the blur's extent.
+        assert_eq!(image.data.data()[3], 0);
+    }
+}
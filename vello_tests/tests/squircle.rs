@@ -0,0 +1,144 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for rectangles with smoothed corners, drawn using [`Scene::fill_squircle_rect`] and
+//! [`Scene::draw_blurred_squircle_rect`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, CubicBez, ParamCurveDeriv, PathEl, Point, Rect, RoundedRect, Shape};
+use vello::path::squircle_rect;
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+const RECT: Rect = Rect::new(10.0, 20.0, 110.0, 80.0);
+
+/// The signed curvature of `cubic` at `t`.
+fn curvature(cubic: CubicBez, t: f64) -> f64 {
+    let d1 = cubic.deriv().eval(t).to_vec2();
+    let d2 = cubic.deriv().deriv().eval(t).to_vec2();
+    d1.cross(d2) / d1.hypot().powi(3)
+}
+
+#[test]
+fn zero_smoothing_is_a_rounded_rect() {
+    let mut squircle = Scene::new();
+    squircle.fill_squircle_rect(Affine::IDENTITY, RECT, palette::css::RED, 12.0, 0.0);
+    let mut rounded = Scene::new();
+    rounded.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &RoundedRect::from_rect(RECT, 12.0),
+    );
+    let (a, b) = (squircle.encoding(), rounded.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_data, b.draw_data);
+}
+
+#[test]
+fn smoothed_corner_eases_into_the_edge() {
+    let radius = 12.0;
+    let path = squircle_rect(RECT, radius, 0.6);
+    let bounds = path.bounding_box();
+    assert!(
+        (bounds.x0 - RECT.x0).abs() < 1e-9
+            && (bounds.y0 - RECT.y0).abs() < 1e-9
+            && (bounds.x1 - RECT.x1).abs() < 1e-9
+            && (bounds.y1 - RECT.y1).abs() < 1e-9,
+        "{bounds:?} should match {RECT:?}"
+    );
+
+    let elements = path.elements();
+    let PathEl::MoveTo(start) = elements[0] else {
+        panic!("the path should start with a move");
+    };
+    // The corner extends `(1 + smoothing) * radius` along each edge.
+    assert!(
+        (start - Point::new(RECT.x1 - 1.6 * radius, RECT.y0)).hypot() < 1e-9,
+        "the corner starts at {start:?}"
+    );
+    let PathEl::CurveTo(p1, p2, p3) = elements[1] else {
+        panic!("the corner should start with a cubic");
+    };
+    let cubic = CubicBez::new(start, p1, p2, p3);
+    // Unlike a circular arc, the curvature starts at zero where the corner meets the edge,
+    // and increases towards the middle of the corner.
+    assert!(curvature(cubic, 0.0).abs() < 1e-9, "the edge is straight");
+    let mut previous = 0.0;
+    for i in 1..=10 {
+        let k = curvature(cubic, f64::from(i) / 10.0);
+        assert!(
+            k >= previous,
+            "the curvature should increase, got {k} after {previous}"
+        );
+        previous = k;
+    }
+    assert!(
+        previous >= 1.0 / radius,
+        "the corner reaches the curvature of the arc"
+    );
+}
+
+#[test]
+fn smoothing_is_reduced_to_fit() {
+    let small = Rect::new(0.0, 0.0, 30.0, 20.0);
+    let path = squircle_rect(small, 8.0, 1.0);
+    // The corners would extend 16 along each edge, which is more than half the height.
+    let bounds = path.bounding_box();
+    assert!(
+        (bounds.size() - small.size()).hypot() < 1e-9,
+        "{bounds:?} should match {small:?}"
+    );
+    let PathEl::MoveTo(start) = path.elements()[0] else {
+        panic!("the path should start with a move");
+    };
+    assert!(
+        (start.x - 20.0).abs() < 1e-9,
+        "the corner is limited to half the height, starting at {start:?}"
+    );
+
+    // Degenerate values don't produce NaN.
+    for (radius, smoothing) in [(f64::NAN, 0.6), (8.0, f64::NAN), (-1.0, 2.0)] {
+        let path = squircle_rect(small, radius, smoothing);
+        assert!(
+            path.bounding_box().is_finite(),
+            "{radius}, {smoothing} gave {path:?}"
+        );
+    }
+}
+
+#[test]
+fn unblurred_squircle_shadow_is_the_squircle() {
+    let mut shadow = Scene::new();
+    shadow.draw_blurred_squircle_rect(Affine::IDENTITY, RECT, palette::css::BLACK, 12.0, 0.6, 0.0);
+    let mut fill = Scene::new();
+    fill.fill_squircle_rect(Affine::IDENTITY, RECT, palette::css::BLACK, 12.0, 0.6);
+    let (a, b) = (shadow.encoding(), fill.encoding());
+    assert_eq!(a.path_tags, b.path_tags);
+    assert_eq!(a.path_data, b.path_data);
+    assert_eq!(a.draw_tags, b.draw_tags);
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn squircle_shadow_matches_silhouette() {
+    let rect = Rect::new(8.0, 8.0, 88.0, 56.0);
+    let mut shadow = Scene::new();
+    shadow.draw_blurred_squircle_rect(Affine::IDENTITY, rect, palette::css::WHITE, 20.0, 0.6, 0.3);
+    let mut fill = Scene::new();
+    fill.fill_squircle_rect(Affine::IDENTITY, rect, palette::css::WHITE, 20.0, 0.6);
+    let params = TestParams::new("squircle_shadow", 96, 64);
+    let shadow = vello_tests::render_then_debug_sync(&shadow, &params).unwrap();
+    let fill = vello_tests::render_then_debug_sync(&fill, &params).unwrap();
+    // The pixels which are mostly covered are the same, apart from a few along the corners.
+    let mismatches = shadow
+        .data
+        .data()
+        .chunks_exact(4)
+        .zip(fill.data.data().chunks_exact(4))
+        .filter(|(a, b)| (a[0] >= 128) != (b[0] >= 128))
+        .count();
+    assert!(mismatches <= 8, "{mismatches} pixels differ");
+}
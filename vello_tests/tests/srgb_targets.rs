@@ -0,0 +1,121 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that blitting to sRGB and non-sRGB targets with [`util::blit_source_view`] gives
+//! the same colors.
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill};
+use vello::util::{self, RenderContext};
+use vello::wgpu::{self, TextureFormat, TextureUsages, util::TextureBlitter};
+use vello::{AaConfig, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene};
+
+const SIZE: u32 = 64;
+
+/// Colors chosen to be far from the ends of the range, where encoding twice is most visible.
+const BASE_COLOR: Color = Color::from_rgb8(0x40, 0x40, 0x40);
+const FILL_COLOR: Color = Color::from_rgb8(0x80, 0x20, 0xc0);
+
+/// Renders the scene, blits it to a texture of each format, and returns the color of the
+/// background and the fill in each.
+fn render_and_blit(formats: &[TextureFormat]) -> Vec<[Color; 2]> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        FILL_COLOR,
+        None,
+        &Rect::new(0.0, 0.0, 32.0, f64::from(SIZE)),
+    );
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[TextureFormat::Rgba8UnormSrgb],
+    });
+    let params = RenderParams {
+        base_color: BASE_COLOR,
+        width: SIZE,
+        height: SIZE,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer
+        .render_to_texture(
+            device,
+            queue,
+            &scene,
+            &target.create_view(&wgpu::TextureViewDescriptor::default()),
+            &params,
+        )
+        .unwrap();
+
+    formats
+        .iter()
+        .map(|&format| {
+            let output = device.create_texture(&wgpu::TextureDescriptor {
+                label: None,
+                size: target.size(),
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+                view_formats: &[],
+            });
+            let blitter = TextureBlitter::new(device, format);
+            let mut encoder =
+                device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            blitter.copy(
+                device,
+                &mut encoder,
+                &util::blit_source_view(&target, format),
+                &output.create_view(&wgpu::TextureViewDescriptor::default()),
+            );
+            queue.submit([encoder.finish()]);
+            [
+                renderer.read_pixel(device, queue, &output, 48, 32).unwrap(),
+                renderer.read_pixel(device, queue, &output, 16, 32).unwrap(),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn srgb_and_linear_targets_match() {
+    let colors = render_and_blit(&[TextureFormat::Rgba8Unorm, TextureFormat::Rgba8UnormSrgb]);
+    for (expected, [unorm, srgb]) in [BASE_COLOR, FILL_COLOR]
+        .into_iter()
+        .zip([[colors[0][0], colors[1][0]], [colors[0][1], colors[1][1]]])
+    {
+        assert!(
+            unorm.difference(expected) < 0.01,
+            "Expected {expected:?} in the `Rgba8Unorm` target, got {unorm:?}"
+        );
+        assert!(
+            srgb.difference(unorm) < 0.01,
+            "Expected {unorm:?} in the `Rgba8UnormSrgb` target, got {srgb:?}"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Scene::push_stacking_context`].
+
+use vello::kurbo::{Affine, Circle, Rect};
+use vello::peniko::{BlendMode, Compose, Fill, Mix, color::palette};
+use vello::{ColorMatrix, Scene, StackingContext};
+use vello_encoding::{DrawTag, Resolver};
+use vello_tests::TestParams;
+
+const SIZE: u32 = 32;
+
+fn context() -> StackingContext {
+    StackingContext {
+        blend: BlendMode::new(Mix::Multiply, Compose::SrcOver),
+        opacity: 0.75,
+        filter: Some(ColorMatrix::sepia(1.0)),
+    }
+}
+
+/// Draws a backdrop, and content which is grouped by the layers which `push` pushes, returning
+/// the number of layers.
+fn scene(push: impl FnOnce(&mut Scene, &Rect) -> usize) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::LIGHT_BLUE,
+        None,
+        &Rect::new(0.0, 0.0, f64::from(SIZE), f64::from(SIZE)),
+    );
+    // The clip is aligned to pixels, so that it isn't anti-aliased. Otherwise, the nested
+    // layers would apply its coverage twice at the edges.
+    let layers = push(&mut scene, &Rect::new(4.0, 4.0, 28.0, 28.0));
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::CRIMSON,
+        None,
+        &Circle::new((12.0, 12.0), 10.0),
+    );
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::GOLD.with_alpha(0.5),
+        None,
+        &Circle::new((20.0, 20.0), 10.0),
+    );
+    for _ in 0..layers {
+        scene.pop_layer();
+    }
+    scene
+}
+
+#[test]
+fn pushes_a_single_layer() {
+    let scene = scene(|scene, clip| {
+        scene.push_stacking_context(&context(), Affine::IDENTITY, clip);
+        1
+    });
+    let layers = scene
+        .encoding()
+        .draw_tags
+        .iter()
+        .filter(|tag| **tag == DrawTag::BEGIN_CLIP)
+        .count();
+    assert_eq!(layers, 1, "Only one layer should be pushed");
+}
+
+#[test]
+fn filters_the_layer_with_its_color_matrix() {
+    let context = context();
+    for (filter, expected) in [(context.filter, 1), (None, 0)] {
+        let scene = scene(|scene, clip| {
+            scene.push_stacking_context(
+                &StackingContext { filter, ..context },
+                Affine::IDENTITY,
+                clip,
+            );
+            1
+        });
+        let mut packed = Vec::new();
+        let (layout, _, _) = Resolver::new().resolve(scene.encoding(), &mut packed);
+        let matrices = layout.color_matrices(&packed);
+        assert_eq!(matrices.len(), expected, "{filter:?}");
+        assert!(
+            matrices
+                .iter()
+                .all(|matrix| Some(ColorMatrix(*matrix)) == filter)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn matches_nested_layers() {
+    let context = context();
+    let nested = scene(|scene, clip| {
+        scene.push_layer(
+            Fill::NonZero,
+            context.blend,
+            context.opacity,
+            Affine::IDENTITY,
+            clip,
+        );
+        scene.push_color_matrix_layer(context.filter.unwrap(), Affine::IDENTITY, clip);
+        2
+    });
+    let stacking = scene(|scene, clip| {
+        scene.push_stacking_context(&context, Affine::IDENTITY, clip);
+        1
+    });
+    let params = TestParams::new("stacking_context", SIZE, SIZE);
+    let expected = vello_tests::render_then_debug_sync(&nested, &params).unwrap();
+    let actual = vello_tests::render_then_debug_sync(&stacking, &params).unwrap();
+    assert!(
+        expected.data.data() == actual.data.data(),
+        "The stacking context should match the nested layers"
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`vello::debug::draw_stats_overlay`].
+
+use std::time::Duration;
+
+use vello::Scene;
+use vello::debug::{FrameStats, draw_stats_overlay};
+use vello::kurbo::{Affine, Point, Rect};
+use vello::peniko::{Fill, color::palette};
+use vello_tests::TestParams;
+
+#[test]
+fn frame_stats_count_scene_content() {
+    let mut scene = Scene::new();
+    for i in 0..3 {
+        let x = f64::from(i) * 10.0;
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            palette::css::RED,
+            None,
+            &Rect::new(x, 0.0, x + 5.0, 5.0),
+        );
+    }
+    let stats = FrameStats::new(Duration::from_millis(16), &scene);
+    assert_eq!(stats.paths, 3);
+    assert_eq!(stats.draw_objects, 3);
+    assert!(stats.encoded_bytes > 0, "Encoded size should be counted");
+
+    draw_stats_overlay(&mut scene, &stats, Point::ZERO);
+    assert!(
+        scene.encoding().n_paths > stats.paths,
+        "The overlay should be drawn into the scene"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn frame_time_bar_is_green_within_budget() {
+    let mut scene = Scene::new();
+    let stats = FrameStats {
+        frame_time: Duration::from_millis(5),
+        ..FrameStats::default()
+    };
+    draw_stats_overlay(&mut scene, &stats, Point::ZERO);
+    let params = TestParams {
+        use_cpu: true,
+        base_color: Some(palette::css::BLACK),
+        ..TestParams::new("stats_overlay", 160, 100)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = |x: usize, y: usize| {
+        let i = (y * 160 + x) * 4;
+        <[u8; 4]>::try_from(&image.data.data()[i..i + 4]).unwrap()
+    };
+    // The start of the frame time bar, which is filled for 5ms of the 33ms scale.
+    assert_eq!(pixel(55, 30), [0x4c, 0xd9, 0x64, 255]);
+    // The unfilled end of the bar.
+    let [r, g, b, _] = pixel(130, 30);
+    assert!(r == g && g == b && r > 0, "Bar background should be gray");
+}
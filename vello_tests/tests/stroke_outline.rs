@@ -0,0 +1,100 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`vello::stroke::outline`].
+
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Cap, Circle, Line, Rect, Shape, Stroke};
+use vello::peniko::{Color, Fill};
+use vello::stroke::outline;
+use vello_tests::TestParams;
+
+fn line() -> BezPath {
+    Line::new((10.0, 20.0), (50.0, 20.0)).to_path(0.1)
+}
+
+#[test]
+fn butt_line_is_a_rectangle() {
+    let outline = outline(&line(), &Stroke::new(4.0).with_caps(Cap::Butt));
+    let bounds = outline.bounding_box();
+    let expected = Rect::new(10.0, 18.0, 50.0, 22.0);
+    for (actual, expected) in [
+        (bounds.x0, expected.x0),
+        (bounds.y0, expected.y0),
+        (bounds.x1, expected.x1),
+        (bounds.y1, expected.y1),
+    ] {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{bounds:?} should be {expected:?}"
+        );
+    }
+    assert!(
+        (outline.area().abs() - 160.0).abs() < 1e-6,
+        "{} should be the area of the rectangle",
+        outline.area()
+    );
+    assert!(outline.contains((30.0, 21.0).into()), "inside the stroke");
+    assert!(!outline.contains((30.0, 23.0).into()), "outside the stroke");
+}
+
+#[test]
+fn caps_and_dashes_are_included() {
+    let square = outline(&line(), &Stroke::new(4.0).with_caps(Cap::Square));
+    assert!(
+        (square.area().abs() - 176.0).abs() < 1e-6,
+        "square caps extend the line by half the width at each end, not {}",
+        square.area()
+    );
+    let round = outline(&line(), &Stroke::new(4.0).with_caps(Cap::Round));
+    let expected = 160.0 + std::f64::consts::PI * 4.0;
+    assert!(
+        (round.area().abs() - expected).abs() < 0.05,
+        "round caps add a circle, not {}",
+        round.area()
+    );
+
+    // Two dashes of length 10, with a gap of 10 between them.
+    let dashed = outline(
+        &line(),
+        &Stroke::new(4.0)
+            .with_caps(Cap::Butt)
+            .with_dashes(0.0, [10.0, 10.0]),
+    );
+    assert!(
+        (dashed.area().abs() - 80.0).abs() < 1e-6,
+        "{} should be the area of two dashes",
+        dashed.area()
+    );
+    assert!(!dashed.contains((25.0, 20.0).into()), "in the gap");
+}
+
+#[test]
+fn zero_width_outline_is_empty() {
+    assert!(outline(&line(), &Stroke::new(0.0)).is_empty());
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn outline_matches_stroke() {
+    let circle = Circle::new((32.0, 32.0), 20.0).to_path(0.01);
+    let style = Stroke::new(6.0);
+
+    let mut stroked = Scene::new();
+    stroked.stroke(&style, Affine::IDENTITY, Color::WHITE, None, &circle);
+    let mut filled = Scene::new();
+    filled.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        Color::WHITE,
+        None,
+        &outline(&circle, &style),
+    );
+
+    let params = TestParams::new("stroke_outline", 64, 64);
+    let stroked = vello_tests::render_then_debug_sync(&stroked, &params).unwrap();
+    let filled = vello_tests::render_then_debug_sync(&filled, &params).unwrap();
+    for (a, b) in stroked.data.data().iter().zip(filled.data.data()) {
+        assert!(a.abs_diff(*b) <= 8, "{a} differs from {b}");
+    }
+}
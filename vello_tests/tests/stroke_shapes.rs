@@ -0,0 +1,135 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that open shapes such as [`Line`], [`Arc`] and [`CubicBez`] are stroked correctly.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use vello::Scene;
+use vello::kurbo::{Affine, Arc, BezPath, Cap, CubicBez, Line, Point, Stroke, Vec2};
+use vello::peniko::{Color, color::palette};
+use vello_encoding::DrawTag;
+use vello_tests::TestParams;
+
+/// The points in the path data of a scene containing a single stroke.
+fn points(scene: &Scene) -> Vec<Point> {
+    scene
+        .encoding()
+        .path_data
+        .chunks_exact(2)
+        .map(|p| {
+            Point::new(
+                f64::from(f32::from_bits(p[0])),
+                f64::from(f32::from_bits(p[1])),
+            )
+        })
+        .collect()
+}
+
+/// Returns the first and last points of the stroked path.
+///
+/// Open strokes end with a marker segment for the start cap, made up of two points, which
+/// follows the last point of the shape.
+fn endpoints(scene: &Scene) -> (Point, Point) {
+    let points = points(scene);
+    assert!(points.len() >= 4, "{points:?} should contain a segment");
+    (points[0], points[points.len() - 3])
+}
+
+fn stroke(shape: &impl vello::kurbo::Shape, transform: Affine) -> Scene {
+    let mut scene = Scene::new();
+    scene.stroke(&Stroke::new(2.0), transform, palette::css::RED, None, shape);
+    assert_eq!(scene.encoding().draw_tags, [DrawTag::COLOR]);
+    scene
+}
+
+fn assert_near(actual: Point, expected: Point) {
+    assert!(
+        actual.distance(expected) < 1e-4,
+        "{actual:?} should be {expected:?}"
+    );
+}
+
+#[test]
+fn arc_endpoints() {
+    let arc = Arc::new((40.0, 30.0), (20.0, 10.0), FRAC_PI_2, 3.0 * FRAC_PI_2, 0.0);
+    let (start, end) = endpoints(&stroke(&arc, Affine::IDENTITY));
+    assert_near(start, Point::new(40.0, 40.0));
+    // A sweep of three quarters of a turn, clockwise in y-down coordinates.
+    assert_near(end, Point::new(60.0, 30.0));
+
+    // With a rotated ellipse.
+    let arc = Arc::new((0.0, 0.0), (8.0, 4.0), 0.0, PI, FRAC_PI_2);
+    let (start, end) = endpoints(&stroke(&arc, Affine::IDENTITY));
+    assert_near(start, Point::new(0.0, 8.0));
+    assert_near(end, Point::new(0.0, -8.0));
+}
+
+#[test]
+fn line_and_cubic_endpoints() {
+    let line = Line::new((4.0, 10.0), (40.0, 24.0));
+    let (start, end) = endpoints(&stroke(&line, Affine::IDENTITY));
+    assert_near(start, line.p0);
+    assert_near(end, line.p1);
+
+    let cubic = CubicBez::new((4.0, 4.0), (20.0, 60.0), (40.0, -20.0), (60.0, 30.0));
+    let (start, end) = endpoints(&stroke(&cubic, Affine::IDENTITY));
+    assert_near(start, cubic.p0);
+    assert_near(end, cubic.p3);
+}
+
+#[test]
+fn magnified_arcs_are_subdivided() {
+    let arc = Arc::new((0.0, 0.0), (1.0, 1.0), 0.0, PI, 0.0);
+    let segments = |transform| {
+        let scene = stroke(&arc, transform);
+        scene.encoding().n_path_segments
+    };
+    let small = segments(Affine::IDENTITY);
+    let large = segments(Affine::scale(1000.0));
+    assert!(
+        large > small,
+        "a magnified arc should use more segments ({large} <= {small})"
+    );
+
+    // The endpoints are in the arc's local coordinates, and so don't depend on the transform.
+    let (start, end) = endpoints(&stroke(&arc, Affine::scale(1000.0)));
+    assert_near(start, Point::new(1.0, 0.0));
+    assert_near(end, Point::new(-1.0, 0.0));
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn arc_matches_equivalent_path() {
+    let arc = Arc::new((32.0, 32.0), (20.0, 20.0), 0.0, 1.5 * PI, 0.0);
+    let style = Stroke::new(6.0).with_caps(Cap::Butt);
+
+    let mut arc_scene = Scene::new();
+    arc_scene.stroke(&style, Affine::IDENTITY, Color::WHITE, None, &arc);
+
+    // The same arc, built from the points of a finely flattened circle.
+    let mut path = BezPath::new();
+    let steps = 600;
+    for i in 0..=steps {
+        let angle = 1.5 * PI * f64::from(i) / f64::from(steps);
+        let point = Point::new(32.0, 32.0) + Vec2::from_angle(angle) * 20.0;
+        if i == 0 {
+            path.move_to(point);
+        } else {
+            path.line_to(point);
+        }
+    }
+    let mut path_scene = Scene::new();
+    path_scene.stroke(&style, Affine::IDENTITY, Color::WHITE, None, &path);
+
+    let params = TestParams::new("stroke_shapes_arc", 64, 64);
+    let arc_image = vello_tests::render_then_debug_sync(&arc_scene, &params).unwrap();
+    let path_image = vello_tests::render_then_debug_sync(&path_scene, &params).unwrap();
+    for (a, b) in arc_image.data.data().iter().zip(path_image.data.data()) {
+        assert!(a.abs_diff(*b) <= 8, "{a} differs from {b}");
+    }
+    assert!(
+        arc_image.data.data().chunks_exact(4).any(|p| p[0] == 255),
+        "the arc should be drawn"
+    );
+}
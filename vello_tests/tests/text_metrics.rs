@@ -0,0 +1,77 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`vello::text::measure`].
+
+use std::sync::Arc;
+
+use skrifa::MetadataProvider;
+use vello::peniko::{Blob, FontData};
+use vello::text::measure;
+
+const ROBOTO_FONT: &[u8] = include_bytes!("../../examples/assets/roboto/Roboto-Regular.ttf");
+
+fn roboto() -> FontData {
+    FontData::new(Blob::new(Arc::new(ROBOTO_FONT)), 0)
+}
+
+fn glyph_ids(text: &str) -> Vec<u32> {
+    let font = skrifa::FontRef::new(ROBOTO_FONT).unwrap();
+    let charmap = font.charmap();
+    text.chars()
+        .map(|ch| charmap.map(ch).unwrap_or_default().to_u32())
+        .collect()
+}
+
+#[test]
+fn width_is_sum_of_advances() {
+    let font = roboto();
+    let l = measure(&font, 20.0, glyph_ids("l")).unwrap();
+    let ll = measure(&font, 20.0, glyph_ids("ll")).unwrap();
+    assert!(l.width > 0.0);
+    assert!((ll.width - 2.0 * l.width).abs() < 1e-4);
+    // The second glyph's outline is offset by the first glyph's advance.
+    assert!((ll.bounds.x1 - l.bounds.x1 - f64::from(l.width)).abs() < 1e-4);
+}
+
+#[test]
+fn metrics_scale_with_font_size() {
+    let font = roboto();
+    let small = measure(&font, 10.0, glyph_ids("Button")).unwrap();
+    let large = measure(&font, 30.0, glyph_ids("Button")).unwrap();
+    assert!((large.width - 3.0 * small.width).abs() < 1e-3);
+    assert!((large.ascent - 3.0 * small.ascent).abs() < 1e-3);
+    assert!((large.descent - 3.0 * small.descent).abs() < 1e-3);
+}
+
+#[test]
+fn bounds_are_relative_to_baseline() {
+    let font = roboto();
+    let metrics = measure(&font, 40.0, glyph_ids("gH")).unwrap();
+    assert!(metrics.ascent > 0.0);
+    assert!(metrics.descent > 0.0);
+    assert!((metrics.height() - (metrics.ascent + metrics.descent)).abs() < 1e-6);
+    // "H" extends above the baseline, and the descender of "g" below it.
+    assert!(metrics.bounds.y0 < 0.0);
+    assert!(metrics.bounds.y1 > 0.0);
+    assert!(metrics.bounds.y0 >= -f64::from(metrics.ascent));
+    assert!(metrics.bounds.y1 <= f64::from(metrics.descent));
+    assert!(metrics.bounds.x1 <= f64::from(metrics.width));
+}
+
+#[test]
+fn empty_and_blank_runs() {
+    let font = roboto();
+    let empty = measure(&font, 16.0, []).unwrap();
+    assert_eq!(empty.width, 0.0);
+    assert_eq!(empty.bounds, vello::kurbo::Rect::ZERO);
+    let space = measure(&font, 16.0, glyph_ids(" ")).unwrap();
+    assert!(space.width > 0.0);
+    assert_eq!(space.bounds, vello::kurbo::Rect::ZERO);
+}
+
+#[test]
+fn invalid_font_is_none() {
+    let font = FontData::new(Blob::new(Arc::new(b"not a font".as_slice())), 0);
+    assert!(measure(&font, 16.0, [1]).is_none());
+}
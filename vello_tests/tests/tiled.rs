@@ -0,0 +1,127 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::render_to_image_tiled`].
+
+use vello::kurbo::{Affine, Rect, RoundedRect};
+use vello::peniko::{Color, Fill, ImageData};
+use vello::util::RenderContext;
+use vello::{
+    AaConfig, ClearMode, DebugView, Error, RenderParams, Renderer, RendererOptions, Scene,
+};
+
+const WIDTH: u32 = 150;
+const HEIGHT: u32 = 110;
+
+/// A scene with blurs and antialiased edges which cross the tile boundaries.
+fn scene() -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        Color::from_rgb8(0x1e, 0x90, 0xff),
+        None,
+        &RoundedRect::new(10.3, 12.7, 131.1, 97.4, 17.0),
+    );
+    scene.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        Rect::new(40.0, 30.0, 110.0, 80.0),
+        Color::BLACK.with_alpha(0.6),
+        10.0,
+        12.0,
+    );
+    scene
+}
+
+fn render_tiled(scene: &Scene, tile_size: u32) -> vello::Result<ImageData> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let params = RenderParams {
+        base_color: Color::WHITE,
+        width: 0,
+        height: 0,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: false,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    };
+    renderer.render_to_image_tiled(device, queue, scene, WIDTH, HEIGHT, tile_size, &params)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn tiles_match_single_render() {
+    // A single tile covers the whole image.
+    let single = render_tiled(&scene(), 256).unwrap();
+    // Tiles which don't divide the image evenly, so the last row and column are partial.
+    let tiled = render_tiled(&scene(), 32).unwrap();
+    assert_eq!((tiled.width, tiled.height), (WIDTH, HEIGHT));
+    let max_difference = single
+        .data
+        .data()
+        .iter()
+        .zip(tiled.data.data())
+        .map(|(a, b)| a.abs_diff(*b))
+        .max()
+        .unwrap();
+    assert!(
+        max_difference <= 1,
+        "Tiled render differs from single render by {max_difference}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn zero_tile_size_is_an_error() {
+    let result = render_tiled(&scene(), 0);
+    assert!(
+        matches!(result, Err(Error::InvalidTileSize(0))),
+        "Unexpected result {:?}",
+        result.map(|_| ())
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn blur_is_continuous_across_tile_boundary() {
+    // A large blur whose falloff straddles the boundary between the first and second columns
+    // of tiles, at x = 64.
+    let mut scene = Scene::new();
+    scene.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        Rect::new(-40.0, -40.0, 64.0, 200.0),
+        Color::BLACK,
+        0.0,
+        20.0,
+    );
+    let single = render_tiled(&scene, 256).unwrap();
+    let tiled = render_tiled(&scene, 64).unwrap();
+    let red = |image: &ImageData, x: u32, y: u32| {
+        let index = usize::try_from((y * WIDTH + x) * 4).unwrap();
+        i32::from(image.data.data()[index])
+    };
+    for y in 0..HEIGHT {
+        for x in 56..72 {
+            let (a, b) = (red(&single, x, y), red(&tiled, x, y));
+            assert!(
+                (a - b).abs() <= 1,
+                "Pixel ({x}, {y}) differs between tiled ({b}) and single ({a}) renders"
+            );
+        }
+        // The step across the seam is no larger than the steps on either side of it.
+        let before = red(&tiled, 63, y) - red(&tiled, 62, y);
+        let across = red(&tiled, 64, y) - red(&tiled, 63, y);
+        let after = red(&tiled, 65, y) - red(&tiled, 64, y);
+        assert!(
+            across.abs() <= before.abs().max(after.abs()) + 1,
+            "Seam at row {y}: steps {before}, {across}, {after}"
+        );
+    }
+}
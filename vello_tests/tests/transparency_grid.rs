@@ -0,0 +1,75 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`draw_transparency_grid`].
+
+use vello::Scene;
+use vello::kurbo::Rect;
+use vello::peniko::Color;
+use vello::peniko::color::palette;
+use vello::util::draw_transparency_grid;
+use vello_tests::TestParams;
+
+const LIGHT: Color = Color::from_rgb8(0xff, 0xff, 0xff);
+const DARK: Color = Color::from_rgb8(0xcc, 0xcc, 0xcc);
+
+#[test]
+fn grid_is_two_draws() {
+    let mut scene = Scene::new();
+    draw_transparency_grid(
+        &mut scene,
+        Rect::new(0.0, 0.0, 200.0, 100.0),
+        8.0,
+        LIGHT,
+        DARK,
+    );
+    assert_eq!(scene.encoding().draw_tags.len(), 2);
+}
+
+#[test]
+fn invalid_cell_size_draws_nothing() {
+    for cell_size in [0.0, -4.0, f64::NAN] {
+        let mut scene = Scene::new();
+        draw_transparency_grid(
+            &mut scene,
+            Rect::new(0.0, 0.0, 64.0, 64.0),
+            cell_size,
+            LIGHT,
+            DARK,
+        );
+        assert!(scene.encoding().draw_tags.is_empty());
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn grid_alternates_colors() {
+    let mut scene = Scene::new();
+    // The grid doesn't fill the target, and the last row and column are cut off.
+    draw_transparency_grid(
+        &mut scene,
+        Rect::new(4.0, 4.0, 42.0, 34.0),
+        8.0,
+        LIGHT,
+        DARK,
+    );
+    let params = TestParams {
+        base_color: Some(palette::css::BLACK),
+        ..TestParams::new("transparency_grid", 48, 48)
+    };
+    let image = vello_tests::render_then_debug_sync(&scene, &params).unwrap();
+    let pixel = |x: usize, y: usize| image.data.data()[(y * 48 + x) * 4];
+    // The centers of the cells in the first two rows and columns.
+    assert_eq!(pixel(8, 8), 0xff);
+    assert_eq!(pixel(16, 8), 0xcc);
+    assert_eq!(pixel(8, 16), 0xcc);
+    assert_eq!(pixel(16, 16), 0xff);
+    // The last, partial, row and column. There are five columns, so the last column of the
+    // first row is light, and four rows, so the last row starts with a dark cell.
+    assert_eq!(pixel(40, 8), 0xff);
+    assert_eq!(pixel(8, 32), 0xcc);
+    // Outside the grid.
+    assert_eq!(pixel(2, 2), 0);
+    assert_eq!(pixel(43, 8), 0);
+    assert_eq!(pixel(8, 35), 0);
+}
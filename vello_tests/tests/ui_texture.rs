@@ -0,0 +1,71 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`UiTexture`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::ui_texture::UiTexture;
+use vello::util::RenderContext;
+use vello::wgpu;
+use vello::{AaConfig, ClearMode, DebugView, RenderParams, Renderer, RendererOptions, Scene};
+
+fn params() -> RenderParams {
+    RenderParams {
+        base_color: Color::TRANSPARENT,
+        // Ignored, as the size of the texture is used.
+        width: 1,
+        height: 1,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: true,
+        flatten_tolerance: None,
+        viewport: None,
+        clear_mode: ClearMode::Clear,
+    }
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn renders_and_reads_back() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+
+    let mut texture = UiTexture::new(device, 0, 8);
+    assert_eq!(texture.size(), (1, 8));
+    assert!(texture.resize(device, 70, 20), "the size changed");
+    assert!(!texture.resize(device, 70, 20), "the size is the same");
+    assert_eq!(texture.size(), (70, 20));
+    assert_eq!(
+        texture.texture().format(),
+        wgpu::TextureFormat::Rgba8Unorm,
+        "Vello renders into an Rgba8Unorm texture"
+    );
+
+    // The left half is opaque red, and the right half is transparent.
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        palette::css::RED,
+        None,
+        &Rect::new(0.0, 0.0, 35.0, 20.0),
+    );
+    texture
+        .render(&mut renderer, device, queue, &scene, &params())
+        .unwrap();
+    let pixels = texture.read_rgba8(device, queue).unwrap();
+    // 70 pixels is not a multiple of the row alignment, so padding must be removed.
+    assert_eq!(pixels.len(), 70 * 20 * 4);
+    for row in pixels.chunks_exact(70 * 4) {
+        assert_eq!(row[..4], [255, 0, 0, 255]);
+        assert_eq!(row[34 * 4..35 * 4], [255, 0, 0, 255]);
+        assert_eq!(row[35 * 4..36 * 4], [0, 0, 0, 0]);
+        assert_eq!(row[69 * 4..], [0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for [`Renderer::validate_target`].
+
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureFormat, TextureUsages};
+use vello::{ClearMode, Error, Renderer, RendererOptions};
+
+fn validate(
+    format: TextureFormat,
+    usage: TextureUsages,
+    clear_mode: ClearMode,
+) -> vello::Result<()> {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device = &context.devices[device_id].device;
+    let renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: 16,
+            height: 16,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage,
+        view_formats: &[],
+    });
+    renderer.validate_target(&texture, clear_mode)
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn storage_texture_is_valid() {
+    validate(
+        TextureFormat::Rgba8Unorm,
+        TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        ClearMode::Clear,
+    )
+    .unwrap();
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn missing_storage_usage_is_an_error() {
+    let result = validate(
+        TextureFormat::Rgba8Unorm,
+        TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        ClearMode::Clear,
+    );
+    assert!(
+        matches!(
+            result,
+            Err(Error::IncompatibleTarget {
+                required_usages: TextureUsages::STORAGE_BINDING,
+                ..
+            })
+        ),
+        "Unexpected result {result:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn wrong_format_is_an_error() {
+    let result = validate(
+        TextureFormat::Bgra8Unorm,
+        TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+        ClearMode::Clear,
+    );
+    assert!(
+        matches!(
+            result,
+            Err(Error::IncompatibleTarget {
+                format: TextureFormat::Bgra8Unorm,
+                ..
+            })
+        ),
+        "Unexpected result {result:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn drawing_over_needs_render_attachment() {
+    validate(
+        TextureFormat::Rgba8Unorm,
+        TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        ClearMode::None,
+    )
+    .unwrap();
+    let result = validate(
+        TextureFormat::Rgba8Unorm,
+        TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        ClearMode::None,
+    );
+    assert!(
+        matches!(
+            result,
+            Err(Error::IncompatibleTarget {
+                required_usages: TextureUsages::RENDER_ATTACHMENT,
+                ..
+            })
+        ),
+        "Unexpected result {result:?}"
+    );
+}
@@ -0,0 +1,219 @@
+// Copyright 2026 the Vello Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for rendering into a region of the target using [`RenderParams::viewport`].
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, Fill, color::palette};
+use vello::ui_texture::UiTexture;
+use vello::util::RenderContext;
+use vello::wgpu::{self, TextureUsages};
+use vello::{
+    AaConfig, ClearMode, DebugView, PostProcessShader, RenderParams, Renderer, RendererOptions,
+    Scene,
+};
+
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 32;
+
+fn params(base_color: Color, viewport: Option<Rect>) -> RenderParams {
+    RenderParams {
+        base_color,
+        // Ignored, as the size of the texture is used.
+        width: 1,
+        height: 1,
+        antialiasing_method: AaConfig::Area,
+        text_gamma: 1.0,
+        alpha_to_coverage: false,
+        debug: DebugView::None,
+        premultiplied_alpha: true,
+        flatten_tolerance: None,
+        viewport,
+        clear_mode: ClearMode::Clear,
+    }
+}
+
+/// A scene with a square of `color` in the top left corner of its own coordinate system.
+fn square(color: Color) -> Scene {
+    let mut scene = Scene::new();
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        color,
+        None,
+        &Rect::new(0.0, 0.0, 8.0, 8.0),
+    );
+    scene
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn scenes_render_into_left_and_right_halves() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    let texture = UiTexture::new(device, WIDTH, HEIGHT);
+    let left = Rect::new(0.0, 0.0, 32.0, 32.0);
+    let right = Rect::new(32.0, 0.0, 64.0, 32.0);
+
+    let background = params(palette::css::LIME, None);
+    texture
+        .render(&mut renderer, device, queue, &Scene::new(), &background)
+        .unwrap();
+    let left_params = params(palette::css::WHITE, Some(left));
+    texture
+        .render(
+            &mut renderer,
+            device,
+            queue,
+            &square(palette::css::RED),
+            &left_params,
+        )
+        .unwrap();
+    let pixels = texture.read_rgba8(device, queue).unwrap();
+    let pixel = |pixels: &[u8], x: u32, y: u32| {
+        let start = usize::try_from((y * WIDTH + x) * 4).unwrap();
+        [
+            pixels[start],
+            pixels[start + 1],
+            pixels[start + 2],
+            pixels[start + 3],
+        ]
+    };
+    assert_eq!(
+        pixel(&pixels, 4, 4),
+        [255, 0, 0, 255],
+        "the red square is drawn"
+    );
+    assert_eq!(
+        pixel(&pixels, 20, 20),
+        [255, 255, 255, 255],
+        "the base color fills the viewport"
+    );
+    assert_eq!(
+        pixel(&pixels, 40, 4),
+        [0, 255, 0, 255],
+        "the target outside the viewport is unchanged"
+    );
+
+    let right_params = params(palette::css::BLACK, Some(right));
+    texture
+        .render(
+            &mut renderer,
+            device,
+            queue,
+            &square(palette::css::BLUE),
+            &right_params,
+        )
+        .unwrap();
+    let pixels = texture.read_rgba8(device, queue).unwrap();
+    assert_eq!(
+        pixel(&pixels, 36, 4),
+        [0, 0, 255, 255],
+        "the origin of the scene is at the top left of the viewport"
+    );
+    assert_eq!(
+        pixel(&pixels, 48, 20),
+        [0, 0, 0, 255],
+        "the right half is cleared"
+    );
+    assert_eq!(
+        pixel(&pixels, 4, 4),
+        [255, 0, 0, 255],
+        "the left half is unchanged"
+    );
+    assert_eq!(
+        pixel(&pixels, 20, 20),
+        [255, 255, 255, 255],
+        "the left half is unchanged"
+    );
+
+    // A viewport outside the target renders nothing.
+    let outside = params(palette::css::BLACK, Some(Rect::new(100.0, 0.0, 120.0, 8.0)));
+    texture
+        .render(
+            &mut renderer,
+            device,
+            queue,
+            &square(palette::css::BLUE),
+            &outside,
+        )
+        .unwrap();
+    assert_eq!(
+        texture.read_rgba8(device, queue).unwrap(),
+        pixels,
+        "nothing is rendered for an empty viewport"
+    );
+}
+
+#[test]
+#[cfg_attr(skip_gpu_tests, ignore)]
+fn viewport_with_post_process() {
+    let mut context = RenderContext::new();
+    let device_id = pollster::block_on(context.device(None)).expect("No compatible device found");
+    let device_handle = &context.devices[device_id];
+    let (device, queue) = (&device_handle.device, &device_handle.queue);
+    let mut renderer = Renderer::new(device, RendererOptions::default()).unwrap();
+    renderer.set_post_process(PostProcessShader::new(
+        "
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(source_texture, source_sampler, in.uv);
+}
+",
+    ));
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: WIDTH,
+            height: HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let right = Rect::new(32.0, 8.0, 64.0, 32.0);
+    let right_params = RenderParams {
+        width: WIDTH,
+        height: HEIGHT,
+        ..params(palette::css::BLACK, Some(right))
+    };
+    renderer
+        .render_to_texture(
+            device,
+            queue,
+            &square(palette::css::BLUE),
+            &view,
+            &right_params,
+        )
+        .unwrap();
+    let pixel = |x, y| {
+        renderer
+            .read_pixel(device, queue, &texture, x, y)
+            .unwrap()
+            .to_rgba8()
+            .to_u8_array()
+    };
+    assert_eq!(
+        pixel(36, 12),
+        [0, 0, 255, 255],
+        "the origin of the scene is at the top left of the viewport"
+    );
+    assert_eq!(
+        pixel(60, 28),
+        [0, 0, 0, 255],
+        "the bottom right of the viewport is rendered"
+    );
+    assert_eq!(
+        pixel(4, 4),
+        [0, 0, 0, 0],
+        "the target outside the viewport is unchanged"
+    );
+}